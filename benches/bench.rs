@@ -39,12 +39,11 @@ mod stream_group {
                 let setup = || make_stream_group(*i);
                 let routine = |mut group: StreamGroup<_>| async move {
                     let mut counter = 0;
-                    black_box({
-                        while group.next().await.is_some() {
-                            counter += 1;
-                        }
-                        assert_eq!(counter, *i);
-                    });
+                    while group.next().await.is_some() {
+                        counter += 1;
+                    }
+                    assert_eq!(counter, *i);
+                    black_box(counter);
                 };
                 b.to_async(FuturesExecutor)
                     .iter_batched(setup, routine, BatchSize::SmallInput)
@@ -53,12 +52,11 @@ mod stream_group {
                 let setup = || make_select_all(*i);
                 let routine = |mut group: SelectAll<_>| async move {
                     let mut counter = 0;
-                    black_box({
-                        while group.next().await.is_some() {
-                            counter += 1;
-                        }
-                        assert_eq!(counter, *i);
-                    });
+                    while group.next().await.is_some() {
+                        counter += 1;
+                    }
+                    assert_eq!(counter, *i);
+                    black_box(counter);
                 };
                 b.to_async(FuturesExecutor)
                     .iter_batched(setup, routine, BatchSize::SmallInput)
@@ -97,12 +95,11 @@ mod future_group {
                 let setup = || make_future_group(*i);
                 let routine = |mut group: FutureGroup<_>| async move {
                     let mut counter = 0;
-                    black_box({
-                        while group.next().await.is_some() {
-                            counter += 1;
-                        }
-                        assert_eq!(counter, *i);
-                    });
+                    while group.next().await.is_some() {
+                        counter += 1;
+                    }
+                    assert_eq!(counter, *i);
+                    black_box(counter);
                 };
                 b.to_async(FuturesExecutor)
                     .iter_batched(setup, routine, BatchSize::SmallInput)
@@ -111,12 +108,11 @@ mod future_group {
                 let setup = || make_futures_unordered(*i);
                 let routine = |mut group: FuturesUnordered<_>| async move {
                     let mut counter = 0;
-                    black_box({
-                        while group.next().await.is_some() {
-                            counter += 1;
-                        }
-                        assert_eq!(counter, *i);
-                    });
+                    while group.next().await.is_some() {
+                        counter += 1;
+                    }
+                    assert_eq!(counter, *i);
+                    black_box(counter);
                 };
                 b.to_async(FuturesExecutor)
                     .iter_batched(setup, routine, BatchSize::SmallInput)
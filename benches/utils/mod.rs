@@ -12,9 +12,7 @@ mod prioritized_waker {
             self.0 == other.0
         }
     }
-    impl Eq for PrioritizedWaker {
-        fn assert_receiver_is_total_eq(&self) {}
-    }
+    impl Eq for PrioritizedWaker {}
     impl PartialOrd for PrioritizedWaker {
         fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
             Some(self.cmp(other))
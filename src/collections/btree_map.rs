@@ -0,0 +1,12 @@
+//! Concurrency operations for [maps][std::collections::BTreeMap]
+//! (`BTreeMap<K, V>`).
+//!
+//! You will rarely need to interact with this module directly unless you need
+//! to name one of the future types.
+//!
+//! The operations in this module keep track of the key of each future, and
+//! report results in key order.
+//!
+//! [std::collections::BTreeMap]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+
+pub use crate::future::race_ok::btree_map::RaceOk;
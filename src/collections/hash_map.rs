@@ -0,0 +1,10 @@
+//! Concurrency operations for [maps][std::collections::HashMap]
+//! (`HashMap<K, V, S>`).
+//!
+//! You will rarely need to interact with this module directly unless you need
+//! to name one of the future types.
+//!
+//! The operations in this module keep track of the key of each future, and
+//! report results under the same key.
+
+pub use crate::future::join::hash_map::Join;
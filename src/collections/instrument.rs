@@ -0,0 +1,318 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use core::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+/// Wrap every item of a collection so that polls and wakes are counted.
+///
+/// The returned items can be passed to any of the `Vec` operations, such as
+/// [`join`] or [`merge`]. Wrapping an item does not change how it is polled;
+/// it only records how often that happens. The counters can be read through
+/// the returned [`InstrumentHandle`], also while the operation is still
+/// running.
+///
+/// [`join`]: crate::future::Join::join
+/// [`merge`]: crate::stream::Merge::merge
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_concurrency::vec;
+/// use std::future;
+///
+/// # futures::executor::block_on(async {
+/// let (futures, handle) = vec::instrumented(vec![future::ready(1), future::ready(2)]);
+/// assert_eq!(futures.join().await, vec![1, 2]);
+///
+/// let metrics = handle.snapshot();
+/// assert_eq!(metrics[0].polls, 1);
+/// assert_eq!(metrics[1].polls, 1);
+/// # });
+/// ```
+pub fn instrumented<I: IntoIterator>(iter: I) -> (Vec<Instrumented<I::Item>>, InstrumentHandle) {
+    let items: Vec<_> = iter.into_iter().collect();
+    let counters = items.iter().map(|_| Counters::default()).collect();
+    let shared = Arc::new(Shared { counters });
+
+    let items = items
+        .into_iter()
+        .enumerate()
+        .map(|(index, inner)| Instrumented {
+            inner,
+            index,
+            shared: shared.clone(),
+            waker: None,
+        })
+        .collect();
+    (items, InstrumentHandle { shared })
+}
+
+/// The counters recorded for a single member of an instrumented collection.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Metrics {
+    /// How many times the member was polled.
+    pub polls: usize,
+    /// How many times the member's waker was called.
+    pub wakes: usize,
+    /// How many polls following a wake returned `Poll::Pending`.
+    pub spurious_wakes: usize,
+}
+
+/// A handle to read the counters of an instrumented collection.
+///
+/// This `struct` is created by the [`instrumented`] function. See its
+/// documentation for more.
+#[derive(Clone)]
+pub struct InstrumentHandle {
+    shared: Arc<Shared>,
+}
+
+impl InstrumentHandle {
+    /// Read the current counters of every member, in their original order.
+    pub fn snapshot(&self) -> Vec<Metrics> {
+        self.shared
+            .counters
+            .iter()
+            .map(|counters| Metrics {
+                polls: counters.polls.load(Ordering::Relaxed),
+                wakes: counters.wakes.load(Ordering::Relaxed),
+                spurious_wakes: counters.spurious_wakes.load(Ordering::Relaxed),
+            })
+            .collect()
+    }
+}
+
+impl fmt::Debug for InstrumentHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.snapshot()).finish()
+    }
+}
+
+/// A future or stream whose polls and wakes are counted.
+///
+/// This `struct` is created by the [`instrumented`] function. See its
+/// documentation for more.
+#[pin_project]
+pub struct Instrumented<T> {
+    #[pin]
+    inner: T,
+    index: usize,
+    shared: Arc<Shared>,
+    /// The parent waker, and the counting waker wrapping it.
+    waker: Option<(Waker, Waker)>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for Instrumented<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Instrumented")
+            .field("inner", &self.inner)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<T> Instrumented<T> {
+    /// Consume the wrapper, returning the inner value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<Fut: Future> Future for Instrumented<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let counters = &this.shared.counters[*this.index];
+        let waker = counting_waker(this.waker, this.shared, *this.index, cx.waker());
+
+        let woken = counters.start_poll();
+        let poll = this.inner.poll(&mut Context::from_waker(waker));
+        counters.end_poll(woken, poll.is_pending());
+        poll
+    }
+}
+
+impl<S: Stream> Stream for Instrumented<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let counters = &this.shared.counters[*this.index];
+        let waker = counting_waker(this.waker, this.shared, *this.index, cx.waker());
+
+        let woken = counters.start_poll();
+        let poll = this.inner.poll_next(&mut Context::from_waker(waker));
+        counters.end_poll(woken, poll.is_pending());
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Get the counting waker for the given parent waker, only allocating a new
+/// one if the parent waker changed since the last poll.
+fn counting_waker<'a>(
+    cached: &'a mut Option<(Waker, Waker)>,
+    shared: &Arc<Shared>,
+    index: usize,
+    parent: &Waker,
+) -> &'a Waker {
+    match cached {
+        Some((prev, _)) if prev.will_wake(parent) => {}
+        _ => {
+            let waker = Waker::from(Arc::new(CountingWaker {
+                parent: parent.clone(),
+                shared: shared.clone(),
+                index,
+            }));
+            *cached = Some((parent.clone(), waker));
+        }
+    }
+    &cached.as_ref().unwrap().1
+}
+
+struct Shared {
+    counters: Box<[Counters]>,
+}
+
+#[derive(Default)]
+struct Counters {
+    polls: AtomicUsize,
+    wakes: AtomicUsize,
+    spurious_wakes: AtomicUsize,
+    woken: AtomicBool,
+}
+
+impl Counters {
+    /// Record the start of a poll, returning whether we were woken since the
+    /// previous poll.
+    fn start_poll(&self) -> bool {
+        self.polls.fetch_add(1, Ordering::Relaxed);
+        self.woken.swap(false, Ordering::AcqRel)
+    }
+
+    fn end_poll(&self, woken: bool, pending: bool) {
+        if woken && pending {
+            self.spurious_wakes.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+struct CountingWaker {
+    parent: Waker,
+    shared: Arc<Shared>,
+    index: usize,
+}
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let counters = &self.shared.counters[self.index];
+        counters.wakes.fetch_add(1, Ordering::Relaxed);
+        counters.woken.store(true, Ordering::Release);
+        self.parent.wake_by_ref();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::future::Join;
+    use crate::stream::Merge;
+    use alloc::vec;
+    use core::pin::pin;
+    use futures_lite::future::{block_on, poll_once};
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    /// Wakes itself and returns `Pending` the given number of times before
+    /// completing.
+    struct Yield(usize);
+
+    impl Future for Yield {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 == 0 {
+                return Poll::Ready(());
+            }
+            self.0 -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn join_counters() {
+        block_on(async {
+            let (futures, handle) = instrumented(vec![Yield(0), Yield(2)]);
+            futures.join().await;
+
+            let metrics = handle.snapshot();
+            assert_eq!(
+                metrics[0],
+                Metrics {
+                    polls: 1,
+                    wakes: 0,
+                    spurious_wakes: 0
+                }
+            );
+            // Woken twice: the first wake leads to another `Pending`, the
+            // second to completion.
+            assert_eq!(
+                metrics[1],
+                Metrics {
+                    polls: 3,
+                    wakes: 2,
+                    spurious_wakes: 1
+                }
+            );
+        })
+    }
+
+    #[test]
+    fn snapshot_while_running() {
+        block_on(async {
+            let (futures, handle) = instrumented(vec![Yield(1), Yield(3)]);
+            let mut fut = pin!(futures.join());
+
+            assert!(poll_once(fut.as_mut()).await.is_none());
+            assert_eq!(handle.snapshot()[0].polls, 1);
+            assert_eq!(handle.snapshot()[1].polls, 1);
+
+            fut.await;
+            assert_eq!(handle.snapshot()[0].polls, 2);
+            assert_eq!(handle.snapshot()[1].polls, 4);
+        })
+    }
+
+    #[test]
+    fn merge_counters() {
+        block_on(async {
+            let (streams, handle) = instrumented(vec![stream::iter(0..2), stream::iter(0..3)]);
+            assert_eq!(streams.merge().count().await, 5);
+
+            let metrics = handle.snapshot();
+            // Every item, plus the final `None`.
+            assert_eq!(metrics[0].polls, 3);
+            assert_eq!(metrics[1].polls, 4);
+            assert_eq!(metrics[0].spurious_wakes, 0);
+        })
+    }
+}
@@ -1,2 +1,8 @@
 #[cfg(feature = "alloc")]
+pub mod btree_map;
+#[cfg(feature = "std")]
+pub mod hash_map;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod instrument;
+#[cfg(feature = "alloc")]
 pub mod vec;
@@ -3,27 +3,52 @@
 //! You will rarely need to interact with this module directly unless you need
 //! to name one of the iterator types.
 //!
+//! The operations in this module which need to track wakers per member, such
+//! as [`Join`] and [`Merge`], keep that state in a single shared allocation.
+//! The wakers for the first 8 members are stored inline in that allocation;
+//! only larger collections allocate additional storage for them.
+//!
 //! [std::vec]: https://doc.rust-lang.org/std/vec/index.html
 
+#[cfg(target_has_atomic = "ptr")]
 use crate::concurrent_stream::{self, FromStream};
+#[cfg(target_has_atomic = "ptr")]
 use crate::prelude::*;
-use crate::utils::{from_iter, FromIter};
-#[cfg(all(feature = "alloc", not(feature = "std")))]
+#[cfg(target_has_atomic = "ptr")]
+use crate::stream::{from_iter, FromIter};
+#[cfg(all(not(feature = "std"), target_has_atomic = "ptr"))]
 use alloc::vec::Vec;
+#[cfg(target_has_atomic = "ptr")]
 use core::future::Ready;
 
-pub use crate::future::join::vec::Join;
-pub use crate::future::race::vec::Race;
+#[cfg(target_has_atomic = "ptr")]
+pub use super::instrument::{instrumented, InstrumentHandle, Instrumented, Metrics};
+#[cfg(feature = "std")]
+pub use crate::future::abortable_join::vec::AbortableJoin;
+pub use crate::future::join::vec::{Join, JoinParts};
+pub use crate::future::join_chunked::vec::JoinChunked;
+#[cfg(target_has_atomic = "ptr")]
+pub use crate::future::join_shared::vec::JoinShared;
+pub use crate::future::race::vec::{Race, RaceDetailed};
 pub use crate::future::race_ok::vec::{AggregateError, RaceOk};
-pub use crate::future::try_join::vec::TryJoin;
+pub use crate::future::try_join::error::TryJoinError;
+pub use crate::future::try_join::vec::{TryJoin, TryJoinIndexed};
+pub use crate::future::try_join_chunked::vec::TryJoinChunked;
 pub use crate::stream::chain::vec::Chain;
+pub use crate::stream::merge::member_timeout::MemberTimeout;
 pub use crate::stream::merge::vec::Merge;
+pub use crate::stream::merge_isolate_errors::vec::MergeIsolateErrors;
+pub use crate::stream::try_chain::vec::TryChain;
+pub use crate::stream::try_merge_tolerating::vec::TryMergeTolerating;
+pub use crate::stream::try_zip::vec::TryZip;
 pub use crate::stream::zip::vec::Zip;
 
 /// Concurrent async iterator that moves out of a vector.
+#[cfg(target_has_atomic = "ptr")]
 #[derive(Debug)]
 pub struct IntoConcurrentStream<T>(FromStream<FromIter<alloc::vec::IntoIter<T>>>);
 
+#[cfg(target_has_atomic = "ptr")]
 impl<T> ConcurrentStream for IntoConcurrentStream<T> {
     type Item = T;
 
@@ -39,8 +64,13 @@ impl<T> ConcurrentStream for IntoConcurrentStream<T> {
     fn concurrency_limit(&self) -> Option<core::num::NonZeroUsize> {
         self.0.concurrency_limit()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
 }
 
+#[cfg(target_has_atomic = "ptr")]
 impl<T> concurrent_stream::IntoConcurrentStream for Vec<T> {
     type Item = T;
 
@@ -64,4 +94,10 @@ mod test {
             assert_eq!(v, &[1, 2, 3, 4, 5]);
         });
     }
+
+    #[test]
+    fn size_hint() {
+        let s = vec![1, 2, 3].into_co_stream();
+        assert_eq!(s.size_hint(), (3, Some(3)));
+    }
 }
@@ -69,6 +69,11 @@ where
         this.inner.send(EnumerateFuture::new(future, count)).await
     }
 
+    async fn ready(self: Pin<&mut Self>) -> super::ConsumerState {
+        let this = self.project();
+        this.inner.ready().await
+    }
+
     async fn progress(self: Pin<&mut Self>) -> super::ConsumerState {
         let this = self.project();
         this.inner.progress().await
@@ -97,7 +102,7 @@ impl<FutT, T> EnumerateFuture<FutT, T>
 where
     FutT: Future<Output = T>,
 {
-    fn new(fut_t: FutT, count: usize) -> Self {
+    pub(crate) fn new(fut_t: FutT, count: usize) -> Self {
         Self {
             done: false,
             fut_t,
@@ -0,0 +1,318 @@
+use super::map::MapFuture;
+use super::{ConcurrentStream, Consumer, ConsumerState};
+use crate::future::Race;
+use futures_buffered::FuturesUnordered;
+use futures_lite::StreamExt;
+use pin_project::pin_project;
+
+use core::future::{ready, Future, Ready};
+use core::marker::PhantomData;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+
+/// A concurrent iterator that both filters and maps.
+///
+/// This `struct` is created by the [`filter_map`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`filter_map`]: ConcurrentStream::filter_map
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct FilterMap<CS, F, FutT, T, FutB, B>
+where
+    CS: ConcurrentStream<Item = T, Future = FutT>,
+    F: Fn(T) -> FutB,
+    F: Clone,
+    FutT: Future<Output = T>,
+    FutB: Future<Output = Option<B>>,
+{
+    inner: CS,
+    f: F,
+    _phantom: PhantomData<(FutT, T, FutB, B)>,
+}
+
+impl<CS, F, FutT, T, FutB, B> FilterMap<CS, F, FutT, T, FutB, B>
+where
+    CS: ConcurrentStream<Item = T, Future = FutT>,
+    F: Fn(T) -> FutB,
+    F: Clone,
+    FutT: Future<Output = T>,
+    FutB: Future<Output = Option<B>>,
+{
+    pub(crate) fn new(inner: CS, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<CS, F, FutT, T, FutB, B> ConcurrentStream for FilterMap<CS, F, FutT, T, FutB, B>
+where
+    CS: ConcurrentStream<Item = T, Future = FutT>,
+    F: Fn(T) -> FutB,
+    F: Clone,
+    FutT: Future<Output = T>,
+    FutB: Future<Output = Option<B>>,
+{
+    type Future = Ready<B>;
+    type Item = B;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        // Whether an item is kept is only known once its future has resolved,
+        // so we evaluate the futures here and only submit the kept outputs to
+        // the next consumer.
+        let limit = match self.inner.concurrency_limit() {
+            Some(n) => n.get(),
+            None => usize::MAX,
+        };
+        let consumer = FilterMapConsumer {
+            inner: consumer,
+            group: FuturesUnordered::new(),
+            slot: None,
+            limit,
+            f: self.f,
+            _phantom: PhantomData,
+        };
+        self.inner.drive(consumer).await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.inner.concurrency_limit()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Any item may be filtered out, so we can't provide a lower bound.
+        let (_, upper) = self.inner.size_hint();
+        (0, upper)
+    }
+}
+
+#[pin_project]
+struct FilterMapConsumer<C, F, FutT, T, FutB, B>
+where
+    FutT: Future<Output = T>,
+    C: Consumer<B, Ready<B>>,
+    F: Fn(T) -> FutB,
+    F: Clone,
+    FutB: Future<Output = Option<B>>,
+{
+    #[pin]
+    inner: C,
+    #[pin]
+    group: FuturesUnordered<MapFuture<F, FutT, T, FutB, Option<B>>>,
+    // An output which has been received, but not yet submitted to the next
+    // consumer. This ensures no outputs are lost if `progress` is dropped.
+    slot: Option<B>,
+    limit: usize,
+    f: F,
+    _phantom: PhantomData<(FutT, T, FutB, B)>,
+}
+
+impl<C, F, FutT, T, FutB, B> FilterMapConsumer<C, F, FutT, T, FutB, B>
+where
+    FutT: Future<Output = T>,
+    C: Consumer<B, Ready<B>>,
+    F: Fn(T) -> FutB,
+    F: Clone,
+    FutB: Future<Output = Option<B>>,
+{
+    /// Submit the output held in the slot to the next consumer, if any.
+    async fn submit(self: Pin<&mut Self>) -> ConsumerState {
+        let mut this = self.project();
+        if this.slot.is_none() {
+            return ConsumerState::Continue;
+        }
+
+        // Wait for the next consumer to have capacity before we take the
+        // output out of the slot, so that we can be dropped at any point.
+        if let ConsumerState::Break = this.inner.as_mut().ready().await {
+            return ConsumerState::Break;
+        }
+        let item = this.slot.take().unwrap();
+        this.inner.send(ready(item)).await
+    }
+
+    /// Wait for the next future in the group to resolve, while also making
+    /// progress on the next consumer.
+    async fn next(self: Pin<&mut Self>) -> Next<B> {
+        let this = self.project();
+        let mut group = this.group;
+        let mut inner = this.inner;
+        let next = {
+            let a = async { Next::Item(group.next().await) };
+            let b = async { Next::Progress(inner.as_mut().progress().await) };
+            (a, b).race().await
+        };
+        match next {
+            // The next consumer has nothing in-flight, so there's nothing for
+            // us to progress. Wait for just the group instead.
+            Next::Progress(ConsumerState::Empty | ConsumerState::Continue) => {
+                Next::Item(group.next().await)
+            }
+            next => next,
+        }
+    }
+
+    /// Resolve all futures in the group, submitting their outputs.
+    async fn drain(mut self: Pin<&mut Self>) -> ConsumerState {
+        loop {
+            if let ConsumerState::Break = self.as_mut().submit().await {
+                return ConsumerState::Break;
+            }
+            match self.as_mut().next().await {
+                Next::Item(Some(item)) => *self.as_mut().project().slot = item,
+                Next::Item(None) => return ConsumerState::Empty,
+                Next::Progress(_) => return ConsumerState::Break,
+            }
+        }
+    }
+}
+
+enum Next<B> {
+    Item(Option<Option<B>>),
+    Progress(ConsumerState),
+}
+
+impl<C, F, FutT, T, FutB, B> Consumer<T, FutT> for FilterMapConsumer<C, F, FutT, T, FutB, B>
+where
+    FutT: Future<Output = T>,
+    C: Consumer<B, Ready<B>>,
+    F: Fn(T) -> FutB,
+    F: Clone,
+    FutB: Future<Output = Option<B>>,
+{
+    type Output = C::Output;
+
+    async fn send(mut self: Pin<&mut Self>, future: FutT) -> ConsumerState {
+        // If we have no space, we're going to provide backpressure until we have space
+        if let ConsumerState::Break = self.as_mut().ready().await {
+            return ConsumerState::Break;
+        }
+
+        // Space was available! - insert the item for posterity
+        let mut this = self.project();
+        let fut = MapFuture::new(this.f.clone(), future);
+        this.group.as_mut().push(fut);
+        ConsumerState::Continue
+    }
+
+    async fn ready(mut self: Pin<&mut Self>) -> ConsumerState {
+        loop {
+            if let ConsumerState::Break = self.as_mut().submit().await {
+                return ConsumerState::Break;
+            }
+            if self.group.len() < self.limit {
+                break;
+            }
+            match self.as_mut().next().await {
+                Next::Item(Some(item)) => *self.as_mut().project().slot = item,
+                Next::Item(None) => break,
+                Next::Progress(_) => return ConsumerState::Break,
+            }
+        }
+        self.project().inner.ready().await
+    }
+
+    async fn progress(mut self: Pin<&mut Self>) -> ConsumerState {
+        if let ConsumerState::Break = self.as_mut().drain().await {
+            return ConsumerState::Break;
+        }
+        self.project().inner.progress().await
+    }
+
+    async fn flush(mut self: Pin<&mut Self>) -> Self::Output {
+        // If the next consumer no longer accepts items, any outputs which are
+        // still in-flight are discarded.
+        self.as_mut().drain().await;
+        self.project().inner.flush().await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use core::num::NonZeroUsize;
+    use futures_lite::stream;
+
+    #[test]
+    fn filter_map() {
+        futures_lite::future::block_on(async {
+            let v = stream::iter(0..10)
+                .co()
+                .filter_map(|n| async move { (n % 2 == 0).then_some(n) })
+                .collect_ordered()
+                .await;
+            assert_eq!(v, &[0, 2, 4, 6, 8]);
+        });
+    }
+
+    #[test]
+    fn size_hint() {
+        let s = stream::iter(0..10)
+            .co()
+            .filter_map(|n| async move { Some(n) })
+            .take(3);
+        assert_eq!(s.size_hint(), (0, Some(3)));
+    }
+
+    #[test]
+    fn filter_map_limit() {
+        futures_lite::future::block_on(async {
+            let v = stream::iter(0..10)
+                .co()
+                .limit(NonZeroUsize::new(2))
+                .filter_map(|n| async move { (n % 3 == 0).then_some(n) })
+                .collect_ordered()
+                .await;
+            assert_eq!(v, &[0, 3, 6, 9]);
+        });
+    }
+
+    /// Uses a channel-backed source so the source returns `Pending` from time
+    /// to time, and verifies that `take` stops pulling from it after the last
+    /// output it needs.
+    #[test]
+    fn filter_map_take_channel() {
+        use crate::future::Join;
+        use crate::utils::channel::local_channel;
+        use core::cell::Cell;
+        use futures::executor::LocalPool;
+        use futures_lite::StreamExt;
+
+        let mut pool = LocalPool::new();
+        let pulled = Cell::new(0);
+        let (send, receive) = local_channel();
+
+        let (v, ()) = pool.run_until(
+            (
+                async {
+                    receive
+                        .inspect(|_| pulled.set(pulled.get() + 1))
+                        .co()
+                        .filter_map(|n: usize| async move { (n % 2 == 0).then_some(n) })
+                        .take(3)
+                        .collect_ordered()
+                        .await
+                },
+                async {
+                    for n in 0..20 {
+                        send.send(n);
+                        futures_lite::future::yield_now().await;
+                    }
+                    drop(send);
+                },
+            )
+                .join(),
+        );
+
+        assert_eq!(v, &[0, 2, 4]);
+        // The fifth item (`4`) produces the third output; no more items may
+        // be pulled after that.
+        assert_eq!(pulled.get(), 5);
+    }
+}
@@ -0,0 +1,238 @@
+use super::{Consumer, ConsumerState};
+use futures_buffered::FuturesUnordered;
+use futures_lite::StreamExt;
+use pin_project::pin_project;
+
+use core::future::Future;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+
+/// Combines the outputs of futures into an accumulator in the order in which
+/// they complete.
+#[pin_project]
+pub(crate) struct FoldConsumer<Fut, B, F>
+where
+    Fut: Future,
+    F: FnMut(B, Fut::Output) -> B,
+{
+    #[pin]
+    group: FuturesUnordered<Fut>,
+    // NOTE: this is only ever `None` while the closure is being called.
+    acc: Option<B>,
+    limit: usize,
+    f: F,
+}
+
+impl<Fut, B, F> FoldConsumer<Fut, B, F>
+where
+    Fut: Future,
+    F: FnMut(B, Fut::Output) -> B,
+{
+    pub(crate) fn new(limit: Option<NonZeroUsize>, init: B, f: F) -> Self {
+        let limit = match limit {
+            Some(n) => n.get(),
+            None => usize::MAX,
+        };
+        Self {
+            group: FuturesUnordered::new(),
+            acc: Some(init),
+            limit,
+            f,
+        }
+    }
+}
+
+impl<Fut, B, F> Consumer<Fut::Output, Fut> for FoldConsumer<Fut, B, F>
+where
+    Fut: Future,
+    F: FnMut(B, Fut::Output) -> B,
+{
+    type Output = B;
+
+    async fn send(mut self: Pin<&mut Self>, future: Fut) -> ConsumerState {
+        // If we have no space, we're going to provide backpressure until we have space
+        self.as_mut().ready().await;
+
+        // Space was available! - insert the item for posterity
+        let mut this = self.project();
+        this.group.as_mut().push(future);
+        ConsumerState::Continue
+    }
+
+    async fn ready(self: Pin<&mut Self>) -> ConsumerState {
+        let mut this = self.project();
+        while this.group.len() >= *this.limit {
+            if let Some(item) = this.group.next().await {
+                let acc = this.acc.take().unwrap();
+                *this.acc = Some((this.f)(acc, item));
+            }
+        }
+        ConsumerState::Continue
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> ConsumerState {
+        let mut this = self.project();
+        while let Some(item) = this.group.next().await {
+            let acc = this.acc.take().unwrap();
+            *this.acc = Some((this.f)(acc, item));
+        }
+        ConsumerState::Empty
+    }
+
+    async fn flush(self: Pin<&mut Self>) -> Self::Output {
+        let mut this = self.project();
+        while let Some(item) = this.group.next().await {
+            let acc = this.acc.take().unwrap();
+            *this.acc = Some((this.f)(acc, item));
+        }
+        this.acc.take().unwrap()
+    }
+}
+
+/// Combines the successful outputs of futures into an accumulator in the
+/// order in which they complete, short-circuiting on the first error.
+#[pin_project]
+pub(crate) struct TryFoldConsumer<Fut, T, E, B, F>
+where
+    Fut: Future<Output = Result<T, E>>,
+    F: FnMut(B, T) -> B,
+{
+    #[pin]
+    group: FuturesUnordered<Fut>,
+    // NOTE: this is only ever `None` while the closure is being called, or
+    // once we've encountered an error.
+    acc: Option<B>,
+    error: Option<E>,
+    limit: usize,
+    f: F,
+}
+
+impl<Fut, T, E, B, F> TryFoldConsumer<Fut, T, E, B, F>
+where
+    Fut: Future<Output = Result<T, E>>,
+    F: FnMut(B, T) -> B,
+{
+    pub(crate) fn new(limit: Option<NonZeroUsize>, init: B, f: F) -> Self {
+        let limit = match limit {
+            Some(n) => n.get(),
+            None => usize::MAX,
+        };
+        Self {
+            group: FuturesUnordered::new(),
+            acc: Some(init),
+            error: None,
+            limit,
+            f,
+        }
+    }
+}
+
+impl<Fut, T, E, B, F> Consumer<Result<T, E>, Fut> for TryFoldConsumer<Fut, T, E, B, F>
+where
+    Fut: Future<Output = Result<T, E>>,
+    F: FnMut(B, T) -> B,
+{
+    type Output = Result<B, E>;
+
+    async fn send(mut self: Pin<&mut Self>, future: Fut) -> ConsumerState {
+        // If we have no space, we're going to provide backpressure until we have space
+        if let ConsumerState::Break = self.as_mut().ready().await {
+            return ConsumerState::Break;
+        }
+
+        // Space was available! - insert the item for posterity
+        let mut this = self.project();
+        this.group.as_mut().push(future);
+        ConsumerState::Continue
+    }
+
+    async fn ready(self: Pin<&mut Self>) -> ConsumerState {
+        let mut this = self.project();
+        while this.error.is_none() && this.group.len() >= *this.limit {
+            match this.group.next().await {
+                Some(Ok(item)) => {
+                    let acc = this.acc.take().unwrap();
+                    *this.acc = Some((this.f)(acc, item));
+                }
+                Some(Err(err)) => *this.error = Some(err),
+                None => break,
+            }
+        }
+        match this.error {
+            Some(_) => ConsumerState::Break,
+            None => ConsumerState::Continue,
+        }
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> ConsumerState {
+        let mut this = self.project();
+        if this.error.is_some() {
+            return ConsumerState::Break;
+        }
+        while let Some(res) = this.group.next().await {
+            match res {
+                Ok(item) => {
+                    let acc = this.acc.take().unwrap();
+                    *this.acc = Some((this.f)(acc, item));
+                }
+                Err(err) => {
+                    *this.error = Some(err);
+                    return ConsumerState::Break;
+                }
+            }
+        }
+        ConsumerState::Empty
+    }
+
+    async fn flush(self: Pin<&mut Self>) -> Self::Output {
+        let mut this = self.project();
+        // Return the error if we stopped iteration because of a previous error.
+        if let Some(err) = this.error.take() {
+            return Err(err);
+        }
+
+        // We will no longer receive any additional futures from the
+        // underlying stream; wait until all the futures in the group have
+        // resolved.
+        while let Some(res) = this.group.next().await {
+            let item = res?;
+            let acc = this.acc.take().unwrap();
+            *this.acc = Some((this.f)(acc, item));
+        }
+        Ok(this.acc.take().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use core::num::NonZeroUsize;
+    use futures_lite::future::yield_now;
+    use futures_lite::stream;
+
+    #[test]
+    fn fold() {
+        futures_lite::future::block_on(async {
+            let sum = stream::iter(1..=10)
+                .co()
+                .limit(NonZeroUsize::new(3))
+                .map(|n| async move {
+                    for _ in 0..(10 - n) {
+                        yield_now().await;
+                    }
+                    n * 2
+                })
+                .fold(0, |acc, n| acc + n)
+                .await;
+            assert_eq!(sum, 110);
+        });
+    }
+
+    #[test]
+    fn fold_empty() {
+        futures_lite::future::block_on(async {
+            let sum = stream::iter(0..0).co().fold(7, |acc, n| acc + n).await;
+            assert_eq!(sum, 7);
+        });
+    }
+}
@@ -59,14 +59,12 @@ where
 {
     type Output = ();
 
-    async fn send(self: Pin<&mut Self>, future: FutT) -> super::ConsumerState {
-        let mut this = self.project();
+    async fn send(mut self: Pin<&mut Self>, future: FutT) -> super::ConsumerState {
         // If we have no space, we're going to provide backpressure until we have space
-        while this.count.load(Ordering::Relaxed) >= *this.limit {
-            this.group.next().await;
-        }
+        self.as_mut().ready().await;
 
         // Space was available! - insert the item for posterity
+        let mut this = self.project();
         this.count.fetch_add(1, Ordering::Relaxed);
         let fut = ForEachFut::new(this.f.clone(), future, this.count.clone());
         this.group.as_mut().push(fut);
@@ -74,6 +72,14 @@ where
         ConsumerState::Continue
     }
 
+    async fn ready(self: Pin<&mut Self>) -> super::ConsumerState {
+        let mut this = self.project();
+        while this.count.load(Ordering::Relaxed) >= *this.limit {
+            this.group.next().await;
+        }
+        ConsumerState::Continue
+    }
+
     async fn progress(self: Pin<&mut Self>) -> super::ConsumerState {
         let mut this = self.project();
         while (this.group.next().await).is_some() {}
@@ -1,13 +1,19 @@
-use super::{ConcurrentStream, Consumer, ConsumerState, IntoConcurrentStream};
+use super::fold::{FoldConsumer, TryFoldConsumer};
+use super::{ConcurrentStream, IntoConcurrentStream};
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::vec::Vec;
-use core::future::Future;
-use core::pin::Pin;
-use futures_buffered::FuturesUnordered;
-use futures_lite::StreamExt;
-use pin_project::pin_project;
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// Conversion from a [`ConcurrentStream`]
+///
+/// This is the concurrent equivalent of [`FromIterator`], and is used by
+/// [`ConcurrentStream::collect`]. Outputs are passed to the collection in the
+/// order in which their futures complete. Collections reserve capacity for
+/// the lower bound of the stream's [`size_hint`][ConcurrentStream::size_hint]
+/// up front where possible.
 #[allow(async_fn_in_trait)]
 pub trait FromConcurrentStream<A>: Sized {
     /// Creates a value from a concurrent iterator.
@@ -16,66 +22,182 @@ pub trait FromConcurrentStream<A>: Sized {
         T: IntoConcurrentStream<Item = A>;
 }
 
+/// Collect all outputs into a `Vec`.
+///
+/// # Examples
+///
+/// ```rust
+/// use futures_concurrency::prelude::*;
+///
+/// # futures::executor::block_on(async {
+/// let mut v: Vec<_> = vec![1, 2, 3]
+///     .into_co_stream()
+///     .map(|n| async move { n * 2 })
+///     .collect()
+///     .await;
+///
+/// v.sort();
+/// assert_eq!(v, &[2, 4, 6]);
+/// # });
+/// ```
 impl<T> FromConcurrentStream<T> for Vec<T> {
     async fn from_concurrent_stream<S>(iter: S) -> Self
     where
         S: IntoConcurrentStream<Item = T>,
     {
         let stream = iter.into_co_stream();
-        let mut output = Vec::with_capacity(stream.size_hint().1.unwrap_or_default());
-        stream.drive(VecConsumer::new(&mut output)).await;
-        output
+        let output = Vec::with_capacity(stream.size_hint().0);
+        collect_into(stream, output).await
     }
 }
 
-// TODO: replace this with a generalized `fold` operation
-#[pin_project]
-pub(crate) struct VecConsumer<'a, Fut: Future> {
-    #[pin]
-    group: FuturesUnordered<Fut>,
-    output: &'a mut Vec<Fut::Output>,
-}
-
-impl<'a, Fut: Future> VecConsumer<'a, Fut> {
-    pub(crate) fn new(output: &'a mut Vec<Fut::Output>) -> Self {
-        Self {
-            group: FuturesUnordered::new(),
-            output,
-        }
+/// Collect all outputs into a `HashSet`.
+#[cfg(feature = "std")]
+impl<T, S> FromConcurrentStream<T> for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    async fn from_concurrent_stream<CS>(iter: CS) -> Self
+    where
+        CS: IntoConcurrentStream<Item = T>,
+    {
+        let stream = iter.into_co_stream();
+        let output = HashSet::with_capacity_and_hasher(stream.size_hint().0, S::default());
+        collect_into(stream, output).await
     }
 }
 
-impl<'a, Item, Fut> Consumer<Item, Fut> for VecConsumer<'a, Fut>
+/// Collect all key-value pairs into a `HashMap`.
+///
+/// # Examples
+///
+/// ```rust
+/// use futures_concurrency::prelude::*;
+/// use std::collections::HashMap;
+///
+/// # futures::executor::block_on(async {
+/// let map: HashMap<_, _> = vec!["chashu", "nori"]
+///     .into_co_stream()
+///     .map(|name| async move { (name, name.len()) })
+///     .collect()
+///     .await;
+///
+/// assert_eq!(map["chashu"], 6);
+/// assert_eq!(map["nori"], 4);
+/// # });
+/// ```
+#[cfg(feature = "std")]
+impl<K, V, S> FromConcurrentStream<(K, V)> for HashMap<K, V, S>
 where
-    Fut: Future<Output = Item>,
+    K: Eq + Hash,
+    S: BuildHasher + Default,
 {
-    type Output = ();
+    async fn from_concurrent_stream<CS>(iter: CS) -> Self
+    where
+        CS: IntoConcurrentStream<Item = (K, V)>,
+    {
+        let stream = iter.into_co_stream();
+        let output = HashMap::with_capacity_and_hasher(stream.size_hint().0, S::default());
+        collect_into(stream, output).await
+    }
+}
 
-    async fn send(self: Pin<&mut Self>, future: Fut) -> super::ConsumerState {
-        let mut this = self.project();
-        // unbounded concurrency, so we just goooo
-        this.group.as_mut().push(future);
-        ConsumerState::Continue
+/// Concatenate all characters into a `String`.
+impl FromConcurrentStream<char> for String {
+    async fn from_concurrent_stream<CS>(iter: CS) -> Self
+    where
+        CS: IntoConcurrentStream<Item = char>,
+    {
+        collect_into(iter.into_co_stream(), String::new()).await
+    }
+}
+
+/// Concatenate all string slices into a `String`.
+impl<'a> FromConcurrentStream<&'a str> for String {
+    async fn from_concurrent_stream<CS>(iter: CS) -> Self
+    where
+        CS: IntoConcurrentStream<Item = &'a str>,
+    {
+        collect_into(iter.into_co_stream(), String::new()).await
     }
+}
 
-    async fn progress(self: Pin<&mut Self>) -> super::ConsumerState {
-        let mut this = self.project();
-        while let Some(item) = this.group.next().await {
-            this.output.push(item);
-        }
-        ConsumerState::Empty
+/// Concatenate all strings into a `String`.
+impl FromConcurrentStream<String> for String {
+    async fn from_concurrent_stream<CS>(iter: CS) -> Self
+    where
+        CS: IntoConcurrentStream<Item = String>,
+    {
+        collect_into(iter.into_co_stream(), String::new()).await
     }
-    async fn flush(self: Pin<&mut Self>) -> Self::Output {
-        let mut this = self.project();
-        while let Some(item) = this.group.next().await {
-            this.output.push(item);
-        }
+}
+
+/// Collect all successful outputs into a collection, short-circuiting on the
+/// first error.
+///
+/// As soon as a future resolves to an error, no more items are pulled from
+/// the underlying stream, all in-flight futures are dropped, and the error is
+/// returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use futures_concurrency::prelude::*;
+///
+/// # futures::executor::block_on(async {
+/// let res: Result<Vec<u8>, _> = vec!["1", "2", "3"]
+///     .into_co_stream()
+///     .map(|s| async move { s.parse::<u8>() })
+///     .collect()
+///     .await;
+/// assert_eq!(res.unwrap().len(), 3);
+///
+/// let res: Result<Vec<u8>, _> = vec!["1", "two", "3"]
+///     .into_co_stream()
+///     .map(|s| async move { s.parse::<u8>() })
+///     .collect()
+///     .await;
+/// assert!(res.is_err());
+/// # });
+/// ```
+impl<T, E, C> FromConcurrentStream<Result<T, E>> for Result<C, E>
+where
+    C: Default + Extend<T>,
+{
+    async fn from_concurrent_stream<CS>(iter: CS) -> Self
+    where
+        CS: IntoConcurrentStream<Item = Result<T, E>>,
+    {
+        let stream = iter.into_co_stream();
+        let limit = stream.concurrency_limit();
+        let consumer = TryFoldConsumer::new(limit, C::default(), |mut acc: C, item| {
+            acc.extend(Some(item));
+            acc
+        });
+        stream.drive(consumer).await
     }
 }
 
+/// Extend a collection with all outputs of a concurrent stream, in the order
+/// in which they complete.
+async fn collect_into<CS, C>(stream: CS, init: C) -> C
+where
+    CS: ConcurrentStream,
+    C: Extend<CS::Item>,
+{
+    let limit = stream.concurrency_limit();
+    let consumer = FoldConsumer::new(limit, init, |mut acc: C, item| {
+        acc.extend(Some(item));
+        acc
+    });
+    stream.drive(consumer).await
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
+    use core::num::NonZeroUsize;
     use futures_lite::stream;
 
     #[test]
@@ -85,4 +207,50 @@ mod test {
             assert_eq!(v, &[1, 1, 1, 1, 1]);
         });
     }
+
+    #[test]
+    fn collect_limit() {
+        futures_lite::future::block_on(async {
+            let v: Vec<_> = stream::repeat(1)
+                .co()
+                .take(5)
+                .limit(NonZeroUsize::new(2))
+                .map(|n| async move { n + 1 })
+                .collect()
+                .await;
+            assert_eq!(v, &[2, 2, 2, 2, 2]);
+        });
+    }
+
+    #[test]
+    fn collect_string() {
+        futures_lite::future::block_on(async {
+            let s: String = stream::iter(["a", "a", "a"]).co().collect().await;
+            assert_eq!(s, "aaa");
+        });
+    }
+
+    #[test]
+    fn collect_result_short_circuits() {
+        use core::cell::Cell;
+        use futures_lite::StreamExt;
+
+        futures_lite::future::block_on(async {
+            let pulled = Cell::new(0);
+            let res: Result<Vec<usize>, usize> = stream::iter(0..10)
+                .inspect(|_| pulled.set(pulled.get() + 1))
+                .co()
+                .limit(NonZeroUsize::new(1))
+                .map(|n| async move {
+                    match n {
+                        3 => Err(n),
+                        n => Ok(n),
+                    }
+                })
+                .collect()
+                .await;
+            assert_eq!(res, Err(3));
+            assert_eq!(pulled.get(), 4);
+        });
+    }
 }
@@ -28,7 +28,7 @@ where
     type Item = S::Item;
     type Future = Ready<Self::Item>;
 
-    async fn drive<C>(self, mut consumer: C) -> C::Output
+    async fn drive<C>(self, consumer: C) -> C::Output
     where
         C: Consumer<Self::Item, Self::Future>,
     {
@@ -46,6 +46,12 @@ where
         // future repeatedly. However for now we're happy to rely on this
         // property here.
         loop {
+            // Wait for the consumer to have capacity before we pull another
+            // item from the stream. This provides backpressure to the source.
+            if let ConsumerState::Break = consumer.as_mut().ready().await {
+                break;
+            }
+
             // Drive the stream forward
             let a = async {
                 let item = iter.next().await;
@@ -63,6 +63,11 @@ where
         this.inner.send(future).await
     }
 
+    async fn ready(self: Pin<&mut Self>) -> super::ConsumerState {
+        let this = self.project();
+        this.inner.ready().await
+    }
+
     async fn progress(self: Pin<&mut Self>) -> super::ConsumerState {
         let this = self.project();
         this.inner.progress().await
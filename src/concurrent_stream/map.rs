@@ -98,6 +98,11 @@ where
 {
     type Output = C::Output;
 
+    async fn ready(self: Pin<&mut Self>) -> super::ConsumerState {
+        let this = self.project();
+        this.inner.ready().await
+    }
+
     async fn progress(self: Pin<&mut Self>) -> super::ConsumerState {
         let this = self.project();
         this.inner.progress().await
@@ -135,7 +140,7 @@ where
     F: Fn(T) -> FutB,
     FutB: Future<Output = B>,
 {
-    fn new(f: F, fut_t: FutT) -> Self {
+    pub(crate) fn new(f: F, fut_t: FutT) -> Self {
         Self {
             done: false,
             f,
@@ -37,6 +37,8 @@
 //! ```
 
 mod enumerate;
+mod filter_map;
+mod fold;
 mod for_each;
 mod from_concurrent_stream;
 mod from_stream;
@@ -44,15 +46,21 @@ mod into_concurrent_stream;
 mod limit;
 mod map;
 mod take;
+mod try_collect;
 mod try_for_each;
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
 use core::future::Future;
 use core::num::NonZeroUsize;
 use core::pin::Pin;
+use fold::FoldConsumer;
 use for_each::ForEachConsumer;
+use try_collect::TryCollectConsumer;
 use try_for_each::TryForEachConsumer;
 
 pub use enumerate::Enumerate;
+pub use filter_map::FilterMap;
 pub use from_concurrent_stream::FromConcurrentStream;
 pub use from_stream::FromStream;
 pub use into_concurrent_stream::IntoConcurrentStream;
@@ -76,6 +84,15 @@ where
     /// Send an item down to the next step in the processing queue.
     async fn send(self: Pin<&mut Self>, fut: Fut) -> ConsumerState;
 
+    /// Wait until the consumer has capacity to receive another future.
+    ///
+    /// Sources should wait for this to resolve before pulling a new item, so
+    /// that no items are taken from them while the consumer is at its
+    /// concurrency limit. By default a consumer is always ready.
+    async fn ready(self: Pin<&mut Self>) -> ConsumerState {
+        ConsumerState::Continue
+    }
+
     /// Make progress on the consumer while doing something else.
     ///
     /// It should always be possible to drop the future returned by this
@@ -135,6 +152,11 @@ pub trait ConcurrentStream {
 
     /// Creates a stream that yields the first `n` elements, or fewer if the
     /// underlying iterator ends sooner.
+    ///
+    /// Once `n` elements have been passed on, no more items are pulled from
+    /// the underlying iterator. When placed after
+    /// [`filter_map`][ConcurrentStream::filter_map], this counts the elements
+    /// which were kept, and any futures still in-flight are dropped.
     fn take(self, limit: usize) -> Take<Self>
     where
         Self: Sized,
@@ -153,6 +175,40 @@ pub trait ConcurrentStream {
         Map::new(self, f)
     }
 
+    /// Creates a concurrent stream that both filters and maps.
+    ///
+    /// The closure is called with each item, and must return a future that
+    /// resolves to an `Option`. Outputs of `None` are dropped, while outputs of
+    /// `Some` are passed on. Because an output can only be kept once its
+    /// future has resolved, the futures are evaluated by this adapter: a
+    /// [`limit`][ConcurrentStream::limit] applied before `filter_map` bounds
+    /// how many of these futures are in-flight at once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let v = vec!["1", "two", "3"]
+    ///     .into_co_stream()
+    ///     .filter_map(|s| async move { s.parse::<u8>().ok() })
+    ///     .collect_ordered()
+    ///     .await;
+    ///
+    /// assert_eq!(v, &[1, 3]);
+    /// # });
+    /// ```
+    fn filter_map<F, FutB, B>(self, f: F) -> FilterMap<Self, F, Self::Future, Self::Item, FutB, B>
+    where
+        Self: Sized,
+        F: Fn(Self::Item) -> FutB,
+        F: Clone,
+        FutB: Future<Output = Option<B>>,
+    {
+        FilterMap::new(self, f)
+    }
+
     /// Iterate over each item concurrently
     async fn for_each<F, Fut>(self, f: F)
     where
@@ -179,7 +235,47 @@ pub trait ConcurrentStream {
         self.drive(TryForEachConsumer::new(limit, f)).await
     }
 
+    /// Combines the outputs of all futures into a single value.
+    ///
+    /// The futures are evaluated concurrently, and only the combination of
+    /// their outputs into the accumulator is serialized. Outputs are combined
+    /// in the order in which their futures complete, which means the closure
+    /// should be commutative and associative for the result to be
+    /// deterministic. Use [`collect_ordered`][ConcurrentStream::collect_ordered]
+    /// followed by a regular fold to combine outputs in source order instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let sum = vec![1, 2, 3, 4]
+    ///     .into_co_stream()
+    ///     .limit(NonZeroUsize::new(2))
+    ///     .map(|n| async move { n * 10 })
+    ///     .fold(0, |acc, n| acc + n)
+    ///     .await;
+    ///
+    /// assert_eq!(sum, 100);
+    /// # });
+    /// ```
+    async fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let limit = self.concurrency_limit();
+        self.drive(FoldConsumer::new(limit, init, f)).await
+    }
+
     /// Transforms an iterator into a collection.
+    ///
+    /// Outputs are collected in the order in which their futures complete,
+    /// which may differ from the order in which items were yielded by the
+    /// source. Use [`collect_ordered`][ConcurrentStream::collect_ordered] to
+    /// preserve the source order instead.
     async fn collect<B>(self) -> B
     where
         B: FromConcurrentStream<Self::Item>,
@@ -187,6 +283,88 @@ pub trait ConcurrentStream {
     {
         B::from_concurrent_stream(self).await
     }
+
+    /// Transforms an iterator into a `Vec`, preserving the order in which
+    /// items were yielded by the source.
+    ///
+    /// Futures are still evaluated concurrently; outputs which complete early
+    /// are held until the collection has finished, after which they are
+    /// placed in source order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::yield_now;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let v = vec![3, 2, 1]
+    ///     .into_co_stream()
+    ///     .map(|n| async move {
+    ///         // Later items resolve sooner.
+    ///         for _ in 0..n {
+    ///             yield_now().await;
+    ///         }
+    ///         n
+    ///     })
+    ///     .collect_ordered()
+    ///     .await;
+    ///
+    /// assert_eq!(v, &[3, 2, 1]);
+    /// # });
+    /// ```
+    async fn collect_ordered(self) -> Vec<Self::Item>
+    where
+        Self: Sized,
+    {
+        let mut items: Vec<(usize, Self::Item)> = self.enumerate().collect().await;
+        items.sort_unstable_by_key(|(index, _)| *index);
+        items.into_iter().map(|(_, item)| item).collect()
+    }
+
+    /// Transforms an iterator of `Result`s into a `Vec`, short-circuiting on
+    /// error.
+    ///
+    /// Outputs are placed in the order in which items were yielded by the
+    /// source. As soon as a future resolves to an error, no more items are
+    /// pulled from the source, all in-flight futures are dropped, and the
+    /// error is returned. If multiple futures resolve to an error during the
+    /// same wake, the error of the earliest item is returned.
+    ///
+    /// Outputs which complete before the outputs preceding them are buffered
+    /// until they can be placed. Buffered outputs count towards the
+    /// concurrency limit, which ensures that a single slow future can't cause
+    /// an unbounded amount of outputs to be buffered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let v: Result<Vec<_>, String> = vec!["1", "2", "3"]
+    ///     .into_co_stream()
+    ///     .map(|s| async move { s.parse::<u8>().map_err(|e| e.to_string()) })
+    ///     .try_collect()
+    ///     .await;
+    /// assert_eq!(v, Ok(vec![1, 2, 3]));
+    ///
+    /// let v: Result<Vec<_>, String> = vec!["1", "two", "3"]
+    ///     .into_co_stream()
+    ///     .map(|s| async move { s.parse::<u8>().map_err(|e| e.to_string()) })
+    ///     .try_collect()
+    ///     .await;
+    /// assert!(v.is_err());
+    /// # });
+    /// ```
+    async fn try_collect<T, E>(self) -> Result<Vec<T>, E>
+    where
+        Self: ConcurrentStream<Item = Result<T, E>> + Sized,
+    {
+        let limit = self.concurrency_limit();
+        let capacity = self.size_hint().0;
+        self.drive(TryCollectConsumer::new(limit, capacity)).await
+    }
 }
 
 /// The state of the consumer, used to communicate back to the source.
@@ -223,6 +401,49 @@ mod test {
         });
     }
 
+    #[test]
+    fn backpressure() {
+        use core::cell::Cell;
+        use core::future::{pending, Future};
+        use core::pin::pin;
+        use core::task::Context;
+        use futures::task::noop_waker;
+
+        let pulled = Cell::new(0);
+        let source = stream::repeat(1).inspect(|_| pulled.set(pulled.get() + 1));
+        let fut = source
+            .co()
+            .limit(NonZeroUsize::new(3))
+            .for_each(|_| pending::<()>());
+        let mut fut = pin!(fut);
+
+        // No new items may be pulled from the source while the limit is reached.
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        for _ in 0..4 {
+            assert!(fut.as_mut().poll(&mut cx).is_pending());
+        }
+        assert_eq!(pulled.get(), 3);
+    }
+
+    #[test]
+    fn collect_ordered() {
+        futures_lite::future::block_on(async {
+            let v = stream::iter(0..10)
+                .co()
+                .limit(NonZeroUsize::new(4))
+                .map(|n| async move {
+                    for _ in 0..(10 - n) {
+                        futures_lite::future::yield_now().await;
+                    }
+                    n
+                })
+                .collect_ordered()
+                .await;
+            assert_eq!(v, (0..10).collect::<Vec<_>>());
+        });
+    }
+
     #[test]
     fn for_each() {
         futures_lite::future::block_on(async {
@@ -48,7 +48,13 @@ impl<CS: ConcurrentStream> ConcurrentStream for Take<CS> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.inner.size_hint()
+        let (lower, upper) = self.inner.size_hint();
+        let lower = lower.min(self.limit);
+        let upper = match upper {
+            Some(upper) => upper.min(self.limit),
+            None => self.limit,
+        };
+        (lower, Some(upper))
     }
 }
 
@@ -68,6 +74,9 @@ where
 
     async fn send(self: Pin<&mut Self>, future: Fut) -> ConsumerState {
         let this = self.project();
+        if this.count >= this.limit {
+            return ConsumerState::Break;
+        }
         *this.count += 1;
         let state = this.inner.send(future).await;
         if this.count >= this.limit {
@@ -77,6 +86,15 @@ where
         }
     }
 
+    async fn ready(self: Pin<&mut Self>) -> ConsumerState {
+        let this = self.project();
+        // Don't pull any more items from the source once we've reached the limit.
+        if this.count >= this.limit {
+            return ConsumerState::Break;
+        }
+        this.inner.ready().await
+    }
+
     async fn progress(self: Pin<&mut Self>) -> ConsumerState {
         let this = self.project();
         this.inner.progress().await
@@ -108,4 +126,27 @@ mod test {
             .await;
         });
     }
+
+    #[test]
+    fn size_hint() {
+        let s = stream::iter(0..10).co();
+        assert_eq!(s.size_hint(), (10, Some(10)));
+
+        let s = stream::iter(0..10).co().map(|n| async move { n }).take(3);
+        assert_eq!(s.size_hint(), (3, Some(3)));
+
+        let s = stream::iter(0..2).co().take(3);
+        assert_eq!(s.size_hint(), (2, Some(2)));
+
+        let s = stream::repeat(1).co().take(3);
+        assert_eq!(s.size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn take_zero() {
+        futures_lite::future::block_on(async {
+            let v: Vec<usize> = stream::repeat(1).co().take(0).collect().await;
+            assert!(v.is_empty());
+        });
+    }
 }
@@ -0,0 +1,252 @@
+use super::enumerate::EnumerateFuture;
+use super::{Consumer, ConsumerState};
+use futures_buffered::FuturesUnordered;
+use futures_lite::StreamExt;
+use pin_project::pin_project;
+
+use alloc::collections::VecDeque;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use core::future::{poll_fn, Future};
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use core::task::Poll;
+
+/// Collects the outputs of fallible futures in source order, short-circuiting
+/// on the first error.
+#[pin_project]
+pub(crate) struct TryCollectConsumer<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    #[pin]
+    group: FuturesUnordered<EnumerateFuture<Fut, Result<T, E>>>,
+    buffer: Buffer<T, E>,
+    limit: usize,
+    sent: usize,
+}
+
+impl<Fut, T, E> TryCollectConsumer<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    pub(crate) fn new(limit: Option<NonZeroUsize>, capacity: usize) -> Self {
+        let limit = match limit {
+            Some(n) => n.get(),
+            None => usize::MAX,
+        };
+        Self {
+            group: FuturesUnordered::new(),
+            buffer: Buffer::new(capacity),
+            limit,
+            sent: 0,
+        }
+    }
+}
+
+impl<Fut, T, E> Consumer<Result<T, E>, Fut> for TryCollectConsumer<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Result<Vec<T>, E>;
+
+    async fn send(mut self: Pin<&mut Self>, future: Fut) -> ConsumerState {
+        // If we have no space, we're going to provide backpressure until we have space
+        if let ConsumerState::Break = self.as_mut().ready().await {
+            return ConsumerState::Break;
+        }
+
+        // Space was available! - insert the item for posterity
+        let mut this = self.project();
+        let fut = EnumerateFuture::new(future, *this.sent);
+        *this.sent += 1;
+        this.group.as_mut().push(fut);
+        ConsumerState::Continue
+    }
+
+    async fn ready(self: Pin<&mut Self>) -> ConsumerState {
+        let mut this = self.project();
+        // Outputs which complete out of order are buffered until all earlier
+        // outputs have completed. We count those buffered outputs against the
+        // limit too, so a single slow future can't cause unbounded buffering.
+        while this.buffer.error.is_none() && *this.sent - this.buffer.emitted >= *this.limit {
+            match this.group.next().await {
+                Some((index, res)) => this.buffer.insert(index, res),
+                None => break,
+            }
+        }
+
+        match this.buffer.error {
+            Some(_) => {
+                drain_ready(this.group, this.buffer).await;
+                ConsumerState::Break
+            }
+            None => ConsumerState::Continue,
+        }
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> ConsumerState {
+        let mut this = self.project();
+        while let Some((index, res)) = this.group.next().await {
+            this.buffer.insert(index, res);
+            if this.buffer.error.is_some() {
+                drain_ready(this.group, this.buffer).await;
+                return ConsumerState::Break;
+            }
+        }
+        ConsumerState::Empty
+    }
+
+    async fn flush(self: Pin<&mut Self>) -> Self::Output {
+        let mut this = self.project();
+        // We will no longer receive any additional futures from the
+        // underlying stream; wait until all the futures in the group have
+        // resolved, or until we encounter an error.
+        while this.buffer.error.is_none() {
+            match this.group.next().await {
+                Some((index, res)) => this.buffer.insert(index, res),
+                None => break,
+            }
+        }
+
+        match this.buffer.error.take() {
+            Some((_, err)) => Err(err),
+            None => Ok(core::mem::take(&mut this.buffer.output)),
+        }
+    }
+}
+
+/// Pick up any outputs which are already available without waiting.
+///
+/// This ensures that when multiple futures fail during the same wake, the
+/// error with the lowest index is the one that's returned.
+async fn drain_ready<Fut, T, E>(
+    mut group: Pin<&mut FuturesUnordered<EnumerateFuture<Fut, Result<T, E>>>>,
+    buffer: &mut Buffer<T, E>,
+) where
+    Fut: Future<Output = Result<T, E>>,
+{
+    poll_fn(|cx| {
+        while let Poll::Ready(Some((index, res))) = group.as_mut().poll_next(cx) {
+            buffer.insert(index, res);
+        }
+        Poll::Ready(())
+    })
+    .await
+}
+
+/// Reorders outputs so they can be emitted in source order.
+struct Buffer<T, E> {
+    /// Outputs which have been emitted in order.
+    output: Vec<T>,
+    /// Outputs which completed before all of their predecessors did.
+    pending: VecDeque<Option<T>>,
+    /// The number of outputs which have been emitted in order.
+    emitted: usize,
+    /// The error with the lowest index we've encountered so far.
+    error: Option<(usize, E)>,
+}
+
+impl<T, E> Buffer<T, E> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            output: Vec::with_capacity(capacity),
+            pending: VecDeque::new(),
+            emitted: 0,
+            error: None,
+        }
+    }
+
+    fn insert(&mut self, index: usize, res: Result<T, E>) {
+        match res {
+            Ok(item) => {
+                let slot = index - self.emitted;
+                if self.pending.len() <= slot {
+                    self.pending.resize_with(slot + 1, || None);
+                }
+                self.pending[slot] = Some(item);
+
+                // Emit all outputs which are now in order.
+                while let Some(Some(_)) = self.pending.front() {
+                    let item = self.pending.pop_front().flatten().unwrap();
+                    self.output.push(item);
+                    self.emitted += 1;
+                }
+            }
+            Err(err) => match &self.error {
+                Some((prev, _)) if *prev < index => {}
+                _ => self.error = Some((index, err)),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use core::num::NonZeroUsize;
+    use futures_lite::future::yield_now;
+    use futures_lite::stream;
+
+    #[test]
+    fn try_collect() {
+        futures_lite::future::block_on(async {
+            let v: Result<Vec<_>, ()> = stream::iter(0..10)
+                .co()
+                .limit(NonZeroUsize::new(3))
+                .map(|n| async move {
+                    for _ in 0..(10 - n) {
+                        yield_now().await;
+                    }
+                    Ok(n)
+                })
+                .try_collect()
+                .await;
+            assert_eq!(v.unwrap(), (0..10).collect::<Vec<_>>());
+        });
+    }
+
+    #[test]
+    fn error_in_middle() {
+        futures_lite::future::block_on(async {
+            // Item 2 is the slowest to fail, after the items following it
+            // have already completed successfully.
+            let v: Result<Vec<usize>, usize> = stream::iter(0..5)
+                .co()
+                .map(|n| async move {
+                    if n == 2 {
+                        for _ in 0..10 {
+                            yield_now().await;
+                        }
+                        Err(n)
+                    } else {
+                        Ok(n)
+                    }
+                })
+                .try_collect()
+                .await;
+            assert_eq!(v, Err(2));
+        });
+    }
+
+    #[test]
+    fn lowest_index_error_wins() {
+        futures_lite::future::block_on(async {
+            let v: Result<Vec<usize>, usize> = stream::iter(0..8)
+                .co()
+                .map(|n| async move {
+                    // Every odd item fails, and they all fail during the same
+                    // wake.
+                    yield_now().await;
+                    if n % 2 == 1 {
+                        Err(n)
+                    } else {
+                        Ok(n)
+                    }
+                })
+                .try_collect()
+                .await;
+            assert_eq!(v, Err(1));
+        });
+    }
+}
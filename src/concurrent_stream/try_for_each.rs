@@ -66,9 +66,22 @@ where
 {
     type Output = B;
 
-    async fn send(self: Pin<&mut Self>, future: FutT) -> super::ConsumerState {
-        let mut this = self.project();
+    async fn send(mut self: Pin<&mut Self>, future: FutT) -> super::ConsumerState {
         // If we have no space, we're going to provide backpressure until we have space
+        if let ConsumerState::Break = self.as_mut().ready().await {
+            return ConsumerState::Break;
+        }
+
+        // Space was available! - insert the item for posterity
+        let mut this = self.project();
+        this.count.fetch_add(1, Ordering::Relaxed);
+        let fut = TryForEachFut::new(this.f.clone(), future, this.count.clone());
+        this.group.as_mut().push(fut);
+        ConsumerState::Continue
+    }
+
+    async fn ready(self: Pin<&mut Self>) -> super::ConsumerState {
+        let mut this = self.project();
         while this.count.load(Ordering::Relaxed) >= *this.limit {
             match this.group.next().await {
                 // Case 1: there are no more items available in the group. We
@@ -89,11 +102,6 @@ where
                 },
             }
         }
-
-        // Space was available! - insert the item for posterity
-        this.count.fetch_add(1, Ordering::Relaxed);
-        let fut = TryForEachFut::new(this.f.clone(), future, this.count.clone());
-        this.group.as_mut().push(fut);
         ConsumerState::Continue
     }
 
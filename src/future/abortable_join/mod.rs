@@ -0,0 +1,156 @@
+use alloc::sync::Arc;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use pin_project::pin_project;
+
+pub(crate) mod vec;
+
+/// Wait for all futures to complete, while allowing each of them to be
+/// aborted individually.
+pub trait AbortableJoin {
+    /// The [`Future`] implementation returned by this method.
+    type Future: Future<Output = Self::Output>;
+
+    /// The resulting output type.
+    type Output;
+
+    /// The handles used to abort the futures.
+    type Handles;
+
+    /// Waits for all futures to complete, returning a handle for each future
+    /// which aborts it.
+    ///
+    /// Aborting a future drops it, and resolves its entry in the output to
+    /// `Err(Aborted)` instead of waiting for it. The future is dropped the
+    /// next time the join is polled, by the task which polls it. Aborting a
+    /// future which has already completed does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::future::Aborted;
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::{pending, ready, Future};
+    /// use std::pin::Pin;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let futs: Vec<Pin<Box<dyn Future<Output = u8> + Send>>> =
+    ///     vec![Box::pin(ready(1)), Box::pin(pending())];
+    /// let (join, handles) = futs.abortable_join();
+    ///
+    /// // The second future would never complete, so abort it.
+    /// handles[1].abort();
+    /// assert_eq!(join.await, [Ok(1), Err(Aborted)]);
+    /// # });
+    /// ```
+    fn abortable_join(self) -> (Self::Future, Self::Handles);
+}
+
+/// The error returned for a future which has been aborted through its
+/// [`AbortHandle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Aborted;
+
+impl fmt::Display for Aborted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the future was aborted")
+    }
+}
+
+impl std::error::Error for Aborted {}
+
+/// A handle which aborts a single future of an [`AbortableJoin`].
+///
+/// Handles can be cloned and sent to other tasks.
+#[derive(Debug, Clone)]
+pub struct AbortHandle {
+    shared: Arc<AbortShared>,
+}
+
+impl AbortHandle {
+    /// Abort the future.
+    ///
+    /// The future is dropped the next time the join is polled. This does
+    /// nothing if the future has already completed.
+    pub fn abort(&self) {
+        if !self.shared.aborted.swap(true, Ordering::AcqRel) {
+            if let Some(waker) = self.shared.waker().take() {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Returns `true` if [`abort`][AbortHandle::abort] has been called.
+    pub fn is_aborted(&self) -> bool {
+        self.shared.aborted.load(Ordering::Acquire)
+    }
+}
+
+/// The state shared between a future and its abort handles.
+#[derive(Debug, Default)]
+struct AbortShared {
+    aborted: AtomicBool,
+    /// The waker of the future, which is woken when it's aborted
+    waker: Mutex<Option<Waker>>,
+}
+
+impl AbortShared {
+    fn waker(&self) -> MutexGuard<'_, Option<Waker>> {
+        self.waker.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A future which resolves to `Err(Aborted)` once it's aborted.
+#[derive(Debug)]
+#[pin_project]
+pub(crate) struct Abortable<F> {
+    #[pin]
+    future: Option<F>,
+    shared: Arc<AbortShared>,
+}
+
+impl<F> Abortable<F> {
+    pub(crate) fn new(future: F) -> (Self, AbortHandle) {
+        let shared = Arc::new(AbortShared::default());
+        let handle = AbortHandle {
+            shared: shared.clone(),
+        };
+        let future = Self {
+            future: Some(future),
+            shared,
+        };
+        (future, handle)
+    }
+}
+
+impl<F: Future> Future for Abortable<F> {
+    type Output = Result<F::Output, Aborted>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        // Register the waker before checking whether we've been aborted. An
+        // abort either happens before the check and is seen by it, or takes
+        // the waker after it was registered and wakes it.
+        {
+            let mut waker = this.shared.waker();
+            match &mut *waker {
+                Some(prev) => prev.clone_from(cx.waker()),
+                None => *waker = Some(cx.waker().clone()),
+            }
+        }
+        if !this.shared.aborted.load(Ordering::Acquire) {
+            if let Some(future) = this.future.as_mut().as_pin_mut() {
+                return future.poll(cx).map(Ok);
+            }
+        }
+
+        // Drop the future here, on the task which polls the join.
+        this.future.set(None);
+        Poll::Ready(Err(Aborted))
+    }
+}
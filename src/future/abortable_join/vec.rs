@@ -0,0 +1,173 @@
+use super::{AbortHandle, Abortable, AbortableJoin as AbortableJoinTrait, Aborted};
+use crate::future::join::vec::Join;
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project::pin_project;
+
+/// A future which waits for all futures to complete, or to be aborted.
+///
+/// This `struct` is created by the [`abortable_join`] method on the
+/// [`AbortableJoin`] trait. See its documentation for more.
+///
+/// [`abortable_join`]: crate::future::AbortableJoin::abortable_join
+/// [`AbortableJoin`]: crate::future::AbortableJoin
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct AbortableJoin<Fut>
+where
+    Fut: Future,
+{
+    #[pin]
+    join: Join<Abortable<Fut>>,
+}
+
+impl<Fut> AbortableJoinTrait for Vec<Fut>
+where
+    Fut: IntoFuture,
+{
+    type Future = AbortableJoin<Fut::IntoFuture>;
+    type Output = Vec<Result<Fut::Output, Aborted>>;
+    type Handles = Vec<AbortHandle>;
+
+    fn abortable_join(self) -> (Self::Future, Self::Handles) {
+        let (futures, handles) = self
+            .into_iter()
+            .map(|fut| Abortable::new(fut.into_future()))
+            .unzip();
+        let join = AbortableJoin {
+            join: Join::new(futures),
+        };
+        (join, handles)
+    }
+}
+
+impl<Fut> fmt::Debug for AbortableJoin<Fut>
+where
+    Fut: Future + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.join.fmt(f)
+    }
+}
+
+impl<Fut> Future for AbortableJoin<Fut>
+where
+    Fut: Future,
+{
+    type Output = Vec<Result<Fut::Output, Aborted>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().join.poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+    use alloc::vec;
+    use core::pin::pin;
+    use futures_lite::future::block_on;
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    #[test]
+    fn abort_from_another_thread() {
+        let (a, _) = ScriptedFuture::new([Step::Ready(1)]);
+        let (b, b_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(2)]);
+        let (join, handles) = vec![a, b].abortable_join();
+        let mut join = pin!(join);
+
+        // Only abort once `b` has been polled, so it's dropped while it's
+        // waiting rather than before it started.
+        let polled = Arc::new(Barrier::new(2));
+        let aborter = thread::spawn({
+            let handle = handles[1].clone();
+            let polled = polled.clone();
+            move || {
+                polled.wait();
+                handle.abort();
+            }
+        });
+        let waker = CountingWaker::new();
+        assert_eq!(step(join.as_mut(), &waker), Poll::Pending);
+        polled.wait();
+        assert_eq!(block_on(join), [Ok(1), Err(Aborted)]);
+        aborter.join().unwrap();
+
+        // The aborted future was dropped without completing.
+        assert!(handles[1].is_aborted());
+        assert_eq!(b_handle.polls(), 1);
+    }
+
+    #[test]
+    fn abort_wakes_join() {
+        let (a, _) = ScriptedFuture::new([Step::WakeLater]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(2)]);
+        let (join, handles) = vec![a, b].abortable_join();
+        let mut join = pin!(join);
+
+        let waker = CountingWaker::new();
+        assert_eq!(step(join.as_mut(), &waker), Poll::Pending);
+        handles[0].abort();
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(
+            step(join.as_mut(), &waker),
+            Poll::Ready(vec![Err(Aborted), Ok(2)])
+        );
+    }
+
+    #[test]
+    fn abort_from_another_thread_between_polls() {
+        for _ in 0..1000 {
+            let (a, _) = ScriptedFuture::<u8>::new([Step::WakeLater]);
+            let (join, handles) = vec![a].abortable_join();
+            let mut join = pin!(join);
+            let waker = CountingWaker::new();
+
+            // The abort races with the first poll. If the first poll misses
+            // it, the abort must wake the join for the second poll.
+            let start = Arc::new(Barrier::new(2));
+            let aborter = thread::spawn({
+                let handle = handles[0].clone();
+                let start = start.clone();
+                move || {
+                    start.wait();
+                    handle.abort();
+                }
+            });
+            start.wait();
+            let first = step(join.as_mut(), &waker);
+            aborter.join().unwrap();
+            if first.is_pending() {
+                assert_eq!(waker.wakes(), 1);
+                assert_eq!(step(join.as_mut(), &waker), Poll::Ready(vec![Err(Aborted)]));
+            } else {
+                assert_eq!(first, Poll::Ready(vec![Err(Aborted)]));
+            }
+        }
+    }
+
+    #[test]
+    fn abort_after_completion() {
+        let (a, _) = ScriptedFuture::new([Step::Ready(1)]);
+        let (b, _) = ScriptedFuture::new([Step::WakeLater]);
+        let (join, handles) = vec![a, b].abortable_join();
+        let mut join = pin!(join);
+
+        let waker = CountingWaker::new();
+        assert_eq!(step(join.as_mut(), &waker), Poll::Pending);
+        // The first future has completed, so aborting it does nothing.
+        handles[0].abort();
+        handles[1].abort();
+        assert_eq!(
+            step(join.as_mut(), &waker),
+            Poll::Ready(vec![Ok(1), Err(Aborted)])
+        );
+    }
+}
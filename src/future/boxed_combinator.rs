@@ -0,0 +1,110 @@
+use alloc::boxed::Box;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::future::FusedFuture;
+
+/// A boxed, type-erased future which keeps a name for its `Debug` output.
+///
+/// This makes it possible to store operations such as `Join` or `Race` in a
+/// struct field without spelling out their types, while still being able to
+/// tell them apart when debugging.
+///
+/// This `struct` is created by the [`boxed_named`] method on [`FutureExt`].
+/// See its documentation for more.
+///
+/// [`boxed_named`]: crate::future::FutureExt::boxed_named
+/// [`FutureExt`]: crate::future::FutureExt
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct BoxedCombinator<T> {
+    future: Pin<Box<dyn Future<Output = T> + Send>>,
+    name: &'static str,
+    polls: usize,
+    done: bool,
+}
+
+impl<T> BoxedCombinator<T> {
+    pub(crate) fn new<F>(future: F, name: &'static str) -> Self
+    where
+        F: Future<Output = T> + Send + 'static,
+    {
+        Self {
+            future: Box::pin(future),
+            name,
+            polls: 0,
+            done: false,
+        }
+    }
+
+    /// Returns the name the future was given.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns `true` if the inner future has completed.
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+impl<T> fmt::Debug for BoxedCombinator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedCombinator")
+            .field("name", &self.name)
+            .field("polls", &self.polls)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<T> Future for BoxedCombinator<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.polls += 1;
+        let output = self.future.as_mut().poll(cx);
+        if output.is_ready() {
+            self.done = true;
+        }
+        output
+    }
+}
+
+impl<T> FusedFuture for BoxedCombinator<T> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::future::{FutureExt, Join};
+    use alloc::format;
+    use core::future;
+    use futures_core::future::FusedFuture;
+    use futures_lite::future::block_on;
+
+    #[test]
+    fn debug() {
+        block_on(async {
+            let mut fut = (future::ready(1), future::ready(2))
+                .join()
+                .boxed_named("pair");
+            assert_eq!(
+                format!("{fut:?}"),
+                r#"BoxedCombinator { name: "pair", polls: 0, done: false }"#
+            );
+            assert!(!fut.is_terminated());
+
+            assert_eq!((&mut fut).await, (1, 2));
+            assert_eq!(fut.name(), "pair");
+            assert!(fut.is_terminated());
+            assert_eq!(
+                format!("{fut:?}"),
+                r#"BoxedCombinator { name: "pair", polls: 1, done: true }"#
+            );
+        });
+    }
+}
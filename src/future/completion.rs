@@ -0,0 +1,305 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// The output of a future, together with the index of the future which
+/// produced it.
+///
+/// Operations which report outputs as their futures complete, rather than in
+/// the order the futures were passed in, use this type to identify each
+/// output. Unlike a bare `(usize, T)` tuple its fields are named, which keeps
+/// their meaning when the value is serialized.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::future::Completion;
+///
+/// let completion = Completion::new(1, "hello");
+/// assert_eq!(completion.index, 1);
+/// assert_eq!(completion.map(str::len), Completion::new(1, 5));
+/// assert_eq!(<(usize, &str)>::from(completion), (1, "hello"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Completion<T> {
+    /// The index of the future which produced the output.
+    pub index: usize,
+    /// The output of the future.
+    pub output: T,
+}
+
+impl<T> Completion<T> {
+    /// Create a new `Completion` for the future at `index`.
+    pub fn new(index: usize, output: T) -> Self {
+        Self { index, output }
+    }
+
+    /// Consume the completion, returning the output.
+    pub fn into_output(self) -> T {
+        self.output
+    }
+
+    /// Map the output, keeping the index.
+    pub fn map<U, F>(self, f: F) -> Completion<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Completion::new(self.index, f(self.output))
+    }
+}
+
+impl<T> From<(usize, T)> for Completion<T> {
+    fn from((index, output): (usize, T)) -> Self {
+        Self::new(index, output)
+    }
+}
+
+impl<T> From<Completion<T>> for (usize, T) {
+    fn from(completion: Completion<T>) -> Self {
+        (completion.index, completion.output)
+    }
+}
+
+/// The error of a future or stream, together with the index of the member
+/// which failed.
+///
+/// This is the counterpart of [`Completion`] for futures which fail. Stream
+/// operations such as [`try_merge_tolerating`] use it for the errors of their
+/// streams as well, so it's displayed as the failure of a "member".
+///
+/// [`try_merge_tolerating`]: crate::stream::TryMergeTolerating::try_merge_tolerating
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::future::Failure;
+///
+/// let failure = Failure::new(2, "oh no");
+/// assert_eq!(failure.to_string(), "member 2 failed: oh no");
+/// assert_eq!(failure.into_error(), "oh no");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Failure<E> {
+    /// The index of the future which failed.
+    pub index: usize,
+    /// The error of the future.
+    pub error: E,
+}
+
+impl<E> Failure<E> {
+    /// Create a new `Failure` for the future at `index`.
+    pub fn new(index: usize, error: E) -> Self {
+        Self { index, error }
+    }
+
+    /// Consume the failure, returning the error.
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    /// Map the error, keeping the index.
+    pub fn map<U, F>(self, f: F) -> Failure<U>
+    where
+        F: FnOnce(E) -> U,
+    {
+        Failure::new(self.index, f(self.error))
+    }
+}
+
+impl<E> From<(usize, E)> for Failure<E> {
+    fn from((index, error): (usize, E)) -> Self {
+        Self::new(index, error)
+    }
+}
+
+impl<E> From<Failure<E>> for (usize, E) {
+    fn from(failure: Failure<E>) -> Self {
+        (failure.index, failure.error)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Failure<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "member {} failed: {}", self.index, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Error + 'static> Error for Failure<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Implement `Serialize` and `Deserialize` for a struct of an `index` and a
+/// single generic field. Both are (de)serialized as a struct with named
+/// fields, or as a sequence of the two fields.
+#[cfg(feature = "serde")]
+macro_rules! impl_serde {
+    ($Name:ident, $field:ident) => {
+        impl<T: serde::Serialize> serde::Serialize for $Name<T> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeStruct;
+
+                let mut state = serializer.serialize_struct(stringify!($Name), 2)?;
+                state.serialize_field("index", &self.index)?;
+                state.serialize_field(stringify!($field), &self.$field)?;
+                state.end()
+            }
+        }
+
+        impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for $Name<T> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                use core::marker::PhantomData;
+                use serde::de::{Error as _, MapAccess, SeqAccess, Visitor};
+
+                const FIELDS: &[&str] = &["index", stringify!($field)];
+
+                enum Field {
+                    Index,
+                    Value,
+                    Ignore,
+                }
+
+                impl<'de> serde::Deserialize<'de> for Field {
+                    fn deserialize<D: serde::Deserializer<'de>>(
+                        deserializer: D,
+                    ) -> Result<Self, D::Error> {
+                        struct FieldVisitor;
+
+                        impl<'de> Visitor<'de> for FieldVisitor {
+                            type Value = Field;
+
+                            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                                write!(f, "a field name")
+                            }
+
+                            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Field, E> {
+                                Ok(match v {
+                                    "index" => Field::Index,
+                                    stringify!($field) => Field::Value,
+                                    _ => Field::Ignore,
+                                })
+                            }
+                        }
+
+                        deserializer.deserialize_identifier(FieldVisitor)
+                    }
+                }
+
+                struct EnvelopeVisitor<T>(PhantomData<T>);
+
+                impl<'de, T: serde::Deserialize<'de>> Visitor<'de> for EnvelopeVisitor<T> {
+                    type Value = $Name<T>;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(f, "struct {}", stringify!($Name))
+                    }
+
+                    fn visit_seq<A: SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Self::Value, A::Error> {
+                        let index = seq
+                            .next_element()?
+                            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                        let value = seq
+                            .next_element()?
+                            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+                        Ok($Name::new(index, value))
+                    }
+
+                    fn visit_map<A: MapAccess<'de>>(
+                        self,
+                        mut map: A,
+                    ) -> Result<Self::Value, A::Error> {
+                        let mut index = None;
+                        let mut value = None;
+                        while let Some(field) = map.next_key()? {
+                            match field {
+                                Field::Index if index.is_some() => {
+                                    return Err(A::Error::duplicate_field("index"));
+                                }
+                                Field::Index => index = Some(map.next_value()?),
+                                Field::Value if value.is_some() => {
+                                    return Err(A::Error::duplicate_field(stringify!($field)));
+                                }
+                                Field::Value => value = Some(map.next_value()?),
+                                Field::Ignore => {
+                                    map.next_value::<serde::de::IgnoredAny>()?;
+                                }
+                            }
+                        }
+                        let index = index.ok_or_else(|| A::Error::missing_field("index"))?;
+                        let value =
+                            value.ok_or_else(|| A::Error::missing_field(stringify!($field)))?;
+                        Ok($Name::new(index, value))
+                    }
+                }
+
+                deserializer.deserialize_struct(
+                    stringify!($Name),
+                    FIELDS,
+                    EnvelopeVisitor(PhantomData),
+                )
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+impl_serde!(Completion, output);
+#[cfg(feature = "serde")]
+impl_serde!(Failure, error);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let completion = Completion::from((1, "hello"));
+        assert_eq!(completion, Completion::new(1, "hello"));
+        assert_eq!(completion.into_output(), "hello");
+        assert_eq!(<(usize, _)>::from(completion), (1, "hello"));
+        assert!(Completion::new(0, 9) < Completion::new(1, 0));
+
+        let failure = Failure::from((3, "oh no")).map(|err| err.len());
+        assert_eq!(failure, Failure::new(3, 5));
+        assert_eq!(<(usize, _)>::from(failure), (3, 5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn failure_source() {
+        use std::io;
+
+        let failure = Failure::new(1, io::Error::other("oh no"));
+        assert_eq!(failure.to_string(), "member 1 failed: oh no");
+        assert_eq!(failure.source().unwrap().to_string(), "oh no");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let completion = Completion::new(1, "hello");
+        let json = serde_json::to_string(&completion).unwrap();
+        assert_eq!(json, r#"{"index":1,"output":"hello"}"#);
+        assert_eq!(
+            serde_json::from_str::<Completion<&str>>(&json).unwrap(),
+            completion
+        );
+
+        let failure = Failure::new(2, 7u8);
+        let json = serde_json::to_string(&failure).unwrap();
+        assert_eq!(json, r#"{"index":2,"error":7}"#);
+        assert_eq!(serde_json::from_str::<Failure<u8>>(&json).unwrap(), failure);
+        assert_eq!(
+            serde_json::from_str::<Failure<u8>>("[2,7]").unwrap(),
+            failure
+        );
+
+        assert!(serde_json::from_str::<Failure<u8>>(r#"{"index":2}"#).is_err());
+        assert!(serde_json::from_str::<Completion<u8>>(r#"{"output":2}"#).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::future::FusedFuture;
+use pin_project::pin_project;
+
+/// A future which can safely be polled after it has completed.
+///
+/// The operations in this crate panic when they are polled again after
+/// returning `Poll::Ready`. Wrapping a future in `Fuse` guards against that:
+/// once the inner future has completed, every subsequent poll returns
+/// `Poll::Pending` without polling the inner future again.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::future::Fuse;
+/// use futures_lite::future::{block_on, poll_once};
+/// use std::future;
+///
+/// block_on(async {
+///     let mut fut = Fuse::new(future::ready(12));
+///     assert!(!fut.is_done());
+///     assert_eq!(poll_once(&mut fut).await, Some(12));
+///
+///     // Polling again does not panic, it just never resolves.
+///     assert!(fut.is_done());
+///     assert_eq!(poll_once(&mut fut).await, None);
+/// })
+/// ```
+#[derive(Debug, Clone)]
+#[pin_project]
+#[must_use = "futures do nothing unless polled or .awaited"]
+pub struct Fuse<F> {
+    #[pin]
+    future: F,
+    done: bool,
+}
+
+impl<F> Fuse<F> {
+    /// Wrap a future so it can be polled after completion.
+    pub fn new(future: F) -> Self {
+        Self {
+            future,
+            done: false,
+        }
+    }
+
+    /// Returns `true` if the inner future has completed.
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Consume the `Fuse`, returning the inner future.
+    pub fn into_inner(self) -> F {
+        self.future
+    }
+}
+
+impl<F: Future> Future for Fuse<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Pending;
+        }
+
+        match this.future.poll(cx) {
+            Poll::Ready(output) => {
+                *this.done = true;
+                Poll::Ready(output)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<F: Future> FusedFuture for Fuse<F> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::future::Join;
+    use core::pin::pin;
+    use futures_lite::future::{block_on, poll_once};
+
+    #[test]
+    fn repoll_after_join() {
+        block_on(async {
+            let mut fut = pin!(Fuse::new((async { 1 }, async { 2 }).join()));
+            assert_eq!(poll_once(fut.as_mut()).await, Some((1, 2)));
+            assert_eq!(poll_once(fut.as_mut()).await, None);
+            assert!(fut.is_terminated());
+        })
+    }
+}
@@ -9,6 +9,11 @@ use slab::Slab;
 
 use crate::utils::{PollState, PollVec, WakerVec};
 
+#[cfg(all(feature = "futures", feature = "std"))]
+mod sink;
+#[cfg(all(feature = "futures", feature = "std"))]
+pub use sink::{GroupSink, GroupStream};
+
 /// A growable group of futures which act as a single unit.
 ///
 /// # Example
@@ -57,7 +62,6 @@ use crate::utils::{PollState, PollVec, WakerVec};
 /// assert_eq!(out, 10);
 /// # });}
 /// ```
-
 #[must_use = "`FutureGroup` does nothing if not iterated over"]
 #[pin_project::pin_project]
 pub struct FutureGroup<F> {
@@ -249,6 +253,45 @@ impl<F> FutureGroup<F> {
     }
 }
 
+impl<F: Unpin> FutureGroup<F> {
+    /// Shrinks the capacity of the group as much as possible.
+    ///
+    /// Keys of futures which are still in the group remain valid. Because
+    /// keys are stable, the capacity can't shrink below the highest key still
+    /// in use.
+    ///
+    /// Shrinking may move futures around in memory, which is why this
+    /// requires futures to be `Unpin`. To use this with futures which aren't
+    /// `Unpin`, wrap them in `Box::pin` first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureGroup;
+    /// use futures_lite::{StreamExt};
+    /// use std::future;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = FutureGroup::new();
+    /// for n in 0..100 {
+    ///     group.insert(future::ready(n));
+    /// }
+    /// while group.next().await.is_some() {}
+    /// assert!(group.capacity() >= 100);
+    ///
+    /// group.shrink_to_fit();
+    /// assert_eq!(group.capacity(), 0);
+    /// # });
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.futures.shrink_to_fit();
+        let new_cap = self.futures.capacity();
+        self.wakers.resize(new_cap);
+        self.states.resize(new_cap);
+        self.capacity = new_cap;
+    }
+}
+
 impl<F: Future> FutureGroup<F> {
     /// Insert a new future into the group.
     ///
@@ -274,7 +317,7 @@ impl<F: Future> FutureGroup<F> {
 
         // Set the corresponding state
         self.states[index].set_pending();
-        self.wakers.readiness().set_ready(index);
+        self.wakers.readiness().set_ready_and_wake(index);
 
         Key(index)
     }
@@ -305,8 +348,7 @@ impl<F: Future> FutureGroup<F> {
 
         // Set the corresponding state
         this.states[index].set_pending();
-        let mut readiness = this.wakers.readiness();
-        readiness.set_ready(index);
+        this.wakers.readiness().set_ready_and_wake(index);
 
         key
     }
@@ -338,6 +380,110 @@ impl<F: Future> FutureGroup<F> {
     }
 }
 
+#[cfg(all(feature = "futures", feature = "std"))]
+impl<F: Future + Unpin> FutureGroup<F> {
+    /// Split the group into a sink which inserts futures, and a stream which
+    /// yields their outputs.
+    ///
+    /// The sink isn't ready to send while `capacity` futures are in flight,
+    /// and is woken once the stream has taken the output of one of them.
+    /// Futures which are already in the group count towards the capacity.
+    /// The stream ends once the sink has been closed or dropped, and all
+    /// futures in the group have completed.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `capacity` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::{SinkExt, StreamExt};
+    /// use futures_concurrency::future::FutureGroup;
+    /// use std::future;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let (mut sink, stream) = FutureGroup::new().split_sink(2);
+    /// sink.send(future::ready(1)).await.unwrap();
+    /// sink.send(future::ready(2)).await.unwrap();
+    /// drop(sink);
+    ///
+    /// let out: Vec<_> = stream.collect().await;
+    /// assert_eq!(out, [1, 2]);
+    /// # });
+    /// ```
+    pub fn split_sink(self, capacity: usize) -> (GroupSink<F>, GroupStream<F>) {
+        sink::split(self, capacity)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T: Send + 'static> FutureGroup<tokio::task::JoinHandle<T>> {
+    /// Spawn a future onto the current tokio runtime, and insert its
+    /// `JoinHandle` into the group.
+    ///
+    /// The group yields a `Result<T, JoinError>` for every task, so a task
+    /// which panicked or was aborted surfaces as an error.
+    ///
+    /// Dropping the group detaches the tasks which are still running rather
+    /// than aborting them.
+    ///
+    /// # Panics
+    ///
+    /// This panics if called from outside of a tokio runtime.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureGroup;
+    /// use futures_lite::StreamExt;
+    ///
+    /// # #[tokio::main] async fn main() {
+    /// let mut group = FutureGroup::new();
+    /// group.spawn(async { 2 });
+    /// group.spawn(async { 4 });
+    ///
+    /// let mut out = 0;
+    /// while let Some(num) = group.next().await {
+    ///     out += num.unwrap();
+    /// }
+    /// assert_eq!(out, 6);
+    /// # }
+    /// ```
+    pub fn spawn<Fut>(&mut self, future: Fut) -> Key
+    where
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        self.insert(tokio::task::spawn(future))
+    }
+
+    /// Spawn a future onto the runtime referenced by `handle`, and insert its
+    /// `JoinHandle` into the group.
+    ///
+    /// See [`FutureGroup::spawn`] for more.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureGroup;
+    /// use futures_lite::StreamExt;
+    ///
+    /// # #[tokio::main] async fn main() {
+    /// let handle = tokio::runtime::Handle::current();
+    /// let mut group = FutureGroup::new();
+    /// group.spawn_on(async { 2 }, &handle);
+    ///
+    /// assert_eq!(group.next().await.unwrap().unwrap(), 2);
+    /// # }
+    /// ```
+    pub fn spawn_on<Fut>(&mut self, future: Fut, handle: &tokio::runtime::Handle) -> Key
+    where
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        self.insert(handle.spawn(future))
+    }
+}
+
 impl<F: Future> FutureGroup<F> {
     fn poll_next_inner(
         self: Pin<&mut Self>,
@@ -424,7 +570,7 @@ impl<F: Future> Stream for FutureGroup<F> {
 impl<F: Future> Extend<F> for FutureGroup<F> {
     fn extend<T: IntoIterator<Item = F>>(&mut self, iter: T) {
         let iter = iter.into_iter();
-        let len = iter.size_hint().1.unwrap_or_default();
+        let len = iter.size_hint().0;
         self.reserve(len);
 
         for future in iter {
@@ -441,6 +587,30 @@ impl<F: Future> FromIterator<F> for FutureGroup<F> {
     }
 }
 
+/// Move the futures out of a `FuturesUnordered` into a new group.
+///
+/// Waker registrations of futures which were in flight are lost in the
+/// conversion, so every future will be polled once more by the group.
+#[cfg(feature = "futures")]
+impl<F: Future + Unpin> From<futures_util::stream::FuturesUnordered<F>> for FutureGroup<F> {
+    fn from(futures: futures_util::stream::FuturesUnordered<F>) -> Self {
+        futures.into_iter().collect()
+    }
+}
+
+/// Move the futures out of a group into a new `FuturesUnordered`.
+///
+/// Waker registrations of futures which were in flight are lost in the
+/// conversion, so every future will be polled once more by the
+/// `FuturesUnordered`.
+#[cfg(feature = "futures")]
+impl<F: Future + Unpin> From<FutureGroup<F>> for futures_util::stream::FuturesUnordered<F> {
+    fn from(mut group: FutureGroup<F>) -> Self {
+        group.keys.clear();
+        group.futures.drain().collect()
+    }
+}
+
 /// A key used to index into the `FutureGroup` type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Key(usize);
@@ -499,6 +669,106 @@ mod test {
         });
     }
 
+    #[test]
+    fn extend_mid_consumption() {
+        use alloc::boxed::Box;
+        use alloc::vec;
+        use core::pin::Pin;
+        use futures::channel::oneshot;
+
+        futures_lite::future::block_on(async {
+            let (send, receive) = oneshot::channel();
+            let first: Pin<Box<dyn Future<Output = usize>>> =
+                Box::pin(async { receive.await.unwrap() });
+            let group: FutureGroup<_> = vec![first].into_iter().collect();
+            let mut group = group.keyed();
+
+            // Poll the group once so the pending future registers its waker.
+            assert!(futures_lite::future::poll_once(group.next())
+                .await
+                .is_none());
+
+            // Extend the group between polls; the new futures must not
+            // collide with the pending one.
+            let more: [Pin<Box<dyn Future<Output = usize>>>; 2] =
+                [Box::pin(future::ready(2)), Box::pin(future::ready(3))];
+            group.extend(more);
+            assert_eq!(group.len(), 3);
+
+            let (a, _) = group.next().await.unwrap();
+            let (b, _) = group.next().await.unwrap();
+            send.send(1).unwrap();
+            let (c, _) = group.next().await.unwrap();
+            assert!(a != b && b != c && a != c);
+            assert!(group.next().await.is_none());
+        });
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::new();
+            let key = group.insert(future::ready(1));
+            let keys: Vec<_> = (0..100_000)
+                .map(|n| group.insert(future::ready(n)))
+                .collect();
+            for key in keys {
+                group.remove(key);
+            }
+            assert!(group.capacity() >= 100_000);
+
+            // Keys of members which are still present remain valid.
+            group.shrink_to_fit();
+            assert!(group.capacity() < 100_000);
+            assert!(group.contains_key(key));
+            assert_eq!(group.next().await, Some(1));
+            assert_eq!(group.next().await, None);
+        });
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn spawn_propagates_join_error() {
+        let mut group = FutureGroup::new();
+        group.spawn(async { 1 });
+        group.spawn_on(
+            async { panic!("oh no") },
+            &tokio::runtime::Handle::current(),
+        );
+
+        let mut ok = 0;
+        let mut err = 0;
+        while let Some(res) = group.next().await {
+            match res {
+                Ok(n) => ok += n,
+                Err(e) => {
+                    assert!(e.is_panic());
+                    err += 1;
+                }
+            }
+        }
+        assert_eq!((ok, err), (1, 1));
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn futures_unordered_round_trip() {
+        use futures::stream::FuturesUnordered;
+
+        futures_lite::future::block_on(async {
+            let mut unordered: FuturesUnordered<_> = (0..5).map(future::ready).collect();
+            assert!(unordered.next().await.is_some());
+
+            let mut group = FutureGroup::from(unordered);
+            assert_eq!(group.len(), 4);
+            assert!(group.next().await.is_some());
+
+            let unordered = FuturesUnordered::from(group);
+            assert_eq!(unordered.len(), 3);
+            assert_eq!(unordered.count().await, 3);
+        });
+    }
+
     #[test]
     fn capacity_grow_on_insert() {
         futures_lite::future::block_on(async {
@@ -0,0 +1,340 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use futures_core::{Future, Stream};
+use futures_util::sink::Sink;
+
+use super::FutureGroup;
+
+/// The state shared by a [`GroupSink`] and its [`GroupStream`].
+///
+/// The group itself is owned by the stream, and is never polled while the
+/// lock is held. The sink only queues futures for the stream to insert.
+struct Shared<F> {
+    /// Futures which have been sent, but not yet moved into the group.
+    queue: Vec<F>,
+    /// The number of futures in flight, whether queued or in the group.
+    len: usize,
+    capacity: usize,
+    /// The task waiting for the group to have room for more futures.
+    sink_waker: Option<Waker>,
+    /// The task polling the stream, which is woken when a future is sent.
+    stream_waker: Option<Waker>,
+    /// Whether the sink has been closed or dropped.
+    closed: bool,
+}
+
+impl<F> Shared<F> {
+    /// Lock the shared state.
+    ///
+    /// No user code runs while the lock is held, so it's only poisoned if
+    /// cloning a waker panicked. The state is still consistent in that case,
+    /// so the poison is ignored.
+    fn lock(this: &Mutex<Self>) -> MutexGuard<'_, Self> {
+        this.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Store `waker` in `slot`, unless it would wake the same task already.
+fn register(slot: &mut Option<Waker>, waker: &Waker) {
+    match slot {
+        Some(prev) if prev.will_wake(waker) => {}
+        Some(prev) => prev.clone_from(waker),
+        None => *slot = Some(waker.clone()),
+    }
+}
+
+/// Split a group into a sink and a stream sharing it.
+pub(super) fn split<F>(group: FutureGroup<F>, capacity: usize) -> (GroupSink<F>, GroupStream<F>) {
+    assert!(
+        capacity != 0,
+        "the capacity of a `GroupSink` must not be zero"
+    );
+    let shared = Arc::new(Mutex::new(Shared {
+        queue: Vec::new(),
+        len: group.len(),
+        capacity,
+        sink_waker: None,
+        stream_waker: None,
+        closed: false,
+    }));
+    let stream = GroupStream {
+        group,
+        shared: shared.clone(),
+    };
+    (GroupSink { shared }, stream)
+}
+
+/// A sink which inserts futures into a [`FutureGroup`], with a bound on the
+/// number of futures in flight.
+///
+/// This `struct` is created by the [`split_sink`] method on [`FutureGroup`].
+/// See its documentation for more.
+///
+/// [`split_sink`]: FutureGroup::split_sink
+#[must_use = "sinks do nothing unless polled"]
+pub struct GroupSink<F> {
+    shared: Arc<Mutex<Shared<F>>>,
+}
+
+impl<F> GroupSink<F> {
+    /// Mark the group as closed, and wake the stream so it can end once the
+    /// group is empty.
+    fn close(&self) {
+        let waker = {
+            let mut shared = Shared::lock(&self.shared);
+            shared.closed = true;
+            shared.stream_waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<F> fmt::Debug for GroupSink<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shared = Shared::lock(&self.shared);
+        f.debug_struct("GroupSink")
+            .field("len", &shared.len)
+            .field("capacity", &shared.capacity)
+            .finish()
+    }
+}
+
+impl<F: Future + Unpin> Sink<F> for GroupSink<F> {
+    type Error = Infallible;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut shared = Shared::lock(&self.shared);
+        if shared.len < shared.capacity {
+            return Poll::Ready(Ok(()));
+        }
+        // The stream wakes us once it has taken an output from the group.
+        register(&mut shared.sink_waker, cx.waker());
+        Poll::Pending
+    }
+
+    fn start_send(self: Pin<&mut Self>, future: F) -> Result<(), Self::Error> {
+        let waker = {
+            let mut shared = Shared::lock(&self.shared);
+            shared.queue.push(future);
+            shared.len += 1;
+            shared.stream_waker.take()
+        };
+        // The stream has to move the future into the group before it can be
+        // polled.
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Futures are part of the group as soon as they're sent.
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.close();
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<F> Drop for GroupSink<F> {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// A stream which yields the outputs of the futures sent into a
+/// [`GroupSink`].
+///
+/// This `struct` is created by the [`split_sink`] method on [`FutureGroup`].
+/// See its documentation for more.
+///
+/// [`split_sink`]: FutureGroup::split_sink
+#[must_use = "streams do nothing unless polled or .awaited"]
+pub struct GroupStream<F> {
+    group: FutureGroup<F>,
+    shared: Arc<Mutex<Shared<F>>>,
+}
+
+impl<F: Future> GroupStream<F> {
+    /// Move the futures which have been sent into the group, and register
+    /// the waker for later sends. Returns whether any futures were added,
+    /// and whether the sink has been closed.
+    fn drain(&mut self, waker: &Waker) -> (bool, bool) {
+        let mut shared = Shared::lock(&self.shared);
+        register(&mut shared.stream_waker, waker);
+        let queue = core::mem::take(&mut shared.queue);
+        let closed = shared.closed;
+        drop(shared);
+
+        let added = !queue.is_empty();
+        self.group.extend(queue);
+        (added, closed)
+    }
+}
+
+impl<F> fmt::Debug for GroupStream<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shared = Shared::lock(&self.shared);
+        f.debug_struct("GroupStream")
+            .field("len", &shared.len)
+            .field("closed", &shared.closed)
+            .finish()
+    }
+}
+
+impl<F: Future + Unpin> Stream for GroupStream<F> {
+    type Item = F::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        this.drain(cx.waker());
+        loop {
+            match Pin::new(&mut this.group).poll_next(cx) {
+                Poll::Ready(Some(output)) => {
+                    // A slot has been freed, so the sink can send another
+                    // future.
+                    let waker = {
+                        let mut shared = Shared::lock(&this.shared);
+                        shared.len -= 1;
+                        shared.sink_waker.take()
+                    };
+                    if let Some(waker) = waker {
+                        waker.wake();
+                    }
+                    return Poll::Ready(Some(output));
+                }
+                // The futures may have sent more futures while they were
+                // polled.
+                Poll::Ready(None) => match this.drain(cx.waker()) {
+                    (true, _) => {}
+                    (false, true) => return Poll::Ready(None),
+                    (false, false) => return Poll::Pending,
+                },
+                Poll::Pending => {
+                    if !this.drain(cx.waker()).0 {
+                        return Poll::Pending;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::{step_stream, CountingWaker, ScriptedFuture, Step};
+    use core::future;
+    use futures::SinkExt;
+    use futures_lite::StreamExt;
+
+    #[test]
+    fn backpressure() {
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, _) = ScriptedFuture::new([Step::WakeLater]);
+        let (mut sink, mut stream) = FutureGroup::new().split_sink(2);
+        let sink_waker = CountingWaker::new();
+        let stream_waker = CountingWaker::new();
+        let mut sink_cx = Context::from_waker(sink_waker.waker());
+
+        assert!(Pin::new(&mut sink).poll_ready(&mut sink_cx).is_ready());
+        Pin::new(&mut sink).start_send(a).unwrap();
+        assert!(Pin::new(&mut sink).poll_ready(&mut sink_cx).is_ready());
+        Pin::new(&mut sink).start_send(b).unwrap();
+
+        // The group is full.
+        assert!(Pin::new(&mut sink).poll_ready(&mut sink_cx).is_pending());
+        assert_eq!(
+            step_stream(Pin::new(&mut stream), &stream_waker),
+            Poll::Pending
+        );
+        assert_eq!(sink_waker.wakes(), 0);
+
+        // Completing a future frees a slot, and wakes the sink.
+        assert!(a_handle.wake());
+        assert_eq!(
+            step_stream(Pin::new(&mut stream), &stream_waker),
+            Poll::Ready(Some(1))
+        );
+        assert_eq!(sink_waker.wakes(), 1);
+        assert!(Pin::new(&mut sink).poll_ready(&mut sink_cx).is_ready());
+    }
+
+    #[test]
+    fn send_wakes_empty_stream() {
+        let (mut sink, mut stream) = FutureGroup::new().split_sink(1);
+        let waker = CountingWaker::new();
+
+        assert_eq!(step_stream(Pin::new(&mut stream), &waker), Poll::Pending);
+        futures_lite::future::block_on(sink.send(future::ready(1))).unwrap();
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(
+            step_stream(Pin::new(&mut stream), &waker),
+            Poll::Ready(Some(1))
+        );
+
+        // The stream ends once the sink is dropped and the group is empty.
+        assert_eq!(step_stream(Pin::new(&mut stream), &waker), Poll::Pending);
+        drop(sink);
+        assert_eq!(waker.wakes(), 2);
+        assert_eq!(
+            step_stream(Pin::new(&mut stream), &waker),
+            Poll::Ready(None)
+        );
+    }
+
+    #[test]
+    fn concurrent_pipeline() {
+        futures_lite::future::block_on(async {
+            let (mut sink, stream) = FutureGroup::new().split_sink(3);
+            let produce = async move {
+                for n in 0..20 {
+                    sink.send(Box::pin(async move {
+                        futures_lite::future::yield_now().await;
+                        n
+                    }))
+                    .await
+                    .unwrap();
+                }
+            };
+            let consume = stream.fold(0, |acc, n| acc + n);
+            let ((), sum) = futures_lite::future::zip(produce, consume).await;
+            assert_eq!(sum, (0..20).sum::<i32>());
+        });
+    }
+
+    #[test]
+    fn member_sends_into_sink() {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+
+        type Member = Pin<Box<dyn Future<Output = i32>>>;
+
+        let slot = Rc::new(RefCell::new(None));
+        let mut group = FutureGroup::<Member>::new();
+        let member_slot = slot.clone();
+        group.insert(Box::pin(async move {
+            // The stream is polling this future while it sends, so sending
+            // must not wait on the group.
+            let mut sink: GroupSink<Member> = member_slot.borrow_mut().take().unwrap();
+            sink.send(Box::pin(async { 2 })).await.unwrap();
+            1
+        }));
+        let (sink, stream) = group.split_sink(2);
+        *slot.borrow_mut() = Some(sink);
+
+        let mut out: Vec<_> = futures_lite::future::block_on(stream.collect());
+        out.sort_unstable();
+        assert_eq!(out, [1, 2]);
+    }
+}
@@ -1,10 +1,16 @@
 use crate::future::Join;
 use crate::future::Race;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 use core::future::IntoFuture;
+#[cfg(feature = "alloc")]
+use core::pin::Pin;
 use futures_core::Future;
 
 use super::join::tuple::Join2;
 use super::race::tuple::Race2;
+#[cfg(feature = "alloc")]
+use super::BoxedCombinator;
 use super::WaitUntil;
 
 /// An extension trait for the `Future` trait.
@@ -58,6 +64,98 @@ pub trait FutureExt: Future {
     {
         WaitUntil::new(self, deadline.into_future())
     }
+
+    /// Box and pin the future, erasing its type.
+    ///
+    /// This makes it possible to store futures of different types in a single
+    /// `Vec`, without having to cast each `Box::pin` to a trait object.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let futs = vec![
+    ///         async { 1 }.boxed_concurrency(),
+    ///         async { 2 }.boxed_concurrency(),
+    ///     ];
+    ///     assert_eq!(futs.join().await, vec![1, 2]);
+    /// });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed_concurrency<'a>(self) -> Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>
+    where
+        Self: Sized + Send + 'a,
+    {
+        Box::pin(self)
+    }
+
+    /// Box and pin the future, erasing its type, without requiring it to be
+    /// `Send`.
+    ///
+    /// This is the counterpart of [`boxed_concurrency`] for futures which
+    /// hold `!Send` state, such as an `Rc`, and run on a single-threaded
+    /// executor.
+    ///
+    /// [`boxed_concurrency`]: FutureExt::boxed_concurrency
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use std::rc::Rc;
+    ///
+    /// block_on(async {
+    ///     let shared = Rc::new(1);
+    ///     let futs = vec![
+    ///         async { *shared }.boxed_concurrency_local(),
+    ///         async { 2 }.boxed_concurrency_local(),
+    ///     ];
+    ///     assert_eq!(futs.join().await, vec![1, 2]);
+    /// });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed_concurrency_local<'a>(self) -> Pin<Box<dyn Future<Output = Self::Output> + 'a>>
+    where
+        Self: Sized + 'a,
+    {
+        Box::pin(self)
+    }
+
+    /// Box and pin the future, erasing its type while keeping a name for its
+    /// `Debug` output.
+    ///
+    /// This is useful to store operations in a struct field without spelling
+    /// out their types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::future::BoxedCombinator;
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    ///
+    /// struct State {
+    ///     fetch: BoxedCombinator<(u8, u8)>,
+    /// }
+    ///
+    /// block_on(async {
+    ///     let fetch = (async { 1 }, async { 2 }).join().boxed_named("fetch");
+    ///     let state = State { fetch };
+    ///     assert!(format!("{:?}", state.fetch).contains("fetch"));
+    ///     assert_eq!(state.fetch.await, (1, 2));
+    /// });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed_named(self, name: &'static str) -> BoxedCombinator<Self::Output>
+    where
+        Self: Sized + Send + 'static,
+    {
+        BoxedCombinator::new(self, name)
+    }
 }
 
 impl<F1> FutureExt for F1
@@ -6,7 +6,7 @@ use core::future::{Future, IntoFuture};
 use core::mem::ManuallyDrop;
 use core::ops::DerefMut;
 use core::pin::Pin;
-use core::task::{Context, Poll};
+use core::task::{ready, Context, Poll};
 
 use pin_project::{pin_project, pinned_drop};
 
@@ -56,42 +56,37 @@ where
     }
 }
 
-impl<Fut, const N: usize> JoinTrait for [Fut; N]
-where
-    Fut: IntoFuture,
-{
-    type Output = [Fut::Output; N];
-    type Future = Join<Fut::IntoFuture, N>;
-
-    #[inline]
-    fn join(self) -> Self::Future {
-        Join::new(self.map(IntoFuture::into_future))
-    }
-}
-
-impl<Fut, const N: usize> fmt::Debug for Join<Fut, N>
-where
-    Fut: Future + fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.state.iter()).finish()
-    }
-}
-
-impl<Fut, const N: usize> Future for Join<Fut, N>
+impl<Fut, const N: usize> Join<Fut, N>
 where
     Fut: Future,
 {
-    type Output = [Fut::Output; N];
-
-    #[inline]
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    /// Poll the futures which are ready to make progress, without resolving
+    /// the join.
+    ///
+    /// The outputs of completed futures are buffered inside the join. This
+    /// returns `Poll::Ready(())` once all futures have completed, after which
+    /// the next call to `poll` returns the outputs immediately. Until then,
+    /// the waker of `cx` is woken whenever more progress can be made. Once
+    /// the outputs have been taken, this keeps returning `Poll::Ready(())`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::{self, poll_fn};
+    /// use std::pin::pin;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut join = pin!([future::ready(1), future::ready(2)].join());
+    /// poll_fn(|cx| join.as_mut().poll_progress(cx)).await;
+    /// assert_eq!(join.await, [1, 2]);
+    /// # });
+    /// ```
+    pub fn poll_progress(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
         let this = self.project();
-
-        assert!(
-            !*this.consumed,
-            "Futures must not be polled after completing"
-        );
+        if *this.consumed {
+            return Poll::Ready(());
+        }
 
         let mut readiness = this.wakers.readiness();
         readiness.set_waker(cx.waker());
@@ -132,25 +127,70 @@ where
 
         // Check whether we're all done now or need to keep going.
         if *this.pending == 0 {
-            // Mark all data as "consumed" before we take it
-            *this.consumed = true;
-            for state in this.state.iter_mut() {
-                debug_assert!(
-                    state.is_ready(),
-                    "Future should have reached a `Ready` state"
-                );
-                state.set_none();
-            }
-
-            // SAFETY: we've checked with the state that all of our outputs have been
-            // filled, which means we're ready to take the data and assume it's initialized.
-            Poll::Ready(unsafe { this.items.take() })
+            Poll::Ready(())
         } else {
             Poll::Pending
         }
     }
 }
 
+impl<Fut, const N: usize> JoinTrait for [Fut; N]
+where
+    Fut: IntoFuture,
+{
+    type Output = [Fut::Output; N];
+    type Future = Join<Fut::IntoFuture, N>;
+
+    #[inline]
+    fn join(self) -> Self::Future {
+        Join::new(self.map(IntoFuture::into_future))
+    }
+}
+
+impl<Fut, const N: usize> fmt::Debug for Join<Fut, N>
+where
+    Fut: Future + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.state.iter()).finish()
+    }
+}
+
+impl<Fut, const N: usize> Future for Join<Fut, N>
+where
+    Fut: Future,
+{
+    type Output = [Fut::Output; N];
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        debug_assert!(
+            !self.consumed,
+            "Futures must not be polled after completing"
+        );
+        if self.consumed {
+            return Poll::Pending;
+        }
+
+        ready!(self.as_mut().poll_progress(cx));
+        let this = self.project();
+
+        // Mark all data as "consumed" before we take it
+        *this.consumed = true;
+        for state in this.state.iter_mut() {
+            debug_assert!(
+                state.is_ready(),
+                "Future should have reached a `Ready` state"
+            );
+            state.set_none();
+        }
+
+        // SAFETY: we've checked with the state that all of our outputs have been
+        // filled, which means we're ready to take the data and assume it's initialized.
+        Poll::Ready(unsafe { this.items.take() })
+    }
+}
+
 /// Drop the already initialized values on cancellation.
 #[pinned_drop]
 impl<Fut, const N: usize> PinnedDrop for Join<Fut, N>
@@ -200,20 +240,40 @@ mod test {
     }
 
     #[test]
-    #[cfg(feature = "alloc")]
+    #[cfg(feature = "std")]
     fn debug() {
-        use crate::utils::DummyWaker;
+        use crate::test_utils::{step, CountingWaker};
         use alloc::format;
-        use alloc::sync::Arc;
-        use core::task::Context;
 
         let mut fut = [future::ready("hello"), future::ready("world")].join();
         assert_eq!(format!("{:?}", fut), "[Pending, Pending]");
         let mut fut = Pin::new(&mut fut);
 
-        let waker = Arc::new(DummyWaker()).into();
-        let mut cx = Context::from_waker(&waker);
-        let _ = fut.as_mut().poll(&mut cx);
+        let _ = step(fut.as_mut(), &CountingWaker::new());
         assert_eq!(format!("{:?}", fut), "[None, None]");
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn poll_progress_until_complete() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let waker = CountingWaker::new();
+        let mut cx = Context::from_waker(waker.waker());
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(2)]);
+        let mut join = pin!([a, b].join());
+
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Pending);
+        assert!(a_handle.wake());
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+        assert_eq!(a_handle.polls(), 2);
+
+        // The outputs are returned by the final poll, without polling again.
+        assert_eq!(step(join.as_mut(), &waker), Poll::Ready([1, 2]));
+        assert_eq!(a_handle.polls(), 2);
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+    }
 }
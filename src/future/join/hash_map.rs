@@ -0,0 +1,176 @@
+use super::vec::Join as VecJoin;
+use super::Join as JoinTrait;
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::hash::{BuildHasher, Hash};
+use core::mem;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use std::collections::HashMap;
+
+use pin_project::pin_project;
+
+/// A future which waits for all futures in a `HashMap` to complete.
+///
+/// This `struct` is created by the [`join`] method on the [`Join`] trait. See
+/// its documentation for more.
+///
+/// [`join`]: crate::future::Join::join
+/// [`Join`]: crate::future::Join
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct Join<K, Fut, S>
+where
+    Fut: Future,
+{
+    /// The keys of the futures, in the order in which they're joined.
+    keys: Vec<K>,
+    /// The hasher of the input map, which is reused by the output map.
+    hasher: S,
+    capacity: usize,
+    #[pin]
+    join: VecJoin<Fut>,
+}
+
+impl<K, Fut, S> fmt::Debug for Join<K, Fut, S>
+where
+    K: fmt::Debug,
+    Fut: Future + fmt::Debug,
+    Fut::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Join")
+            .field("keys", &self.keys)
+            .field("futures", &self.join)
+            .finish()
+    }
+}
+
+impl<K, Fut, S> Future for Join<K, Fut, S>
+where
+    K: Eq + Hash,
+    Fut: Future,
+    S: BuildHasher + Clone,
+{
+    type Output = HashMap<K, Fut::Output, S>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let outputs = ready!(this.join.poll(cx));
+
+        let mut map = HashMap::with_capacity_and_hasher(*this.capacity, this.hasher.clone());
+        map.extend(mem::take(this.keys).into_iter().zip(outputs));
+        Poll::Ready(map)
+    }
+}
+
+/// Wait for all futures in a map to complete, keeping the output of each
+/// future under its key.
+///
+/// The output map uses a clone of the hasher of the input map, and is
+/// allocated with the same capacity.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use std::collections::HashMap;
+/// use std::future;
+///
+/// # futures_lite::future::block_on(async {
+/// let sizes = HashMap::from([
+///     ("nori", future::ready(3)),
+///     ("chashu", future::ready(5)),
+/// ]);
+/// let sizes = sizes.join().await;
+/// assert_eq!(sizes["nori"], 3);
+/// assert_eq!(sizes["chashu"], 5);
+/// # });
+/// ```
+impl<K, Fut, S> JoinTrait for HashMap<K, Fut, S>
+where
+    K: Eq + Hash,
+    Fut: IntoFuture,
+    S: BuildHasher + Clone,
+{
+    type Output = HashMap<K, Fut::Output, S>;
+    type Future = Join<K, Fut::IntoFuture, S>;
+
+    fn join(self) -> Self::Future {
+        let hasher = self.hasher().clone();
+        let capacity = self.capacity();
+        let (keys, futures) = self
+            .into_iter()
+            .map(|(key, fut)| (key, fut.into_future()))
+            .unzip();
+        Join {
+            keys,
+            hasher,
+            capacity,
+            join: VecJoin::new(futures),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future;
+    use std::collections::hash_map::RandomState;
+
+    /// A hasher which can be told apart from other instances.
+    #[derive(Clone)]
+    struct TaggedState {
+        tag: u8,
+        inner: RandomState,
+    }
+
+    impl BuildHasher for TaggedState {
+        type Hasher = <RandomState as BuildHasher>::Hasher;
+
+        fn build_hasher(&self) -> Self::Hasher {
+            self.inner.build_hasher()
+        }
+    }
+
+    #[test]
+    fn keeps_keys() {
+        futures_lite::future::block_on(async {
+            let futures: HashMap<_, _> = (0..8).map(|n| (n, future::ready(n * 2))).collect();
+            let outputs = futures.join().await;
+            assert_eq!(outputs.len(), 8);
+            for (key, output) in outputs {
+                assert_eq!(output, key * 2);
+            }
+        });
+    }
+
+    #[test]
+    fn reuses_hasher_and_capacity() {
+        futures_lite::future::block_on(async {
+            let hasher = TaggedState {
+                tag: 7,
+                inner: RandomState::new(),
+            };
+            let mut futures = HashMap::with_capacity_and_hasher(32, hasher);
+            futures.insert("a", future::ready(1));
+            futures.insert("b", future::ready(2));
+            let capacity = futures.capacity();
+
+            let outputs = futures.join().await;
+            assert_eq!(outputs.hasher().tag, 7);
+            assert!(outputs.capacity() >= capacity);
+            assert_eq!(outputs["a"], 1);
+            assert_eq!(outputs["b"], 2);
+        });
+    }
+
+    #[test]
+    fn empty() {
+        futures_lite::future::block_on(async {
+            let futures: HashMap<u8, future::Ready<u8>> = HashMap::new();
+            assert!(futures.join().await.is_empty());
+        });
+    }
+}
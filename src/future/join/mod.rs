@@ -1,6 +1,8 @@
 use core::future::Future;
 
 pub(crate) mod array;
+#[cfg(feature = "std")]
+pub(crate) mod hash_map;
 pub(crate) mod tuple;
 #[cfg(feature = "alloc")]
 pub(crate) mod vec;
@@ -9,6 +11,24 @@ pub(crate) mod vec;
 ///
 /// Awaits multiple futures simultaneously, returning the output of the futures
 /// in the same container type they were created once all complete.
+///
+/// # Drop order
+///
+/// When a join over a tuple is dropped before it completes, the outputs of
+/// the futures which have already completed are dropped first, followed by
+/// the futures which are still pending, both in the order of the tuple.
+///
+/// # Implementing for other containers
+///
+/// This trait is not sealed, and can be implemented for containers defined
+/// outside of this crate. The returned future must wait for every future in
+/// the container, and yield their outputs in the same order as the futures
+/// were stored. Rather than writing a poll loop by hand, implementations can
+/// collect their futures into a `Vec` and return [`vec::Join`] through
+/// [`vec::Join::new`].
+///
+/// [`vec::Join`]: crate::vec::Join
+/// [`vec::Join::new`]: crate::vec::Join::new
 pub trait Join {
     /// The resulting output type.
     type Output;
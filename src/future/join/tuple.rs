@@ -1,5 +1,5 @@
 use super::Join as JoinTrait;
-use crate::utils::{PollArray, WakerArray};
+use crate::utils::{PollArray, Trace, WakerArray};
 
 use core::fmt::{self, Debug};
 use core::future::{Future, IntoFuture};
@@ -17,7 +17,7 @@ use pin_project::{pin_project, pinned_drop};
 //
 // This is implemented as a tt-muncher of the future name `$($F:ident)`
 // and the future index `$($rest)`, taking advantage that we only support
-// tuples up to  12 elements
+// tuples up to 16 elements
 //
 // # References
 // TT Muncher: https://veykril.github.io/tlborm/decl-macros/patterns/tt-muncher.html
@@ -26,13 +26,16 @@ macro_rules! unsafe_poll {
     (@inner $iteration:ident, $this:ident, $futures:ident, $cx:ident, $fut_name:ident $($F:ident)* | $fut_idx:tt $($rest:tt)*) => {
         if $fut_idx == $iteration {
 
-            if let Poll::Ready(value) = unsafe {
+            let poll = unsafe {
                 $futures.$fut_name.as_mut()
                     .map_unchecked_mut(|t| t.deref_mut())
                     .poll(&mut $cx)
-            } {
+            };
+            $this.trace.polled($fut_idx, poll.is_ready());
+            if let Poll::Ready(value) = poll {
                 $this.outputs.$fut_idx.write(value);
                 *$this.completed += 1;
+                $this.trace.completed($fut_idx, *$this.completed);
                 $this.state[$fut_idx].set_ready();
                 // SAFETY: the future state has been changed to "ready" which
                 // means we'll no longer poll the future, so it's safe to drop
@@ -47,7 +50,7 @@ macro_rules! unsafe_poll {
 
     // macro start
     ($iteration:ident, $this:ident, $futures:ident, $cx:ident, $LEN:ident, $($F:ident,)+) => {
-        unsafe_poll!(@inner $iteration, $this, $futures, $cx, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11);
+        unsafe_poll!(@inner $iteration, $this, $futures, $cx, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15);
     };
 }
 
@@ -69,7 +72,7 @@ macro_rules! drop_initialized_values {
 
     // macro start
     ($($outs:ident,)+ | $states:expr) => {
-        drop_initialized_values!(@drop $($outs,)+ | $states, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11,);
+        drop_initialized_values!(@drop $($outs,)+ | $states, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,);
     };
 }
 
@@ -92,7 +95,7 @@ macro_rules! drop_pending_futures {
 
     // macro start
     ($states:ident, $futures:ident, $($F:ident,)+) => {
-        drop_pending_futures!(@inner $states, $futures, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11);
+        drop_pending_futures!(@inner $states, $futures, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15);
     };
 }
 
@@ -107,6 +110,7 @@ macro_rules! impl_join_tuple {
         /// [`Join`]: crate::future::Join
         #[must_use = "futures do nothing unless you `.await` or poll them"]
         #[allow(non_snake_case)]
+        #[derive(Clone)]
         pub struct $StructName {}
 
         impl fmt::Debug for $StructName {
@@ -168,6 +172,19 @@ macro_rules! impl_join_tuple {
             state: PollArray<{$mod_name::LEN}>,
             wakers: WakerArray<{$mod_name::LEN}>,
             completed: usize,
+            trace: Trace,
+        }
+
+        impl<$($F: Future),+> $StructName<$($F),+> {
+            /// Name the span in which this join is traced, so its events can
+            /// be told apart from those of other joins.
+            ///
+            /// This requires the `tracing` feature.
+            #[cfg(feature = "tracing")]
+            pub fn named(self, name: &str) -> Self {
+                self.trace.set_name(name);
+                self
+            }
         }
 
         impl<$($F),+> Debug for $StructName<$($F),+>
@@ -193,13 +210,16 @@ macro_rules! impl_join_tuple {
                 const LEN: usize = $mod_name::LEN;
 
                 let mut this = self.project();
-                let all_completed = !(*this.completed == LEN);
-                assert!(all_completed, "Futures must not be polled after completing");
+                debug_assert!(*this.completed != LEN, "Futures must not be polled after completing");
+                if *this.completed == LEN {
+                    return Poll::Pending;
+                }
 
                 let mut futures = this.futures.project();
 
                 let mut readiness = this.wakers.readiness();
                 readiness.set_waker(cx.waker());
+                readiness.set_trace(this.trace);
 
                 for index in 0..LEN {
                     if !readiness.any_ready() {
@@ -271,6 +291,7 @@ macro_rules! impl_join_tuple {
                     outputs: ($(MaybeUninit::<$F::Output>::uninit(),)+),
                     wakers: WakerArray::new(),
                     completed: 0,
+                    trace: Trace::new("join", $mod_name::LEN),
                 }
             }
         }
@@ -290,6 +311,14 @@ impl_join_tuple! { join9 Join9 A B C D E F G H I }
 impl_join_tuple! { join10 Join10 A B C D E F G H I J }
 impl_join_tuple! { join11 Join11 A B C D E F G H I J K }
 impl_join_tuple! { join12 Join12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join13 Join13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join14 Join14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join15 Join15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join16 Join16 A B C D E F G H I J K L M N O P }
 
 #[cfg(test)]
 mod test {
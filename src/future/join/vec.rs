@@ -1,5 +1,5 @@
 use super::Join as JoinTrait;
-use crate::utils::{FutureVec, OutputVec, PollVec, WakerVec};
+use crate::utils::{FutureVec, OutputVec, PollVec, Trace, WakerVec};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
@@ -9,7 +9,8 @@ use core::future::{Future, IntoFuture};
 use core::mem::ManuallyDrop;
 use core::ops::DerefMut;
 use core::pin::Pin;
-use core::task::{Context, Poll};
+use core::ptr;
+use core::task::{ready, Context, Poll};
 
 use pin_project::{pin_project, pinned_drop};
 
@@ -31,6 +32,7 @@ where
     items: OutputVec<<Fut as Future>::Output>,
     wakers: WakerVec,
     state: PollVec,
+    trace: Trace,
     #[pin]
     futures: FutureVec<Fut>,
 }
@@ -39,56 +41,208 @@ impl<Fut> Join<Fut>
 where
     Fut: Future,
 {
-    pub(crate) fn new(futures: Vec<Fut>) -> Self {
+    /// Create a new future which waits for all `futures` to complete.
+    ///
+    /// This is equivalent to calling [`join`] on the vector, and is intended
+    /// for implementing [`Join`] for other containers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::vec::Join;
+    /// use std::future;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let outputs = Join::new(vec![future::ready(1), future::ready(2)]).await;
+    /// assert_eq!(outputs, vec![1, 2]);
+    /// # });
+    /// ```
+    ///
+    /// [`join`]: crate::future::Join::join
+    /// [`Join`]: crate::future::Join
+    pub fn new(futures: Vec<Fut>) -> Self {
         let len = futures.len();
+        // A single future is polled with the caller's waker directly, so its
+        // wakers are never allocated.
+        let wakers = match len {
+            1 => WakerVec::lazy(len),
+            _ => WakerVec::new(len),
+        };
         Join {
             consumed: false,
             pending: len,
             items: OutputVec::uninit(len),
-            wakers: WakerVec::new(len),
+            wakers,
             state: PollVec::new_pending(len),
+            trace: Trace::new("join", len),
             futures: FutureVec::new(futures),
         }
     }
+
+    /// Name the span in which this join is traced, so its events can be told
+    /// apart from those of other joins.
+    ///
+    /// This requires the `tracing` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let join = vec![future::ready(1), future::ready(2)].join();
+    /// let outputs = join.named("startup-join").await;
+    /// assert_eq!(outputs, vec![1, 2]);
+    /// # });
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn named(self, name: &str) -> Self {
+        self.trace.set_name(name);
+        self
+    }
 }
 
-impl<Fut> JoinTrait for Vec<Fut>
+impl<Fut> Join<Fut>
 where
-    Fut: IntoFuture,
+    Fut: Future,
 {
-    type Output = Vec<Fut::Output>;
-    type Future = Join<Fut::IntoFuture>;
+    /// Create a new future which waits for all futures in `parts` to
+    /// complete, reusing the allocations of a previous join.
+    ///
+    /// The futures are the ones which were pushed into
+    /// [`JoinParts::futures_mut`]. Only the outputs are allocated anew, as
+    /// they're returned when the join completes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::vec::{Join, JoinParts};
+    /// use std::future::{self, Ready};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut parts = JoinParts::<Ready<usize>>::default();
+    /// for batch in 0..3 {
+    ///     parts.futures_mut().extend((0..4).map(|n| future::ready(batch * n)));
+    ///     let mut join = Join::from_parts(parts);
+    ///     let outputs = (&mut join).await;
+    ///     assert_eq!(outputs, [0, batch, batch * 2, batch * 3]);
+    ///     parts = join.into_parts();
+    /// }
+    /// # });
+    /// ```
+    pub fn from_parts(parts: JoinParts<Fut>) -> Self {
+        let JoinParts {
+            futures,
+            mut wakers,
+            mut state,
+        } = parts;
+        let len = futures.len();
 
-    fn join(self) -> Self::Future {
-        Join::new(self.into_iter().map(IntoFuture::into_future).collect())
+        // Clear any state left behind by the previous join.
+        wakers.resize(len);
+        wakers.readiness().set_all_ready();
+        state.resize(len);
+        state.set_all_pending();
+
+        Join {
+            consumed: false,
+            pending: len,
+            items: OutputVec::uninit(len),
+            wakers,
+            state,
+            trace: Trace::new("join", len),
+            futures: FutureVec::new(futures),
+        }
     }
-}
 
-impl<Fut> fmt::Debug for Join<Fut>
-where
-    Fut: Future + fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.state.iter()).finish()
+    /// Take apart a completed join, so that its allocations can be reused by
+    /// [`Join::from_parts`].
+    ///
+    /// # Panics
+    ///
+    /// This panics if the join hasn't completed yet.
+    pub fn into_parts(self) -> JoinParts<Fut> {
+        assert!(
+            self.consumed,
+            "cannot take apart a `Join` which hasn't completed"
+        );
+        let this = ManuallyDrop::new(self);
+        // SAFETY: every field is read exactly once, and `this` is never
+        // dropped. The join has completed, so all futures have been dropped
+        // and all outputs have been taken.
+        unsafe {
+            drop(ptr::read(&this.items));
+            // The span is only dropped with the `tracing` feature.
+            #[allow(clippy::drop_non_drop)]
+            drop(ptr::read(&this.trace));
+            JoinParts {
+                futures: ptr::read(&this.futures).into_empty_vec(),
+                wakers: ptr::read(&this.wakers),
+                state: ptr::read(&this.state),
+            }
+        }
     }
 }
 
-impl<Fut> Future for Join<Fut>
+impl<Fut> Join<Fut>
 where
     Fut: Future,
 {
-    type Output = Vec<Fut::Output>;
-
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    /// Poll the futures which are ready to make progress, without resolving
+    /// the join.
+    ///
+    /// The outputs of completed futures are buffered inside the join. This
+    /// returns `Poll::Ready(())` once all futures have completed, after which
+    /// the next call to `poll` returns the outputs immediately. Until then,
+    /// the waker of `cx` is woken whenever more progress can be made. Once
+    /// the outputs have been taken, this keeps returning `Poll::Ready(())`.
+    ///
+    /// This makes it possible to drive a join which is stored in a larger
+    /// state machine, without committing to `.await` it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::{self, poll_fn};
+    /// use std::pin::pin;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut join = pin!(vec![future::ready(1), future::ready(2)].join());
+    /// poll_fn(|cx| join.as_mut().poll_progress(cx)).await;
+    /// assert_eq!(join.await, vec![1, 2]);
+    /// # });
+    /// ```
+    pub fn poll_progress(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
         let mut this = self.project();
+        if *this.consumed {
+            return Poll::Ready(());
+        }
 
-        assert!(
-            !*this.consumed,
-            "Futures must not be polled after completing"
-        );
+        // A single future doesn't need to be told apart from any others, so
+        // it's polled with the caller's waker directly.
+        if this.state.len() == 1 {
+            if this.state[0].is_pending() {
+                let mut fut = this.futures.as_mut().iter().next().unwrap();
+                // SAFETY: the future's state is "pending", so it's safe to poll
+                let poll = unsafe { fut.as_mut().map_unchecked_mut(|t| t.deref_mut()).poll(cx) };
+                this.trace.polled(0, poll.is_ready());
+                let value = ready!(poll);
+                this.items.write(0, value);
+                this.state[0].set_ready();
+                *this.pending -= 1;
+                this.trace.completed(0, 1);
+                // SAFETY: the future state has been changed to "ready" which
+                // means we'll no longer poll the future, so it's safe to drop
+                unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
+            }
+            return Poll::Ready(());
+        }
 
         let mut readiness = this.wakers.readiness();
         readiness.set_waker(cx.waker());
+        readiness.set_trace(this.trace);
         if *this.pending != 0 && !readiness.any_ready() {
             // Nothing is ready yet
             return Poll::Pending;
@@ -108,14 +262,17 @@ where
 
                 // Poll the future
                 // SAFETY: the future's state was "pending", so it's safe to poll
-                if let Poll::Ready(value) = unsafe {
+                let poll = unsafe {
                     fut.as_mut()
                         .map_unchecked_mut(|t| t.deref_mut())
                         .poll(&mut cx)
-                } {
+                };
+                this.trace.polled(i, poll.is_ready());
+                if let Poll::Ready(value) = poll {
                     this.items.write(i, value);
                     states[i].set_ready();
                     *this.pending -= 1;
+                    this.trace.completed(i, states.len() - *this.pending);
                     // SAFETY: the future state has been changed to "ready" which
                     // means we'll no longer poll the future, so it's safe to drop
                     unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
@@ -128,25 +285,168 @@ where
 
         // Check whether we're all done now or need to keep going.
         if *this.pending == 0 {
-            // Mark all data as "consumed" before we take it
-            *this.consumed = true;
-            this.state.iter_mut().for_each(|state| {
-                debug_assert!(
-                    state.is_ready(),
-                    "Future should have reached a `Ready` state"
-                );
-                state.set_none();
-            });
-
-            // SAFETY: we've checked with the state that all of our outputs have been
-            // filled, which means we're ready to take the data and assume it's initialized.
-            Poll::Ready(unsafe { this.items.take() })
+            Poll::Ready(())
         } else {
             Poll::Pending
         }
     }
 }
 
+/// The allocations of a completed [`Join`], which can be reused to join
+/// another batch of futures of the same type.
+///
+/// This is created by [`Join::into_parts`], and turned back into a [`Join`]
+/// by [`Join::from_parts`].
+pub struct JoinParts<Fut> {
+    futures: Vec<Fut>,
+    wakers: WakerVec,
+    state: PollVec,
+}
+
+impl<Fut> JoinParts<Fut> {
+    /// Returns the futures which will be joined by [`Join::from_parts`].
+    ///
+    /// After [`Join::into_parts`] this is empty, but keeps the capacity of
+    /// the previous batch of futures.
+    pub fn futures_mut(&mut self) -> &mut Vec<Fut> {
+        &mut self.futures
+    }
+}
+
+impl<Fut> Default for JoinParts<Fut> {
+    fn default() -> Self {
+        Self {
+            futures: Vec::new(),
+            wakers: WakerVec::default(),
+            state: PollVec::default(),
+        }
+    }
+}
+
+impl<Fut> fmt::Debug for JoinParts<Fut>
+where
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinParts")
+            .field("futures", &self.futures)
+            .finish()
+    }
+}
+
+impl<Fut> JoinTrait for Vec<Fut>
+where
+    Fut: IntoFuture,
+{
+    type Output = Vec<Fut::Output>;
+    type Future = Join<Fut::IntoFuture>;
+
+    fn join(self) -> Self::Future {
+        Join::new(self.into_iter().map(IntoFuture::into_future).collect())
+    }
+}
+
+/// Join futures which were constructed fallibly.
+///
+/// The results are checked eagerly: if constructing any of the futures
+/// failed, the conversion returns the first error, and none of the futures
+/// are ever polled. Otherwise the futures are joined as with [`join`].
+///
+/// `Vec<Result<Fut, E>>` can't implement [`TryJoin`] directly, since that is
+/// reserved for futures which resolve to `Result`. For tuples, apply `?` to
+/// each member before joining instead.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::vec::Join;
+/// use std::future::{self, Ready};
+///
+/// fn make(n: u8) -> Result<Ready<u8>, String> {
+///     match n {
+///         0 => Err("zero".to_string()),
+///         n => Ok(future::ready(n)),
+///     }
+/// }
+///
+/// # futures_lite::future::block_on(async {
+/// let join = Join::try_from(vec![make(1), make(2)]).unwrap();
+/// assert_eq!(join.await, [1, 2]);
+///
+/// let err = Join::try_from(vec![make(1), make(0)]).unwrap_err();
+/// assert_eq!(err, "zero");
+/// # });
+/// ```
+///
+/// [`join`]: crate::future::Join::join
+/// [`TryJoin`]: crate::future::TryJoin
+impl<Fut, E> TryFrom<Vec<Result<Fut, E>>> for Join<Fut::IntoFuture>
+where
+    Fut: IntoFuture,
+{
+    type Error = E;
+
+    fn try_from(results: Vec<Result<Fut, E>>) -> Result<Self, Self::Error> {
+        results
+            .into_iter()
+            .map(|res| res.map(IntoFuture::into_future))
+            .collect::<Result<_, _>>()
+            .map(Join::new)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T> From<Vec<tokio::task::JoinHandle<T>>> for Join<tokio::task::JoinHandle<T>> {
+    /// Wait for all spawned tasks to complete, yielding each task's
+    /// `Result<T, JoinError>`.
+    fn from(handles: Vec<tokio::task::JoinHandle<T>>) -> Self {
+        Join::new(handles)
+    }
+}
+
+impl<Fut> fmt::Debug for Join<Fut>
+where
+    Fut: Future + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.state.iter()).finish()
+    }
+}
+
+impl<Fut> Future for Join<Fut>
+where
+    Fut: Future,
+{
+    type Output = Vec<Fut::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        debug_assert!(
+            !self.consumed,
+            "Futures must not be polled after completing"
+        );
+        if self.consumed {
+            return Poll::Pending;
+        }
+
+        ready!(self.as_mut().poll_progress(cx));
+        let this = self.project();
+
+        // Mark all data as "consumed" before we take it
+        *this.consumed = true;
+        this.state.iter_mut().for_each(|state| {
+            debug_assert!(
+                state.is_ready(),
+                "Future should have reached a `Ready` state"
+            );
+            state.set_none();
+        });
+
+        // SAFETY: we've checked with the state that all of our outputs have been
+        // filled, which means we're ready to take the data and assume it's initialized.
+        Poll::Ready(unsafe { this.items.take() })
+    }
+}
+
 /// Drop the already initialized values on cancellation.
 #[pinned_drop]
 impl<Fut> PinnedDrop for Join<Fut>
@@ -175,10 +475,9 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::utils::DummyWaker;
+    use crate::test_utils::{step, CountingWaker};
 
     use alloc::format;
-    use alloc::sync::Arc;
     use alloc::vec;
     use core::future;
 
@@ -205,9 +504,155 @@ mod test {
         assert_eq!(format!("{:?}", fut), "[Pending, Pending]");
         let mut fut = Pin::new(&mut fut);
 
-        let waker = Arc::new(DummyWaker()).into();
-        let mut cx = Context::from_waker(&waker);
-        let _ = fut.as_mut().poll(&mut cx);
+        let _ = step(fut.as_mut(), &CountingWaker::new());
         assert_eq!(format!("{:?}", fut), "[None, None]");
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "Futures must not be polled after completing")
+    )]
+    fn poll_after_ready() {
+        use crate::test_utils::{step, CountingWaker};
+        use alloc::string::String;
+        use core::pin::pin;
+
+        // The outputs have been moved out of their slots, so polling again
+        // must not read them a second time.
+        let futures = vec![
+            future::ready(String::from("a")),
+            future::ready(String::from("b")),
+        ];
+        let mut fut = pin!(futures.join());
+        let waker = CountingWaker::new();
+        assert_eq!(
+            step(fut.as_mut(), &waker),
+            Poll::Ready(vec!["a".into(), "b".into()])
+        );
+        // In release builds this returns `Pending` rather than panicking.
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reuse_parts() {
+        use crate::test_utils::{ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let waker = CountingWaker::new();
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(2)]);
+        let mut parts = JoinParts::default();
+        parts.futures_mut().extend([a, b]);
+
+        let mut join = Join::from_parts(parts);
+        assert_eq!(step(Pin::new(&mut join), &waker), Poll::Pending);
+        assert!(a_handle.wake());
+        assert_eq!(step(Pin::new(&mut join), &waker), Poll::Ready(vec![1, 2]));
+        let mut parts = join.into_parts();
+        assert!(parts.futures_mut().is_empty());
+        assert!(parts.futures_mut().capacity() >= 2);
+
+        // A larger batch polls every one of its futures, regardless of the
+        // readiness left behind by the previous batch.
+        let handles: Vec<_> = (0..3)
+            .map(|n| {
+                let (fut, handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(n)]);
+                parts.futures_mut().push(fut);
+                handle
+            })
+            .collect();
+        let mut join = pin!(Join::from_parts(parts));
+        assert_eq!(step(join.as_mut(), &waker), Poll::Pending);
+        assert!(handles.iter().all(|handle| handle.polls() == 1));
+
+        // Only the woken future is polled again.
+        assert!(handles[1].wake());
+        assert_eq!(step(join.as_mut(), &waker), Poll::Pending);
+        assert_eq!(handles[0].polls(), 1);
+        assert_eq!(handles[1].polls(), 2);
+
+        assert!(handles[0].wake());
+        assert!(handles[2].wake());
+        assert_eq!(step(join.as_mut(), &waker), Poll::Ready(vec![0, 1, 2]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn single_future() {
+        use crate::test_utils::{ScriptedFuture, Step};
+
+        // The future is handed the caller's waker, so waking it wakes the
+        // caller directly.
+        let waker = CountingWaker::new();
+        let (fut, handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(1)]);
+        let mut join = vec![fut].join();
+        assert_eq!(step(Pin::new(&mut join), &waker), Poll::Pending);
+        assert!(handle.wake());
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(step(Pin::new(&mut join), &waker), Poll::Ready(vec![1]));
+        assert_eq!(handle.polls(), 2);
+
+        // A single future can be joined from reused parts as well.
+        let mut parts = join.into_parts();
+        parts
+            .futures_mut()
+            .push(ScriptedFuture::new([Step::Ready(2)]).0);
+        let mut join = Join::from_parts(parts);
+        assert_eq!(step(Pin::new(&mut join), &waker), Poll::Ready(vec![2]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn try_from_results() {
+        use crate::test_utils::{ScriptedFuture, Step};
+
+        // Constructing the second future failed, so the first one is never
+        // polled.
+        let (fut, handle) = ScriptedFuture::new([Step::Ready(1)]);
+        let results = vec![
+            Ok(fut),
+            Err("oh no"),
+            Ok(ScriptedFuture::new([Step::Ready(3)]).0),
+        ];
+        assert_eq!(Join::try_from(results).unwrap_err(), "oh no");
+        assert_eq!(handle.polls(), 0);
+
+        let results: Vec<Result<_, &str>> = vec![Ok(future::ready(1)), Ok(future::ready(2))];
+        let join = Join::try_from(results).unwrap();
+        assert_eq!(futures_lite::future::block_on(join), [1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot take apart a `Join` which hasn't completed")]
+    fn into_parts_before_completion() {
+        let join = vec![future::pending::<()>()].join();
+        drop(join.into_parts());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn poll_progress_until_complete() {
+        use crate::test_utils::{ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let waker = CountingWaker::new();
+        let mut cx = Context::from_waker(waker.waker());
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(2)]);
+        let mut join = pin!(vec![a, b].join());
+
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Pending);
+        assert!(a_handle.wake());
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+        assert_eq!(a_handle.polls(), 2);
+
+        // The outputs are returned by the final poll, without polling again.
+        assert_eq!(step(join.as_mut(), &waker), Poll::Ready(vec![1, 2]));
+        assert_eq!(a_handle.polls(), 2);
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+    }
 }
@@ -0,0 +1,207 @@
+use super::JoinChunked as JoinChunkedTrait;
+use crate::future::join::array::Join;
+use crate::future::Join as _;
+use crate::utils::OutputArray;
+
+use core::array;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use pin_project::{pin_project, pinned_drop};
+
+/// A future which waits for chunks of futures to complete, one chunk after
+/// another.
+///
+/// This `struct` is created by the [`join_chunked`] method on the
+/// [`JoinChunked`] trait. See its documentation for more.
+///
+/// [`join_chunked`]: crate::future::JoinChunked::join_chunked
+/// [`JoinChunked`]: crate::future::JoinChunked
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project(PinnedDrop)]
+pub struct JoinChunked<Fut, const M: usize, const N: usize>
+where
+    Fut: IntoFuture,
+{
+    /// A boolean which holds whether the future has completed
+    consumed: bool,
+    /// The number of chunks which have completed
+    completed: usize,
+    /// The chunks which haven't been started yet
+    chunks: array::IntoIter<[Fut; M], N>,
+    /// The chunk which is currently in-flight
+    #[pin]
+    current: Option<Join<Fut::IntoFuture, M>>,
+    /// The output data, to be returned after the future completes
+    items: OutputArray<[Fut::Output; M], N>,
+}
+
+impl<Fut, const M: usize, const N: usize> JoinChunked<Fut, M, N>
+where
+    Fut: IntoFuture,
+{
+    #[inline]
+    pub(crate) fn new(chunks: [[Fut; M]; N]) -> Self {
+        Self {
+            consumed: false,
+            completed: 0,
+            chunks: chunks.into_iter(),
+            current: None,
+            items: OutputArray::uninit(),
+        }
+    }
+}
+
+impl<Fut, const M: usize, const N: usize> JoinChunkedTrait for [[Fut; M]; N]
+where
+    Fut: IntoFuture,
+{
+    type Output = [[Fut::Output; M]; N];
+    type Future = JoinChunked<Fut, M, N>;
+
+    #[inline]
+    fn join_chunked(self) -> Self::Future {
+        JoinChunked::new(self)
+    }
+}
+
+impl<Fut, const M: usize, const N: usize> fmt::Debug for JoinChunked<Fut, M, N>
+where
+    Fut: IntoFuture + fmt::Debug,
+    Fut::IntoFuture: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinChunked")
+            .field("current", &self.current)
+            .field("remaining", &self.chunks.as_slice())
+            .finish()
+    }
+}
+
+impl<Fut, const M: usize, const N: usize> Future for JoinChunked<Fut, M, N>
+where
+    Fut: IntoFuture,
+{
+    type Output = [[Fut::Output; M]; N];
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        debug_assert!(
+            !*this.consumed,
+            "Futures must not be polled after completing"
+        );
+        if *this.consumed {
+            return Poll::Pending;
+        }
+
+        loop {
+            if let Some(join) = this.current.as_mut().as_pin_mut() {
+                let output = ready!(join.poll(cx));
+                this.current.set(None);
+                this.items.write(*this.completed, output);
+                *this.completed += 1;
+            }
+
+            // Only start the next chunk once the previous one has completed.
+            match this.chunks.next() {
+                Some(chunk) => this.current.set(Some(chunk.join())),
+                None => {
+                    *this.consumed = true;
+                    // SAFETY: every chunk has completed and written its
+                    // output, which means all items are initialized.
+                    return Poll::Ready(unsafe { this.items.take() });
+                }
+            }
+        }
+    }
+}
+
+/// Drop the already initialized values on cancellation.
+#[pinned_drop]
+impl<Fut, const M: usize, const N: usize> PinnedDrop for JoinChunked<Fut, M, N>
+where
+    Fut: IntoFuture,
+{
+    fn drop(self: Pin<&mut Self>) {
+        let this = self.project();
+        if *this.consumed {
+            return;
+        }
+
+        for i in 0..*this.completed {
+            // SAFETY: the outputs of the completed chunks have been written,
+            // and haven't been taken yet.
+            unsafe { this.items.drop(i) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future;
+
+    #[test]
+    fn smoke() {
+        futures_lite::future::block_on(async {
+            let chunks = [
+                [future::ready(1), future::ready(2)],
+                [future::ready(3), future::ready(4)],
+                [future::ready(5), future::ready(6)],
+            ];
+            assert_eq!(chunks.join_chunked().await, [[1, 2], [3, 4], [5, 6]]);
+        });
+    }
+
+    #[test]
+    fn empty() {
+        futures_lite::future::block_on(async {
+            let chunks: [[future::Ready<()>; 2]; 0] = [];
+            assert_eq!(chunks.join_chunked().await, [] as [[(); 2]; 0]);
+            let chunks: [[future::Ready<()>; 0]; 2] = [[], []];
+            assert_eq!(chunks.join_chunked().await, [[], []]);
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn chunks_run_in_sequence() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, b_handle) = ScriptedFuture::new([Step::Ready(2)]);
+        let (c, c_handle) = ScriptedFuture::new([Step::Ready(3)]);
+        let (d, d_handle) = ScriptedFuture::new([Step::Ready(4)]);
+        let mut fut = pin!([[a, b], [c, d]].join_chunked());
+        let waker = CountingWaker::new();
+
+        // The second chunk isn't polled until the first one completes.
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert_eq!((a_handle.polls(), b_handle.polls()), (1, 1));
+        assert_eq!((c_handle.polls(), d_handle.polls()), (0, 0));
+
+        assert!(a_handle.wake());
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready([[1, 2], [3, 4]]));
+        assert_eq!((c_handle.polls(), d_handle.polls()), (1, 1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn drop_after_first_chunk() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use alloc::rc::Rc;
+
+        // The outputs of completed chunks are dropped on cancellation.
+        let output = Rc::new(());
+        let (a, _) = ScriptedFuture::new([Step::Ready(output.clone())]);
+        let (b, _) = ScriptedFuture::new([Step::WakeLater]);
+        let mut fut = Box::pin([[a], [b]].join_chunked());
+        assert_eq!(step(fut.as_mut(), &CountingWaker::new()), Poll::Pending);
+        assert_eq!(Rc::strong_count(&output), 2);
+        drop(fut);
+        assert_eq!(Rc::strong_count(&output), 1);
+    }
+}
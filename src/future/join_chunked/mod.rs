@@ -0,0 +1,40 @@
+use core::future::Future;
+
+pub(crate) mod array;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Wait for chunks of futures to complete, one chunk after another.
+///
+/// The futures within a chunk run concurrently, as with
+/// [`Join`][crate::future::Join], but each chunk is only started once the
+/// previous chunk has completed. The futures of a chunk aren't converted
+/// using `IntoFuture` until their chunk is started.
+pub trait JoinChunked {
+    /// The resulting output type.
+    type Output;
+
+    /// The [`Future`] implementation returned by this method.
+    type Future: Future<Output = Self::Output>;
+
+    /// Waits for chunks of futures to complete, one chunk after another.
+    ///
+    /// The outputs are returned in the same nested container type the
+    /// futures were stored in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::ready;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let waves = [[ready(1), ready(2)], [ready(3), ready(4)]];
+    /// assert_eq!(waves.join_chunked().await, [[1, 2], [3, 4]]);
+    ///
+    /// let waves = vec![vec![ready(1)], vec![ready(2), ready(3)]];
+    /// assert_eq!(waves.join_chunked().await, vec![vec![1], vec![2, 3]]);
+    /// # });
+    /// ```
+    fn join_chunked(self) -> Self::Future;
+}
@@ -0,0 +1,168 @@
+use super::JoinChunked as JoinChunkedTrait;
+use crate::future::join::vec::Join;
+use crate::future::Join as _;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::vec;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use pin_project::pin_project;
+
+/// A future which waits for chunks of futures to complete, one chunk after
+/// another.
+///
+/// This `struct` is created by the [`join_chunked`] method on the
+/// [`JoinChunked`] trait. See its documentation for more.
+///
+/// [`join_chunked`]: crate::future::JoinChunked::join_chunked
+/// [`JoinChunked`]: crate::future::JoinChunked
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct JoinChunked<Fut>
+where
+    Fut: IntoFuture,
+{
+    /// A boolean which holds whether the future has completed
+    consumed: bool,
+    /// The chunks which haven't been started yet
+    chunks: vec::IntoIter<Vec<Fut>>,
+    /// The chunk which is currently in-flight
+    #[pin]
+    current: Option<Join<Fut::IntoFuture>>,
+    /// The outputs of the completed chunks
+    items: Vec<Vec<Fut::Output>>,
+}
+
+impl<Fut> JoinChunked<Fut>
+where
+    Fut: IntoFuture,
+{
+    pub(crate) fn new(chunks: Vec<Vec<Fut>>) -> Self {
+        Self {
+            consumed: false,
+            items: Vec::with_capacity(chunks.len()),
+            chunks: chunks.into_iter(),
+            current: None,
+        }
+    }
+}
+
+impl<Fut> JoinChunkedTrait for Vec<Vec<Fut>>
+where
+    Fut: IntoFuture,
+{
+    type Output = Vec<Vec<Fut::Output>>;
+    type Future = JoinChunked<Fut>;
+
+    fn join_chunked(self) -> Self::Future {
+        JoinChunked::new(self)
+    }
+}
+
+impl<Fut> fmt::Debug for JoinChunked<Fut>
+where
+    Fut: IntoFuture + fmt::Debug,
+    Fut::IntoFuture: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinChunked")
+            .field("current", &self.current)
+            .field("remaining", &self.chunks.as_slice())
+            .finish()
+    }
+}
+
+impl<Fut> Future for JoinChunked<Fut>
+where
+    Fut: IntoFuture,
+{
+    type Output = Vec<Vec<Fut::Output>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        debug_assert!(
+            !*this.consumed,
+            "Futures must not be polled after completing"
+        );
+        if *this.consumed {
+            return Poll::Pending;
+        }
+
+        loop {
+            if let Some(join) = this.current.as_mut().as_pin_mut() {
+                let output = ready!(join.poll(cx));
+                this.current.set(None);
+                this.items.push(output);
+            }
+
+            // Only start the next chunk once the previous one has completed.
+            match this.chunks.next() {
+                Some(chunk) => this.current.set(Some(chunk.join())),
+                None => {
+                    *this.consumed = true;
+                    return Poll::Ready(core::mem::take(this.items));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use core::future;
+
+    #[test]
+    fn smoke() {
+        futures_lite::future::block_on(async {
+            let chunks = vec![
+                vec![future::ready(1)],
+                vec![future::ready(2), future::ready(3)],
+                vec![],
+            ];
+            assert_eq!(
+                chunks.join_chunked().await,
+                vec![vec![1], vec![2, 3], vec![]]
+            );
+        });
+    }
+
+    #[test]
+    fn empty() {
+        futures_lite::future::block_on(async {
+            let chunks: Vec<Vec<future::Ready<()>>> = vec![];
+            assert!(chunks.join_chunked().await.is_empty());
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn chunks_run_in_sequence() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, b_handle) = ScriptedFuture::new([Step::Ready(2)]);
+        let (c, c_handle) = ScriptedFuture::new([Step::Ready(3)]);
+        let mut fut = pin!(vec![vec![a, b], vec![c]].join_chunked());
+        let waker = CountingWaker::new();
+
+        // The second chunk isn't polled until the first one completes.
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert_eq!((a_handle.polls(), b_handle.polls()), (1, 1));
+        assert_eq!(c_handle.polls(), 0);
+
+        assert!(a_handle.wake());
+        assert_eq!(
+            step(fut.as_mut(), &waker),
+            Poll::Ready(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(c_handle.polls(), 1);
+    }
+}
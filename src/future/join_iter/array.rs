@@ -0,0 +1,228 @@
+use super::JoinIter as JoinIterTrait;
+use crate::future::join::vec::Join;
+
+use alloc::vec::{self, Vec};
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use pin_project::pin_project;
+
+/// A future which waits for all futures to complete, and then resolves to
+/// an iterator over their outputs.
+///
+/// This `struct` is created by the [`join_iter`] method on the [`JoinIter`]
+/// trait. See its documentation for more.
+///
+/// The futures are moved into a heap allocation when the join is created,
+/// and each output is written into a second heap allocation as soon as its
+/// future completes. Neither the futures nor the outputs are ever held on
+/// the stack as a whole once the join has been created, which keeps the
+/// stack usage of large arrays independent of `N`.
+///
+/// [`join_iter`]: crate::future::JoinIter::join_iter
+/// [`JoinIter`]: crate::future::JoinIter
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct JoinIter<Fut, const N: usize>
+where
+    Fut: Future,
+{
+    #[pin]
+    join: Join<Fut>,
+}
+
+impl<Fut, const N: usize> JoinIter<Fut, N>
+where
+    Fut: Future,
+{
+    pub(crate) fn new(futures: [Fut; N]) -> Self {
+        Self {
+            join: Join::new(Vec::from(futures)),
+        }
+    }
+}
+
+impl<Fut, const N: usize> JoinIterTrait for [Fut; N]
+where
+    Fut: IntoFuture,
+{
+    type Item = Fut::Output;
+    type Iter = JoinOutputs<Fut::IntoFuture, N>;
+    type Future = JoinIter<Fut::IntoFuture, N>;
+
+    fn join_iter(self) -> Self::Future {
+        JoinIter::new(self.map(IntoFuture::into_future))
+    }
+}
+
+impl<Fut, const N: usize> fmt::Debug for JoinIter<Fut, N>
+where
+    Fut: Future + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.join.fmt(f)
+    }
+}
+
+impl<Fut, const N: usize> Future for JoinIter<Fut, N>
+where
+    Fut: Future,
+{
+    type Output = JoinOutputs<Fut, N>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let outputs = ready!(self.project().join.poll(cx));
+        Poll::Ready(JoinOutputs {
+            outputs: outputs.into_iter(),
+        })
+    }
+}
+
+/// An iterator which moves the outputs out of a completed [`JoinIter`].
+///
+/// This `struct` is created by awaiting a [`JoinIter`]. See its
+/// documentation for more.
+pub struct JoinOutputs<Fut, const N: usize>
+where
+    Fut: Future,
+{
+    /// The outputs which haven't been yielded yet, in the allocation they
+    /// were written into
+    outputs: vec::IntoIter<Fut::Output>,
+}
+
+impl<Fut, const N: usize> fmt::Debug for JoinOutputs<Fut, N>
+where
+    Fut: Future,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinOutputs")
+            .field("remaining", &self.outputs.len())
+            .finish()
+    }
+}
+
+impl<Fut, const N: usize> Iterator for JoinOutputs<Fut, N>
+where
+    Fut: Future,
+{
+    type Item = Fut::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.outputs.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.outputs.size_hint()
+    }
+}
+
+impl<Fut, const N: usize> DoubleEndedIterator for JoinOutputs<Fut, N>
+where
+    Fut: Future,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.outputs.next_back()
+    }
+}
+
+impl<Fut, const N: usize> ExactSizeIterator for JoinOutputs<Fut, N> where Fut: Future {}
+
+// These tests use no timers or threads so they can run under Miri, which
+// checks that the outputs are moved out and dropped exactly once.
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::rc::Rc;
+    use alloc::vec::Vec;
+    use core::future;
+    use futures_lite::future::block_on;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let iter = [future::ready(1), future::ready(2), future::ready(3)]
+                .join_iter()
+                .await;
+            assert_eq!(iter.len(), 3);
+            assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+        });
+    }
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let futs: [future::Ready<()>; 0] = [];
+            assert_eq!(futs.join_iter().await.next(), None);
+        });
+    }
+
+    #[test]
+    fn both_ends() {
+        block_on(async {
+            let mut iter = [1, 2, 3, 4].map(future::ready).join_iter().await;
+            assert_eq!(iter.next(), Some(1));
+            assert_eq!(iter.next_back(), Some(4));
+            assert_eq!(iter.collect::<Vec<_>>(), [2, 3]);
+        });
+    }
+
+    #[test]
+    fn drop_partially_consumed() {
+        block_on(async {
+            let output = Rc::new(());
+            let futs = [(); 4].map(|_| future::ready(output.clone()));
+            let mut iter = futs.join_iter().await;
+            assert_eq!(Rc::strong_count(&output), 5);
+
+            drop(iter.next());
+            drop(iter.next_back());
+            assert_eq!(Rc::strong_count(&output), 3);
+
+            drop(iter);
+            assert_eq!(Rc::strong_count(&output), 1);
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn drop_before_completion() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+
+        let output = Rc::new(());
+        let (a, _) = ScriptedFuture::new([Step::Ready(output.clone())]);
+        let (b, _) = ScriptedFuture::new([Step::WakeLater]);
+        let mut fut = [a, b].join_iter();
+        assert!(step(Pin::new(&mut fut), &CountingWaker::new()).is_pending());
+        assert_eq!(Rc::strong_count(&output), 2);
+
+        drop(fut);
+        assert_eq!(Rc::strong_count(&output), 1);
+    }
+
+    /// The outputs of a large array are never placed on the stack together,
+    /// so joining them fits in a stack which is much smaller than they are.
+    /// This spawns a thread to control the stack size, so it doesn't run
+    /// under Miri.
+    #[cfg(all(feature = "std", not(miri)))]
+    #[test]
+    fn large_outputs_on_small_stack() {
+        const N: usize = 256;
+
+        let futs: [_; N] = core::array::from_fn(|n| async move { [n as u8; 4096] });
+        let sum = std::thread::Builder::new()
+            .stack_size(256 * 1024)
+            .spawn(move || {
+                block_on(async {
+                    let outputs = futs.join_iter().await;
+                    outputs.map(|output| output[0] as usize).sum::<usize>()
+                })
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(sum, (0..N).map(|n| n as u8 as usize).sum::<usize>());
+    }
+}
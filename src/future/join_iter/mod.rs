@@ -0,0 +1,39 @@
+use core::future::Future;
+
+pub(crate) mod array;
+
+/// Wait for all futures to complete, and iterate over their outputs.
+///
+/// Unlike [`Join`][crate::future::Join], the outputs are never gathered into
+/// a single value: they're kept in a heap allocation while the futures run,
+/// and the returned iterator moves them out one by one. This avoids placing
+/// large arrays of outputs on the stack.
+pub trait JoinIter {
+    /// The outputs of the futures.
+    type Item;
+
+    /// The iterator over the outputs.
+    type Iter: Iterator<Item = Self::Item>;
+
+    /// The [`Future`] implementation returned by this method.
+    type Future: Future<Output = Self::Iter>;
+
+    /// Waits for all futures to complete, returning an iterator over their
+    /// outputs in order.
+    ///
+    /// Outputs which aren't consumed are dropped along with the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::ready;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let futs = [ready(1), ready(2), ready(3)];
+    /// let sum: i32 = futs.join_iter().await.sum();
+    /// assert_eq!(sum, 6);
+    /// # });
+    /// ```
+    fn join_iter(self) -> Self::Future;
+}
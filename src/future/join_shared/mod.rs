@@ -0,0 +1,63 @@
+use alloc::sync::Arc;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use pin_project::pin_project;
+
+pub(crate) mod vec;
+
+/// Wait for all futures to complete, sharing each output through an `Arc`.
+pub trait JoinShared {
+    /// The resulting output type.
+    type Output;
+
+    /// The [`Future`] implementation returned by this method.
+    type Future: Future<Output = Self::Output>;
+
+    /// Waits for all futures to complete, wrapping each output in an `Arc`.
+    ///
+    /// Each output is moved into its `Arc` as soon as its future completes,
+    /// and the future is dropped right away. This avoids a separate pass
+    /// over the outputs once all futures have completed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::ready;
+    /// use std::sync::Arc;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let buffers = vec![ready(vec![0u8; 1024]), ready(vec![1u8; 1024])];
+    /// let outputs: Vec<Arc<Vec<u8>>> = buffers.join_shared().await;
+    ///
+    /// let consumer = outputs.clone();
+    /// assert!(Arc::ptr_eq(&consumer[0], &outputs[0]));
+    /// # });
+    /// ```
+    fn join_shared(self) -> Self::Future;
+}
+
+/// A future which moves its output into an `Arc`.
+#[derive(Debug)]
+#[pin_project]
+pub(crate) struct Shared<F> {
+    #[pin]
+    future: F,
+}
+
+impl<F> Shared<F> {
+    pub(crate) fn new(future: F) -> Self {
+        Self { future }
+    }
+}
+
+impl<F: Future> Future for Shared<F> {
+    type Output = Arc<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let output = ready!(self.project().future.poll(cx));
+        Poll::Ready(Arc::new(output))
+    }
+}
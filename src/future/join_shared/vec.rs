@@ -0,0 +1,116 @@
+use super::{JoinShared as JoinSharedTrait, Shared};
+use crate::future::join::vec::Join;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::sync::Arc;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project::pin_project;
+
+/// A future which waits for all futures to complete, sharing each output
+/// through an `Arc`.
+///
+/// This `struct` is created by the [`join_shared`] method on the
+/// [`JoinShared`] trait. See its documentation for more.
+///
+/// [`join_shared`]: crate::future::JoinShared::join_shared
+/// [`JoinShared`]: crate::future::JoinShared
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct JoinShared<Fut>
+where
+    Fut: Future,
+{
+    #[pin]
+    join: Join<Shared<Fut>>,
+}
+
+impl<Fut> JoinSharedTrait for Vec<Fut>
+where
+    Fut: IntoFuture,
+{
+    type Output = Vec<Arc<Fut::Output>>;
+    type Future = JoinShared<Fut::IntoFuture>;
+
+    fn join_shared(self) -> Self::Future {
+        JoinShared {
+            join: Join::new(
+                self.into_iter()
+                    .map(|fut| Shared::new(fut.into_future()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl<Fut> fmt::Debug for JoinShared<Fut>
+where
+    Fut: Future + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.join.fmt(f)
+    }
+}
+
+impl<Fut> Future for JoinShared<Fut>
+where
+    Fut: Future,
+{
+    type Output = Vec<Arc<Fut::Output>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().join.poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use core::future;
+    use futures_lite::future::block_on;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let outputs = vec![future::ready(1), future::ready(2)].join_shared().await;
+            assert_eq!(outputs, [Arc::new(1), Arc::new(2)]);
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn drop_counts() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let buffer = Arc::new(());
+        let (a, _) = ScriptedFuture::new([Step::Ready(buffer.clone())]);
+        let (b, b_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(buffer.clone())]);
+        let mut join = pin!(vec![a, b].join_shared());
+        let waker = CountingWaker::new();
+
+        // The first future has completed and was dropped, and its output has
+        // been moved into an `Arc` without being cloned.
+        assert_eq!(step(join.as_mut(), &waker), Poll::Pending);
+        assert_eq!(Arc::strong_count(&buffer), 3);
+
+        assert!(b_handle.wake());
+        let Poll::Ready(outputs) = step(join.as_mut(), &waker) else {
+            panic!("the join should have completed");
+        };
+        assert_eq!(Arc::strong_count(&buffer), 3);
+        assert!(outputs.iter().all(|output| Arc::strong_count(output) == 1));
+
+        let shared = outputs.clone();
+        drop(outputs);
+        assert_eq!(Arc::strong_count(&buffer), 3);
+        drop(shared);
+        assert_eq!(Arc::strong_count(&buffer), 1);
+    }
+}
@@ -0,0 +1,159 @@
+use core::future::{Future, IntoFuture};
+
+use super::try_join::{array, tuple};
+use crate::utils::private::NoneError;
+
+/// Wait for all futures to resolve to `Some`, or abort early on `None`.
+///
+/// This is the `Option` counterpart of [`TryJoin`][super::TryJoin]: in the
+/// case a future resolves to `None`, all other futures will be cancelled, and
+/// the output is `None`. If futures have been completed, their outputs will be
+/// discarded.
+pub trait JoinSome {
+    /// The resulting output type.
+    type Output;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Option<Self::Output>>;
+
+    /// Waits for multiple futures to complete, either returning when all
+    /// futures resolve to `Some`, or return early when any future resolves to
+    /// `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::ready;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let found = (ready(Some(1)), ready(Some("two"))).join_some().await;
+    /// assert_eq!(found, Some((1, "two")));
+    ///
+    /// let missing = (ready(Some(1)), ready(None::<&str>)).join_some().await;
+    /// assert_eq!(missing, None);
+    /// # });
+    /// ```
+    fn join_some(self) -> Self::Future;
+}
+
+macro_rules! impl_join_some_tuple {
+    ($StructName:ident $(($F:ident $T:ident))+) => {
+        impl<$($F, $T,)+> JoinSome for ($($F,)+)
+        where $(
+            $F: IntoFuture<Output = Option<$T>>,
+        )+ {
+            type Output = ($($T,)+);
+            type Future = tuple::$StructName<$($F::IntoFuture, $T,)+ NoneError>;
+
+            #[allow(non_snake_case)]
+            fn join_some(self) -> Self::Future {
+                let ($($F,)+) = self;
+                tuple::$StructName::new(($($F.into_future(),)+))
+            }
+        }
+    };
+}
+
+impl_join_some_tuple! { TryJoin1 (A ResA) }
+impl_join_some_tuple! { TryJoin2 (A ResA) (B ResB) }
+impl_join_some_tuple! { TryJoin3 (A ResA) (B ResB) (C ResC) }
+impl_join_some_tuple! { TryJoin4 (A ResA) (B ResB) (C ResC) (D ResD) }
+impl_join_some_tuple! { TryJoin5 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) }
+impl_join_some_tuple! { TryJoin6 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) }
+impl_join_some_tuple! { TryJoin7 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) }
+impl_join_some_tuple! { TryJoin8 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) }
+impl_join_some_tuple! { TryJoin9 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) }
+impl_join_some_tuple! { TryJoin10 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) }
+impl_join_some_tuple! { TryJoin11 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) }
+impl_join_some_tuple! { TryJoin12 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { TryJoin13 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { TryJoin14 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { TryJoin15 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { TryJoin16 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) }
+
+impl<Fut, T, const N: usize> JoinSome for [Fut; N]
+where
+    Fut: IntoFuture<Output = Option<T>>,
+{
+    type Output = [T; N];
+    type Future = array::TryJoin<Fut::IntoFuture, T, NoneError, N>;
+
+    fn join_some(self) -> Self::Future {
+        array::TryJoin::new(self.map(IntoFuture::into_future))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Fut, T> JoinSome for alloc::vec::Vec<Fut>
+where
+    Fut: IntoFuture<Output = Option<T>>,
+{
+    type Output = alloc::vec::Vec<T>;
+    type Future = super::try_join::vec::TryJoin<Fut::IntoFuture, T, NoneError>;
+
+    fn join_some(self) -> Self::Future {
+        super::try_join::vec::TryJoin::new(self.into_iter().map(IntoFuture::into_future).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::rc::Rc;
+    use alloc::vec;
+    use core::future::{pending, ready};
+    use futures_lite::future::block_on;
+
+    #[test]
+    fn all_some() {
+        block_on(async {
+            assert_eq!(
+                (ready(Some(1)), ready(Some("two")), async { Some(3) })
+                    .join_some()
+                    .await,
+                Some((1, "two", 3))
+            );
+            assert_eq!(
+                [ready(Some(1)), ready(Some(2))].join_some().await,
+                Some([1, 2])
+            );
+            assert_eq!(
+                vec![ready(Some(1)), ready(Some(2))].join_some().await,
+                Some(vec![1, 2])
+            );
+        })
+    }
+
+    #[test]
+    fn early_none() {
+        block_on(async {
+            // The pending future holds a reference, which is released when
+            // the future is dropped.
+            let guard = Rc::new(());
+            let held = guard.clone();
+            let never = async move {
+                let _held = held;
+                pending::<Option<u8>>().await
+            };
+            let out = (never, ready(None::<u8>)).join_some().await;
+            assert_eq!(out, None);
+            assert_eq!(Rc::strong_count(&guard), 1);
+        })
+    }
+
+    #[test]
+    fn simultaneous_none() {
+        block_on(async {
+            let out = (ready(None::<u8>), ready(None::<&str>), ready(Some(3)))
+                .join_some()
+                .await;
+            assert_eq!(out, None);
+            assert_eq!(vec![ready(None::<u8>), ready(None)].join_some().await, None);
+        })
+    }
+}
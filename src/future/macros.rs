@@ -0,0 +1,255 @@
+//! Variadic versions of the tuple combinators.
+//!
+//! Tuples implement the combinator traits up to a length of 12, unless the
+//! `large-tuples` feature is enabled. The macros in this module split longer
+//! argument lists into nested tuples, where the last member of each tuple is
+//! the combinator for the remaining futures, so they work regardless of the
+//! feature.
+
+/// Wait for all futures to complete, for any number of futures.
+///
+/// This expands to calls to [`Join::join`] on tuples of the futures, and
+/// evaluates to a future which resolves to a tuple of all outputs. Tuples
+/// implement [`Join`] up to a length of 12, so longer argument lists are
+/// split into nested tuples and the nested outputs are flattened again.
+///
+/// [`Join`]: crate::future::Join
+/// [`Join::join`]: crate::future::Join::join
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::join;
+/// use std::future::ready;
+///
+/// # futures_lite::future::block_on(async {
+/// let (a, b, c) = join!(ready(1), ready("two"), async { 3.0 }).await;
+/// assert_eq!((a, b, c), (1, "two", 3.0));
+/// # });
+/// ```
+#[macro_export]
+macro_rules! join {
+    ($($fut:expr),+ $(,)?) => {
+        $crate::__nest!(@name join [] $($fut,)+)
+    };
+}
+
+/// Wait for all futures to complete successfully, or abort early on error,
+/// for any number of futures.
+///
+/// This expands to calls to [`TryJoin::try_join`] on tuples of the futures,
+/// and evaluates to a future which resolves to a tuple of all outputs. See
+/// [`join!`] for how longer argument lists are handled.
+///
+/// [`TryJoin::try_join`]: crate::future::TryJoin::try_join
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::try_join;
+/// use std::future::ready;
+///
+/// # futures_lite::future::block_on(async {
+/// let res = try_join!(ready(Ok::<_, ()>(1)), ready(Ok("two"))).await;
+/// assert_eq!(res, Ok((1, "two")));
+/// # });
+/// ```
+#[macro_export]
+macro_rules! try_join {
+    ($($fut:expr),+ $(,)?) => {
+        $crate::__nest!(@name try_join [] $($fut,)+)
+    };
+}
+
+/// Wait for the first future to complete, for any number of futures.
+///
+/// This expands to calls to [`Race::race`] on tuples of the futures. See
+/// [`join!`] for how longer argument lists are handled.
+///
+/// [`Race::race`]: crate::future::Race::race
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::race;
+/// use std::future::{pending, ready};
+///
+/// # futures_lite::future::block_on(async {
+/// let out = race!(pending(), ready(1)).await;
+/// assert_eq!(out, 1);
+/// # });
+/// ```
+#[macro_export]
+macro_rules! race {
+    ($($fut:expr),+ $(,)?) => {
+        $crate::__nest!(@name race [] $($fut,)+)
+    };
+}
+
+/// Wait for the first successful future to complete, for any number of
+/// futures.
+///
+/// This expands to calls to [`RaceOk::race_ok`] on tuples of the futures. See
+/// [`join!`] for how longer argument lists are handled. If all futures fail,
+/// the future resolves to an [`array::AggregateError`] of all errors, in the
+/// order the futures were passed in, for any number of futures.
+///
+/// [`RaceOk::race_ok`]: crate::future::RaceOk::race_ok
+/// [`array::AggregateError`]: crate::array::AggregateError
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::race_ok;
+/// use std::future::ready;
+///
+/// # futures_lite::future::block_on(async {
+/// let res = race_ok!(ready(Err("oh no")), ready(Ok(1))).await;
+/// assert!(matches!(res, Ok(1)));
+/// # });
+/// ```
+#[macro_export]
+macro_rules! race_ok {
+    ($($fut:expr),+ $(,)?) => {
+        $crate::__nest!(@name race_ok [] $($fut,)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nest {
+    // Give every future a name to bind its output to. Each `x` is introduced
+    // by a separate expansion, so hygiene keeps them apart.
+    (@name $op:ident [$($acc:tt)*] $fut:expr, $($rest:expr,)*) => {
+        $crate::__nest!(@name $op [$($acc)* x $fut,] $($rest,)*)
+    };
+    (@name $op:ident [$($acc:tt)*]) => {
+        $crate::__nest!(@ $op $($acc)*)
+    };
+
+    // With more than 12 futures, the first 11 are combined with a nested
+    // combinator for the remaining futures.
+    (@join
+        $n0:ident $f0:expr, $n1:ident $f1:expr, $n2:ident $f2:expr, $n3:ident $f3:expr,
+        $n4:ident $f4:expr, $n5:ident $f5:expr, $n6:ident $f6:expr, $n7:ident $f7:expr,
+        $n8:ident $f8:expr, $n9:ident $f9:expr, $n10:ident $f10:expr, $n11:ident $f11:expr,
+        $($n:ident $f:expr,)+
+    ) => {{
+        let fut = $crate::future::Join::join((
+            $f0, $f1, $f2, $f3, $f4, $f5, $f6, $f7, $f8, $f9, $f10,
+            $crate::__nest!(@join $n11 $f11, $($n $f,)+),
+        ));
+        async move {
+            let (
+                $n0, $n1, $n2, $n3, $n4, $n5, $n6, $n7, $n8, $n9, $n10,
+                ($n11, $($n,)+),
+            ) = fut.await;
+            ($n0, $n1, $n2, $n3, $n4, $n5, $n6, $n7, $n8, $n9, $n10, $n11, $($n,)+)
+        }
+    }};
+    (@join $($n:ident $f:expr,)+) => {
+        $crate::future::Join::join(($($f,)+))
+    };
+
+    (@try_join
+        $n0:ident $f0:expr, $n1:ident $f1:expr, $n2:ident $f2:expr, $n3:ident $f3:expr,
+        $n4:ident $f4:expr, $n5:ident $f5:expr, $n6:ident $f6:expr, $n7:ident $f7:expr,
+        $n8:ident $f8:expr, $n9:ident $f9:expr, $n10:ident $f10:expr, $n11:ident $f11:expr,
+        $($n:ident $f:expr,)+
+    ) => {{
+        let fut = $crate::future::TryJoin::try_join((
+            $f0, $f1, $f2, $f3, $f4, $f5, $f6, $f7, $f8, $f9, $f10,
+            $crate::__nest!(@try_join $n11 $f11, $($n $f,)+),
+        ));
+        async move {
+            fut.await.map(|(
+                $n0, $n1, $n2, $n3, $n4, $n5, $n6, $n7, $n8, $n9, $n10,
+                ($n11, $($n,)+),
+            )| ($n0, $n1, $n2, $n3, $n4, $n5, $n6, $n7, $n8, $n9, $n10, $n11, $($n,)+))
+        }
+    }};
+    (@try_join $($n:ident $f:expr,)+) => {
+        $crate::future::TryJoin::try_join(($($f,)+))
+    };
+
+    (@race
+        $n0:ident $f0:expr, $n1:ident $f1:expr, $n2:ident $f2:expr, $n3:ident $f3:expr,
+        $n4:ident $f4:expr, $n5:ident $f5:expr, $n6:ident $f6:expr, $n7:ident $f7:expr,
+        $n8:ident $f8:expr, $n9:ident $f9:expr, $n10:ident $f10:expr, $n11:ident $f11:expr,
+        $($n:ident $f:expr,)+
+    ) => {
+        $crate::future::Race::race((
+            $f0, $f1, $f2, $f3, $f4, $f5, $f6, $f7, $f8, $f9, $f10,
+            $crate::__nest!(@race $n11 $f11, $($n $f,)+),
+        ))
+    };
+    (@race $($n:ident $f:expr,)+) => {
+        $crate::future::Race::race(($($f,)+))
+    };
+
+    // `RaceOk` requires all members to share an error type, so the errors are
+    // wrapped before racing, and flattened into a single aggregate afterwards.
+    (@race_ok
+        $n0:ident $f0:expr, $n1:ident $f1:expr, $n2:ident $f2:expr, $n3:ident $f3:expr,
+        $n4:ident $f4:expr, $n5:ident $f5:expr, $n6:ident $f6:expr, $n7:ident $f7:expr,
+        $n8:ident $f8:expr, $n9:ident $f9:expr, $n10:ident $f10:expr, $n11:ident $f11:expr,
+        $($n:ident $f:expr,)+
+    ) => {{
+        let fut = $crate::future::RaceOk::race_ok((
+            $crate::__nest!(@leaf $f0),
+            $crate::__nest!(@leaf $f1),
+            $crate::__nest!(@leaf $f2),
+            $crate::__nest!(@leaf $f3),
+            $crate::__nest!(@leaf $f4),
+            $crate::__nest!(@leaf $f5),
+            $crate::__nest!(@leaf $f6),
+            $crate::__nest!(@leaf $f7),
+            $crate::__nest!(@leaf $f8),
+            $crate::__nest!(@leaf $f9),
+            $crate::__nest!(@leaf $f10),
+            $crate::__nest!(@node $crate::__nest!(@race_ok $n11 $f11, $($n $f,)+)),
+        ));
+        async move {
+            fut.await.map_err(|err| {
+                let [
+                    $crate::private::NestedError::Leaf($n0),
+                    $crate::private::NestedError::Leaf($n1),
+                    $crate::private::NestedError::Leaf($n2),
+                    $crate::private::NestedError::Leaf($n3),
+                    $crate::private::NestedError::Leaf($n4),
+                    $crate::private::NestedError::Leaf($n5),
+                    $crate::private::NestedError::Leaf($n6),
+                    $crate::private::NestedError::Leaf($n7),
+                    $crate::private::NestedError::Leaf($n8),
+                    $crate::private::NestedError::Leaf($n9),
+                    $crate::private::NestedError::Leaf($n10),
+                    $crate::private::NestedError::Node(inner),
+                ] = err.into_inner()
+                else {
+                    unreachable!()
+                };
+                let [$n11, $($n,)+] = inner.into_inner();
+                $crate::array::AggregateError::from([
+                    $n0, $n1, $n2, $n3, $n4, $n5, $n6, $n7, $n8, $n9, $n10, $n11, $($n,)+
+                ])
+            })
+        }
+    }};
+    // The aggregate error of a tuple is converted, so the error type is the
+    // same whether or not the futures had to be nested.
+    (@race_ok $($n:ident $f:expr,)+) => {{
+        let fut = $crate::future::RaceOk::race_ok(($($f,)+));
+        async move {
+            fut.await
+                .map_err(|err| $crate::array::AggregateError::from(err.into_inner()))
+        }
+    }};
+    (@leaf $fut:expr) => {{
+        let fut = $fut;
+        async move { fut.await.map_err($crate::private::NestedError::Leaf) }
+    }};
+    (@node $fut:expr) => {{
+        let fut = $fut;
+        async move { fut.await.map_err($crate::private::NestedError::Node) }
+    }};
+}
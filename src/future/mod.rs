@@ -66,25 +66,117 @@
 //!
 //! - `future::TryMerge`: wait for all futures in the set to complete _successfully_, or return on the first error.
 //! - `future::RaceOk`: wait for the first _successful_ future in the set to
-//!    complete, or return an `Err` if *no* futures complete successfully.
+//!   complete, or return an `Err` if *no* futures complete successfully.
 //!
+//! Futures which return `Option` types have counterparts of their own,
+//! which treat `None` as the error:
+//!
+//! - `future::JoinSome`: wait for all futures to return `Some`, or return
+//!   `None` on the first `None`.
+//! - `future::RaceSome`: wait for the first future to return `Some`, or
+//!   return `None` if all futures return `None`.
+//!
+//! # Configuring Operations
+//!
+//! The futures returned by the concurrency operations double as their
+//! builders: optional configuration is set by calling methods which take the
+//! future by value, before it is awaited. Because configuration is stored on
+//! the future itself, it can't be lost by converting between types, and
+//! awaiting works the same with or without configuration.
+//!
+//! ```
+//! use futures_concurrency::prelude::*;
+//! use std::future;
+//!
+//! # futures_lite::future::block_on(async {
+//! let err = vec![future::ready(Err::<(), _>("timeout"))]
+//!     .race_ok()
+//!     .labels(["eu-mirror"])
+//!     .await
+//!     .unwrap_err();
+//! assert_eq!(err.label(0), Some("eu-mirror"));
+//! # });
+//! ```
+//!
+//! Configuration methods panic when called on a future which has already
+//! completed, rather than silently having no effect.
+//!
+//! # Borrowed Futures
+//!
+//! The concurrency operations accept anything which implements `IntoFuture`,
+//! which includes futures borrowed as `Pin<&mut F>`, and as `&mut F` if `F` is
+//! `Unpin`. Completing or dropping the operation then only drops the borrows:
+//! futures which didn't finish keep their progress, and can be awaited or
+//! raced again afterwards. This is what makes it possible to replace a
+//! hand-rolled `select!` loop.
+//!
+//! ```
+//! use futures_concurrency::prelude::*;
+//! use futures_lite::future;
+//! use std::pin::pin;
+//!
+//! # future::block_on(async {
+//! let mut slow = pin!(async {
+//!     future::yield_now().await;
+//!     "slow"
+//! });
+//! let fast = async { "fast" };
+//!
+//! assert_eq!((slow.as_mut(), pin!(fast)).race().await, "fast");
+//! assert_eq!(slow.await, "slow");
+//! # });
+//! ```
+//!
+#[cfg(feature = "std")]
+pub use abortable_join::{AbortHandle, AbortableJoin, Aborted};
+#[cfg(feature = "alloc")]
+pub use boxed_combinator::BoxedCombinator;
+pub use completion::{Completion, Failure};
+pub use fuse::Fuse;
 #[doc(inline)]
 #[cfg(feature = "alloc")]
 pub use future_group::FutureGroup;
 pub use futures_ext::FutureExt;
 pub use join::Join;
-pub use race::Race;
+pub use join_chunked::JoinChunked;
+#[cfg(feature = "alloc")]
+pub use join_iter::JoinIter;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use join_shared::JoinShared;
+pub use join_some::JoinSome;
+pub use race::{Race, RaceOutcome};
 pub use race_ok::RaceOk;
+pub use race_some::RaceSome;
+#[cfg(feature = "std")]
+pub use scope::{scope, Scope, ScopeHandle};
 pub use try_join::TryJoin;
+pub use try_join_chunked::TryJoinChunked;
 pub use wait_until::WaitUntil;
 
 /// A growable group of futures which act as a single unit.
 #[cfg(feature = "alloc")]
 pub mod future_group;
 
+#[cfg(feature = "std")]
+pub(crate) mod abortable_join;
+#[cfg(feature = "alloc")]
+mod boxed_combinator;
+mod completion;
+mod fuse;
 mod futures_ext;
 pub(crate) mod join;
+pub(crate) mod join_chunked;
+#[cfg(feature = "alloc")]
+pub(crate) mod join_iter;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub(crate) mod join_shared;
+pub(crate) mod join_some;
+mod macros;
 pub(crate) mod race;
 pub(crate) mod race_ok;
+pub(crate) mod race_some;
+#[cfg(feature = "std")]
+mod scope;
 pub(crate) mod try_join;
+pub(crate) mod try_join_chunked;
 pub(crate) mod wait_until;
@@ -1,11 +1,12 @@
 use crate::utils::{self, Indexer};
 
-use super::Race as RaceTrait;
+use super::{Race as RaceTrait, RaceOutcome};
+use crate::future::Completion;
 
 use core::fmt;
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
-use core::task::{Context, Poll};
+use core::task::{ready, Context, Poll};
 
 use pin_project::pin_project;
 
@@ -38,22 +39,57 @@ where
     }
 }
 
-impl<Fut, const N: usize> Future for Race<Fut, N>
+impl<Fut, const N: usize> Race<Fut, N>
 where
     Fut: Future,
 {
-    type Output = Fut::Output;
+    /// Report which future won the race, and how long it took.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the race has already completed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::future::Completion;
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::{pending, ready};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let futs = [pending().boxed_concurrency(), ready(1).boxed_concurrency()];
+    /// let outcome = futs.race().detailed().await;
+    /// assert_eq!(outcome.winner, Completion::new(1, 1));
+    /// assert_eq!(outcome.losers, 1);
+    /// assert_eq!(outcome.elapsed_polls, 1);
+    /// # });
+    /// ```
+    pub fn detailed(self) -> RaceDetailed<Fut, N> {
+        assert!(
+            !self.done,
+            "cannot add details to a `Race` which has already completed"
+        );
+        RaceDetailed {
+            race: self,
+            polls: 0,
+        }
+    }
 
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    /// Poll the futures, returning the output of the winner together with
+    /// its index.
+    fn poll_indexed(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<(usize, Fut::Output)> {
         let mut this = self.project();
-        assert!(!*this.done, "Futures must not be polled after completing");
+        debug_assert!(!*this.done, "Futures must not be polled after completing");
+        if *this.done {
+            return Poll::Pending;
+        }
 
         for index in this.indexer.iter() {
             let fut = utils::get_pin_mut(this.futures.as_mut(), index).unwrap();
             match fut.poll(cx) {
                 Poll::Ready(item) => {
                     *this.done = true;
-                    return Poll::Ready(item);
+                    return Poll::Ready((index, item));
                 }
                 Poll::Pending => continue,
             }
@@ -62,6 +98,63 @@ where
     }
 }
 
+impl<Fut, const N: usize> Future for Race<Fut, N>
+where
+    Fut: Future,
+{
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.poll_indexed(cx).map(|(_, output)| output)
+    }
+}
+
+/// A future which waits for the first future to complete, reporting which
+/// future won.
+///
+/// This `struct` is created by the [`detailed`] method on [`Race`]. See its
+/// documentation for more.
+///
+/// [`detailed`]: Race::detailed
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct RaceDetailed<Fut, const N: usize>
+where
+    Fut: Future,
+{
+    #[pin]
+    race: Race<Fut, N>,
+    polls: usize,
+}
+
+impl<Fut, const N: usize> fmt::Debug for RaceDetailed<Fut, N>
+where
+    Fut: Future + fmt::Debug,
+    Fut::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.race.fmt(f)
+    }
+}
+
+impl<Fut, const N: usize> Future for RaceDetailed<Fut, N>
+where
+    Fut: Future,
+{
+    type Output = RaceOutcome<Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        *this.polls += 1;
+        let (winner_index, output) = ready!(this.race.poll_indexed(cx));
+        Poll::Ready(RaceOutcome {
+            winner: Completion::new(winner_index, output),
+            losers: N - 1,
+            elapsed_polls: *this.polls,
+        })
+    }
+}
+
 impl<Fut, const N: usize> RaceTrait for [Fut; N]
 where
     Fut: IntoFuture,
@@ -93,4 +186,30 @@ mod test {
             assert!(matches!(res, "hello" | "world"));
         });
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn detailed() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        // Without wakers of their own, every future is polled on each poll.
+        let (a, _) = ScriptedFuture::new([Step::WakeLater; 3]);
+        let (b, b_handle) =
+            ScriptedFuture::new([Step::WakeLater, Step::WakeLater, Step::Ready("b")]);
+        let (c, _) = ScriptedFuture::new([Step::WakeLater; 3]);
+        let mut fut = pin!([a, b, c].race().detailed());
+        let waker = CountingWaker::new();
+
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert!(b_handle.wake());
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert!(b_handle.wake());
+        let outcome = RaceOutcome {
+            winner: Completion::new(1, "b"),
+            losers: 2,
+            elapsed_polls: 3,
+        };
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(outcome));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::future::Completion;
 use core::future::Future;
 
 pub(crate) mod array;
@@ -9,6 +10,14 @@ pub(crate) mod vec;
 ///
 /// Awaits multiple future at once, returning as soon as one completes. The
 /// other futures are cancelled.
+///
+/// Unlike [`Join`][crate::future::Join], this is not implemented for the empty
+/// tuple: a race without any futures would never have a winner.
+///
+/// # Drop order
+///
+/// The futures of a race over a tuple are dropped in the order of the
+/// tuple, whether the race is dropped before or after it completes.
 pub trait Race {
     /// The resulting output type.
     type Output;
@@ -24,3 +33,23 @@ pub trait Race {
     /// This function returns a new future which polls all futures concurrently.
     fn race(self) -> Self::Future;
 }
+
+/// The output of a race, together with details about how it was won.
+///
+/// This is the output of the futures created by the `detailed` method on
+/// [`array::Race`] and [`vec::Race`].
+///
+/// [`array::Race`]: crate::array::Race::detailed
+/// [`vec::Race`]: crate::vec::Race::detailed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RaceOutcome<T> {
+    /// The output of the future which won the race, together with its
+    /// index.
+    pub winner: Completion<T>,
+    /// The number of futures which lost the race. They were dropped without
+    /// completing.
+    pub losers: usize,
+    /// The number of times the race was polled, including the poll in which
+    /// it completed.
+    pub elapsed_polls: usize,
+}
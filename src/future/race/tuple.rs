@@ -1,5 +1,5 @@
 use super::Race as RaceTrait;
-use crate::utils;
+use crate::utils::{self, Trace};
 
 use core::fmt::{self, Debug};
 use core::future::{Future, IntoFuture};
@@ -26,6 +26,9 @@ macro_rules! impl_race_tuple {
         )* {
             done: bool,
             indexer: utils::Indexer,
+            trace: Trace,
+            // Fields are dropped in declaration order, which drops the
+            // futures in the order of the tuple as documented on `Race`.
             $(#[pin] $F: $F,)*
         }
 
@@ -40,6 +43,34 @@ macro_rules! impl_race_tuple {
             }
         }
 
+        impl<T, $($F),*> $StructName<T, $($F),*>
+        where $(
+            $F: Future<Output = T>,
+        )* {
+            /// Name the span in which this race is traced, so its events can
+            /// be told apart from those of other races.
+            ///
+            /// This requires the `tracing` feature.
+            #[cfg(feature = "tracing")]
+            pub fn named(self, name: &str) -> Self {
+                self.trace.set_name(name);
+                self
+            }
+        }
+
+        /// Cloning creates a new, unpolled race from clones of the futures.
+        /// A clone of a race which has completed has completed as well.
+        impl<T, $($F),*> Clone for $StructName<T, $($F),*>
+        where $(
+            $F: Future<Output = T> + Clone,
+        )* {
+            fn clone(&self) -> Self {
+                let mut race = ($(self.$F.clone(),)*).race();
+                race.done = self.done;
+                race
+            }
+        }
+
         impl<T, $($F),*> RaceTrait for ($($F,)*)
         where $(
             $F: IntoFuture<Output = T>,
@@ -52,6 +83,7 @@ macro_rules! impl_race_tuple {
                 $StructName {
                     done: false,
                     indexer: utils::Indexer::new(utils::tuple_len!($($F,)*)),
+                    trace: Trace::new("race", utils::tuple_len!($($F,)*)),
                     $($F: $F.into_future()),*
                 }
             }
@@ -67,7 +99,10 @@ macro_rules! impl_race_tuple {
                 self: Pin<&mut Self>, cx: &mut Context<'_>
             ) -> Poll<Self::Output> {
                 let mut this = self.project();
-                assert!(!*this.done, "Futures must not be polled after completing");
+                debug_assert!(!*this.done, "Futures must not be polled after completing");
+                if *this.done {
+                    return Poll::Pending;
+                }
 
                 #[repr(usize)]
                 enum Indexes {
@@ -77,10 +112,12 @@ macro_rules! impl_race_tuple {
                 for i in this.indexer.iter() {
                     utils::gen_conditions!(i, this, cx, poll, $((Indexes::$F as usize; $F, {
                         Poll::Ready(output) => {
+                            this.trace.polled(i, true);
+                            this.trace.completed(i, 1);
                             *this.done = true;
                             return Poll::Ready(output);
                         },
-                        _ => continue,
+                        _ => this.trace.polled(i, false),
                     }))*);
                 }
 
@@ -102,6 +139,14 @@ impl_race_tuple! { Race9 A B C D E F G H I }
 impl_race_tuple! { Race10 A B C D E F G H I J }
 impl_race_tuple! { Race11 A B C D E F G H I J K }
 impl_race_tuple! { Race12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race16 A B C D E F G H I J K L M N O P }
 
 #[cfg(test)]
 mod test {
@@ -135,4 +180,29 @@ mod test {
             assert!(matches!(result, "hello" | "world"));
         });
     }
+
+    /// The polling order is deterministic, so a race between futures which
+    /// are all ready on the first poll must always pick the same winner.
+    #[test]
+    fn race_3_deterministic_winner() {
+        for _ in 0..100 {
+            let winner = futures_lite::future::block_on(async {
+                let a = future::ready("a");
+                let b = future::ready("b");
+                let c = future::ready("c");
+                (a, b, c).race().await
+            });
+            assert_eq!(winner, "a");
+        }
+    }
+
+    #[test]
+    fn clone_runs_independently() {
+        futures_lite::future::block_on(async {
+            let fut = (future::pending(), future::ready("world")).race();
+            let clone = fut.clone();
+            assert_eq!(fut.await, "world");
+            assert_eq!(clone.await, "world");
+        });
+    }
 }
@@ -1,6 +1,7 @@
-use crate::utils::{self, Indexer};
+use crate::utils::{Indexer, PinnedVec, Trace, WakerVec};
 
-use super::Race as RaceTrait;
+use super::{Race as RaceTrait, RaceOutcome};
+use crate::future::Completion;
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
@@ -8,7 +9,7 @@ use alloc::vec::Vec;
 use core::fmt;
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
-use core::task::{Context, Poll};
+use core::task::{ready, Context, Poll};
 
 use pin_project::pin_project;
 
@@ -25,10 +26,11 @@ pub struct Race<Fut>
 where
     Fut: Future,
 {
-    #[pin]
-    futures: Vec<Fut>,
+    futures: PinnedVec<Fut>,
+    wakers: WakerVec,
     indexer: Indexer,
     done: bool,
+    trace: Trace,
 }
 
 impl<Fut> fmt::Debug for Race<Fut>
@@ -41,30 +43,182 @@ where
     }
 }
 
-impl<Fut> Future for Race<Fut>
+/// Cloning creates a new, unpolled race from clones of the futures. A clone
+/// of a race which has completed has completed as well, so that the futures
+/// are never polled after completing.
+impl<Fut> Clone for Race<Fut>
+where
+    Fut: Future + Clone,
+{
+    fn clone(&self) -> Self {
+        let mut race = self.futures.iter().cloned().collect::<Vec<_>>().race();
+        race.done = self.done;
+        race
+    }
+}
+
+impl<Fut> Race<Fut>
 where
     Fut: Future,
 {
-    type Output = Fut::Output;
+    /// Name the span in which this race is traced, so its events can be told
+    /// apart from those of other races.
+    ///
+    /// This requires the `tracing` feature.
+    #[cfg(feature = "tracing")]
+    pub fn named(self, name: &str) -> Self {
+        self.trace.set_name(name);
+        self
+    }
 
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mut this = self.project();
-        assert!(!*this.done, "Futures must not be polled after completing");
+    /// Report which future won the race, and how long it took.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the race has already completed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::future::Completion;
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::{pending, ready};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let futs = vec![pending().boxed_concurrency(), ready(1).boxed_concurrency()];
+    /// let outcome = futs.race().detailed().await;
+    /// assert_eq!(outcome.winner, Completion::new(1, 1));
+    /// assert_eq!(outcome.losers, 1);
+    /// assert_eq!(outcome.elapsed_polls, 1);
+    /// # });
+    /// ```
+    pub fn detailed(self) -> RaceDetailed<Fut> {
+        assert!(
+            !self.done,
+            "cannot add details to a `Race` which has already completed"
+        );
+        RaceDetailed {
+            race: self,
+            polls: 0,
+        }
+    }
+
+    /// Poll the futures, returning the output of the winner together with
+    /// its index.
+    fn poll_indexed(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<(usize, Fut::Output)> {
+        let this = self.project();
+        debug_assert!(!*this.done, "Futures must not be polled after completing");
+        if *this.done {
+            return Poll::Pending;
+        }
 
+        // A single future doesn't need to be told apart from any others, so
+        // it's polled with the caller's waker directly.
+        if this.futures.len() == 1 {
+            let fut = this.futures.get_pin_mut(0).unwrap();
+            let poll = fut.poll(cx);
+            this.trace.polled(0, poll.is_ready());
+            let output = ready!(poll);
+            *this.done = true;
+            this.trace.completed(0, 1);
+            return Poll::Ready((0, output));
+        }
+
+        let mut readiness = this.wakers.readiness();
+        readiness.set_waker(cx.waker());
+        readiness.set_trace(this.trace);
+        if !readiness.any_ready() {
+            // Nothing is ready yet
+            return Poll::Pending;
+        }
+
+        // Only poll the futures which have been woken since they were last
+        // polled.
         for index in this.indexer.iter() {
-            let fut = utils::get_pin_mut_from_vec(this.futures.as_mut(), index).unwrap();
-            match fut.poll(cx) {
-                Poll::Ready(item) => {
-                    *this.done = true;
-                    return Poll::Ready(item);
-                }
-                Poll::Pending => continue,
+            if !readiness.clear_ready(index) {
+                continue;
+            }
+
+            // unlock readiness so we don't deadlock when polling
+            #[allow(clippy::drop_non_drop)]
+            drop(readiness);
+
+            // Obtain the intermediate waker.
+            let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+
+            let fut = this.futures.get_pin_mut(index).unwrap();
+            let poll = fut.poll(&mut cx);
+            this.trace.polled(index, poll.is_ready());
+            if let Poll::Ready(item) = poll {
+                *this.done = true;
+                this.trace.completed(index, 1);
+                return Poll::Ready((index, item));
             }
+
+            // Lock readiness so we can use it again
+            readiness = this.wakers.readiness();
         }
         Poll::Pending
     }
 }
 
+impl<Fut> Future for Race<Fut>
+where
+    Fut: Future,
+{
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.poll_indexed(cx).map(|(_, output)| output)
+    }
+}
+
+/// A future which waits for the first future to complete, reporting which
+/// future won.
+///
+/// This `struct` is created by the [`detailed`] method on [`Race`]. See its
+/// documentation for more.
+///
+/// [`detailed`]: Race::detailed
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct RaceDetailed<Fut>
+where
+    Fut: Future,
+{
+    #[pin]
+    race: Race<Fut>,
+    polls: usize,
+}
+
+impl<Fut> fmt::Debug for RaceDetailed<Fut>
+where
+    Fut: Future + fmt::Debug,
+    Fut::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.race.fmt(f)
+    }
+}
+
+impl<Fut> Future for RaceDetailed<Fut>
+where
+    Fut: Future,
+{
+    type Output = RaceOutcome<Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        *this.polls += 1;
+        let (winner_index, output) = ready!(this.race.as_mut().poll_indexed(cx));
+        Poll::Ready(RaceOutcome {
+            winner: Completion::new(winner_index, output),
+            losers: this.race.futures.len() - 1,
+            elapsed_polls: *this.polls,
+        })
+    }
+}
+
 impl<Fut> RaceTrait for Vec<Fut>
 where
     Fut: IntoFuture,
@@ -73,9 +227,17 @@ where
     type Future = Race<Fut::IntoFuture>;
 
     fn race(self) -> Self::Future {
+        // A single future is polled with the caller's waker directly, so its
+        // wakers are never allocated.
+        let wakers = match self.len() {
+            1 => WakerVec::lazy(1),
+            len => WakerVec::new(len),
+        };
         Race {
+            wakers,
             indexer: Indexer::new(self.len()),
-            futures: self.into_iter().map(|fut| fut.into_future()).collect(),
+            trace: Trace::new("race", self.len()),
+            futures: PinnedVec::new(self.into_iter().map(|fut| fut.into_future()).collect()),
             done: false,
         }
     }
@@ -97,4 +259,118 @@ mod test {
             assert!(matches!(res, "hello" | "world"));
         });
     }
+
+    /// Racing no futures must not panic; there is no winner, so the race
+    /// never resolves.
+    #[test]
+    fn empty() {
+        futures_lite::future::block_on(async {
+            let mut fut = Vec::<future::Ready<u8>>::new().race();
+            assert_eq!(futures_lite::future::poll_once(&mut fut).await, None);
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "Futures must not be polled after completing")
+    )]
+    fn poll_after_ready() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        // `a` is woken after the race has been won, which marks it as ready
+        // to be polled. It still mustn't be polled again.
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(2)]);
+        let mut fut = pin!(vec![a, b].race());
+        let waker = CountingWaker::new();
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(2));
+        assert!(a_handle.wake());
+        // In release builds this returns `Pending` rather than panicking.
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert_eq!(a_handle.polls(), 1);
+    }
+
+    #[test]
+    fn clone_runs_independently() {
+        futures_lite::future::block_on(async {
+            let fut = vec![future::ready("hello"), future::ready("world")].race();
+            let clone = fut.clone();
+            assert_eq!(fut.await, "hello");
+            assert_eq!(clone.await, "hello");
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn clone_after_completion() {
+        use crate::test_utils::{step, CountingWaker};
+        use core::pin::pin;
+
+        let mut fut = pin!(vec![future::ready(1), future::ready(2)].race());
+        let waker = CountingWaker::new();
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(1));
+        // The clone has completed as well, so the winner isn't polled again.
+        assert!(fut.clone().done);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn only_polls_woken_futures() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let (futures, handles): (Vec<_>, Vec<_>) = (0..100)
+            .map(|i| ScriptedFuture::new([Step::WakeLater, Step::WakeLater, Step::Ready(i)]))
+            .unzip();
+        let mut fut = pin!(futures.race());
+        let waker = CountingWaker::new();
+
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert!(handles.iter().all(|handle| handle.polls() == 1));
+
+        // Waking a single future only polls that future.
+        assert!(handles[42].wake());
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert_eq!(handles[42].polls(), 2);
+        assert_eq!(
+            handles.iter().map(|handle| handle.polls()).sum::<usize>(),
+            101
+        );
+
+        assert!(handles[42].wake());
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(42));
+        assert_eq!(
+            handles.iter().map(|handle| handle.polls()).sum::<usize>(),
+            102
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn detailed() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let (a, _) = ScriptedFuture::new([Step::WakeLater]);
+        let (b, b_handle) =
+            ScriptedFuture::new([Step::WakeLater, Step::WakeLater, Step::Ready("b")]);
+        let (c, _) = ScriptedFuture::new([Step::WakeLater]);
+        let mut fut = pin!(vec![a, b, c].race().detailed());
+        let waker = CountingWaker::new();
+
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert!(b_handle.wake());
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert!(b_handle.wake());
+        let outcome = RaceOutcome {
+            winner: Completion::new(1, "b"),
+            losers: 2,
+            elapsed_polls: 3,
+        };
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(outcome));
+    }
 }
@@ -1,9 +1,17 @@
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::future::race_ok::format;
+
 use core::fmt;
 use core::ops::{Deref, DerefMut};
 #[cfg(feature = "std")]
 use std::error::Error;
 
 /// A collection of errors.
+#[derive(Clone, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct AggregateError<E, const N: usize> {
     inner: [E; N],
@@ -13,23 +21,105 @@ impl<E, const N: usize> AggregateError<E, N> {
     pub(super) fn new(inner: [E; N]) -> Self {
         Self { inner }
     }
+
+    /// Consume the aggregate, returning the errors of all futures in the
+    /// order the futures were passed in.
+    pub fn into_inner(self) -> [E; N] {
+        self.inner
+    }
+
+    /// Returns a reference to the error of the `i`th future, or `None` if
+    /// `i` is out of bounds.
+    ///
+    /// The aggregate can also be indexed like the array of errors it derefs
+    /// to, e.g. `errs[i]` or `&errs[1..]`, which panics if the index is out
+    /// of bounds.
+    pub fn get(&self, i: usize) -> Option<&E> {
+        self.inner.get(i)
+    }
+
+    /// Returns the error of the first future, or `None` if there were no
+    /// futures.
+    pub fn first(&self) -> Option<&E> {
+        self.inner.first()
+    }
+
+    /// Returns the error of the last future, or `None` if there were no
+    /// futures.
+    pub fn last(&self) -> Option<&E> {
+        self.inner.last()
+    }
+
+    /// Returns the number of errors, which is always `N`.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the aggregate holds no errors, which is only the
+    /// case for `N == 0`.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the errors of all futures, in the order the futures were
+    /// passed in.
+    pub fn errors(&self) -> &[E] {
+        &self.inner
+    }
 }
 
-impl<E: fmt::Display, const N: usize> fmt::Debug for AggregateError<E, N> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "{self}:")?;
+/// Implement downcasting helpers for aggregates of boxed errors.
+#[cfg(feature = "std")]
+macro_rules! impl_downcast {
+    ($($Error:ty),*) => {$(
+        impl<const N: usize> AggregateError<Box<$Error>, N> {
+            /// Attempts to downcast the error of the `i`th future to a
+            /// concrete type.
+            ///
+            /// # Panics
+            ///
+            /// This panics if `i` is out of bounds.
+            pub fn downcast_ref<T: Error + 'static>(&self, i: usize) -> Option<&T> {
+                self.inner[i].downcast_ref::<T>()
+            }
 
-        for (i, err) in self.inner.iter().enumerate() {
-            writeln!(f, "- Error {}: {err}", i + 1)?;
+            /// Returns an iterator over all errors which are of type `T`.
+            pub fn iter_downcast<T: Error + 'static>(&self) -> impl Iterator<Item = &T> {
+                self.inner.iter().filter_map(|err| err.downcast_ref::<T>())
+            }
         }
+    )*};
+}
 
-        Ok(())
+#[cfg(feature = "std")]
+impl_downcast! {
+    dyn Error + 'static,
+    dyn Error + Send + 'static,
+    dyn Error + Send + Sync + 'static
+}
+
+impl<E, const N: usize> From<[E; N]> for AggregateError<E, N> {
+    fn from(inner: [E; N]) -> Self {
+        Self::new(inner)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E, const N: usize> From<AggregateError<E, N>> for Vec<E> {
+    fn from(err: AggregateError<E, N>) -> Self {
+        err.inner.into()
+    }
+}
+
+impl<E: fmt::Display, const N: usize> fmt::Debug for AggregateError<E, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format::debug::<_, &str>(f, &self.inner, &[])
     }
 }
 
 impl<E: fmt::Display, const N: usize> fmt::Display for AggregateError<E, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} errors occured", self.inner.len())
+        format::display::<_, &str>(f, &self.inner, &[])
     }
 }
 
@@ -49,3 +139,49 @@ impl<E, const N: usize> DerefMut for AggregateError<E, N> {
 
 #[cfg(feature = "std")]
 impl<E: Error, const N: usize> std::error::Error for AggregateError<E, N> {}
+
+/// Serializes as a plain sequence of errors.
+#[cfg(feature = "serde")]
+impl<E: serde::Serialize, const N: usize> serde::Serialize for AggregateError<E, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.inner)
+    }
+}
+
+/// Deserializes from a sequence of errors, which must contain exactly `N`
+/// elements.
+#[cfg(feature = "serde")]
+impl<'de, E: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de>
+    for AggregateError<E, N>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::marker::PhantomData;
+        use serde::de::{Error as _, SeqAccess, Visitor};
+
+        struct AggregateVisitor<E, const N: usize>(PhantomData<E>);
+
+        impl<'de, E: serde::Deserialize<'de>, const N: usize> Visitor<'de> for AggregateVisitor<E, N> {
+            type Value = AggregateError<E, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of {N} errors")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut errors: [Option<E>; N] = core::array::from_fn(|_| None);
+                for (i, slot) in errors.iter_mut().enumerate() {
+                    match seq.next_element()? {
+                        Some(err) => *slot = Some(err),
+                        None => return Err(A::Error::invalid_length(i, &self)),
+                    }
+                }
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(A::Error::invalid_length(N + 1, &self));
+                }
+                Ok(AggregateError::new(errors.map(|err| err.unwrap())))
+            }
+        }
+
+        deserializer.deserialize_seq(AggregateVisitor(PhantomData))
+    }
+}
@@ -1,6 +1,8 @@
 use super::RaceOk as RaceOkTrait;
 use crate::utils::array_assume_init;
 use crate::utils::iter_pin_mut;
+use crate::utils::private::TryOutput;
+use crate::utils::PollArray;
 
 use core::array;
 use core::fmt;
@@ -9,7 +11,7 @@ use core::mem::{self, MaybeUninit};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use pin_project::pin_project;
+use pin_project::{pin_project, pinned_drop};
 
 mod error;
 
@@ -23,20 +25,24 @@ pub use error::AggregateError;
 /// [`race_ok`]: crate::future::RaceOk::race_ok
 /// [`RaceOk`]: crate::future::RaceOk
 #[must_use = "futures do nothing unless you `.await` or poll them"]
-#[pin_project]
+#[pin_project(PinnedDrop)]
 pub struct RaceOk<Fut, T, E, const N: usize>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
 {
     #[pin]
     futures: [Fut; N],
     errors: [MaybeUninit<E>; N],
+    errors_states: PollArray<N>,
     completed: usize,
+    done: bool,
 }
 
 impl<Fut, T, E, const N: usize> fmt::Debug for RaceOk<Fut, T, E, N>
 where
-    Fut: Future<Output = Result<T, E>> + fmt::Debug,
+    Fut: Future + fmt::Debug,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
     Fut::Output: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -46,21 +52,36 @@ where
 
 impl<Fut, T, E, const N: usize> Future for RaceOk<Fut, T, E, N>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
 {
-    type Output = Result<T, AggregateError<E, N>>;
+    type Output = <Fut::Output as TryOutput>::WithErrors<T, AggregateError<E, N>>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.project();
 
+        debug_assert!(!*this.done, "Futures must not be polled after completing");
+        if *this.done {
+            return Poll::Pending;
+        }
+
         let futures = iter_pin_mut(this.futures);
 
-        for (fut, out) in futures.zip(this.errors.iter_mut()) {
+        for (i, fut) in futures.enumerate() {
+            // A future which has failed is never polled again, so that it
+            // can't complete twice.
+            if !this.errors_states[i].is_pending() {
+                continue;
+            }
             if let Poll::Ready(output) = fut.poll(cx) {
-                match output {
-                    Ok(ok) => return Poll::Ready(Ok(ok)),
+                match output.into_result() {
+                    Ok(ok) => {
+                        *this.done = true;
+                        return Poll::Ready(Fut::Output::from_errors(Ok(ok)));
+                    }
                     Err(err) => {
-                        *out = MaybeUninit::new(err);
+                        this.errors[i] = MaybeUninit::new(err);
+                        this.errors_states[i].set_ready();
                         *this.completed += 1;
                     }
                 }
@@ -69,19 +90,58 @@ where
 
         let all_completed = *this.completed == N;
         if all_completed {
+            // mark all error states as consumed before we return it
+            this.errors_states.set_all_none();
+
             let mut errors = array::from_fn(|_| MaybeUninit::uninit());
             mem::swap(&mut errors, this.errors);
 
             // SAFETY: we know that all futures are properly initialized because they're all completed
             let result = unsafe { array_assume_init(errors) };
 
-            Poll::Ready(Err(AggregateError::new(result)))
+            *this.done = true;
+            Poll::Ready(Fut::Output::from_errors(Err(AggregateError::new(result))))
         } else {
             Poll::Pending
         }
     }
 }
 
+/// Drop the errors of the futures which have failed on cancellation.
+#[pinned_drop]
+impl<Fut, T, E, const N: usize> PinnedDrop for RaceOk<Fut, T, E, N>
+where
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
+{
+    fn drop(self: Pin<&mut Self>) {
+        let this = self.project();
+        for (state, err) in this.errors_states.iter_mut().zip(this.errors.iter_mut()) {
+            if state.is_ready() {
+                // SAFETY: we've filtered down to only the initialized errors.
+                unsafe { err.assume_init_drop() };
+                state.set_none();
+            }
+        }
+    }
+}
+
+impl<Fut, T, E, const N: usize> RaceOk<Fut, T, E, N>
+where
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
+{
+    pub(crate) fn new(futures: [Fut; N]) -> Self {
+        RaceOk {
+            futures,
+            errors: array::from_fn(|_| MaybeUninit::uninit()),
+            errors_states: PollArray::new_pending(),
+            completed: 0,
+            done: false,
+        }
+    }
+}
+
 impl<Fut, T, E, const N: usize> RaceOkTrait for [Fut; N]
 where
     Fut: IntoFuture<Output = Result<T, E>>,
@@ -91,11 +151,7 @@ where
     type Future = RaceOk<Fut::IntoFuture, T, E, N>;
 
     fn race_ok(self) -> Self::Future {
-        RaceOk {
-            futures: self.map(|fut| fut.into_future()),
-            errors: array::from_fn(|_| MaybeUninit::uninit()),
-            completed: 0,
-        }
+        RaceOk::new(self.map(IntoFuture::into_future))
     }
 }
 
@@ -138,4 +194,174 @@ mod test {
             assert_eq!(errs[1], "oh no");
         });
     }
+
+    #[test]
+    fn accessors() {
+        futures_lite::future::block_on(async {
+            let errs = [
+                future::ready(Err::<(), _>("oops")),
+                future::ready(Err("oh no")),
+            ]
+            .race_ok()
+            .await
+            .unwrap_err();
+            assert_eq!(errs.get(1), Some(&"oh no"));
+            assert_eq!(errs.get(2), None);
+            assert_eq!(errs.first(), Some(&"oops"));
+            assert_eq!(errs.last(), Some(&"oh no"));
+            assert_eq!(errs.len(), 2);
+            assert!(!errs.is_empty());
+            assert_eq!(errs[1], "oh no");
+            assert_eq!(errs.errors(), ["oops", "oh no"]);
+            assert_eq!(Vec::from(errs), vec!["oops", "oh no"]);
+        });
+    }
+
+    #[test]
+    fn downcast() {
+        use std::boxed::Box;
+        use std::error::Error;
+        use std::{fmt, io};
+
+        #[derive(Debug)]
+        struct TimeoutError;
+        impl fmt::Display for TimeoutError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "timed out")
+            }
+        }
+        impl Error for TimeoutError {}
+
+        type BoxError = Box<dyn Error + Send + Sync>;
+        type BoxFuture = core::pin::Pin<Box<dyn Future<Output = Result<(), BoxError>>>>;
+
+        futures_lite::future::block_on(async {
+            let a: BoxFuture = Box::pin(async { Err(TimeoutError.into()) });
+            let b: BoxFuture = Box::pin(async { Err(io::Error::other("oh no").into()) });
+            let errs = [a, b].race_ok().await.unwrap_err();
+            assert!(errs.downcast_ref::<TimeoutError>(0).is_some());
+            assert!(errs.downcast_ref::<TimeoutError>(1).is_none());
+            assert_eq!(errs.iter_downcast::<io::Error>().count(), 1);
+        });
+    }
+
+    #[test]
+    fn display() {
+        let errs = AggregateError::new(["oops", "oh no"]);
+        assert_eq!(
+            errs.to_string(),
+            "2 errors occurred: Error 1: oops; Error 2: oh no"
+        );
+        assert_eq!(
+            format!("{errs:?}"),
+            "2 errors occurred:\n- Error 1: oops\n- Error 2: oh no\n"
+        );
+    }
+
+    #[test]
+    fn index_through_deref() {
+        let mut errs = AggregateError::new(["oops", "oh no", "oh dear"]);
+        assert_eq!(&errs[1..], ["oh no", "oh dear"]);
+        errs[0] = "fixed";
+        assert_eq!(errs[0], "fixed");
+    }
+
+    #[test]
+    fn clone_and_eq() {
+        let errs = AggregateError::new(["oops", "oh no"]);
+        assert_eq!(errs.clone(), errs);
+        assert_ne!(errs, AggregateError::new(["oops", "oh yes"]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let errs = AggregateError::new([1, 2]);
+        let json = serde_json::to_string(&errs).unwrap();
+        assert_eq!(json, "[1,2]");
+        let back: AggregateError<u8, 2> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, errs);
+
+        assert!(serde_json::from_str::<AggregateError<u8, 3>>(&json).is_err());
+        assert!(serde_json::from_str::<AggregateError<u8, 1>>(&json).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "Futures must not be polled after completing")
+    )]
+    fn poll_after_all_errors() {
+        use crate::test_utils::{step, CountingWaker};
+        use alloc::string::String;
+        use core::pin::pin;
+
+        // The errors have been moved out of their slots, so polling again
+        // must not read them a second time.
+        let futures = [
+            future::ready(Err::<u8, _>(String::from("a"))),
+            future::ready(Err(String::from("b"))),
+        ];
+        let mut fut = pin!(futures.race_ok());
+        let waker = CountingWaker::new();
+        let Poll::Ready(Err(errs)) = step(fut.as_mut(), &waker) else {
+            panic!("all futures should have failed");
+        };
+        assert_eq!(*errs, ["a", "b"]);
+        // In release builds this returns `Pending` rather than panicking.
+        assert!(step(fut.as_mut(), &waker).is_pending());
+    }
+
+    /// Every failure is stored in the error slot of its index exactly once,
+    /// even if the future keeps resolving after it has failed.
+    #[cfg(feature = "std")]
+    #[test]
+    fn failed_future_is_not_polled_again() {
+        use crate::test_utils::{ScriptedFuture, Step};
+
+        futures_lite::future::block_on(async {
+            let (a, a_handle) = ScriptedFuture::new([Step::Ready(Err(1))]);
+            let (b, b_handle) = ScriptedFuture::new([Step::Pending, Step::Ready(Err(2))]);
+            let (c, c_handle) =
+                ScriptedFuture::new([Step::Pending, Step::Pending, Step::Ready(Err::<(), u8>(3))]);
+            let futures = [a.resolve_repeatedly(), b.resolve_repeatedly(), c];
+            let errs = futures.race_ok().await.unwrap_err();
+            assert_eq!(*errs, [1, 2, 3]);
+            assert_eq!(a_handle.polls(), 1);
+            assert_eq!(b_handle.polls(), 2);
+            assert_eq!(c_handle.polls(), 3);
+        });
+    }
+
+    /// The race succeeds whichever of the final failure and the success is
+    /// visited first.
+    #[cfg(feature = "std")]
+    #[test]
+    fn success_wins_within_a_poll_pass() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        // Members are visited in order, so swap the last two.
+        let mut orders = Vec::new();
+        for swap in [false, true] {
+            let (a, _) = ScriptedFuture::new([Step::Ready(Err("a"))]);
+            let (b, b_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Err("b"))]);
+            let (c, c_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Ok(3))]);
+            let mut futures = [a, b, c];
+            if swap {
+                futures.swap(1, 2);
+            }
+            let mut race = pin!(futures.race_ok());
+            let waker = CountingWaker::new();
+
+            assert!(step(race.as_mut(), &waker).is_pending());
+            assert!(b_handle.wake());
+            assert!(c_handle.wake());
+            assert!(matches!(step(race.as_mut(), &waker), Poll::Ready(Ok(3))));
+            // `b` is only polled a final time if it is visited before `c`.
+            orders.push(b_handle.polls() == 2);
+        }
+        assert!(orders.contains(&true) && orders.contains(&false));
+    }
 }
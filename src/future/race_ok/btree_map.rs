@@ -0,0 +1,224 @@
+use super::vec::RaceOk as VecRaceOk;
+use super::RaceOk as RaceOkTrait;
+
+use alloc::collections::BTreeMap;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::mem;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use pin_project::pin_project;
+
+/// A future which waits for the first successful future in a `BTreeMap` to
+/// complete.
+///
+/// This `struct` is created by the [`race_ok`] method on the [`RaceOk`] trait.
+/// See its documentation for more.
+///
+/// [`race_ok`]: crate::future::RaceOk::race_ok
+/// [`RaceOk`]: crate::future::RaceOk
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RaceOk<K, Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    /// The keys of the futures, in the order of the map.
+    keys: Vec<K>,
+    race: Option<VecRaceOk<Indexed<Fut>, (usize, T), E>>,
+}
+
+// The keys are never pinned, and the futures are pinned on the heap.
+impl<K, Fut, T, E> Unpin for RaceOk<K, Fut, T, E> where Fut: Future<Output = Result<T, E>> {}
+
+impl<K, Fut, T, E> fmt::Debug for RaceOk<K, Fut, T, E>
+where
+    K: fmt::Debug,
+    Fut: Future<Output = Result<T, E>> + fmt::Debug,
+    T: fmt::Debug,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RaceOk")
+            .field("keys", &self.keys)
+            .field("futures", &self.race)
+            .finish()
+    }
+}
+
+impl<K, Fut, T, E> Future for RaceOk<K, Fut, T, E>
+where
+    K: Ord,
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Result<(K, T), BTreeMap<K, E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        debug_assert!(
+            this.race.is_some(),
+            "Futures must not be polled after completing"
+        );
+        let Some(race) = &mut this.race else {
+            return Poll::Pending;
+        };
+
+        let res = ready!(Pin::new(race).poll(cx));
+        // Drop the siblings of the winner right away, rather than when the
+        // race itself is dropped.
+        this.race = None;
+        let keys = mem::take(&mut this.keys);
+        Poll::Ready(match res {
+            Ok((index, output)) => Ok((keys.into_iter().nth(index).unwrap(), output)),
+            // The keys are in the order of the map, and so are the errors.
+            Err(errors) => Err(keys.into_iter().zip(errors).collect()),
+        })
+    }
+}
+
+/// Race the futures in a map, resolving to the key and the output of the
+/// first future which succeeds.
+///
+/// If all futures fail, this resolves to a map of every error under the key
+/// of its future, which iterates in key order.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use std::collections::BTreeMap;
+/// use std::future;
+///
+/// # futures_lite::future::block_on(async {
+/// let mirrors = BTreeMap::from([
+///     ("us", future::ready(Err::<&str, _>("dns"))),
+///     ("eu", future::ready(Err("timeout"))),
+/// ]);
+/// let errors = mirrors.race_ok().await.unwrap_err();
+/// assert_eq!(errors.into_iter().collect::<Vec<_>>(), [("eu", "timeout"), ("us", "dns")]);
+/// # });
+/// ```
+impl<K, Fut, T, E> RaceOkTrait for BTreeMap<K, Fut>
+where
+    K: Ord,
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = (K, T);
+    type Error = BTreeMap<K, E>;
+    type Future = RaceOk<K, Fut::IntoFuture, T, E>;
+
+    fn race_ok(self) -> Self::Future {
+        let (keys, futures): (Vec<_>, Vec<_>) = self
+            .into_iter()
+            .enumerate()
+            .map(|(index, (key, fut))| {
+                let fut = Indexed {
+                    index,
+                    fut: fut.into_future(),
+                };
+                (key, fut)
+            })
+            .unzip();
+        RaceOk {
+            keys,
+            race: Some(futures.race_ok()),
+        }
+    }
+}
+
+/// A future which pairs its successful output with its position in the map.
+#[pin_project]
+#[derive(Debug)]
+struct Indexed<Fut> {
+    index: usize,
+    #[pin]
+    fut: Fut,
+}
+
+impl<Fut, T, E> Future for Indexed<Fut>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Result<(usize, T), E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let index = *this.index;
+        this.fut.poll(cx).map_ok(|output| (index, output))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future;
+
+    #[test]
+    fn first_ok() {
+        futures_lite::future::block_on(async {
+            let futures = BTreeMap::from([
+                ("a", future::ready(Err("oops"))),
+                ("b", future::ready(Ok(2))),
+                ("c", future::ready(Ok(3))),
+            ]);
+            assert_eq!(futures.race_ok().await, Ok(("b", 2)));
+        });
+    }
+
+    #[test]
+    fn errors_in_key_order() {
+        futures_lite::future::block_on(async {
+            // Insert the futures out of order, and have them fail in reverse
+            // key order.
+            let mut futures = BTreeMap::new();
+            for (key, delay) in [(3, 0), (1, 2), (2, 1)] {
+                futures.insert(key, async move {
+                    for _ in 0..delay {
+                        futures_lite::future::yield_now().await;
+                    }
+                    Err::<(), _>(key * 10)
+                });
+            }
+            let errors = futures.race_ok().await.unwrap_err();
+            let errors: Vec<_> = errors.into_iter().collect();
+            assert_eq!(errors, [(1, 10), (2, 20), (3, 30)]);
+        });
+    }
+
+    #[test]
+    fn empty() {
+        futures_lite::future::block_on(async {
+            let futures: BTreeMap<u8, future::Ready<Result<(), ()>>> = BTreeMap::new();
+            assert_eq!(futures.race_ok().await, Err(BTreeMap::new()));
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn siblings_dropped_on_success() {
+        use crate::test_utils::{step, CountingWaker};
+        use alloc::boxed::Box;
+        use std::sync::Arc;
+
+        type BoxFuture = Pin<Box<dyn Future<Output = Result<u8, ()>>>>;
+
+        let guard = Arc::new(());
+        let sibling = Arc::clone(&guard);
+        let a: BoxFuture = Box::pin(future::ready(Ok(1)));
+        let b: BoxFuture = Box::pin(async move {
+            let _sibling = sibling;
+            future::pending().await
+        });
+        let futures = BTreeMap::from([("a", a), ("b", b)]);
+        let waker = CountingWaker::new();
+        let mut race = futures.race_ok();
+        assert_eq!(step(Pin::new(&mut race), &waker), Poll::Ready(Ok(("a", 1))));
+        // The pending sibling is dropped as soon as the race completes, not
+        // once the race itself is dropped.
+        assert_eq!(Arc::strong_count(&guard), 1);
+        drop(race);
+    }
+}
@@ -0,0 +1,47 @@
+//! The formatting shared by the aggregate errors of `race_ok`.
+
+use core::fmt;
+
+/// Write the name of the `i`th error: its label if there is one, or else its
+/// position.
+fn label<L: AsRef<str>>(f: &mut fmt::Formatter<'_>, labels: &[L], i: usize) -> fmt::Result {
+    match labels.get(i) {
+        Some(label) => f.write_str(label.as_ref()),
+        None => write!(f, "Error {}", i + 1),
+    }
+}
+
+/// Write the errors on a single line, for `Display`.
+pub(crate) fn display<E, L>(f: &mut fmt::Formatter<'_>, errors: &[E], labels: &[L]) -> fmt::Result
+where
+    E: fmt::Display,
+    L: AsRef<str>,
+{
+    write!(f, "{} errors occurred", errors.len())?;
+
+    for (i, err) in errors.iter().enumerate() {
+        let sep = if i == 0 { ':' } else { ';' };
+        write!(f, "{sep} ")?;
+        label(f, labels, i)?;
+        write!(f, ": {err}")?;
+    }
+
+    Ok(())
+}
+
+/// Write the errors as a list with one error per line, for `Debug`.
+pub(crate) fn debug<E, L>(f: &mut fmt::Formatter<'_>, errors: &[E], labels: &[L]) -> fmt::Result
+where
+    E: fmt::Display,
+    L: AsRef<str>,
+{
+    writeln!(f, "{} errors occurred:", errors.len())?;
+
+    for (i, err) in errors.iter().enumerate() {
+        write!(f, "- ")?;
+        label(f, labels, i)?;
+        writeln!(f, ": {err}")?;
+    }
+
+    Ok(())
+}
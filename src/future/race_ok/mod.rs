@@ -1,6 +1,9 @@
 use core::future::Future;
 
 pub(crate) mod array;
+#[cfg(feature = "alloc")]
+pub(crate) mod btree_map;
+mod format;
 pub(crate) mod tuple;
 #[cfg(feature = "alloc")]
 pub(crate) mod vec;
@@ -10,6 +13,52 @@ pub(crate) mod vec;
 /// Awaits multiple futures simultaneously, returning the output of the first
 /// future which completes. If no future completes successfully, returns an
 /// aggregate error of all failed futures.
+///
+/// To race futures which resolve to `Option`, use
+/// [`RaceSome`][crate::future::RaceSome] instead.
+///
+/// Unlike [`TryJoin`][crate::future::TryJoin], this is not implemented for the
+/// empty tuple, as there would be no future to determine the output type.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use std::future::ready;
+///
+/// # futures_lite::future::block_on(async {
+/// let res = (ready(Err("oh no")), ready(Ok(1))).race_ok().await;
+/// assert!(matches!(res, Ok(1)));
+/// # });
+/// ```
+///
+/// The output of the race is always a `Result`, so it can be awaited by
+/// generic code:
+///
+/// ```
+/// use futures_concurrency::future::RaceOk;
+///
+/// async fn first_ok<T: RaceOk>(futures: T) -> Result<T::Output, T::Error> {
+///     futures.race_ok().await
+/// }
+/// # futures_lite::future::block_on(async {
+/// # let res = first_ok([std::future::ready(Ok::<_, ()>(1))]).await;
+/// # assert!(matches!(res, Ok(1)));
+/// # });
+/// ```
+///
+/// # Completing in the same poll
+///
+/// When the final failure and a success are both observed while polling the
+/// race, the success wins, in whichever order the two were polled. A race
+/// only resolves to the aggregate error once every member has been polled and
+/// none of them has succeeded.
+///
+/// # Drop order
+///
+/// When a race over a tuple is dropped, the errors of the futures which have
+/// already failed are dropped first, followed by the futures themselves, both
+/// in the order of the tuple.
 pub trait RaceOk {
     /// The resulting output type.
     type Output;
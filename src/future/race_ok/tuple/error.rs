@@ -1,3 +1,5 @@
+use crate::future::race_ok::format;
+
 use core::fmt;
 use core::ops::{Deref, DerefMut};
 #[cfg(feature = "std")]
@@ -13,12 +15,18 @@ impl<E, const N: usize> AggregateError<E, N> {
     pub(super) fn new(inner: [E; N]) -> Self {
         Self { inner }
     }
+
+    /// Consume the aggregate, returning the errors of all futures in the
+    /// order the futures were passed in.
+    pub fn into_inner(self) -> [E; N] {
+        self.inner
+    }
 }
 
 #[cfg(feature = "std")]
 impl<E: Error, const N: usize> fmt::Debug for AggregateError<E, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "{self}:")?;
+        writeln!(f, "{N} errors occurred:")?;
 
         for (i, err) in self.inner.iter().enumerate() {
             writeln!(f, "- Error {}: {err}", i + 1)?;
@@ -36,27 +44,21 @@ impl<E: Error, const N: usize> fmt::Debug for AggregateError<E, N> {
 #[cfg(not(feature = "std"))]
 impl<E: fmt::Display, const N: usize> fmt::Debug for AggregateError<E, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "{self}:")?;
-
-        for (i, err) in self.inner.iter().enumerate() {
-            writeln!(f, "- Error {}: {err}", i + 1)?;
-        }
-
-        Ok(())
+        format::debug::<_, &str>(f, &self.inner, &[])
     }
 }
 
 #[cfg(feature = "std")]
 impl<E: Error, const N: usize> fmt::Display for AggregateError<E, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} errors occured", self.inner.len())
+        format::display::<_, &str>(f, &self.inner, &[])
     }
 }
 
 #[cfg(not(feature = "std"))]
 impl<E: fmt::Display, const N: usize> fmt::Display for AggregateError<E, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} errors occured", self.inner.len())
+        format::display::<_, &str>(f, &self.inner, &[])
     }
 }
 
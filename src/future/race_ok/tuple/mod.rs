@@ -1,11 +1,13 @@
 use super::RaceOk;
-use crate::utils::{self, PollArray};
+use crate::utils::private::TryOutput;
+use crate::utils::{self, PollArray, Trace};
 
 use core::array;
 use core::fmt;
 use core::future::{Future, IntoFuture};
-use core::mem::{self, MaybeUninit};
+use core::mem::{self, ManuallyDrop, MaybeUninit};
 use core::pin::Pin;
+use core::ptr;
 use core::task::{Context, Poll};
 
 use pin_project::{pin_project, pinned_drop};
@@ -31,9 +33,9 @@ macro_rules! impl_race_ok_tuple {
         #[must_use = "futures do nothing unless you `.await` or poll them"]
         #[allow(non_snake_case)]
         #[pin_project(PinnedDrop)]
-        pub struct $StructName<T, ERR, $($F),*>
+        pub struct $StructName<T, ERR, $($F,)* INSPECT = fn(usize, &ERR)>
         where
-            $( $F: Future<Output = Result<T, ERR>>, )*
+            $( $F: Future, $F::Output: TryOutput<Ok = T, Error = ERR>, )*
             ERR: fmt::Debug,
         {
             completed: usize,
@@ -41,12 +43,67 @@ macro_rules! impl_race_ok_tuple {
             indexer: utils::Indexer,
             errors: [MaybeUninit<ERR>; $StructName],
             errors_states: PollArray<{ $StructName }>,
+            inspect: Option<INSPECT>,
+            trace: Trace,
+            // The futures are dropped after `PinnedDrop::drop` has dropped
+            // the errors, in the order of the tuple as documented on
+            // `RaceOk`.
             $( #[pin] $F: $F, )*
         }
 
-        impl<T, ERR, $($F),*> fmt::Debug for $StructName<T, ERR, $($F),*>
+        impl<T, ERR, $($F,)* INSPECT> $StructName<T, ERR, $($F,)* INSPECT>
         where
-            $( $F: Future<Output = Result<T, ERR>> + fmt::Debug, )*
+            $( $F: Future, $F::Output: TryOutput<Ok = T, Error = ERR>, )*
+            ERR: fmt::Debug,
+        {
+            /// Name the span in which this race is traced, so its events can
+            /// be told apart from those of other races.
+            ///
+            /// This requires the `tracing` feature.
+            #[cfg(feature = "tracing")]
+            pub fn named(self, name: &str) -> Self {
+                self.trace.set_name(name);
+                self
+            }
+
+            /// Call `f` with the index and the error of each future, as soon
+            /// as that future fails.
+            ///
+            /// The error is passed by reference, and still becomes part of
+            /// the aggregate error if all futures fail. `f` is never called
+            /// for the future which succeeds.
+            ///
+            /// # Panics
+            ///
+            /// This panics if the race has already completed.
+            pub fn inspect_err<FN>(self, f: FN) -> $StructName<T, ERR, $($F,)* FN>
+            where
+                FN: FnMut(usize, &ERR),
+            {
+                assert!(!self.done, "cannot inspect a `RaceOk` which has already completed");
+                let this = ManuallyDrop::new(self);
+                // SAFETY: every field is read exactly once, and `this` is
+                // never dropped. The futures haven't been pinned yet, as
+                // `self` is taken by value.
+                unsafe {
+                    drop(ptr::read(&this.inspect));
+                    $StructName {
+                        completed: ptr::read(&this.completed),
+                        done: ptr::read(&this.done),
+                        indexer: ptr::read(&this.indexer),
+                        errors: ptr::read(&this.errors),
+                        errors_states: ptr::read(&this.errors_states),
+                        inspect: Some(f),
+                        trace: ptr::read(&this.trace),
+                        $($F: ptr::read(&this.$F)),*
+                    }
+                }
+            }
+        }
+
+        impl<T, ERR, $($F,)* INSPECT> fmt::Debug for $StructName<T, ERR, $($F,)* INSPECT>
+        where
+            $( $F: Future + fmt::Debug, $F::Output: TryOutput<Ok = T, Error = ERR>, )*
             ERR: fmt::Debug,
         {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -56,6 +113,56 @@ macro_rules! impl_race_ok_tuple {
             }
         }
 
+        /// Cloning creates a race from clones of the futures, which keeps the
+        /// errors of the futures which have already failed, and never polls
+        /// them again. A clone of a race which has completed has completed
+        /// as well. Races with an [`inspect_err`] callback can't be cloned,
+        /// as the callback may hold state of its own.
+        ///
+        /// [`inspect_err`]: Self::inspect_err
+        impl<T, ERR, $($F,)*> Clone for $StructName<T, ERR, $($F,)*>
+        where
+            $( $F: Future + Clone, $F::Output: TryOutput<Ok = T, Error = ERR>, )*
+            ERR: fmt::Debug + Clone,
+        {
+            fn clone(&self) -> Self {
+                let mut race = Self::new(($(self.$F.clone(),)*));
+                for i in 0..$StructName {
+                    if self.errors_states[i].is_ready() {
+                        // SAFETY: the error of a future which has failed is
+                        // initialized until all errors are taken, which
+                        // marks their states as consumed.
+                        let err = unsafe { self.errors[i].assume_init_ref() };
+                        race.errors[i] = MaybeUninit::new(err.clone());
+                        race.errors_states[i].set_ready();
+                    }
+                }
+                race.completed = self.completed;
+                race.done = self.done;
+                race
+            }
+        }
+
+        impl<T, ERR, $($F,)*> $StructName<T, ERR, $($F,)*>
+        where
+            $( $F: Future, $F::Output: TryOutput<Ok = T, Error = ERR>, )*
+            ERR: fmt::Debug,
+        {
+            #[allow(non_snake_case)]
+            pub(crate) fn new(($($F,)*): ($($F,)*)) -> Self {
+                $StructName {
+                    completed: 0,
+                    done: false,
+                    indexer: utils::Indexer::new($StructName),
+                    errors: array::from_fn(|_| MaybeUninit::uninit()),
+                    errors_states: PollArray::new_pending(),
+                    inspect: None,
+                    trace: Trace::new("race_ok", $StructName),
+                    $($F),*
+                }
+            }
+        }
+
         impl<T, ERR, $($F),*> RaceOk for ($($F,)*)
         where
             $( $F: IntoFuture<Output = Result<T, ERR>>, )*
@@ -67,23 +174,22 @@ macro_rules! impl_race_ok_tuple {
 
             fn race_ok(self) -> Self::Future {
                 let ($($F,)*): ($($F,)*) = self;
-                $StructName {
-                    completed: 0,
-                    done: false,
-                    indexer: utils::Indexer::new($StructName),
-                    errors: array::from_fn(|_| MaybeUninit::uninit()),
-                    errors_states: PollArray::new_pending(),
-                    $($F: $F.into_future()),*
-                }
+                $StructName::new(($($F.into_future(),)*))
             }
         }
 
-        impl<T, ERR, $($F),*> Future for $StructName<T, ERR, $($F),*>
+        impl<T, ERR, $($F,)* INSPECT> Future for $StructName<T, ERR, $($F,)* INSPECT>
         where
-            $( $F: Future<Output = Result<T, ERR>>, )*
+            $( $F: Future, $F::Output: TryOutput<Ok = T, Error = ERR>, )*
             ERR: fmt::Debug,
+            INSPECT: FnMut(usize, &ERR),
         {
-            type Output = Result<T, AggregateError<ERR, { $StructName }>>;
+            // The kind of output is decided by the first future, and all other
+            // futures are required to share its error type.
+            type Output = <<utils::tuple_first!($($F,)*) as Future>::Output as TryOutput>::WithErrors<
+                T,
+                AggregateError<ERR, { $StructName }>,
+            >;
 
             fn poll(
                 self: Pin<&mut Self>, cx: &mut Context<'_>
@@ -92,30 +198,47 @@ macro_rules! impl_race_ok_tuple {
 
                 let mut this = self.project();
 
-                let can_poll = !*this.done;
-                assert!(can_poll, "Futures must not be polled after completing");
+                debug_assert!(!*this.done, "Futures must not be polled after completing");
+                if *this.done {
+                    return Poll::Pending;
+                }
 
                 #[repr(usize)]
                 enum Indexes {
                     $($F),*
                 }
 
+                // Wraps the output in the kind of output of the first future.
+                let wrap = <<utils::tuple_first!($($F,)*) as Future>::Output as TryOutput>::from_errors;
+
                 for i in this.indexer.iter() {
+                    // A future which has failed is never polled again, so
+                    // that it can't complete twice.
+                    if !this.errors_states[i].is_pending() {
+                        continue;
+                    }
                     utils::gen_conditions!(i, this, cx, poll, $((Indexes::$F as usize; $F, {
-                        Poll::Ready(output) => match output {
+                        Poll::Ready(output) => match output.into_result() {
                             Ok(output) => {
                                 *this.done = true;
                                 *this.completed += 1;
-                                return Poll::Ready(Ok(output));
+                                this.trace.polled(i, true);
+                                this.trace.completed(i, *this.completed);
+                                return Poll::Ready(wrap(Ok(output)));
                             },
                             Err(err) => {
+                                if let Some(inspect) = this.inspect {
+                                    inspect(i, &err);
+                                }
                                 this.errors[i] = MaybeUninit::new(err);
                                 this.errors_states[i].set_ready();
                                 *this.completed += 1;
+                                this.trace.polled(i, true);
+                                this.trace.completed(i, *this.completed);
                                 continue;
                             },
                         },
-                        _ => continue,
+                        _ => this.trace.polled(i, false),
                     }))*);
                 }
 
@@ -130,7 +253,7 @@ macro_rules! impl_race_ok_tuple {
                     let result = unsafe { utils::array_assume_init(errors) };
 
                     *this.done = true;
-                    return Poll::Ready(Err(AggregateError::new(result)));
+                    return Poll::Ready(wrap(Err(AggregateError::new(result))));
                 }
 
                 Poll::Pending
@@ -138,9 +261,9 @@ macro_rules! impl_race_ok_tuple {
         }
 
         #[pinned_drop]
-        impl<T, ERR, $($F,)*> PinnedDrop for $StructName<T, ERR, $($F,)*>
+        impl<T, ERR, $($F,)* INSPECT> PinnedDrop for $StructName<T, ERR, $($F,)* INSPECT>
         where
-            $( $F: Future<Output = Result<T, ERR>>, )*
+            $( $F: Future, $F::Output: TryOutput<Ok = T, Error = ERR>, )*
             ERR: fmt::Debug,
         {
             fn drop(self: Pin<&mut Self>) {
@@ -173,6 +296,14 @@ impl_race_ok_tuple! { RaceOk9 A B C D E F G H I }
 impl_race_ok_tuple! { RaceOk10 A B C D E F G H I J }
 impl_race_ok_tuple! { RaceOk11 A B C D E F G H I J K }
 impl_race_ok_tuple! { RaceOk12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk16 A B C D E F G H I J K L M N O P }
 
 #[cfg(test)]
 mod test {
@@ -219,4 +350,150 @@ mod test {
             assert_eq!(errors[1], "world");
         });
     }
+
+    #[test]
+    fn clone_runs_independently() {
+        futures_lite::future::block_on(async {
+            let fut = (
+                future::ready(Err::<&str, _>(())),
+                future::ready(Ok("world")),
+            )
+                .race_ok();
+            let clone = fut.clone();
+            assert!(matches!(fut.await, Ok("world")));
+            assert!(matches!(clone.await, Ok("world")));
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn clone_keeps_errors() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let (a, a_handle) = ScriptedFuture::new([Step::Ready(Err::<(), _>("a"))]);
+        let (b, _) = ScriptedFuture::new([Step::Pending, Step::Ready(Err("b"))]);
+        let mut fut = pin!((a, b).race_ok());
+        let waker = CountingWaker::new();
+        assert!(step(fut.as_mut(), &waker).is_pending());
+
+        // The clone keeps the error of `a`, rather than polling it again.
+        let clone = pin!(fut.clone());
+        let Poll::Ready(Err(errors)) = step(clone, &waker) else {
+            panic!("all futures have failed");
+        };
+        assert_eq!(errors.into_inner(), ["a", "b"]);
+        assert_eq!(a_handle.polls(), 1);
+
+        let Poll::Ready(Err(errors)) = step(fut, &waker) else {
+            panic!("all futures have failed");
+        };
+        assert_eq!(errors.into_inner(), ["a", "b"]);
+    }
+
+    /// A member which keeps resolving after it has failed is skipped while
+    /// a member of a different type is still running.
+    #[cfg(feature = "std")]
+    #[test]
+    fn failed_future_is_not_polled_again() {
+        use crate::test_utils::{ScriptedFuture, Step};
+
+        futures_lite::future::block_on(async {
+            let (a, a_handle) = ScriptedFuture::new([Step::Ready(Err::<u8, _>("a"))]);
+            let b = async {
+                futures_lite::future::yield_now().await;
+                futures_lite::future::yield_now().await;
+                Ok(2)
+            };
+            let out = (a.resolve_repeatedly(), b).race_ok().await;
+            assert!(matches!(out, Ok(2)));
+            assert_eq!(a_handle.polls(), 1);
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn inspect_err_in_failure_order() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::cell::RefCell;
+        use core::pin::pin;
+
+        // Every pending future is polled each time, so the futures fail
+        // after a different number of polls.
+        let (a, _) = ScriptedFuture::new([Step::Pending, Step::Pending, Step::Ready(Err("a"))]);
+        let (b, _) = ScriptedFuture::new([Step::Pending, Step::Ready(Err("b"))]);
+        let (c, _) = ScriptedFuture::new([
+            Step::Pending,
+            Step::Pending,
+            Step::Pending,
+            Step::Ready(Ok(3)),
+        ]);
+        let failures = RefCell::new(Vec::new());
+        let mut race = pin!((a, b, c)
+            .race_ok()
+            .inspect_err(|index, err: &&str| failures.borrow_mut().push((index, *err))));
+        let waker = CountingWaker::new();
+
+        assert!(step(race.as_mut(), &waker).is_pending());
+        assert!(failures.borrow().is_empty());
+
+        assert!(step(race.as_mut(), &waker).is_pending());
+        assert_eq!(*failures.borrow(), [(1, "b")]);
+
+        assert!(step(race.as_mut(), &waker).is_pending());
+        assert_eq!(*failures.borrow(), [(1, "b"), (0, "a")]);
+
+        // The callback isn't called for the winner.
+        assert!(matches!(step(race.as_mut(), &waker), Poll::Ready(Ok(3))));
+        assert_eq!(*failures.borrow(), [(1, "b"), (0, "a")]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn inspect_err_keeps_aggregate() {
+        futures_lite::future::block_on(async {
+            let mut count = 0;
+            let err = (future::ready(Err::<(), _>("oh")), future::ready(Err("no")))
+                .race_ok()
+                .inspect_err(|_, _| count += 1)
+                .await
+                .unwrap_err();
+            assert_eq!(count, 2);
+            assert_eq!(err[0], "oh");
+            assert_eq!(err[1], "no");
+        });
+    }
+
+    /// The race succeeds whichever of the final failure and the success is
+    /// visited first.
+    #[cfg(feature = "std")]
+    #[test]
+    fn success_wins_within_a_poll_pass() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        // The tuple starts each pass at the next member, so the number of
+        // idle passes decides whether `b` or `c` is visited first.
+        let mut orders = Vec::new();
+        for seed in 0..3 {
+            let idle = || core::iter::repeat(Step::Pending).take(seed);
+            let (a, _) = ScriptedFuture::new(idle().chain([Step::Ready(Err("a"))]));
+            let (b, b_handle) =
+                ScriptedFuture::new(idle().chain([Step::WakeLater, Step::Ready(Err("b"))]));
+            let (c, c_handle) =
+                ScriptedFuture::new(idle().chain([Step::WakeLater, Step::Ready(Ok(3))]));
+            let mut race = pin!((a, b, c).race_ok());
+            let waker = CountingWaker::new();
+
+            for _ in 0..=seed {
+                assert!(step(race.as_mut(), &waker).is_pending());
+            }
+            assert!(b_handle.wake());
+            assert!(c_handle.wake());
+            assert!(matches!(step(race.as_mut(), &waker), Poll::Ready(Ok(3))));
+            // `b` is only polled a final time if it is visited before `c`.
+            orders.push(b_handle.polls() == seed + 2);
+        }
+        assert!(orders.contains(&true) && orders.contains(&false));
+    }
 }
@@ -1,6 +1,9 @@
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 
+use crate::future::race_ok::{array, format};
+
+use alloc::borrow::Cow;
 use core::fmt;
 use core::ops::Deref;
 use core::ops::DerefMut;
@@ -8,37 +11,82 @@ use core::ops::DerefMut;
 use std::error::Error;
 
 /// A collection of errors.
-#[repr(transparent)]
 pub struct AggregateError<E> {
     pub(crate) inner: Vec<E>,
+    /// The labels of the futures, or empty if no labels were provided.
+    labels: Vec<Cow<'static, str>>,
 }
 
 impl<E> AggregateError<E> {
     pub(crate) fn new(inner: Vec<E>) -> Self {
-        Self { inner }
+        Self::with_labels(inner, Vec::new())
+    }
+
+    pub(crate) fn with_labels(inner: Vec<E>, labels: Vec<Cow<'static, str>>) -> Self {
+        debug_assert!(labels.is_empty() || labels.len() == inner.len());
+        Self { inner, labels }
+    }
+
+    /// Returns the label of the `i`th future, if labels were provided using
+    /// [`RaceOk::labels`][super::RaceOk::labels].
+    pub fn label(&self, i: usize) -> Option<&str> {
+        self.labels.get(i).map(|label| &**label)
+    }
+
+    /// Consume the aggregate, returning the errors of all futures in the
+    /// order the futures were passed in.
+    pub fn into_inner(self) -> Vec<E> {
+        self.inner
+    }
+
+    /// Returns a reference to the error of the `i`th future, or `None` if
+    /// `i` is out of bounds.
+    ///
+    /// The aggregate can also be indexed like the slice of errors it derefs
+    /// to, e.g. `errs[i]` or `&errs[1..]`, which panics if the index is out
+    /// of bounds.
+    pub fn get(&self, i: usize) -> Option<&E> {
+        self.inner.get(i)
+    }
+
+    /// Returns the error of the first future, or `None` if there were no
+    /// futures.
+    pub fn first(&self) -> Option<&E> {
+        self.inner.first()
+    }
+
+    /// Returns the error of the last future, or `None` if there were no
+    /// futures.
+    pub fn last(&self) -> Option<&E> {
+        self.inner.last()
+    }
+
+    /// Returns the number of errors, which is the number of futures.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the aggregate holds no errors, which is only the
+    /// case if there were no futures.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
     }
 }
 
 impl<E: fmt::Display> fmt::Debug for AggregateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "{self}:")?;
-
-        for (i, err) in self.inner.iter().enumerate() {
-            writeln!(f, "- Error {}: {err}", i + 1)?;
-        }
-
-        Ok(())
+        format::debug(f, &self.inner, &self.labels)
     }
 }
 
 impl<E: fmt::Display> fmt::Display for AggregateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} errors occurred", self.inner.len())
+        format::display(f, &self.inner, &self.labels)
     }
 }
 
 impl<E> Deref for AggregateError<E> {
-    type Target = Vec<E>;
+    type Target = [E];
 
     fn deref(&self) -> &Self::Target {
         &self.inner
@@ -51,5 +99,29 @@ impl<E> DerefMut for AggregateError<E> {
     }
 }
 
+impl<E> IntoIterator for AggregateError<E> {
+    type Item = E;
+    type IntoIter = alloc::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a AggregateError<E> {
+    type Item = &'a E;
+    type IntoIter = core::slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<E, const N: usize> From<array::AggregateError<E, N>> for AggregateError<E> {
+    fn from(err: array::AggregateError<E, N>) -> Self {
+        Self::new(err.into())
+    }
+}
+
 #[cfg(feature = "std")]
 impl<E: Error> Error for AggregateError<E> {}
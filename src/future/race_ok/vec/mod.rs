@@ -1,10 +1,12 @@
 use super::RaceOk as RaceOkTrait;
 use crate::utils::iter_pin_mut;
-use crate::utils::MaybeDone;
+use crate::utils::private::TryOutput;
+use crate::utils::{MaybeDone, PollVec, Trace, WakerVec};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::{boxed::Box, vec::Vec};
 
+use alloc::borrow::Cow;
 use core::fmt;
 use core::future::{Future, IntoFuture};
 use core::mem;
@@ -23,16 +25,155 @@ mod error;
 /// [`race_ok`]: crate::future::RaceOk::race_ok
 /// [`RaceOk`]: crate::future::RaceOk
 #[must_use = "futures do nothing unless you `.await` or poll them"]
-pub struct RaceOk<Fut, T, E>
+pub struct RaceOk<Fut, T, E, F = fn(usize, &E)>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
 {
     elems: Pin<Box<[MaybeDone<Fut>]>>,
+    labels: Vec<Cow<'static, str>>,
+    inspect: Option<F>,
+    wakers: WakerVec,
+    state: PollVec,
+    pending: usize,
+    done: bool,
+    trace: Trace,
 }
 
-impl<Fut, T, E> fmt::Debug for RaceOk<Fut, T, E>
+impl<Fut, T, E, F> RaceOk<Fut, T, E, F>
 where
-    Fut: Future<Output = Result<T, E>> + fmt::Debug,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
+{
+    /// Label each of the futures, in order.
+    ///
+    /// If all futures fail, the labels are included in the `Display` output
+    /// of the [`AggregateError`] in place of the futures' positions, and can
+    /// be looked up using [`AggregateError::label`].
+    ///
+    /// # Panics
+    ///
+    /// This panics if the number of labels doesn't match the number of
+    /// futures, or if the race has already completed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mirrors = vec![future::ready(Err::<(), _>("timeout")), future::ready(Err("dns"))];
+    /// let err = mirrors
+    ///     .race_ok()
+    ///     .labels(["eu-mirror", "us-mirror"])
+    ///     .await
+    ///     .unwrap_err();
+    /// assert_eq!(err.label(1), Some("us-mirror"));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "2 errors occurred: eu-mirror: timeout; us-mirror: dns"
+    /// );
+    /// # });
+    /// ```
+    pub fn labels<I>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'static, str>>,
+    {
+        assert!(
+            !self.done,
+            "cannot label a `RaceOk` which has already completed"
+        );
+        self.labels = labels.into_iter().map(Into::into).collect();
+        assert_eq!(
+            self.labels.len(),
+            self.elems.len(),
+            "the number of labels must match the number of futures"
+        );
+        self
+    }
+
+    /// Name the span in which this race is traced, so its events can be told
+    /// apart from those of other races.
+    ///
+    /// This requires the `tracing` feature.
+    #[cfg(feature = "tracing")]
+    pub fn named(self, name: &str) -> Self {
+        self.trace.set_name(name);
+        self
+    }
+
+    /// Call `f` with the index and the error of each future, as soon as
+    /// that future fails.
+    ///
+    /// The error is passed by reference, and still becomes part of the
+    /// [`AggregateError`] if all futures fail. `f` is never called for the
+    /// future which succeeds.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the race has already completed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mirrors = vec![future::ready(Err("timeout")), future::ready(Ok("data"))];
+    /// let res = mirrors
+    ///     .race_ok()
+    ///     .inspect_err(|index, err| eprintln!("mirror {index} failed: {err}"))
+    ///     .await;
+    /// assert_eq!(res.unwrap(), "data");
+    /// # });
+    /// ```
+    pub fn inspect_err<G>(self, f: G) -> RaceOk<Fut, T, E, G>
+    where
+        G: FnMut(usize, &E),
+    {
+        assert!(
+            !self.done,
+            "cannot inspect a `RaceOk` which has already completed"
+        );
+        let RaceOk {
+            elems,
+            labels,
+            inspect: _,
+            wakers,
+            state,
+            pending,
+            done,
+            trace,
+        } = self;
+        RaceOk {
+            elems,
+            labels,
+            inspect: Some(f),
+            wakers,
+            state,
+            pending,
+            done,
+            trace,
+        }
+    }
+}
+
+// The futures are pinned in their own allocation, and the callback is never
+// pinned.
+impl<Fut, T, E, F> Unpin for RaceOk<Fut, T, E, F>
+where
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
+{
+}
+
+impl<Fut, T, E, F> fmt::Debug for RaceOk<Fut, T, E, F>
+where
+    Fut: Future + fmt::Debug,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
     Fut::Output: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -40,25 +181,76 @@ where
     }
 }
 
-impl<Fut, T, E> Future for RaceOk<Fut, T, E>
+impl<Fut, T, E, F> Future for RaceOk<Fut, T, E, F>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
+    F: FnMut(usize, &E),
 {
-    type Output = Result<T, AggregateError<E>>;
+    type Output = <Fut::Output as TryOutput>::WithErrors<T, AggregateError<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        debug_assert!(!this.done, "Futures must not be polled after completing");
+        if this.done {
+            return Poll::Pending;
+        }
+
+        let mut readiness = this.wakers.readiness();
+        readiness.set_waker(cx.waker());
+        readiness.set_trace(&this.trace);
+        if this.pending != 0 && !readiness.any_ready() {
+            // Nothing is ready yet
+            return Poll::Pending;
+        }
+
+        // Only poll the futures which have been woken since they were last
+        // polled. Futures which have failed are masked, so their wakers no
+        // longer wake us.
+        for i in 0..this.state.len() {
+            if !this.state[i].is_pending() || !readiness.clear_ready(i) {
+                continue;
+            }
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mut all_done = true;
+            // unlock readiness so we don't deadlock when polling
+            #[allow(clippy::drop_non_drop)]
+            drop(readiness);
 
-        for mut elem in iter_pin_mut(self.elems.as_mut()) {
-            if elem.as_mut().poll(cx).is_pending() {
-                all_done = false
-            } else if let Some(output) = elem.take_ok() {
-                return Poll::Ready(Ok(output));
+            // Obtain the intermediate waker.
+            let mut cx = Context::from_waker(this.wakers.get(i).unwrap());
+
+            // SAFETY: the element is never moved out of the pinned slice.
+            let mut elem = unsafe { this.elems.as_mut().map_unchecked_mut(|elems| &mut elems[i]) };
+            let ready = elem.as_mut().poll(&mut cx).is_ready();
+            this.trace.polled(i, ready);
+            if ready {
+                this.trace.completed(i, this.state.len() - this.pending + 1);
+                if let Some(output) = elem.as_mut().take_ok() {
+                    this.done = true;
+                    return Poll::Ready(Fut::Output::from_errors(Ok(output)));
+                }
+                if let Some(inspect) = &mut this.inspect {
+                    // The error is put back, so that it becomes part of the
+                    // aggregate error.
+                    if let Some(err) = elem.as_mut().take_err() {
+                        inspect(i, &err);
+                        elem.set(MaybeDone::Done(TryOutput::from_error(err)));
+                    }
+                }
+                this.state[i].set_ready();
+                this.pending -= 1;
+                this.wakers.readiness().mask(i);
             }
+
+            // Lock readiness so we can use it again
+            readiness = this.wakers.readiness();
         }
+        #[allow(clippy::drop_non_drop)]
+        drop(readiness);
 
-        if all_done {
-            let mut elems = mem::replace(&mut self.elems, Box::pin([]));
+        if this.pending == 0 {
+            this.done = true;
+            let mut elems = mem::replace(&mut this.elems, Box::pin([]));
             let result: Vec<E> = iter_pin_mut(elems.as_mut())
                 .map(|e| match e.take_err() {
                     Some(err) => err,
@@ -67,13 +259,37 @@ where
                     None => unreachable!(),
                 })
                 .collect();
-            Poll::Ready(Err(AggregateError::new(result)))
+            let labels = mem::take(&mut this.labels);
+            Poll::Ready(Fut::Output::from_errors(Err(AggregateError::with_labels(
+                result, labels,
+            ))))
         } else {
             Poll::Pending
         }
     }
 }
 
+impl<Fut, T, E> RaceOk<Fut, T, E>
+where
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
+{
+    pub(crate) fn new(futures: Vec<Fut>) -> Self {
+        let elems: Box<[_]> = futures.into_iter().map(MaybeDone::new).collect();
+        let len = elems.len();
+        RaceOk {
+            elems: elems.into(),
+            labels: Vec::new(),
+            inspect: None,
+            wakers: WakerVec::new(len),
+            state: PollVec::new_pending(len),
+            pending: len,
+            done: false,
+            trace: Trace::new("race_ok", len),
+        }
+    }
+}
+
 impl<Fut, T, E> RaceOkTrait for Vec<Fut>
 where
     Fut: IntoFuture<Output = Result<T, E>>,
@@ -83,13 +299,7 @@ where
     type Future = RaceOk<Fut::IntoFuture, T, E>;
 
     fn race_ok(self) -> Self::Future {
-        let elems: Box<[_]> = self
-            .into_iter()
-            .map(|fut| MaybeDone::new(fut.into_future()))
-            .collect();
-        RaceOk {
-            elems: elems.into(),
-        }
+        RaceOk::new(self.into_iter().map(IntoFuture::into_future).collect())
     }
 }
 
@@ -133,4 +343,239 @@ mod test {
             assert_eq!(errs[1], "oh no");
         });
     }
+
+    #[test]
+    fn aggregate_error() {
+        futures_lite::future::block_on(async {
+            let errs = vec![
+                future::ready(Err::<(), _>("oops")),
+                future::ready(Err("oh no")),
+            ]
+            .race_ok()
+            .await
+            .unwrap_err();
+            assert_eq!(
+                errs.to_string(),
+                "2 errors occurred: Error 1: oops; Error 2: oh no"
+            );
+            assert_eq!(errs.iter().count(), 2);
+            assert_eq!(errs.get(1), Some(&"oh no"));
+            assert_eq!(errs.get(2), None);
+            assert_eq!(errs.first(), Some(&"oops"));
+            assert_eq!(errs.last(), Some(&"oh no"));
+            assert_eq!(errs.len(), 2);
+            assert!(!errs.is_empty());
+            assert_eq!((&errs).into_iter().count(), 2);
+            assert_eq!(errs.into_iter().collect::<Vec<_>>(), ["oops", "oh no"]);
+
+            let errs = [future::ready(Err::<(), _>("oops"))]
+                .race_ok()
+                .await
+                .unwrap_err();
+            let errs = AggregateError::from(errs);
+            assert_eq!(errs.into_inner(), ["oops"]);
+        });
+    }
+
+    #[test]
+    fn index_through_deref() {
+        let mut errs = AggregateError::new(vec!["oops", "oh no", "oh dear"]);
+        assert_eq!(&errs[1..], ["oh no", "oh dear"]);
+        errs[0] = "fixed";
+        assert_eq!(errs[0], "fixed");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "Futures must not be polled after completing")
+    )]
+    fn poll_after_ready() {
+        use crate::test_utils::{step, CountingWaker};
+        use core::pin::pin;
+
+        // The errors and labels have been moved into the aggregate error, so
+        // polling again must not hand them out a second time.
+        let futures = vec![future::ready(Err::<u8, _>(1)), future::ready(Err(2))];
+        let mut fut = pin!(futures.race_ok().labels(["a", "b"]));
+        let waker = CountingWaker::new();
+        let Poll::Ready(Err(errs)) = step(fut.as_mut(), &waker) else {
+            panic!("all futures should have failed");
+        };
+        assert_eq!(errs.label(1), Some("b"));
+        // In release builds this returns `Pending` rather than panicking.
+        assert!(step(fut.as_mut(), &waker).is_pending());
+    }
+
+    #[test]
+    fn labels() {
+        futures_lite::future::block_on(async {
+            let race = vec![
+                future::ready(Err::<(), _>("timeout")),
+                future::ready(Err("dns")),
+            ]
+            .race_ok()
+            .labels(vec!["eu-mirror".to_string(), "us-mirror".to_string()]);
+            let errs = race.await.unwrap_err();
+            assert_eq!(errs.label(0), Some("eu-mirror"));
+            assert_eq!(
+                errs.to_string(),
+                "2 errors occurred: eu-mirror: timeout; us-mirror: dns"
+            );
+            assert!(format!("{errs:?}").contains("- us-mirror: dns"));
+
+            let errs = vec![future::ready(Err::<(), _>("timeout"))]
+                .race_ok()
+                .await
+                .unwrap_err();
+            assert_eq!(errs.label(0), None);
+            assert_eq!(errs.to_string(), "1 errors occurred: Error 1: timeout");
+        })
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of labels must match the number of futures")]
+    fn labels_length_mismatch() {
+        drop(
+            vec![future::ready(Err::<(), ()>(()))]
+                .race_ok()
+                .labels(["a", "b"]),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot label a `RaceOk` which has already completed")]
+    fn labels_after_completion() {
+        futures_lite::future::block_on(async {
+            let mut fut = vec![future::ready(Ok::<_, ()>(1))].race_ok();
+            assert!(futures_lite::future::poll_once(&mut fut).await.is_some());
+            drop(fut.labels(["a"]));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot inspect a `RaceOk` which has already completed")]
+    fn inspect_err_after_completion() {
+        futures_lite::future::block_on(async {
+            let mut fut = vec![future::ready(Ok::<_, ()>(1))].race_ok();
+            assert!(futures_lite::future::poll_once(&mut fut).await.is_some());
+            drop(fut.inspect_err(|_, _| {}));
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn only_polls_woken_futures() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let (futures, handles): (Vec<_>, Vec<_>) = (0..100)
+            .map(|i| match i {
+                99 => ScriptedFuture::new([Step::WakeLater, Step::WakeLater, Step::Ready(Ok(i))]),
+                _ => ScriptedFuture::new([Step::WakeLater, Step::Ready(Err(i))]),
+            })
+            .unzip();
+        let (failing, last) = handles.split_at(99);
+        let mut fut = pin!(futures.race_ok());
+        let waker = CountingWaker::new();
+
+        assert!(step(fut.as_mut(), &waker).is_pending());
+        assert!(handles.iter().all(|handle| handle.polls() == 1));
+
+        // All but the last future fail.
+        assert!(failing.iter().all(|handle| handle.wake()));
+        assert!(step(fut.as_mut(), &waker).is_pending());
+        assert!(failing.iter().all(|handle| handle.polls() == 2));
+        assert_eq!(last[0].polls(), 1);
+
+        // From now on, only the last future is polled.
+        assert!(last[0].wake());
+        assert!(step(fut.as_mut(), &waker).is_pending());
+        assert_eq!(last[0].polls(), 2);
+        assert!(last[0].wake());
+        assert!(matches!(step(fut.as_mut(), &waker), Poll::Ready(Ok(99))));
+        assert_eq!(last[0].polls(), 3);
+        assert!(failing.iter().all(|handle| handle.polls() == 2));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn inspect_err_in_failure_order() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::cell::RefCell;
+        use core::pin::pin;
+
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Err("a"))]);
+        let (b, b_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Err("b"))]);
+        let (c, c_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Ok(3))]);
+        let failures = RefCell::new(Vec::new());
+        let mut race = pin!(vec![a, b, c]
+            .race_ok()
+            .inspect_err(|index, err: &&str| failures.borrow_mut().push((index, *err))));
+        let waker = CountingWaker::new();
+
+        assert!(step(race.as_mut(), &waker).is_pending());
+        assert!(failures.borrow().is_empty());
+
+        assert!(b_handle.wake());
+        assert!(step(race.as_mut(), &waker).is_pending());
+        assert_eq!(*failures.borrow(), [(1, "b")]);
+
+        assert!(a_handle.wake());
+        assert!(step(race.as_mut(), &waker).is_pending());
+        assert_eq!(*failures.borrow(), [(1, "b"), (0, "a")]);
+
+        // The callback isn't called for the winner.
+        assert!(c_handle.wake());
+        assert!(matches!(step(race.as_mut(), &waker), Poll::Ready(Ok(3))));
+        assert_eq!(*failures.borrow(), [(1, "b"), (0, "a")]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn inspect_err_keeps_aggregate() {
+        futures_lite::future::block_on(async {
+            let mut count = 0;
+            let err = vec![future::ready(Err::<(), _>("oh")), future::ready(Err("no"))]
+                .race_ok()
+                .inspect_err(|_, _| count += 1)
+                .await
+                .unwrap_err();
+            assert_eq!(count, 2);
+            assert_eq!(err[0], "oh");
+            assert_eq!(err[1], "no");
+        });
+    }
+
+    /// The race succeeds whichever of the final failure and the success is
+    /// visited first.
+    #[cfg(feature = "std")]
+    #[test]
+    fn success_wins_within_a_poll_pass() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        // Members are visited in order, so swap the last two.
+        let mut orders = Vec::new();
+        for swap in [false, true] {
+            let (a, _) = ScriptedFuture::new([Step::Ready(Err("a"))]);
+            let (b, b_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Err("b"))]);
+            let (c, c_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Ok(3))]);
+            let mut futures = vec![a, b, c];
+            if swap {
+                futures.swap(1, 2);
+            }
+            let mut race = pin!(futures.race_ok());
+            let waker = CountingWaker::new();
+
+            assert!(step(race.as_mut(), &waker).is_pending());
+            assert!(b_handle.wake());
+            assert!(c_handle.wake());
+            assert!(matches!(step(race.as_mut(), &waker), Poll::Ready(Ok(3))));
+            // `b` is only polled a final time if it is visited before `c`.
+            orders.push(b_handle.polls() == 2);
+        }
+        assert!(orders.contains(&true) && orders.contains(&false));
+    }
 }
@@ -0,0 +1,128 @@
+use core::future::{Future, IntoFuture};
+
+use super::race_ok::{array, tuple};
+use crate::utils::private::NoneError;
+
+/// Wait for the first future to resolve to `Some`.
+///
+/// This is the `Option` counterpart of [`RaceOk`][super::RaceOk]: futures
+/// which resolve to `None` are ignored, and the race resolves to `None` only
+/// once all futures have resolved to `None`.
+pub trait RaceSome {
+    /// The resulting output type.
+    type Output;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Option<Self::Output>>;
+
+    /// Waits for the first future to resolve to `Some`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::ready;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let found = (ready(None), ready(Some(1))).race_some().await;
+    /// assert_eq!(found, Some(1));
+    ///
+    /// let missing = (ready(None::<u8>), ready(None)).race_some().await;
+    /// assert_eq!(missing, None);
+    /// # });
+    /// ```
+    fn race_some(self) -> Self::Future;
+}
+
+macro_rules! impl_race_some_tuple {
+    ($StructName:ident $($F:ident)+) => {
+        impl<T, $($F,)+> RaceSome for ($($F,)+)
+        where $(
+            $F: IntoFuture<Output = Option<T>>,
+        )+ {
+            type Output = T;
+            type Future = tuple::$StructName<T, NoneError, $($F::IntoFuture,)+>;
+
+            #[allow(non_snake_case)]
+            fn race_some(self) -> Self::Future {
+                let ($($F,)+) = self;
+                tuple::$StructName::new(($($F.into_future(),)+))
+            }
+        }
+    };
+}
+
+impl_race_some_tuple! { RaceOk1 A }
+impl_race_some_tuple! { RaceOk2 A B }
+impl_race_some_tuple! { RaceOk3 A B C }
+impl_race_some_tuple! { RaceOk4 A B C D }
+impl_race_some_tuple! { RaceOk5 A B C D E }
+impl_race_some_tuple! { RaceOk6 A B C D E F }
+impl_race_some_tuple! { RaceOk7 A B C D E F G }
+impl_race_some_tuple! { RaceOk8 A B C D E F G H }
+impl_race_some_tuple! { RaceOk9 A B C D E F G H I }
+impl_race_some_tuple! { RaceOk10 A B C D E F G H I J }
+impl_race_some_tuple! { RaceOk11 A B C D E F G H I J K }
+impl_race_some_tuple! { RaceOk12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { RaceOk13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { RaceOk14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { RaceOk15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { RaceOk16 A B C D E F G H I J K L M N O P }
+
+impl<Fut, T, const N: usize> RaceSome for [Fut; N]
+where
+    Fut: IntoFuture<Output = Option<T>>,
+{
+    type Output = T;
+    type Future = array::RaceOk<Fut::IntoFuture, T, NoneError, N>;
+
+    fn race_some(self) -> Self::Future {
+        array::RaceOk::new(self.map(IntoFuture::into_future))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Fut, T> RaceSome for alloc::vec::Vec<Fut>
+where
+    Fut: IntoFuture<Output = Option<T>>,
+{
+    type Output = T;
+    type Future = super::race_ok::vec::RaceOk<Fut::IntoFuture, T, NoneError>;
+
+    fn race_some(self) -> Self::Future {
+        super::race_ok::vec::RaceOk::new(self.into_iter().map(IntoFuture::into_future).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use core::future::{pending, ready};
+    use futures_lite::future::block_on;
+
+    #[test]
+    fn first_some() {
+        block_on(async {
+            let out = (pending(), ready(None), async { Some("world") })
+                .race_some()
+                .await;
+            assert_eq!(out, Some("world"));
+            assert_eq!([ready(None), ready(Some(2))].race_some().await, Some(2));
+            assert_eq!(vec![ready(None), ready(Some(2))].race_some().await, Some(2));
+        })
+    }
+
+    #[test]
+    fn all_none() {
+        block_on(async {
+            assert_eq!((ready(None::<u8>), ready(None)).race_some().await, None);
+            assert_eq!([ready(None::<u8>), ready(None)].race_some().await, None);
+            assert_eq!(vec![ready(None::<u8>), ready(None)].race_some().await, None);
+        })
+    }
+}
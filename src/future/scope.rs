@@ -0,0 +1,322 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::marker::PhantomData;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use futures_core::Stream;
+
+use super::FutureGroup;
+
+type Member<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Create a scope which runs futures concurrently, until all of them have
+/// completed.
+///
+/// The closure receives a [`ScopeHandle`] which adds futures to the scope.
+/// The handle can be cloned and moved into the futures themselves, which
+/// allows running futures to add more futures to the scope. The scope only
+/// completes once every future has completed, including the ones which were
+/// added while the scope was running.
+///
+/// Like the scope of [`std::thread::scope`], the handle is branded with a
+/// `'scope` lifetime of its own, which can't be named outside of the closure.
+/// This means handles can only be kept by the closure and by the futures in
+/// the scope, so futures can't be added once the scope has completed.
+///
+/// The outputs are returned in the order the futures completed in. For
+/// futures which return `()`, the returned `Vec` never allocates.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::future::scope;
+///
+/// # futures_lite::future::block_on(async {
+/// let mut outputs = scope(|s| {
+///     let handle = s.clone();
+///     s.add(async move {
+///         // Futures can add more futures while they're running.
+///         handle.add(async { 2 });
+///         1
+///     });
+///     s.add(async { 3 });
+/// })
+/// .await;
+///
+/// outputs.sort_unstable();
+/// assert_eq!(outputs, [1, 2, 3]);
+/// # });
+/// ```
+pub fn scope<'env, T, F>(f: F) -> Scope<'env, T>
+where
+    F: for<'scope> FnOnce(&ScopeHandle<'scope, 'env, T>),
+{
+    // The closure can't tell that `'scope` is `'env`, so its futures can't
+    // borrow anything which doesn't outlive the scope.
+    let handle = ScopeHandle {
+        shared: Arc::new(Mutex::new(Shared {
+            queue: Vec::new(),
+            waker: None,
+            done: false,
+        })),
+        scope: PhantomData,
+        env: PhantomData,
+    };
+    f(&handle);
+    Scope {
+        group: FutureGroup::new(),
+        shared: handle.shared,
+        outputs: Vec::new(),
+    }
+}
+
+/// The state shared between a scope and its handles.
+struct Shared<'a, T> {
+    /// Futures which have been added, but not yet moved into the group
+    queue: Vec<Member<'a, T>>,
+    /// The waker of the task which polls the scope
+    waker: Option<Waker>,
+    /// Whether the scope has completed or has been dropped
+    done: bool,
+}
+
+fn lock<'s, 'a, T>(shared: &'s Mutex<Shared<'a, T>>) -> MutexGuard<'s, Shared<'a, T>> {
+    shared.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A handle to add futures to a [`Scope`].
+///
+/// This `struct` is passed to the closure given to [`scope`]. See its
+/// documentation for more.
+pub struct ScopeHandle<'scope, 'env: 'scope, T> {
+    shared: Arc<Mutex<Shared<'scope, T>>>,
+    /// Invariance over `'scope`, so the closure can't shorten it to the
+    /// lifetime of one of its locals.
+    scope: PhantomData<&'scope mut &'scope ()>,
+    /// Invariance over `'env`, like for `std::thread::Scope`.
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, T> ScopeHandle<'scope, '_, T> {
+    /// Add a future to the scope.
+    ///
+    /// A future which is added while the scope is being dropped, such as
+    /// by the destructor of another future in the scope, is dropped right
+    /// away.
+    pub fn add<Fut>(&self, future: Fut)
+    where
+        Fut: Future<Output = T> + Send + 'scope,
+    {
+        let mut shared = lock(&self.shared);
+        if shared.done {
+            drop(shared);
+            drop(future);
+            return;
+        }
+        shared.queue.push(Box::pin(future));
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Clone for ScopeHandle<'_, '_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+            scope: PhantomData,
+            env: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for ScopeHandle<'_, '_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScopeHandle").finish_non_exhaustive()
+    }
+}
+
+/// A future which runs all futures added to a scope, and waits for them to
+/// complete.
+///
+/// This `struct` is created by the [`scope`] function. See its documentation
+/// for more.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Scope<'a, T> {
+    group: FutureGroup<Member<'a, T>>,
+    shared: Arc<Mutex<Shared<'a, T>>>,
+    outputs: Vec<T>,
+}
+
+// The futures are boxed, and the outputs are never pinned.
+impl<T> Unpin for Scope<'_, T> {}
+
+impl<T> Scope<'_, T> {
+    /// Move the futures which have been added into the group, and register
+    /// the waker for later additions. Returns whether any futures were added.
+    fn drain(&mut self, waker: &Waker) -> bool {
+        let mut shared = lock(&self.shared);
+        match &mut shared.waker {
+            Some(prev) => prev.clone_from(waker),
+            None => shared.waker = Some(waker.clone()),
+        }
+        let queue = mem::take(&mut shared.queue);
+        drop(shared);
+
+        let added = !queue.is_empty();
+        self.group.extend(queue);
+        added
+    }
+}
+
+impl<T> fmt::Debug for Scope<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scope")
+            .field("running", &self.group.len())
+            .field("completed", &self.outputs.len())
+            .finish()
+    }
+}
+
+impl<T> Future for Scope<'_, T> {
+    type Output = Vec<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        debug_assert!(
+            !lock(&this.shared).done,
+            "Futures must not be polled after completing"
+        );
+
+        this.drain(cx.waker());
+        loop {
+            match Pin::new(&mut this.group).poll_next(cx) {
+                Poll::Ready(Some(output)) => this.outputs.push(output),
+                // The futures which have completed may have added more
+                // futures before completing.
+                Poll::Ready(None) => {
+                    if !this.drain(cx.waker()) {
+                        lock(&this.shared).done = true;
+                        return Poll::Ready(mem::take(&mut this.outputs));
+                    }
+                }
+                Poll::Pending => {
+                    if !this.drain(cx.waker()) {
+                        return Poll::Pending;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T> Drop for Scope<'_, T> {
+    fn drop(&mut self) {
+        // Futures which haven't been moved into the group yet may hold
+        // handles to the scope, which would otherwise keep each other alive.
+        let queue = {
+            let mut shared = lock(&self.shared);
+            shared.done = true;
+            mem::take(&mut shared.queue)
+        };
+        drop(queue);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::{step, CountingWaker};
+    use futures_lite::future::{block_on, yield_now};
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let outputs = scope(|_: &ScopeHandle<'_, '_, ()>| {}).await;
+            assert!(outputs.is_empty());
+        });
+    }
+
+    #[test]
+    fn member_adds_members_mid_flight() {
+        block_on(async {
+            let outputs = scope(|s| {
+                let handle = s.clone();
+                s.add(async move {
+                    yield_now().await;
+                    let inner = handle.clone();
+                    handle.add(async move {
+                        yield_now().await;
+                        "second"
+                    });
+                    handle.add(async move {
+                        yield_now().await;
+                        yield_now().await;
+                        inner.add(async { "fourth" });
+                        "third"
+                    });
+                    "first"
+                });
+            })
+            .await;
+            assert_eq!(outputs, ["first", "second", "third", "fourth"]);
+        });
+    }
+
+    #[test]
+    fn borrows_from_environment() {
+        block_on(async {
+            let names = ["a", "b"];
+            let outputs = scope(|s| {
+                for name in &names {
+                    s.add(async move { name.len() });
+                }
+            })
+            .await;
+            assert_eq!(outputs, [1, 1]);
+        });
+    }
+
+    #[test]
+    fn add_while_dropping() {
+        /// Adds a future to the scope when it's dropped.
+        struct AddOnDrop<'scope, 'env>(ScopeHandle<'scope, 'env, ()>);
+        impl Drop for AddOnDrop<'_, '_> {
+            fn drop(&mut self) {
+                self.0.add(async {});
+            }
+        }
+
+        let shared = {
+            let mut fut = scope(|s| {
+                let guard = AddOnDrop(s.clone());
+                s.add(async move {
+                    let _guard = guard;
+                    core::future::pending::<()>().await
+                });
+            });
+            let waker = CountingWaker::new();
+            assert!(step(Pin::new(&mut fut), &waker).is_pending());
+            Arc::downgrade(&fut.shared)
+        };
+        assert!(shared.upgrade().is_none());
+    }
+
+    #[test]
+    fn drop_with_queued_members() {
+        let shared = {
+            let fut = scope(|s| {
+                let handle = s.clone();
+                s.add(async move { drop(handle) });
+            });
+            Arc::downgrade(&fut.shared)
+        };
+        assert!(shared.upgrade().is_none());
+    }
+}
@@ -1,4 +1,5 @@
 use super::TryJoin as TryJoinTrait;
+use crate::utils::private::TryOutput;
 use crate::utils::{FutureArray, OutputArray, PollArray, WakerArray};
 
 use core::fmt;
@@ -6,7 +7,7 @@ use core::future::{Future, IntoFuture};
 use core::mem::ManuallyDrop;
 use core::ops::DerefMut;
 use core::pin::Pin;
-use core::task::{Context, Poll};
+use core::task::{ready, Context, Poll};
 
 use pin_project::{pin_project, pinned_drop};
 
@@ -21,12 +22,15 @@ use pin_project::{pin_project, pinned_drop};
 #[pin_project(PinnedDrop)]
 pub struct TryJoin<Fut, T, E, const N: usize>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
 {
     /// A boolean which holds whether the future has completed
     consumed: bool,
     /// The number of futures which are currently still in-flight
     pending: usize,
+    /// The first error, to be returned after the future completes
+    error: Option<E>,
     /// The output data, to be returned after the future completes
     items: OutputArray<T, N>,
     /// A structure holding the waker passed to the future, and the various
@@ -41,13 +45,15 @@ where
 
 impl<Fut, T, E, const N: usize> TryJoin<Fut, T, E, N>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
 {
     #[inline]
     pub(crate) fn new(futures: [Fut; N]) -> Self {
         Self {
             consumed: false,
             pending: N,
+            error: None,
             items: OutputArray::uninit(),
             wakers: WakerArray::new(),
             state: PollArray::new_pending(),
@@ -56,42 +62,45 @@ where
     }
 }
 
-impl<Fut, T, E, const N: usize> TryJoinTrait for [Fut; N]
-where
-    Fut: IntoFuture<Output = Result<T, E>>,
-{
-    type Output = [T; N];
-    type Error = E;
-    type Future = TryJoin<Fut::IntoFuture, T, E, N>;
-
-    fn try_join(self) -> Self::Future {
-        TryJoin::new(self.map(IntoFuture::into_future))
-    }
-}
-
-impl<Fut, T, E, const N: usize> fmt::Debug for TryJoin<Fut, T, E, N>
-where
-    Fut: Future<Output = Result<T, E>> + fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.state.iter()).finish()
-    }
-}
-
-impl<Fut, T, E, const N: usize> Future for TryJoin<Fut, T, E, N>
+impl<Fut, T, E, const N: usize> TryJoin<Fut, T, E, N>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
 {
-    type Output = Result<[T; N], E>;
-
-    #[inline]
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    /// Poll the futures which are ready to make progress, without resolving
+    /// the join.
+    ///
+    /// The outputs of completed futures are buffered inside the join. This
+    /// returns `Poll::Ready(())` once all futures have completed successfully
+    /// or one of them has failed, after which the next call to `poll` returns
+    /// the outputs or the error immediately. Until then, the waker of `cx` is
+    /// woken whenever more progress can be made. Once the result has been
+    /// taken, this keeps returning `Poll::Ready(())`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::{self, poll_fn};
+    /// use std::pin::pin;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut join = pin!([future::ready(Ok(1)), future::ready(Err("oh no"))].try_join());
+    /// poll_fn(|cx| join.as_mut().poll_progress(cx)).await;
+    /// assert_eq!(join.await, Err("oh no"));
+    /// # });
+    /// ```
+    pub fn poll_progress(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
         let this = self.project();
+        if *this.consumed || this.error.is_some() {
+            return Poll::Ready(());
+        }
 
-        assert!(
-            !*this.consumed,
-            "Futures must not be polled after completing"
-        );
+        // An empty join has nothing to wait for, and completes right away
+        // without touching its wakers.
+        if *this.pending == 0 {
+            return Poll::Ready(());
+        }
 
         let mut readiness = this.wakers.readiness();
         readiness.set_waker(cx.waker());
@@ -120,7 +129,7 @@ where
                     *this.pending -= 1;
 
                     // Check the value, short-circuit on error.
-                    match value {
+                    match value.into_result() {
                         Ok(value) => {
                             this.items.write(i, value);
 
@@ -132,18 +141,16 @@ where
                             unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
                         }
                         Err(err) => {
-                            // The future should no longer be polled after we're done here
-                            *this.consumed = true;
-
-                            // SAFETY: We're about to return the error value
-                            // from the future, and drop the entire future.
-                            // We're marking the future as consumed, and then
-                            // proceeding to drop all other futures and
-                            // initiatlized values in the destructor.
+                            // SAFETY: We're buffering the error value from
+                            // the future, and drop the entire future. No
+                            // other futures are polled after this, and they
+                            // are dropped together with the initialized
+                            // values in the destructor.
+                            *this.error = Some(err);
                             this.state[i].set_none();
                             unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
 
-                            return Poll::Ready(Err(err));
+                            return Poll::Ready(());
                         }
                     }
                 }
@@ -155,25 +162,80 @@ where
 
         // Check whether we're all done now or need to keep going.
         if *this.pending == 0 {
-            // Mark all data as "consumed" before we take it
-            *this.consumed = true;
-
-            // SAFETY: we check with the state that all of our outputs have been
-            // filled, which means we're ready to take the data and assume it's initialized.
-            debug_assert!(this.state.iter().all(|entry| entry.is_ready()));
-            this.state.set_all_none();
-            Poll::Ready(Ok(unsafe { this.items.take() }))
+            Poll::Ready(())
         } else {
             Poll::Pending
         }
     }
 }
 
+impl<Fut, T, E, const N: usize> TryJoinTrait for [Fut; N]
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = [T; N];
+    type Error = E;
+    type Future = TryJoin<Fut::IntoFuture, T, E, N>;
+
+    fn try_join(self) -> Self::Future {
+        TryJoin::new(self.map(IntoFuture::into_future))
+    }
+}
+
+impl<Fut, T, E, const N: usize> fmt::Debug for TryJoin<Fut, T, E, N>
+where
+    Fut: Future + fmt::Debug,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.state.iter()).finish()
+    }
+}
+
+impl<Fut, T, E, const N: usize> Future for TryJoin<Fut, T, E, N>
+where
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
+{
+    type Output = <Fut::Output as TryOutput>::WithOk<[T; N]>;
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        debug_assert!(
+            !self.consumed,
+            "Futures must not be polled after completing"
+        );
+        if self.consumed {
+            return Poll::Pending;
+        }
+
+        ready!(self.as_mut().poll_progress(cx));
+        let this = self.project();
+
+        // Short-circuit on error.
+        if let Some(err) = this.error.take() {
+            // The future should no longer be polled after we're done here
+            *this.consumed = true;
+            return Poll::Ready(TryOutput::from_error(err));
+        }
+
+        // Mark all data as "consumed" before we take it
+        *this.consumed = true;
+
+        // SAFETY: we check with the state that all of our outputs have been
+        // filled, which means we're ready to take the data and assume it's initialized.
+        debug_assert!(this.state.iter().all(|entry| entry.is_ready()));
+        this.state.set_all_none();
+        Poll::Ready(TryOutput::from_ok(unsafe { this.items.take() }))
+    }
+}
+
 /// Drop the already initialized values on cancellation.
 #[pinned_drop]
 impl<Fut, T, E, const N: usize> PinnedDrop for TryJoin<Fut, T, E, N>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
 {
     fn drop(self: Pin<&mut Self>) {
         let mut this = self.project();
@@ -218,6 +280,20 @@ mod test {
         });
     }
 
+    #[test]
+    fn empty_completes_on_first_poll() {
+        use futures_lite::future::poll_once;
+
+        /// An error which can't be created by the join.
+        struct Error;
+
+        futures_lite::future::block_on(async {
+            let data: [future::Pending<Result<u8, Error>>; 0] = [];
+            let res = poll_once(data.try_join()).await;
+            assert!(matches!(res, Some(Ok([]))));
+        });
+    }
+
     #[test]
     fn one_err() {
         futures_lite::future::block_on(async {
@@ -227,4 +303,46 @@ mod test {
             assert_eq!(res.unwrap_err(), "oh no");
         });
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn poll_progress_until_complete() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let waker = CountingWaker::new();
+        let mut cx = Context::from_waker(waker.waker());
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Ok(1))]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(Ok::<_, ()>(2))]);
+        let mut join = pin!([a, b].try_join());
+
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Pending);
+        assert!(a_handle.wake());
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+
+        // The outputs are returned by the final poll, without polling again.
+        assert_eq!(step(join.as_mut(), &waker), Poll::Ready(Ok([1, 2])));
+        assert_eq!(a_handle.polls(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn poll_progress_buffers_error() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let waker = CountingWaker::new();
+        let mut cx = Context::from_waker(waker.waker());
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Ok(1))]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(Err("oh no"))]);
+        let mut join = pin!([a, b].try_join());
+
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+
+        // Once an error is buffered, no other futures are polled.
+        assert!(a_handle.wake());
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+        assert_eq!(a_handle.polls(), 1);
+        assert_eq!(step(join.as_mut(), &waker), Poll::Ready(Err("oh no")));
+    }
 }
@@ -0,0 +1,86 @@
+use crate::future::Failure;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// The error of a [`TryJoin`] which reports which future failed, and which
+/// futures had completed successfully before it did.
+///
+/// This `struct` is returned by the future created by the [`indexed`] method
+/// on the Vec [`TryJoin`]. See its documentation for more.
+///
+/// [`TryJoin`]: crate::vec::TryJoin
+/// [`indexed`]: crate::vec::TryJoin::indexed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryJoinError<E> {
+    failure: Failure<E>,
+    completed: Vec<usize>,
+}
+
+impl<E> TryJoinError<E> {
+    pub(crate) fn new(failure: Failure<E>, completed: Vec<usize>) -> Self {
+        Self { failure, completed }
+    }
+
+    /// Returns a reference to the error of the future which failed.
+    pub fn error(&self) -> &E {
+        &self.failure.error
+    }
+
+    /// Returns the error of the future which failed.
+    pub fn into_error(self) -> E {
+        self.failure.error
+    }
+
+    /// Returns the index of the future which failed.
+    pub fn index(&self) -> usize {
+        self.failure.index
+    }
+
+    /// Returns the error of the future which failed, together with its
+    /// index.
+    pub fn failure(&self) -> &Failure<E> {
+        &self.failure
+    }
+
+    /// Returns the error of the future which failed, together with its
+    /// index, discarding which futures had completed.
+    pub fn into_failure(self) -> Failure<E> {
+        self.failure
+    }
+
+    /// Returns how many futures had completed successfully before the
+    /// failure.
+    pub fn completed_before_failure(&self) -> usize {
+        self.completed.len()
+    }
+
+    /// Returns the indexes of the futures which had completed successfully
+    /// before the failure, in ascending order.
+    pub fn completed_indices(&self) -> &[usize] {
+        &self.completed
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for TryJoinError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "future {} failed after {} futures completed: {}",
+            self.failure.index,
+            self.completed.len(),
+            self.failure.error
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Error + 'static> Error for TryJoinError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.failure.error)
+    }
+}
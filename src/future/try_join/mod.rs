@@ -1,6 +1,8 @@
 use core::future::Future;
 
 pub(crate) mod array;
+#[cfg(feature = "alloc")]
+pub(crate) mod error;
 pub(crate) mod tuple;
 #[cfg(feature = "alloc")]
 pub(crate) mod vec;
@@ -12,6 +14,42 @@ pub(crate) mod vec;
 ///
 /// If you want to keep partial data in the case of failure, see the `merge`
 /// operation.
+///
+/// This is implemented for futures which resolve to `Result`. Futures which
+/// resolve to `Option` are joined with [`JoinSome`][crate::future::JoinSome].
+///
+/// A try-join of no futures, such as an empty `Vec`, boxed slice, array, or
+/// the empty tuple, succeeds with an empty output on its first poll.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use std::future::ready;
+///
+/// # futures_lite::future::block_on(async {
+/// let res = (ready(Ok::<_, ()>(1)), ready(Ok("two"))).try_join().await;
+/// assert_eq!(res, Ok((1, "two")));
+/// # });
+/// ```
+///
+/// The output of the future is always a `Result`, so code can be generic
+/// over any collection of futures which can be joined:
+///
+/// ```
+/// use futures_concurrency::future::TryJoin;
+///
+/// async fn join_all<T: TryJoin>(futures: T) -> Result<T::Output, T::Error> {
+///     futures.try_join().await
+/// }
+/// ```
+///
+/// # Drop order
+///
+/// When a try-join over a tuple is dropped, including after it aborted on an
+/// error, the outputs of the futures which completed are dropped first,
+/// followed by the futures which are still pending, both in the order of the
+/// tuple.
 pub trait TryJoin {
     /// The resulting output type.
     type Output;
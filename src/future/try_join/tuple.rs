@@ -1,5 +1,6 @@
 use super::TryJoin as TryJoinTrait;
-use crate::utils::{PollArray, WakerArray};
+use crate::utils::private::TryOutput;
+use crate::utils::{self, PollArray, Trace, WakerArray};
 
 use core::fmt::{self, Debug};
 use core::future::{Future, IntoFuture};
@@ -19,7 +20,7 @@ use pin_project::{pin_project, pinned_drop};
 //
 // This is implemented as a tt-muncher of the future name `$($F:ident)`
 // and the future index `$($rest)`, taking advantage that we only support
-// tuples up to  12 elements
+// tuples up to 16 elements
 //
 // # References
 // TT Muncher: https://veykril.github.io/tlborm/decl-macros/patterns/tt-muncher.html
@@ -28,15 +29,18 @@ macro_rules! unsafe_poll {
     (@inner $iteration:ident, $this:ident, $futures:ident, $cx:ident, $fut_name:ident $($F:ident)* | $fut_idx:tt $($rest:tt)*) => {
         if $fut_idx == $iteration {
 
-            if let Poll::Ready(value) = unsafe {
+            let poll = unsafe {
                 $futures.$fut_name.as_mut()
                     .map_unchecked_mut(|t| t.deref_mut())
                     .poll(&mut $cx)
-            } {
+            };
+            $this.trace.polled($fut_idx, poll.is_ready());
+            if let Poll::Ready(value) = poll {
                 *$this.completed += 1;
+                $this.trace.completed($fut_idx, *$this.completed);
 
                 // Check the value, short-circuit on error.
-                match value {
+                match value.into_result() {
                     Ok(value) => {
                         $this.outputs.$fut_idx.write(value);
 
@@ -59,7 +63,7 @@ macro_rules! unsafe_poll {
                         $this.state[$fut_idx].set_none();
                         unsafe { ManuallyDrop::drop($futures.$fut_name.as_mut().get_unchecked_mut()) };
 
-                        return Poll::Ready(Err(err));
+                        return Poll::Ready(TryOutput::from_error(err));
                     }
                 }
             }
@@ -72,7 +76,7 @@ macro_rules! unsafe_poll {
 
     // macro start
     ($iteration:ident, $this:ident, $futures:ident, $cx:ident, $LEN:ident, $($F:ident,)+) => {
-        unsafe_poll!(@inner $iteration, $this, $futures, $cx, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11);
+        unsafe_poll!(@inner $iteration, $this, $futures, $cx, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15);
     };
 }
 
@@ -94,7 +98,7 @@ macro_rules! drop_initialized_values {
 
     // macro start
     ($($outs:ident,)+ | $states:expr) => {
-        drop_initialized_values!(@drop $($outs,)+ | $states, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11,);
+        drop_initialized_values!(@drop $($outs,)+ | $states, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,);
     };
 }
 
@@ -117,7 +121,7 @@ macro_rules! drop_pending_futures {
 
     // macro start
     ($states:ident, $futures:ident, $($F:ident,)+) => {
-        drop_pending_futures!(@inner $states, $futures, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11);
+        drop_pending_futures!(@inner $states, $futures, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15);
     };
 }
 
@@ -198,9 +202,37 @@ macro_rules! impl_try_join_tuple {
             wakers: WakerArray<{$mod_name::LEN}>,
             completed: usize,
             consumed: bool,
+            trace: Trace,
             _phantom: PhantomData<Err>,
         }
 
+        impl<$($F, $T,)+ Err> $StructName<$($F, $T,)+ Err> {
+            pub(crate) fn new(($($F,)+): ($($F,)+)) -> Self {
+                $StructName {
+                    futures: $mod_name::Futures {$(
+                        $F: ManuallyDrop::new($F),
+                    )+},
+                    state: PollArray::new_pending(),
+                    outputs: ($(MaybeUninit::<$T>::uninit(),)+),
+                    wakers: WakerArray::new(),
+                    completed: 0,
+                    consumed: false,
+                    trace: Trace::new("try_join", $mod_name::LEN),
+                    _phantom: PhantomData,
+                }
+            }
+
+            /// Name the span in which this join is traced, so its events can
+            /// be told apart from those of other joins.
+            ///
+            /// This requires the `tracing` feature.
+            #[cfg(feature = "tracing")]
+            pub fn named(self, name: &str) -> Self {
+                self.trace.set_name(name);
+                self
+            }
+        }
+
         impl<$($F, $T,)+ Err> Debug for $StructName<$($F, $T,)+ Err>
         where
             $( $F: Future + Debug, )+
@@ -217,9 +249,12 @@ macro_rules! impl_try_join_tuple {
         #[allow(unused_variables)]
         impl<$($F, $T,)+ Err> Future for $StructName<$($F, $T,)+ Err>
         where $(
-            $F: Future<Output = Result<$T, Err>>,
+            $F: Future,
+            $F::Output: TryOutput<Ok = $T, Error = Err>,
         )+ {
-            type Output = Result<($($T,)+), Err>;
+            // The kind of output is decided by the first future, and all other
+            // futures are required to share its error type.
+            type Output = <<utils::tuple_first!($($F,)+) as Future>::Output as TryOutput>::WithOk<($($T,)+)>;
 
             fn poll(
                 self: Pin<&mut Self>, cx: &mut Context<'_>
@@ -227,12 +262,16 @@ macro_rules! impl_try_join_tuple {
                 const LEN: usize = $mod_name::LEN;
 
                 let mut this = self.project();
-                assert!(!*this.consumed, "Futures must not be polled after completing");
+                debug_assert!(!*this.consumed, "Futures must not be polled after completing");
+                if *this.consumed {
+                    return Poll::Pending;
+                }
 
                 let mut futures = this.futures.project();
 
                 let mut readiness = this.wakers.readiness();
                 readiness.set_waker(cx.waker());
+                readiness.set_trace(this.trace);
 
                 for index in 0..LEN {
                     if !readiness.any_ready() {
@@ -266,7 +305,7 @@ macro_rules! impl_try_join_tuple {
                         this.state.set_all_none();
                         *this.consumed = true;
 
-                        return Poll::Ready(Ok(out));
+                        return Poll::Ready(TryOutput::from_ok(out));
                     }
                     readiness = this.wakers.readiness();
                 }
@@ -300,17 +339,7 @@ macro_rules! impl_try_join_tuple {
 
             fn try_join(self) -> Self::Future {
                 let ($($F,)+): ($($F,)+) = self;
-                $StructName {
-                    futures: $mod_name::Futures {$(
-                        $F: ManuallyDrop::new($F.into_future()),
-                    )+},
-                    state: PollArray::new_pending(),
-                    outputs: ($(MaybeUninit::<$T>::uninit(),)+),
-                    wakers: WakerArray::new(),
-                    completed: 0,
-                    consumed: false,
-                    _phantom: PhantomData,
-                }
+                $StructName::new(($($F.into_future(),)+))
             }
         }
     };
@@ -329,6 +358,14 @@ impl_try_join_tuple! { try_join_9 TryJoin9 (A ResA) (B ResB) (C ResC) (D ResD) (
 impl_try_join_tuple! { try_join_10 TryJoin10 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) }
 impl_try_join_tuple! { try_join_11 TryJoin11 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) }
 impl_try_join_tuple! { try_join_12 TryJoin12 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_13 TryJoin13 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_14 TryJoin14 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_15 TryJoin15 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_16 TryJoin16 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) }
 
 #[cfg(test)]
 mod test {
@@ -337,6 +374,23 @@ mod test {
     use core::convert::Infallible;
     use core::future;
 
+    #[test]
+    fn try_join_0() {
+        futures_lite::future::block_on(async {
+            assert_eq!(().try_join().await, Ok(()));
+        })
+    }
+
+    #[test]
+    fn try_join_1() {
+        futures_lite::future::block_on(async {
+            let a = future::ready(Ok::<_, ()>("hello"));
+            assert_eq!((a,).try_join().await, Ok(("hello",)));
+            let a = future::ready(Err::<&str, _>("oh no"));
+            assert_eq!((a,).try_join().await, Err("oh no"));
+        })
+    }
+
     #[test]
     fn all_ok() {
         futures_lite::future::block_on(async {
@@ -1,15 +1,18 @@
+use super::error::TryJoinError;
 use super::TryJoin as TryJoinTrait;
-use crate::utils::{FutureVec, OutputVec, PollVec, WakerVec};
+use crate::future::Failure;
+use crate::utils::private::TryOutput;
+use crate::utils::{FutureVec, OutputVec, PollVec, Trace, WakerVec};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 
 use core::fmt;
 use core::future::{Future, IntoFuture};
 use core::mem::ManuallyDrop;
 use core::ops::DerefMut;
 use core::pin::Pin;
-use core::task::{Context, Poll};
+use core::task::{ready, Context, Poll};
 
 use pin_project::{pin_project, pinned_drop};
 
@@ -24,12 +27,15 @@ use pin_project::{pin_project, pinned_drop};
 #[pin_project(PinnedDrop)]
 pub struct TryJoin<Fut, T, E>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
 {
     /// A boolean which holds whether the future has completed
     consumed: bool,
     /// The number of futures which are currently still in-flight
     pending: usize,
+    /// The first error, to be returned after the future completes
+    error: Option<E>,
     /// The output data, to be returned after the future completes
     items: OutputVec<T>,
     /// A structure holding the waker passed to the future, and the various
@@ -37,6 +43,8 @@ where
     wakers: WakerVec,
     /// The individual poll state of each future.
     state: PollVec,
+    /// The span the futures are traced in.
+    trace: Trace,
     #[pin]
     /// The array of futures passed to the structure.
     futures: FutureVec<Fut>,
@@ -44,61 +52,113 @@ where
 
 impl<Fut, T, E> TryJoin<Fut, T, E>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
 {
     #[inline]
     pub(crate) fn new(futures: Vec<Fut>) -> Self {
         let len = futures.len();
+        // A single future is polled with the caller's waker directly, and an
+        // empty join completes right away, so their wakers are never
+        // allocated.
+        let wakers = match len {
+            0 | 1 => WakerVec::lazy(len),
+            _ => WakerVec::new(len),
+        };
         Self {
             consumed: false,
             pending: len,
+            error: None,
             items: OutputVec::uninit(len),
-            wakers: WakerVec::new(len),
+            wakers,
             state: PollVec::new_pending(len),
+            trace: Trace::new("try_join", len),
             futures: FutureVec::new(futures),
         }
     }
-}
-
-impl<Fut, T, E> TryJoinTrait for Vec<Fut>
-where
-    Fut: IntoFuture<Output = Result<T, E>>,
-{
-    type Output = Vec<T>;
-    type Error = E;
-    type Future = TryJoin<Fut::IntoFuture, T, E>;
 
-    fn try_join(self) -> Self::Future {
-        TryJoin::new(self.into_iter().map(IntoFuture::into_future).collect())
+    /// Name the span in which this join is traced, so its events can be told
+    /// apart from those of other joins.
+    ///
+    /// This requires the `tracing` feature.
+    #[cfg(feature = "tracing")]
+    pub fn named(self, name: &str) -> Self {
+        self.trace.set_name(name);
+        self
     }
 }
 
-impl<Fut, T, E> fmt::Debug for TryJoin<Fut, T, E>
-where
-    Fut: Future<Output = Result<T, E>> + fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.state.iter()).finish()
-    }
-}
-
-impl<Fut, T, E> Future for TryJoin<Fut, T, E>
+impl<Fut, T, E> TryJoin<Fut, T, E>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
 {
-    type Output = Result<Vec<T>, E>;
-
-    #[inline]
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    /// Poll the futures which are ready to make progress, without resolving
+    /// the join.
+    ///
+    /// The outputs of completed futures are buffered inside the join. This
+    /// returns `Poll::Ready(())` once all futures have completed successfully
+    /// or one of them has failed, after which the next call to `poll` returns
+    /// the outputs or the error immediately. Until then, the waker of `cx` is
+    /// woken whenever more progress can be made. Once the result has been
+    /// taken, this keeps returning `Poll::Ready(())`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::{self, poll_fn};
+    /// use std::pin::pin;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut join = pin!(vec![future::ready(Ok(1)), future::ready(Err("oh no"))].try_join());
+    /// poll_fn(|cx| join.as_mut().poll_progress(cx)).await;
+    /// assert_eq!(join.await, Err("oh no"));
+    /// # });
+    /// ```
+    pub fn poll_progress(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
         let this = self.project();
+        if *this.consumed || this.error.is_some() {
+            return Poll::Ready(());
+        }
 
-        assert!(
-            !*this.consumed,
-            "Futures must not be polled after completing"
-        );
+        // An empty join has nothing to wait for, and completes right away
+        // without touching its wakers.
+        if *this.pending == 0 {
+            return Poll::Ready(());
+        }
+
+        // A single future doesn't need to be told apart from any others, so
+        // it's polled with the caller's waker directly.
+        if this.state.len() == 1 {
+            if this.state[0].is_pending() {
+                let mut fut = this.futures.iter().next().unwrap();
+                // SAFETY: the future's state is "pending", so it's safe to poll
+                let poll = unsafe { fut.as_mut().map_unchecked_mut(|t| t.deref_mut()).poll(cx) };
+                this.trace.polled(0, poll.is_ready());
+                let value = ready!(poll);
+                *this.pending -= 1;
+                this.trace.completed(0, 1);
+                match value.into_result() {
+                    Ok(value) => {
+                        this.items.write(0, value);
+                        this.state[0].set_ready();
+                    }
+                    Err(err) => {
+                        *this.error = Some(err);
+                        this.state[0].set_none();
+                    }
+                }
+                // SAFETY: the future has completed and won't be used after
+                // this point, so it's safe to drop.
+                unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
+            }
+            return Poll::Ready(());
+        }
 
         let mut readiness = this.wakers.readiness();
         readiness.set_waker(cx.waker());
+        readiness.set_trace(this.trace);
         if *this.pending != 0 && !readiness.any_ready() {
             // Nothing is ready yet
             return Poll::Pending;
@@ -116,15 +176,18 @@ where
 
                 // Poll the future
                 // SAFETY: the future's state was "pending", so it's safe to poll
-                if let Poll::Ready(value) = unsafe {
+                let poll = unsafe {
                     fut.as_mut()
                         .map_unchecked_mut(|t| t.deref_mut())
                         .poll(&mut cx)
-                } {
+                };
+                this.trace.polled(i, poll.is_ready());
+                if let Poll::Ready(value) = poll {
                     *this.pending -= 1;
+                    this.trace.completed(i, this.state.len() - *this.pending);
 
                     // Check the value, short-circuit on error.
-                    match value {
+                    match value.into_result() {
                         Ok(value) => {
                             this.items.write(i, value);
 
@@ -136,18 +199,16 @@ where
                             unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
                         }
                         Err(err) => {
-                            // The future should no longer be polled after we're done here
-                            *this.consumed = true;
-
-                            // SAFETY: We're about to return the error value
-                            // from the future, and drop the entire future.
-                            // We're marking the future as consumed, and then
-                            // proceeding to drop all other futures and
-                            // initiatlized values in the destructor.
+                            // SAFETY: We're buffering the error value from
+                            // the future, and drop the entire future. No
+                            // other futures are polled after this, and they
+                            // are dropped together with the initialized
+                            // values in the destructor.
+                            *this.error = Some(err);
                             this.state[i].set_none();
                             unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
 
-                            return Poll::Ready(Err(err));
+                            return Poll::Ready(());
                         }
                     }
                 }
@@ -159,30 +220,199 @@ where
 
         // Check whether we're all done now or need to keep going.
         if *this.pending == 0 {
-            // Mark all data as "consumed" before we take it
-            *this.consumed = true;
-            for state in this.state.iter_mut() {
-                debug_assert!(
-                    state.is_ready(),
-                    "Future should have reached a `Ready` state"
-                );
-                state.set_none();
-            }
-
-            // SAFETY: we've checked with the state that all of our outputs have been
-            // filled, which means we're ready to take the data and assume it's initialized.
-            Poll::Ready(Ok(unsafe { this.items.take() }))
+            Poll::Ready(())
         } else {
             Poll::Pending
         }
     }
 }
 
+impl<Fut, T, E> TryJoin<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    /// Report which future failed, and which futures had completed
+    /// successfully before it did.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the future has already completed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::ready;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let futs = vec![ready(Ok(1)), ready(Err("oh no")), ready(Ok(3))];
+    /// let err = futs.try_join().indexed().await.unwrap_err();
+    /// assert_eq!(err.index(), 1);
+    /// assert_eq!(err.completed_indices(), [0]);
+    /// assert_eq!(*err.error(), "oh no");
+    /// # });
+    /// ```
+    pub fn indexed(self) -> TryJoinIndexed<Fut, T, E> {
+        assert!(
+            !self.consumed,
+            "cannot index a `TryJoin` which has already completed"
+        );
+        TryJoinIndexed { join: self }
+    }
+}
+
+/// A future which waits for all futures to complete successfully, or abort
+/// early on error, reporting which future failed.
+///
+/// This `struct` is created by the [`indexed`] method on [`TryJoin`]. See its
+/// documentation for more.
+///
+/// [`indexed`]: TryJoin::indexed
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct TryJoinIndexed<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    #[pin]
+    join: TryJoin<Fut, T, E>,
+}
+
+impl<Fut, T, E> fmt::Debug for TryJoinIndexed<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.join.fmt(f)
+    }
+}
+
+impl<Fut, T, E> Future for TryJoinIndexed<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Result<Vec<T>, TryJoinError<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut join = self.project().join;
+        ready!(join.as_mut().poll_progress(cx));
+
+        // Read the state before the outputs are taken. Only the future which
+        // failed has been marked as "none" before the join completes.
+        let failure = join.error.as_ref().map(|_| {
+            let index = join.state.iter().position(|state| state.is_none());
+            let completed = join.state.ready_indexes().collect();
+            (
+                index.expect("the failed future should be marked"),
+                completed,
+            )
+        });
+        let output = ready!(join.poll(cx));
+        Poll::Ready(output.map_err(|error| {
+            let (index, completed) = failure.expect("the join should have failed");
+            TryJoinError::new(Failure::new(index, error), completed)
+        }))
+    }
+}
+
+impl<Fut, T, E> TryJoinTrait for Vec<Fut>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = Vec<T>;
+    type Error = E;
+    type Future = TryJoin<Fut::IntoFuture, T, E>;
+
+    fn try_join(self) -> Self::Future {
+        TryJoin::new(self.into_iter().map(IntoFuture::into_future).collect())
+    }
+}
+
+impl<Fut, T, E> TryJoinTrait for Box<[Fut]>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = Vec<T>;
+    type Error = E;
+    type Future = TryJoin<Fut::IntoFuture, T, E>;
+
+    fn try_join(self) -> Self::Future {
+        Vec::from(self).try_join()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T> From<Vec<tokio::task::JoinHandle<T>>>
+    for TryJoin<tokio::task::JoinHandle<T>, T, tokio::task::JoinError>
+{
+    /// Wait for all spawned tasks to complete, returning early with the
+    /// `JoinError` of the first task which panicked or was aborted.
+    ///
+    /// The remaining tasks are detached, not aborted.
+    fn from(handles: Vec<tokio::task::JoinHandle<T>>) -> Self {
+        TryJoin::new(handles)
+    }
+}
+
+impl<Fut, T, E> fmt::Debug for TryJoin<Fut, T, E>
+where
+    Fut: Future + fmt::Debug,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.state.iter()).finish()
+    }
+}
+
+impl<Fut, T, E> Future for TryJoin<Fut, T, E>
+where
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
+{
+    type Output = <Fut::Output as TryOutput>::WithOk<Vec<T>>;
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        debug_assert!(
+            !self.consumed,
+            "Futures must not be polled after completing"
+        );
+        if self.consumed {
+            return Poll::Pending;
+        }
+
+        ready!(self.as_mut().poll_progress(cx));
+        let this = self.project();
+
+        // Short-circuit on error.
+        if let Some(err) = this.error.take() {
+            // The future should no longer be polled after we're done here
+            *this.consumed = true;
+            return Poll::Ready(TryOutput::from_error(err));
+        }
+
+        // Mark all data as "consumed" before we take it
+        *this.consumed = true;
+        for state in this.state.iter_mut() {
+            debug_assert!(
+                state.is_ready(),
+                "Future should have reached a `Ready` state"
+            );
+            state.set_none();
+        }
+
+        // SAFETY: we've checked with the state that all of our outputs have been
+        // filled, which means we're ready to take the data and assume it's initialized.
+        Poll::Ready(TryOutput::from_ok(unsafe { this.items.take() }))
+    }
+}
+
 /// Drop the already initialized values on cancellation.
 #[pinned_drop]
 impl<Fut, T, E> PinnedDrop for TryJoin<Fut, T, E>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput<Ok = T, Error = E>,
 {
     fn drop(self: Pin<&mut Self>) {
         let mut this = self.project();
@@ -228,6 +458,33 @@ mod test {
         });
     }
 
+    #[test]
+    fn empty_completes_on_first_poll() {
+        use alloc::boxed::Box;
+        use futures_lite::future::poll_once;
+
+        /// An error which can't be created by the join.
+        struct Error;
+
+        futures_lite::future::block_on(async {
+            let data: Vec<future::Pending<Result<u8, Error>>> = vec![];
+            let res = poll_once(data.try_join()).await;
+            assert!(matches!(res, Some(Ok(items)) if items.is_empty()));
+
+            let data: Box<[future::Pending<Result<u8, Error>>]> = Box::new([]);
+            let res = poll_once(data.try_join()).await;
+            assert!(matches!(res, Some(Ok(items)) if items.is_empty()));
+        });
+    }
+
+    #[test]
+    fn boxed_slice() {
+        futures_lite::future::block_on(async {
+            let data: Box<[_]> = vec![future::ready(Ok(1)), future::ready(Err("oh no"))].into();
+            assert_eq!(data.try_join().await, Err("oh no"));
+        });
+    }
+
     #[test]
     fn one_err() {
         futures_lite::future::block_on(async {
@@ -237,4 +494,126 @@ mod test {
             assert_eq!(res.unwrap_err(), "oh no");
         });
     }
+
+    #[test]
+    fn single_future() {
+        futures_lite::future::block_on(async {
+            let res: Result<_, ()> = vec![future::ready(Ok("hello"))].try_join().await;
+            assert_eq!(res.unwrap(), ["hello"]);
+            let res: Result<Vec<()>, _> = vec![future::ready(Err("oh no"))].try_join().await;
+            assert_eq!(res.unwrap_err(), "oh no");
+        });
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn join_handles() {
+        let handles = vec![tokio::spawn(async { 1 }), tokio::spawn(async { 2 })];
+        let res = TryJoin::from(handles).await;
+        assert_eq!(res.unwrap(), vec![1, 2]);
+
+        let handles = vec![
+            tokio::spawn(async { 1 }),
+            tokio::spawn(async { panic!("oh no") }),
+        ];
+        let res = handles.try_join().await;
+        assert!(res.unwrap_err().is_panic());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "Futures must not be polled after completing")
+    )]
+    fn poll_after_ready() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        // The error short-circuits while `b` is still pending, and `b` isn't
+        // polled again afterwards.
+        let (a, _) = ScriptedFuture::new([Step::Ready(Err(1))]);
+        let (b, b_handle) = ScriptedFuture::new([Step::Pending, Step::Ready(Ok::<u8, u8>(2))]);
+        let mut fut = pin!(vec![b, a].try_join());
+        let waker = CountingWaker::new();
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(Err(1)));
+        // In release builds this returns `Pending` rather than panicking.
+        assert!(step(fut.as_mut(), &waker).is_pending());
+        assert_eq!(b_handle.polls(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn poll_progress_until_complete() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let waker = CountingWaker::new();
+        let mut cx = Context::from_waker(waker.waker());
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Ok(1))]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(Ok::<_, ()>(2))]);
+        let mut join = pin!(vec![a, b].try_join());
+
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Pending);
+        assert!(a_handle.wake());
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+
+        // The outputs are returned by the final poll, without polling again.
+        assert_eq!(step(join.as_mut(), &waker), Poll::Ready(Ok(vec![1, 2])));
+        assert_eq!(a_handle.polls(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn poll_progress_buffers_error() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let waker = CountingWaker::new();
+        let mut cx = Context::from_waker(waker.waker());
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Ok(1))]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(Err("oh no"))]);
+        let mut join = pin!(vec![a, b].try_join());
+
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+
+        // Once an error is buffered, no other futures are polled.
+        assert!(a_handle.wake());
+        assert_eq!(join.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+        assert_eq!(a_handle.polls(), 1);
+        assert_eq!(step(join.as_mut(), &waker), Poll::Ready(Err("oh no")));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn indexed_failure() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let (a, _) = ScriptedFuture::new([Step::Ready(Ok(1))]);
+        let (b, b_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(Err("oh no"))]);
+        let (c, _) = ScriptedFuture::new([Step::Ready(Ok(3))]);
+        let (d, _) = ScriptedFuture::new([Step::WakeLater]);
+        let mut join = pin!(vec![a, b, c, d].try_join().indexed());
+        let waker = CountingWaker::new();
+
+        // Two futures complete before the third one fails.
+        assert_eq!(step(join.as_mut(), &waker), Poll::Pending);
+        assert!(b_handle.wake());
+        let Poll::Ready(Err(err)) = step(join.as_mut(), &waker) else {
+            panic!("the join should have failed");
+        };
+        assert_eq!(err.index(), 1);
+        assert_eq!(err.completed_before_failure(), 2);
+        assert_eq!(err.completed_indices(), [0, 2]);
+        assert_eq!(err.into_failure(), Failure::new(1, "oh no"));
+    }
+
+    #[test]
+    fn indexed_success() {
+        futures_lite::future::block_on(async {
+            let futs = vec![future::ready(Ok::<_, ()>(1)), future::ready(Ok(2))];
+            assert_eq!(futs.try_join().indexed().await, Ok(vec![1, 2]));
+        });
+    }
 }
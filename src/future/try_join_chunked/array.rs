@@ -0,0 +1,191 @@
+use super::TryJoinChunked as TryJoinChunkedTrait;
+use crate::future::try_join::array::TryJoin;
+use crate::future::TryJoin as _;
+use crate::utils::OutputArray;
+
+use core::array;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use pin_project::{pin_project, pinned_drop};
+
+/// A future which waits for chunks of futures to complete successfully, one
+/// chunk after another, or abort early on error.
+///
+/// This `struct` is created by the [`try_join_chunked`] method on the
+/// [`TryJoinChunked`] trait. See its documentation for more.
+///
+/// [`try_join_chunked`]: crate::future::TryJoinChunked::try_join_chunked
+/// [`TryJoinChunked`]: crate::future::TryJoinChunked
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project(PinnedDrop)]
+pub struct TryJoinChunked<Fut, T, E, const M: usize, const N: usize>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    /// A boolean which holds whether the future has completed
+    consumed: bool,
+    /// The number of chunks which have completed
+    completed: usize,
+    /// The chunks which haven't been started yet
+    chunks: array::IntoIter<[Fut; M], N>,
+    /// The chunk which is currently in-flight
+    #[pin]
+    current: Option<TryJoin<Fut::IntoFuture, T, E, M>>,
+    /// The output data, to be returned after the future completes
+    items: OutputArray<[T; M], N>,
+}
+
+impl<Fut, T, E, const M: usize, const N: usize> TryJoinChunked<Fut, T, E, M, N>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    #[inline]
+    pub(crate) fn new(chunks: [[Fut; M]; N]) -> Self {
+        Self {
+            consumed: false,
+            completed: 0,
+            chunks: chunks.into_iter(),
+            current: None,
+            items: OutputArray::uninit(),
+        }
+    }
+}
+
+impl<Fut, T, E, const M: usize, const N: usize> TryJoinChunkedTrait for [[Fut; M]; N]
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = [[T; M]; N];
+    type Error = E;
+    type Future = TryJoinChunked<Fut, T, E, M, N>;
+
+    #[inline]
+    fn try_join_chunked(self) -> Self::Future {
+        TryJoinChunked::new(self)
+    }
+}
+
+impl<Fut, T, E, const M: usize, const N: usize> fmt::Debug for TryJoinChunked<Fut, T, E, M, N>
+where
+    Fut: IntoFuture<Output = Result<T, E>> + fmt::Debug,
+    Fut::IntoFuture: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryJoinChunked")
+            .field("current", &self.current)
+            .field("remaining", &self.chunks.as_slice())
+            .finish()
+    }
+}
+
+impl<Fut, T, E, const M: usize, const N: usize> Future for TryJoinChunked<Fut, T, E, M, N>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = Result<[[T; M]; N], E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        debug_assert!(
+            !*this.consumed,
+            "Futures must not be polled after completing"
+        );
+        if *this.consumed {
+            return Poll::Pending;
+        }
+
+        loop {
+            if let Some(join) = this.current.as_mut().as_pin_mut() {
+                let output = ready!(join.poll(cx));
+                this.current.set(None);
+                match output {
+                    Ok(output) => {
+                        this.items.write(*this.completed, output);
+                        *this.completed += 1;
+                    }
+                    // Short-circuit on error, without starting later chunks.
+                    Err(err) => {
+                        *this.consumed = true;
+                        for i in 0..*this.completed {
+                            // SAFETY: the outputs of the completed chunks
+                            // have been written, and are only dropped once.
+                            unsafe { this.items.drop(i) };
+                        }
+                        return Poll::Ready(Err(err));
+                    }
+                }
+            }
+
+            // Only start the next chunk once the previous one has completed.
+            match this.chunks.next() {
+                Some(chunk) => this.current.set(Some(chunk.try_join())),
+                None => {
+                    *this.consumed = true;
+                    // SAFETY: every chunk has completed and written its
+                    // output, which means all items are initialized.
+                    return Poll::Ready(Ok(unsafe { this.items.take() }));
+                }
+            }
+        }
+    }
+}
+
+/// Drop the already initialized values on cancellation.
+#[pinned_drop]
+impl<Fut, T, E, const M: usize, const N: usize> PinnedDrop for TryJoinChunked<Fut, T, E, M, N>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    fn drop(self: Pin<&mut Self>) {
+        let this = self.project();
+        if *this.consumed {
+            return;
+        }
+
+        for i in 0..*this.completed {
+            // SAFETY: the outputs of the completed chunks have been written,
+            // and haven't been taken yet.
+            unsafe { this.items.drop(i) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future;
+
+    #[test]
+    fn all_ok() {
+        futures_lite::future::block_on(async {
+            let chunks = [
+                [future::ready(Ok::<_, ()>(1)), future::ready(Ok(2))],
+                [future::ready(Ok(3)), future::ready(Ok(4))],
+            ];
+            assert_eq!(chunks.try_join_chunked().await, Ok([[1, 2], [3, 4]]));
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn error_stops_later_chunks() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use alloc::rc::Rc;
+        use core::pin::pin;
+
+        let output = Rc::new(());
+        let (a, _) = ScriptedFuture::new([Step::Ready(Ok(output.clone()))]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(Err("oh no"))]);
+        let (c, c_handle) = ScriptedFuture::new([Step::Ready(Ok(output.clone()))]);
+        let mut fut = pin!([[a], [b], [c]].try_join_chunked());
+
+        let waker = CountingWaker::new();
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(Err("oh no")));
+        assert_eq!(c_handle.polls(), 0);
+        // The output of the first chunk has been dropped.
+        assert_eq!(Rc::strong_count(&output), 2);
+    }
+}
@@ -0,0 +1,40 @@
+use core::future::Future;
+
+pub(crate) mod array;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Wait for chunks of futures to complete successfully, one chunk after
+/// another, or abort early on error.
+///
+/// The futures within a chunk run concurrently, as with
+/// [`TryJoin`][crate::future::TryJoin], but each chunk is only started once
+/// the previous chunk has completed successfully. If a future fails, the
+/// remaining futures of its chunk are cancelled, and later chunks are never
+/// started.
+pub trait TryJoinChunked {
+    /// The resulting output type.
+    type Output;
+
+    /// The resulting error type.
+    type Error;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Result<Self::Output, Self::Error>>;
+
+    /// Waits for chunks of futures to complete successfully, one chunk after
+    /// another, or returns early on the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::ready;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let waves = [[ready(Ok(1)), ready(Ok(2))], [ready(Ok(3)), ready(Err("oh no"))]];
+    /// assert_eq!(waves.try_join_chunked().await, Err("oh no"));
+    /// # });
+    /// ```
+    fn try_join_chunked(self) -> Self::Future;
+}
@@ -0,0 +1,159 @@
+use super::TryJoinChunked as TryJoinChunkedTrait;
+use crate::future::try_join::vec::TryJoin;
+use crate::future::TryJoin as _;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::vec;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use pin_project::pin_project;
+
+/// A future which waits for chunks of futures to complete successfully, one
+/// chunk after another, or abort early on error.
+///
+/// This `struct` is created by the [`try_join_chunked`] method on the
+/// [`TryJoinChunked`] trait. See its documentation for more.
+///
+/// [`try_join_chunked`]: crate::future::TryJoinChunked::try_join_chunked
+/// [`TryJoinChunked`]: crate::future::TryJoinChunked
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct TryJoinChunked<Fut, T, E>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    /// A boolean which holds whether the future has completed
+    consumed: bool,
+    /// The chunks which haven't been started yet
+    chunks: vec::IntoIter<Vec<Fut>>,
+    /// The chunk which is currently in-flight
+    #[pin]
+    current: Option<TryJoin<Fut::IntoFuture, T, E>>,
+    /// The outputs of the completed chunks
+    items: Vec<Vec<T>>,
+}
+
+impl<Fut, T, E> TryJoinChunked<Fut, T, E>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    pub(crate) fn new(chunks: Vec<Vec<Fut>>) -> Self {
+        Self {
+            consumed: false,
+            items: Vec::with_capacity(chunks.len()),
+            chunks: chunks.into_iter(),
+            current: None,
+        }
+    }
+}
+
+impl<Fut, T, E> TryJoinChunkedTrait for Vec<Vec<Fut>>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = Vec<Vec<T>>;
+    type Error = E;
+    type Future = TryJoinChunked<Fut, T, E>;
+
+    fn try_join_chunked(self) -> Self::Future {
+        TryJoinChunked::new(self)
+    }
+}
+
+impl<Fut, T, E> fmt::Debug for TryJoinChunked<Fut, T, E>
+where
+    Fut: IntoFuture<Output = Result<T, E>> + fmt::Debug,
+    Fut::IntoFuture: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryJoinChunked")
+            .field("current", &self.current)
+            .field("remaining", &self.chunks.as_slice())
+            .finish()
+    }
+}
+
+impl<Fut, T, E> Future for TryJoinChunked<Fut, T, E>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = Result<Vec<Vec<T>>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        debug_assert!(
+            !*this.consumed,
+            "Futures must not be polled after completing"
+        );
+        if *this.consumed {
+            return Poll::Pending;
+        }
+
+        loop {
+            if let Some(join) = this.current.as_mut().as_pin_mut() {
+                let output = ready!(join.poll(cx));
+                this.current.set(None);
+                match output {
+                    Ok(output) => this.items.push(output),
+                    // Short-circuit on error, without starting later chunks.
+                    Err(err) => {
+                        *this.consumed = true;
+                        this.items.clear();
+                        return Poll::Ready(Err(err));
+                    }
+                }
+            }
+
+            // Only start the next chunk once the previous one has completed.
+            match this.chunks.next() {
+                Some(chunk) => this.current.set(Some(chunk.try_join())),
+                None => {
+                    *this.consumed = true;
+                    return Poll::Ready(Ok(core::mem::take(this.items)));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use core::future;
+
+    #[test]
+    fn all_ok() {
+        futures_lite::future::block_on(async {
+            let chunks = vec![
+                vec![future::ready(Ok::<_, ()>(1))],
+                vec![future::ready(Ok(2)), future::ready(Ok(3))],
+            ];
+            assert_eq!(
+                chunks.try_join_chunked().await,
+                Ok(vec![vec![1], vec![2, 3]])
+            );
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn error_stops_later_chunks() {
+        use crate::test_utils::{step, CountingWaker, ScriptedFuture, Step};
+        use core::pin::pin;
+
+        let (a, _) = ScriptedFuture::new([Step::Ready(Ok(1))]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(Err("oh no"))]);
+        let (c, c_handle) = ScriptedFuture::new([Step::Ready(Ok(3))]);
+        let mut fut = pin!(vec![vec![a], vec![b], vec![c]].try_join_chunked());
+
+        let waker = CountingWaker::new();
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(Err("oh no")));
+        assert_eq!(c_handle.polls(), 0);
+    }
+}
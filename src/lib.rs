@@ -111,9 +111,9 @@
 //!
 //! - [`StreamGroup`][stream::StreamGroup]: A growable group of streams which operate as a single unit.
 //! - [`ConcurrentStream`][concurrent_stream::ConcurrentStream]: A trait for asynchronous streams which can concurrently process items.
-//! - `tuple`: [`chain`][stream::Chain#impl-Chain-for-(A,+B)], [`merge`][stream::Merge#impl-Merge-for-(A,+B)], [`zip`][stream::Zip#impl-Zip-for-(A,+B)]
+//! - `tuple`: [`chain`][stream::Chain#impl-Chain-for-(A,+B)], [`merge`][stream::Merge#impl-Merge-for-(A,+B)], [`zip`][stream::Zip#impl-Zip-for-(A,+B)], [`try_zip`][stream::TryZip#impl-TryZip-for-(A,+B)], [`try_chain`][stream::TryChain#impl-TryChain-for-(A,+B)]
 //! - `array`: [`chain`][stream::Chain#impl-Chain-for-\[Fut;+N\]], [`merge`][stream::Merge#impl-Merge-for-\[Fut;+N\]], [`zip`][stream::Zip#impl-Zip-for-\[Fut;+N\]]
-//! - `Vec`: [`chain`][stream::Chain#impl-Chain-for-Vec<Fut>], [`merge`][stream::Merge#impl-Merge-for-Vec<Fut>], [`zip`][stream::Zip#impl-Zip-for-Vec<Fut>]
+//! - `Vec`: [`chain`][stream::Chain#impl-Chain-for-Vec<Fut>], [`merge`][stream::Merge#impl-Merge-for-Vec<Fut>], [`zip`][stream::Zip#impl-Zip-for-Vec<Fut>], [`try_zip`][stream::TryZip#impl-TryZip-for-Vec<S>], [`try_chain`][stream::TryChain#impl-TryChain-for-Vec<S>]
 //!
 //! # Runtime Support
 //!
@@ -123,6 +123,51 @@
 //! `#[no_std]` environments, allowing it to be used with embedded async
 //! runtimes such as `embassy`.
 //!
+//! None of the operations in this crate read from the clock or from an
+//! entropy source: fairness between the members of races and merges is
+//! provided by deterministically rotating the order in which they are polled.
+//! This means `futures-concurrency` can be used on targets such as
+//! `wasm32-unknown-unknown` without any additional configuration.
+//!
+//! ## Polling order
+//!
+//! [`Race`][future::Race] over tuples, arrays and vectors,
+//! [`RaceOk`][future::RaceOk] and [`RaceSome`][future::RaceSome] over
+//! tuples, and [`Merge`][stream::Merge] over tuples, arrays and vectors poll
+//! their members in index order, wrapping around from a starting index. The
+//! first poll starts at index `0`, and every following poll starts one index
+//! further along, so each member goes first once every `len` polls. Within a
+//! single poll every member is visited at most once. Since the order only
+//! depends on the number of polls, it's the same across runs and can be
+//! relied on in tests.
+//!
+//! All other operations, including [`Join`][future::Join],
+//! [`TryJoin`][future::TryJoin], and `RaceOk` and `RaceSome` over arrays and
+//! vectors, poll their members in index order starting from index `0` on
+//! every poll. When several members of such a race succeed in the same poll,
+//! the one with the lowest index wins.
+//!
+//! [`vec::Merge`] can be configured to poll its streams in the order in which
+//! they were woken instead, through [`vec::Merge::strategy`], and
+//! [`vec::Merge::max_consecutive`] moves a stream which yielded too many
+//! items in a row to the end of the order.
+//!
+//! ## Local executors
+//!
+//! Operations are `Send` and `Sync` exactly when their members are: the
+//! wakers they hand to their members only track which members were woken,
+//! and never hold on to the members themselves. This means futures and
+//! streams which hold `!Send` state, such as an `Rc`, can be joined, raced,
+//! merged and zipped on single-threaded executors like
+//! `futures::executor::LocalPool` or `tokio::task::LocalSet`.
+//!
+//! The only APIs which require `Send` are the ones which erase the type of
+//! their members: [`future::scope`], and the `boxed_concurrency` and
+//! `boxed_named` methods. For `!Send` members, use
+//! [`FutureExt::boxed_concurrency_local`][future::FutureExt::boxed_concurrency_local]
+//! and [`StreamExt::boxed_concurrency_local`][stream::StreamExt::boxed_concurrency_local]
+//! instead.
+//!
 //! # Feature Flags
 //!
 //! The `std` feature flag is enabled by default. To target `alloc` or `no_std`
@@ -137,6 +182,68 @@
 //! futures-concurrency = { version = "7.5.0", default-features = false, features = ["alloc"] }
 //! ```
 //!
+//! Without the `std` feature, the wakers given to the members of an operation
+//! forward to the parent waker instead of tracking which member was woken, so
+//! every member is polled whenever the operation is. This fallback doesn't
+//! need any atomics, which means the crate also builds on targets without
+//! compare-and-swap such as `thumbv6m-none-eabi`, with or without `alloc`.
+//! The APIs which hand out an `Arc`, which are `ConcurrentStream`,
+//! `JoinShared` and `vec::instrumented`, are left out on those targets.
+//!
+//! The `critical-section` feature flag tracks which member was woken without
+//! `std`. The state shared between the wakers is protected by a critical
+//! section from the [`critical-section`] crate, and reference counted with
+//! `portable-atomic`, so the application has to provide a critical section
+//! implementation for its target. Operations then only poll the members
+//! which were woken, `Strategy::Fifo` takes effect, and `StreamGroup` can
+//! pause its streams. If `std` is enabled as well, the critical section is
+//! used instead of a `Mutex`.
+//!
+//! [`critical-section`]: https://docs.rs/critical-section
+//!
+//! On nightly, the `unstable-async-iter` feature flag bridges the stream
+//! operations with `core::async_iter::AsyncIterator`: async iterators can be
+//! converted using `stream::from_async_iter`, and the streams returned by
+//! `chain`, `merge` and `zip` implement `AsyncIterator`. This feature is not
+//! covered by the semver guarantees of this crate.
+//!
+//! The `tokio` feature flag adds integration with tokio's task handles:
+//! `FutureGroup` can spawn tasks directly using `spawn` and `spawn_on`, and
+//! vectors of `JoinHandle`s can be converted into `vec::Join` and
+//! `vec::TryJoin`.
+//!
+//! The `futures` feature flag adds conversions from the `futures` crate's
+//! `FuturesUnordered` and `SelectAll` into `FutureGroup` and `vec::Merge`,
+//! which can ease migrating between the two crates. Together with `std`, it
+//! also lets a `FutureGroup` be fed through a `Sink` using `split_sink`.
+//!
+//! The `serde` feature flag implements `Serialize` and `Deserialize` for
+//! `array::AggregateError`, `future::Completion` and `future::Failure`.
+//!
+//! The `tracing` feature flag emits trace-level events from the vector and
+//! tuple implementations of `join`, `try_join`, `race`, `race_ok` and `merge`:
+//! each operation opens a span, within which it records every member it
+//! polls, every member which is woken, and every member which completes. The
+//! span can be given a name using the `named` method of each operation, so
+//! that a stuck member can be traced back to the operation it belongs to.
+//! Without this feature, none of this state is stored.
+//!
+//! Tuples implement the operations of this crate for up to 12 members. The
+//! `large-tuples` feature flag raises that limit to 16 members, for both the
+//! future and the stream operations. It's opt-in because every additional
+//! arity generates a new set of types for each operation, which makes the
+//! crate noticeably slower to compile.
+//!
+//! The `test-utils` feature flag enables the `test_utils` module, which
+//! contains scripted futures and streams for testing code built on top of
+//! this crate. This module is not covered by the semver guarantees of this
+//! crate.
+//!
+//! The `interleaving-harness` feature flag additionally enables
+//! `test_utils::harness`, a deterministic executor which drives operations
+//! through random interleavings of wakes, and checks that no wake is lost
+//! and no member is polled after it completed.
+//!
 //! # Further Reading
 //!
 //! `futures-concurrency` has been developed over the span of several years. It is
@@ -152,6 +259,7 @@
 #![warn(missing_docs)]
 #![allow(non_snake_case)]
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "unstable-async-iter", feature(async_iterator))]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -167,37 +275,63 @@ pub mod prelude {
     pub use super::future::FutureExt as _;
     pub use super::stream::StreamExt as _;
 
+    #[cfg(feature = "std")]
+    pub use super::future::AbortableJoin as _;
     pub use super::future::Join as _;
+    pub use super::future::JoinChunked as _;
+    #[cfg(feature = "alloc")]
+    pub use super::future::JoinIter as _;
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    pub use super::future::JoinShared as _;
+    pub use super::future::JoinSome as _;
     pub use super::future::Race as _;
     pub use super::future::RaceOk as _;
+    pub use super::future::RaceSome as _;
     pub use super::future::TryJoin as _;
+    pub use super::future::TryJoinChunked as _;
     pub use super::stream::Chain as _;
     pub use super::stream::IntoStream as _;
     pub use super::stream::Merge as _;
+    pub use super::stream::MergeIsolateErrors as _;
+    pub use super::stream::TryChain as _;
+    #[cfg(feature = "alloc")]
+    pub use super::stream::TryMergeTolerating as _;
+    pub use super::stream::TryZip as _;
     pub use super::stream::Zip as _;
 
-    #[cfg(feature = "alloc")]
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
     pub use super::concurrent_stream::{
         ConcurrentStream, FromConcurrentStream, IntoConcurrentStream,
     };
 }
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
 pub mod concurrent_stream;
 
+#[cfg(feature = "alloc")]
+pub use collections::btree_map;
+#[cfg(feature = "std")]
+pub use collections::hash_map;
 #[cfg(feature = "alloc")]
 pub use collections::vec;
 
 pub mod future;
 pub mod stream;
+#[cfg(any(all(test, feature = "std"), feature = "test-utils"))]
+pub mod test_utils;
 
 /// Helper functions and types for fixed-length arrays.
 pub mod array {
     pub use crate::future::join::array::Join;
-    pub use crate::future::race::array::Race;
+    pub use crate::future::join_chunked::array::JoinChunked;
+    #[cfg(feature = "alloc")]
+    pub use crate::future::join_iter::array::{JoinIter, JoinOutputs};
+    pub use crate::future::race::array::{Race, RaceDetailed};
     pub use crate::future::race_ok::array::{AggregateError, RaceOk};
     pub use crate::future::try_join::array::TryJoin;
+    pub use crate::future::try_join_chunked::array::TryJoinChunked;
     pub use crate::stream::chain::array::Chain;
     pub use crate::stream::merge::array::Merge;
+    pub use crate::stream::merge_isolate_errors::array::MergeIsolateErrors;
     pub use crate::stream::zip::array::Zip;
 }
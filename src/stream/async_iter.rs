@@ -0,0 +1,135 @@
+use core::async_iter::AsyncIterator;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::Stream;
+use pin_project::pin_project;
+
+/// A stream that was created from an async iterator.
+///
+/// This `struct` is created by the [`from_async_iter`] function. See its
+/// documentation for more.
+#[pin_project]
+#[derive(Clone, Debug)]
+#[must_use = "streams do nothing unless polled or .awaited"]
+pub struct FromAsyncIter<I> {
+    #[pin]
+    iter: I,
+}
+
+/// Converts an [`AsyncIterator`] into a stream.
+///
+/// Because every [`Stream`] already implements [`IntoStream`], coherence rules
+/// prevent us from implementing `IntoStream` for async iterators directly.
+/// This function wraps the async iterator in an adapter which implements
+/// `Stream`, and through it `IntoStream`, forwarding both items and the size
+/// hint.
+///
+/// This function is only available on nightly, with the
+/// `unstable-async-iter` feature enabled.
+///
+/// [`IntoStream`]: crate::stream::IntoStream
+pub fn from_async_iter<I: AsyncIterator>(iter: I) -> FromAsyncIter<I> {
+    FromAsyncIter { iter }
+}
+
+impl<I> FromAsyncIter<I> {
+    /// Consume the adapter, returning the underlying async iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: AsyncIterator> Stream for FromAsyncIter<I> {
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().iter.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: AsyncIterator> AsyncIterator for FromAsyncIter<I> {
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().iter.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::stream::{Chain, Merge, Zip};
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+
+    /// Yields the numbers in `start..end`.
+    struct Counter {
+        start: u32,
+        end: u32,
+    }
+
+    impl AsyncIterator for Counter {
+        type Item = u32;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<u32>> {
+            if self.start == self.end {
+                return Poll::Ready(None);
+            }
+            self.start += 1;
+            Poll::Ready(Some(self.start - 1))
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let len = (self.end - self.start) as usize;
+            (len, Some(len))
+        }
+    }
+
+    /// Drain an async iterator using its own `poll_next`.
+    async fn collect<I: AsyncIterator + Unpin>(mut iter: I) -> Vec<I::Item> {
+        let mut out = Vec::new();
+        while let Some(item) =
+            core::future::poll_fn(|cx| AsyncIterator::poll_next(Pin::new(&mut iter), cx)).await
+        {
+            out.push(item);
+        }
+        out
+    }
+
+    #[test]
+    fn into_stream() {
+        block_on(async {
+            let s = from_async_iter(Counter { start: 0, end: 3 });
+            assert_eq!(Stream::size_hint(&s), (3, Some(3)));
+            assert_eq!(s.collect::<Vec<_>>().await, vec![0, 1, 2]);
+        })
+    }
+
+    #[test]
+    fn combinators_are_async_iterators() {
+        block_on(async {
+            let a = from_async_iter(Counter { start: 0, end: 2 });
+            let b = from_async_iter(Counter { start: 2, end: 4 });
+            assert_eq!(collect((a, b).chain()).await, vec![0, 1, 2, 3]);
+
+            let a = from_async_iter(Counter { start: 0, end: 2 });
+            let b = from_async_iter(Counter { start: 2, end: 4 });
+            assert_eq!(collect([a, b].zip()).await, vec![[0, 2], [1, 3]]);
+
+            let a = from_async_iter(Counter { start: 0, end: 2 });
+            let b = from_async_iter(Counter { start: 2, end: 4 });
+            let mut out = collect(vec![a, b].merge()).await;
+            out.sort_unstable();
+            assert_eq!(out, vec![0, 1, 2, 3]);
+        })
+    }
+}
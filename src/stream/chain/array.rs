@@ -62,6 +62,19 @@ where
     }
 }
 
+#[cfg(feature = "unstable-async-iter")]
+impl<S: Stream, const N: usize> core::async_iter::AsyncIterator for Chain<S, N> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Stream::poll_next(self, cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Stream::size_hint(self)
+    }
+}
+
 impl<S: Stream, const N: usize> ChainTrait for [S; N] {
     type Item = S::Item;
 
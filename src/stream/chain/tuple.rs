@@ -75,6 +75,22 @@ macro_rules! impl_chain_for_tuple {
             }
         }
 
+        #[cfg(feature = "unstable-async-iter")]
+        impl<T, $($F,)+> core::async_iter::AsyncIterator for $StructName<$($F,)+>
+        where
+            $($F: Stream<Item = T>,)+
+        {
+            type Item = T;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                Stream::poll_next(self, cx)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                Stream::size_hint(self)
+            }
+        }
+
         impl<T, $($F,)+> Chain for ($($F,)+)
         where
             $($F: Stream<Item = T>,)+
@@ -107,6 +123,14 @@ impl_chain_for_tuple! { chain_9 Chain9 A B C D E F G H I }
 impl_chain_for_tuple! { chain_10 Chain10 A B C D E F G H I J }
 impl_chain_for_tuple! { chain_11 Chain11 A B C D E F G H I J K }
 impl_chain_for_tuple! { chain_12 Chain12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_13 Chain13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_14 Chain14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_15 Chain15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_16 Chain16 A B C D E F G H I J K L M N O P }
 
 #[cfg(test)]
 mod tests {
@@ -130,4 +154,33 @@ mod tests {
             assert_eq!(s.next().await, None);
         })
     }
+
+    #[cfg(feature = "large-tuples")]
+    #[test]
+    fn chain_16() {
+        block_on(async {
+            let s = stream::once;
+            let s = (
+                s(0),
+                s(1),
+                s(2),
+                s(3),
+                s(4),
+                s(5),
+                s(6),
+                s(7),
+                s(8),
+                s(9),
+                s(10),
+                s(11),
+                s(12),
+                s(13),
+                s(14),
+                s(15),
+            )
+                .chain();
+            let out: Vec<_> = s.collect().await;
+            assert_eq!(out, (0..16).collect::<Vec<_>>());
+        })
+    }
 }
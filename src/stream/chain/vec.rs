@@ -8,7 +8,7 @@ use core::task::{Context, Poll};
 use futures_core::Stream;
 use pin_project::pin_project;
 
-use crate::utils;
+use crate::utils::PinnedVec;
 
 use super::Chain as ChainTrait;
 
@@ -21,8 +21,7 @@ use super::Chain as ChainTrait;
 /// [`Chain`]: trait.Chain.html
 #[pin_project]
 pub struct Chain<S> {
-    #[pin]
-    streams: Vec<S>,
+    streams: PinnedVec<S>,
     index: usize,
     len: usize,
     done: bool,
@@ -32,7 +31,7 @@ impl<S: Stream> Stream for Chain<S> {
     type Item = S::Item;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let mut this = self.project();
+        let this = self.project();
 
         assert!(!*this.done, "Stream should not be polled after completion");
 
@@ -41,9 +40,7 @@ impl<S: Stream> Stream for Chain<S> {
                 *this.done = true;
                 return Poll::Ready(None);
             }
-            let stream = utils::iter_pin_mut_vec(this.streams.as_mut())
-                .nth(*this.index)
-                .unwrap();
+            let stream = this.streams.get_pin_mut(*this.index).unwrap();
             match stream.poll_next(cx) {
                 Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
                 Poll::Ready(None) => {
@@ -65,6 +62,19 @@ where
     }
 }
 
+#[cfg(feature = "unstable-async-iter")]
+impl<S: Stream> core::async_iter::AsyncIterator for Chain<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Stream::poll_next(self, cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Stream::size_hint(self)
+    }
+}
+
 impl<S: Stream> ChainTrait for Vec<S> {
     type Item = S::Item;
 
@@ -73,7 +83,7 @@ impl<S: Stream> ChainTrait for Vec<S> {
     fn chain(self) -> Self::Stream {
         Chain {
             len: self.len(),
-            streams: self,
+            streams: PinnedVec::new(self),
             index: 0,
             done: false,
         }
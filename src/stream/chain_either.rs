@@ -0,0 +1,170 @@
+use core::fmt;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+use super::{Either, IntoStream};
+
+/// A stream which yields the items of one stream, followed by the items of a
+/// stream with a different item type.
+///
+/// This `struct` is created by the [`chain_either`] method on [`StreamExt`].
+/// See its documentation for more.
+///
+/// [`chain_either`]: crate::stream::StreamExt::chain_either
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct ChainEither<S1, S2>
+where
+    S2: IntoStream,
+{
+    #[pin]
+    first: Option<S1>,
+    /// The second stream, until it's converted once the first one has ended.
+    pending: Option<S2>,
+    #[pin]
+    second: Option<S2::IntoStream>,
+}
+
+impl<S1, S2> ChainEither<S1, S2>
+where
+    S2: IntoStream,
+{
+    pub(crate) fn new(first: S1, second: S2) -> Self {
+        Self {
+            first: Some(first),
+            pending: Some(second),
+            second: None,
+        }
+    }
+}
+
+impl<S1, S2> fmt::Debug for ChainEither<S1, S2>
+where
+    S1: fmt::Debug,
+    S2: IntoStream + fmt::Debug,
+    S2::IntoStream: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_tuple("ChainEither");
+        if let Some(first) = &self.first {
+            f.field(first);
+        }
+        match (&self.pending, &self.second) {
+            (Some(pending), _) => f.field(pending),
+            (None, Some(second)) => f.field(second),
+            (None, None) => &mut f,
+        };
+        f.finish()
+    }
+}
+
+impl<S1, S2> Stream for ChainEither<S1, S2>
+where
+    S1: Stream,
+    S2: IntoStream,
+{
+    type Item = Either<S1::Item, S2::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if let Some(first) = this.first.as_mut().as_pin_mut() {
+            match ready!(first.poll_next(cx)) {
+                Some(item) => return Poll::Ready(Some(Either::Left(item))),
+                None => {
+                    // Only now that the first stream has ended do we convert
+                    // the second one.
+                    this.first.set(None);
+                    let second = this.pending.take().map(IntoStream::into_stream);
+                    this.second.set(second);
+                }
+            }
+        }
+
+        let Some(second) = this.second.as_mut().as_pin_mut() else {
+            return Poll::Ready(None);
+        };
+        match ready!(second.poll_next(cx)) {
+            Some(item) => Poll::Ready(Some(Either::Right(item))),
+            None => {
+                this.second.set(None);
+                Poll::Ready(None)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (&self.first, &self.second) {
+            // The second stream hasn't been converted yet, so we can't know
+            // how many items it will yield.
+            (Some(first), _) => (first.size_hint().0, None),
+            (None, Some(second)) => second.size_hint(),
+            (None, None) => (0, Some(0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream::StreamExt;
+    use alloc::vec::Vec;
+    use futures_lite::future::block_on;
+    use futures_lite::stream;
+
+    #[test]
+    fn replay_then_live() {
+        block_on(async {
+            let (tx, rx) = futures::channel::mpsc::unbounded();
+            for n in 3..5 {
+                tx.unbounded_send(n).unwrap();
+            }
+            drop(tx);
+
+            let replay = stream::iter(["a", "b", "c"]);
+            let s = replay.chain_either(rx);
+            let items: Vec<_> = futures_lite::StreamExt::collect(s).await;
+            assert_eq!(
+                items,
+                [
+                    Either::Left("a"),
+                    Either::Left("b"),
+                    Either::Left("c"),
+                    Either::Right(3),
+                    Either::Right(4),
+                ]
+            );
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn second_is_not_polled_early() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        let (a, a_handle) = ScriptedStream::new([Step::Ready(1), Step::WakeLater]);
+        let (b, b_handle) = ScriptedStream::new([Step::Ready("two")]);
+        let mut s = pin!(a.chain_either(b));
+        let waker = CountingWaker::new();
+
+        assert_eq!(
+            step_stream(s.as_mut(), &waker),
+            Poll::Ready(Some(Either::Left(1)))
+        );
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert_eq!(b_handle.polls(), 0);
+
+        assert!(a_handle.wake());
+        assert_eq!(
+            step_stream(s.as_mut(), &waker),
+            Poll::Ready(Some(Either::Right("two")))
+        );
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+    }
+}
@@ -0,0 +1,97 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::Stream;
+use pin_project::pin_project;
+
+/// A stream that was created from an iterator.
+///
+/// This `struct` is created by the [`from_iter`] function. See its
+/// documentation for more.
+#[pin_project]
+#[derive(Clone, Debug)]
+#[must_use = "streams do nothing unless polled or .awaited"]
+pub struct FromIter<I> {
+    iter: I,
+}
+
+/// Converts an iterator into a stream.
+///
+/// Because every [`Stream`] already implements [`IntoStream`], coherence rules
+/// prevent us from implementing `IntoStream` for iterators directly. This
+/// function serves as the conversion instead. Any type implementing
+/// [`IntoIterator`] can be passed, which includes `Vec<T>`, arrays, ranges,
+/// and `Option<T>` (which yields zero or one item). To create a stream which
+/// yields a single `Result<T, E>`, wrap it in `Some` or a one-element array.
+///
+/// The resulting stream never returns `Poll::Pending`, and forwards the
+/// iterator's size hint.
+///
+/// [`IntoStream`]: crate::stream::IntoStream
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_concurrency::stream;
+/// use futures_lite::future::block_on;
+/// use futures_lite::prelude::*;
+///
+/// block_on(async {
+///     let a = futures_lite::stream::once(1);
+///     let b = stream::from_iter(vec![2, 3, 4]);
+///     let c = stream::from_iter(Some(5));
+///
+///     let mut buf: Vec<_> = (a, b, c).merge().collect().await;
+///     buf.sort_unstable();
+///     assert_eq!(buf, vec![1, 2, 3, 4, 5]);
+/// })
+/// ```
+pub fn from_iter<I: IntoIterator>(iter: I) -> FromIter<I::IntoIter> {
+    FromIter {
+        iter: iter.into_iter(),
+    }
+}
+
+impl<I: Iterator> Stream for FromIter<I> {
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.project().iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+
+    #[test]
+    fn size_hint() {
+        block_on(async {
+            let mut s = from_iter([1, 2, 3]);
+            assert_eq!(s.size_hint(), (3, Some(3)));
+            assert_eq!(s.next().await, Some(1));
+            assert_eq!(s.size_hint(), (2, Some(2)));
+        })
+    }
+
+    #[test]
+    fn option_and_result() {
+        block_on(async {
+            let none: Option<u8> = None;
+            assert_eq!(from_iter(none).count().await, 0);
+            assert_eq!(from_iter(Some(1)).count().await, 1);
+
+            let res: Result<u8, ()> = Err(());
+            let mut s = from_iter(Some(res));
+            assert_eq!(s.next().await, Some(Err(())));
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
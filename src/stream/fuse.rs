@@ -0,0 +1,112 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+/// A stream which can safely be polled after it has completed.
+///
+/// Once the inner stream has yielded `None`, every subsequent poll returns
+/// `Poll::Ready(None)` without polling the inner stream again. This guards
+/// against the "polled after completion" panics of the operations in this
+/// crate.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_concurrency::stream::Fuse;
+/// use futures_lite::future::block_on;
+/// use futures_lite::prelude::*;
+/// use futures_lite::stream;
+///
+/// block_on(async {
+///     let mut s = Fuse::new((stream::once(1), stream::once(2)).chain());
+///     assert_eq!(s.next().await, Some(1));
+///     assert_eq!(s.next().await, Some(2));
+///     assert_eq!(s.next().await, None);
+///
+///     // Polling again does not panic, it keeps returning `None`.
+///     assert!(s.is_done());
+///     assert_eq!(s.next().await, None);
+/// })
+/// ```
+#[derive(Debug, Clone)]
+#[pin_project]
+#[must_use = "streams do nothing unless polled or .awaited"]
+pub struct Fuse<S> {
+    #[pin]
+    stream: S,
+    done: bool,
+}
+
+impl<S> Fuse<S> {
+    /// Wrap a stream so it can be polled after completion.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            done: false,
+        }
+    }
+
+    /// Returns `true` if the inner stream has completed.
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Consume the `Fuse`, returning the inner stream.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S: Stream> Stream for Fuse<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        let item = core::task::ready!(this.stream.poll_next(cx));
+        if item.is_none() {
+            *this.done = true;
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.done {
+            true => (0, Some(0)),
+            false => self.stream.size_hint(),
+        }
+    }
+}
+
+impl<S: Stream> FusedStream for Fuse<S> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream::Merge;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn repoll_after_merge() {
+        block_on(async {
+            let mut s = Fuse::new([stream::once(1), stream::once(2)].merge());
+            assert_eq!(s.next().await.unwrap() + s.next().await.unwrap(), 3);
+            assert_eq!(s.next().await, None);
+            assert_eq!(s.next().await, None);
+            assert!(s.is_terminated());
+            assert_eq!(s.size_hint(), (0, Some(0)));
+        })
+    }
+}
@@ -5,6 +5,9 @@ use futures_core::Stream;
 /// By implementing `IntoStream` for a type, you define how it will be
 /// converted to an iterator. This is common for types which describe a
 /// collection of some kind.
+///
+/// Every [`Stream`] implements `IntoStream`. To convert an iterator into a
+/// stream, use [`stream::from_iter`][crate::stream::from_iter].
 pub trait IntoStream {
     /// The type of the elements being iterated over.
     type Item;
@@ -1,6 +1,10 @@
+use super::idle::IdleMarkers;
+use super::state::MergeState;
 use super::Merge as MergeTrait;
+use crate::private::IndexedStream;
+use crate::private_impl;
 use crate::stream::IntoStream;
-use crate::utils::{self, Indexer, PollArray, WakerArray};
+use crate::utils::{self, PollArray, WakerArray};
 
 use core::fmt;
 use core::pin::Pin;
@@ -21,11 +25,7 @@ where
 {
     #[pin]
     streams: [S; N],
-    indexer: Indexer,
-    wakers: WakerArray<N>,
-    state: PollArray<N>,
-    complete: usize,
-    done: bool,
+    state: MergeState<WakerArray<N>, PollArray<N>>,
 }
 
 impl<S, const N: usize> Merge<S, N>
@@ -35,13 +35,107 @@ where
     pub(crate) fn new(streams: [S; N]) -> Self {
         Self {
             streams,
-            indexer: Indexer::new(N),
-            wakers: WakerArray::new(),
-            state: PollArray::new_pending(),
-            complete: 0,
-            done: false,
+            state: MergeState::new(WakerArray::new(), PollArray::new_pending()),
         }
     }
+
+    /// Limit how many items in a row are taken from the same stream.
+    ///
+    /// Once a stream has yielded `max` consecutive items, all other streams
+    /// which are ready are polled before it is polled again. By default a
+    /// stream may yield up to one item less than there are streams in a row,
+    /// while other streams are ready.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `max` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let noisy = stream::iter(vec!["noisy"; 3]);
+    /// let quiet = stream::iter(vec!["quiet"]);
+    /// let s = [noisy, quiet].merge().max_consecutive(1);
+    /// let items: Vec<_> = s.collect().await;
+    /// assert_eq!(items.iter().filter(|s| **s == "quiet").count(), 1);
+    /// # });
+    /// ```
+    pub fn max_consecutive(mut self, max: usize) -> Self {
+        self.state.streak.set_max(max);
+        self
+    }
+
+    /// Name the span in which this merge is traced, so its events can be told
+    /// apart from those of other merges.
+    ///
+    /// This requires the `tracing` feature.
+    #[cfg(feature = "tracing")]
+    pub fn named(self, name: &str) -> Self {
+        self.state.trace.set_name(name);
+        self
+    }
+
+    /// Yield a [`Marked::Idle`] marker whenever none of the streams have an
+    /// item ready, after at least one item was yielded.
+    ///
+    /// Every item is wrapped in [`Marked::Item`]. A marker is yielded at most
+    /// once between two items, and never before the first item or after the
+    /// last, so consumers which batch items can flush on every marker.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_concurrency::stream::Marked;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let s = [stream::iter(vec![1, 2]), stream::iter(vec![3])].merge().with_idle_markers();
+    /// let items: Vec<_> = s.collect().await;
+    /// assert!(items.iter().all(|item| !item.is_idle()));
+    /// assert_eq!(items.len(), 3);
+    /// # });
+    /// ```
+    ///
+    /// [`Marked::Idle`]: crate::stream::Marked::Idle
+    /// [`Marked::Item`]: crate::stream::Marked::Item
+    pub fn with_idle_markers(self) -> IdleMarkers<Self> {
+        IdleMarkers::new(self)
+    }
+
+    /// Returns the number of streams in the merge, including the ones which
+    /// have completed.
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the merge contains no streams.
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the number of streams which have not completed yet.
+    ///
+    /// Like all methods which take `&self`, this can be called on a pinned
+    /// merge as well, e.g. through `Pin::as_ref`.
+    pub fn pending_members(&self) -> usize {
+        self.state.pending_members()
+    }
+
+    /// Returns `true` if the stream at `index` has completed.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    pub fn is_member_done(&self, index: usize) -> bool {
+        self.state.is_member_done(index)
+    }
 }
 
 impl<S, const N: usize> fmt::Debug for Merge<S, N>
@@ -49,7 +143,11 @@ where
     S: Stream + fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.streams.iter()).finish()
+        f.debug_struct("Merge")
+            .field("len", &self.len())
+            .field("pending_members", &self.pending_members())
+            .field("streams", &self.streams)
+            .finish()
     }
 }
 
@@ -60,51 +158,42 @@ where
     type Item = S::Item;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let mut this = self.project();
-
-        let mut readiness = this.wakers.readiness();
-        readiness.set_waker(cx.waker());
-
-        // Iterate over our streams one-by-one. If a stream yields a value,
-        // we exit early. By default we'll return `Poll::Ready(None)`, but
-        // this changes if we encounter a `Poll::Pending`.
-        for index in this.indexer.iter() {
-            if !readiness.any_ready() {
-                // Nothing is ready yet
-                return Poll::Pending;
-            } else if !readiness.clear_ready(index) || this.state[index].is_none() {
-                continue;
-            }
-
-            // unlock readiness so we don't deadlock when polling
-            #[allow(clippy::drop_non_drop)]
-            drop(readiness);
+        self.poll_next_indexed(cx)
+            .map(|item| item.map(|(_, item)| item))
+    }
+}
 
-            // Obtain the intermediate waker.
-            let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+impl<S, const N: usize> IndexedStream for Merge<S, N>
+where
+    S: Stream,
+{
+    private_impl! {}
 
+    fn poll_next_indexed(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<(usize, Self::Item)>> {
+        let mut this = self.project();
+        this.state.poll_next(cx, |index, cx| {
             let stream = utils::get_pin_mut(this.streams.as_mut(), index).unwrap();
-            match stream.poll_next(&mut cx) {
-                Poll::Ready(Some(item)) => {
-                    // Mark ourselves as ready again because we need to poll for the next item.
-                    this.wakers.readiness().set_ready(index);
-                    return Poll::Ready(Some(item));
-                }
-                Poll::Ready(None) => {
-                    *this.complete += 1;
-                    this.state[index].set_none();
-                    if *this.complete == this.streams.len() {
-                        return Poll::Ready(None);
-                    }
-                }
-                Poll::Pending => {}
-            }
+            stream.poll_next(cx)
+        })
+    }
+}
 
-            // Lock readiness so we can use it again
-            readiness = this.wakers.readiness();
-        }
+#[cfg(feature = "unstable-async-iter")]
+impl<S, const N: usize> core::async_iter::AsyncIterator for Merge<S, N>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Stream::poll_next(self, cx)
+    }
 
-        Poll::Pending
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Stream::size_hint(self)
     }
 }
 
@@ -144,6 +233,16 @@ mod tests {
         })
     }
 
+    #[test]
+    fn merge_array_empty() {
+        block_on(async {
+            let streams: [stream::Once<u8>; 0] = [];
+            let mut s = streams.merge();
+            assert_eq!(s.next().await, None);
+            assert_eq!(s.next().await, None);
+        })
+    }
+
     #[test]
     fn merge_array_2x2() {
         block_on(async {
@@ -215,4 +314,89 @@ mod tests {
             pool.run_until_stalled()
         }
     }
+
+    #[cfg(feature = "std")]
+    /// The starting point rotates deterministically, so streams which are
+    /// always ready take turns.
+    #[test]
+    fn rotation_distribution() {
+        block_on(async {
+            let a = stream::repeat(0);
+            let b = stream::repeat(1);
+            let c = stream::repeat(2);
+            let mut counts = [0usize; 3];
+            let mut s = [a, b, c].merge().take(100);
+            while let Some(index) = s.next().await {
+                counts[index] += 1;
+            }
+            assert!(
+                counts.iter().all(|&n| n.abs_diff(100 / 3) <= 1),
+                "{counts:?}"
+            );
+        })
+    }
+
+    #[test]
+    fn member_counts() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        let (a, _) = ScriptedStream::new([Step::Ready(1)]);
+        let (b, _) = ScriptedStream::new([Step::WakeLater]);
+        let mut s = pin!([a, b].merge());
+        let waker = CountingWaker::new();
+        assert_eq!(s.len(), 2);
+        assert_eq!(s.pending_members(), 2);
+
+        // `a` yields its item, then completes on the next poll.
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(1)));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert_eq!(s.pending_members(), 1);
+        assert!(s.is_member_done(0));
+        assert!(!s.is_member_done(1));
+
+        let debug = format!("{:?}", s.as_ref().get_ref());
+        assert!(
+            debug.starts_with("Merge { len: 2, pending_members: 1, streams: ["),
+            "{debug}"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn max_consecutive() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        /// Returns how many items the noisy stream yields after the slow
+        /// stream has been woken, before the slow stream's item is yielded.
+        fn delay(max: Option<usize>) -> usize {
+            let (idle_a, _) = ScriptedStream::new([Step::WakeLater]);
+            let (noisy, _) = ScriptedStream::new([Step::Ready(0); 16]);
+            let (slow, slow_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+            let (idle_b, _) = ScriptedStream::new([Step::WakeLater]);
+            let mut s = [idle_a, noisy, slow, idle_b].merge();
+            if let Some(max) = max {
+                s = s.max_consecutive(max);
+            }
+            let mut s = pin!(s);
+            let waker = CountingWaker::new();
+
+            for _ in 0..3 {
+                assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(0)));
+            }
+            assert!(slow_handle.wake());
+            let mut delay = 0;
+            while step_stream(s.as_mut(), &waker) == Poll::Ready(Some(0)) {
+                delay += 1;
+            }
+            delay
+        }
+
+        // By default the rotation lets the noisy stream go first three times.
+        assert_eq!(delay(None), 3);
+        assert_eq!(delay(Some(1)), 0);
+        assert_eq!(delay(Some(2)), 0);
+        assert_eq!(delay(Some(8)), 3);
+    }
 }
@@ -0,0 +1,171 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+/// An item of a merge, or a marker that the merge has run out of items for
+/// now.
+///
+/// This is the item type of [`IdleMarkers`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Marked<T> {
+    /// An item yielded by one of the merged streams.
+    Item(T),
+    /// None of the merged streams had an item ready, after at least one
+    /// item was yielded since the previous marker.
+    Idle,
+}
+
+impl<T> Marked<T> {
+    /// Returns the item, or `None` for an idle marker.
+    pub fn into_item(self) -> Option<T> {
+        match self {
+            Marked::Item(item) => Some(item),
+            Marked::Idle => None,
+        }
+    }
+
+    /// Returns `true` if this is an idle marker.
+    pub fn is_idle(&self) -> bool {
+        matches!(self, Marked::Idle)
+    }
+}
+
+/// A stream which merges multiple streams, and yields a marker whenever the
+/// merge runs out of items.
+///
+/// This `struct` is created by the `with_idle_markers` method on
+/// [`array::Merge`] and [`vec::Merge`]. See its documentation for more.
+///
+/// [`array::Merge`]: crate::array::Merge::with_idle_markers
+/// [`vec::Merge`]: crate::vec::Merge::with_idle_markers
+#[derive(Debug, Clone)]
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct IdleMarkers<S> {
+    #[pin]
+    stream: S,
+    /// Whether an item was yielded since the last marker.
+    active: bool,
+}
+
+impl<S> IdleMarkers<S> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream,
+            active: false,
+        }
+    }
+}
+
+impl<S> Stream for IdleMarkers<S>
+where
+    S: Stream,
+{
+    type Item = Marked<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                *this.active = true;
+                Poll::Ready(Some(Marked::Item(item)))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            // The merge has registered the waker for its streams, so the
+            // marker can be yielded in place of waiting.
+            Poll::Pending if *this.active => {
+                *this.active = false;
+                Poll::Ready(Some(Marked::Idle))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.stream.size_hint();
+        // Every item may be followed by a marker.
+        (low, high.and_then(|high| high.checked_mul(2)))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+    use core::pin::pin;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+
+    #[test]
+    fn one_marker_between_bursts() {
+        let (a, a_handle) = ScriptedStream::new([
+            Step::Ready(1),
+            Step::Ready(2),
+            Step::WakeLater,
+            Step::Ready(5),
+        ]);
+        let (b, b_handle) = ScriptedStream::new([Step::Ready(3), Step::WakeLater, Step::Ready(4)]);
+        let mut s = pin!([a, b].merge().with_idle_markers());
+        let waker = CountingWaker::new();
+
+        let mut burst = Vec::new();
+        let marker = loop {
+            match step_stream(s.as_mut(), &waker) {
+                Poll::Ready(Some(Marked::Item(n))) => burst.push(n),
+                other => break other,
+            }
+        };
+        burst.sort_unstable();
+        assert_eq!(burst, [1, 2, 3]);
+        assert_eq!(marker, Poll::Ready(Some(Marked::Idle)));
+
+        // The marker is only yielded once, until more items arrive.
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+
+        assert!(a_handle.wake());
+        assert!(b_handle.wake());
+        let mut burst = Vec::new();
+        loop {
+            match step_stream(s.as_mut(), &waker) {
+                Poll::Ready(Some(Marked::Item(n))) => burst.push(n),
+                Poll::Ready(None) => break,
+                other => panic!("unexpected {other:?}"),
+            }
+        }
+        burst.sort_unstable();
+        assert_eq!(burst, [4, 5]);
+    }
+
+    #[test]
+    fn no_marker_before_first_item() {
+        let (a, a_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, _) = ScriptedStream::new(Vec::<Step<u8>>::new());
+        let mut s = pin!(vec![a, b].merge().with_idle_markers());
+        let waker = CountingWaker::new();
+
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert!(a_handle.wake());
+        assert_eq!(
+            step_stream(s.as_mut(), &waker),
+            Poll::Ready(Some(Marked::Item(1)))
+        );
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+    }
+
+    #[test]
+    fn ready_streams_have_no_markers() {
+        block_on(async {
+            let s = vec![
+                futures_lite::stream::iter(vec![1, 2]),
+                futures_lite::stream::iter(vec![3]),
+            ];
+            let items: Vec<_> = s.merge().with_idle_markers().collect().await;
+            assert!(items.iter().all(|item| !item.is_idle()));
+            assert_eq!(items.len(), 3);
+        });
+    }
+}
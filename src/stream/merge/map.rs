@@ -0,0 +1,97 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+use crate::private::IndexedStream;
+
+/// A stream which merges multiple streams, and maps each item together with
+/// the index of the stream which yielded it.
+///
+/// This `struct` is created by the [`merge_map`] method on the [`Merge`]
+/// trait. See its documentation for more.
+///
+/// [`merge_map`]: crate::stream::Merge::merge_map
+/// [`Merge`]: crate::stream::Merge
+#[derive(Debug, Clone)]
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct MergeMap<S, F> {
+    #[pin]
+    stream: S,
+    f: F,
+}
+
+impl<S, F> MergeMap<S, F> {
+    pub(crate) fn new(stream: S, f: F) -> Self {
+        Self { stream, f }
+    }
+}
+
+impl<S, F, U> Stream for MergeMap<S, F>
+where
+    S: IndexedStream,
+    F: FnMut(usize, S::Item) -> U,
+{
+    type Item = U;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream
+            .poll_next_indexed(cx)
+            .map(|item| item.map(|(index, item)| (this.f)(index, item)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::Merge;
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn tagged_vec() {
+        block_on(async {
+            let s = vec![stream::iter(vec![1u8, 2]), stream::iter(vec![3u8])]
+                .merge_map(|index, n| format!("{index}:{n}"));
+            let mut out: Vec<String> = s.collect().await;
+            out.sort_unstable();
+            assert_eq!(out, ["0:1", "0:2", "1:3"]);
+        })
+    }
+
+    #[test]
+    fn tagged_tuple() {
+        block_on(async {
+            let celsius = stream::iter([20, 25]);
+            let fahrenheit = stream::iter([70]);
+            let s = (celsius, fahrenheit).merge_map(|index, n| match index {
+                0 => format!("{n}°C"),
+                _ => format!("{n}°F"),
+            });
+            let mut out: Vec<String> = s.collect().await;
+            out.sort_unstable();
+            assert_eq!(out, ["20°C", "25°C", "70°F"]);
+        })
+    }
+
+    #[test]
+    fn tagged_array() {
+        block_on(async {
+            let s = [stream::once(1), stream::once(2)].merge_map(|index, n| index * 10 + n);
+            let mut out: Vec<usize> = s.collect().await;
+            out.sort_unstable();
+            assert_eq!(out, [1, 12]);
+        })
+    }
+}
@@ -0,0 +1,294 @@
+use super::vec::Merge;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+/// A stream which merges multiple streams, dropping each stream which stays
+/// silent for too long.
+///
+/// This `struct` is created by the [`member_timeout`] method on [`Merge`].
+/// See its documentation for more.
+///
+/// [`member_timeout`]: crate::vec::Merge::member_timeout
+/// [`Merge`]: crate::vec::Merge
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct MemberTimeout<S, F, D>
+where
+    S: Stream,
+    F: FnMut() -> D,
+    D: Future,
+{
+    #[pin]
+    merge: Merge<Deadline<S, F, D>>,
+}
+
+impl<S, F, D> MemberTimeout<S, F, D>
+where
+    S: Stream,
+    F: FnMut() -> D,
+    D: Future,
+{
+    pub(crate) fn new(merge: Merge<Deadline<S, F, D>>) -> Self {
+        Self { merge }
+    }
+
+    /// Returns the number of streams which have neither ended nor timed out.
+    pub fn pending_members(&self) -> usize {
+        self.merge.pending_members()
+    }
+}
+
+impl<S, F, D> fmt::Debug for MemberTimeout<S, F, D>
+where
+    S: Stream + fmt::Debug,
+    F: FnMut() -> D,
+    D: Future,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.merge.fmt(f)
+    }
+}
+
+impl<S, F, D> Stream for MemberTimeout<S, F, D>
+where
+    S: Stream,
+    F: FnMut() -> D,
+    D: Future,
+{
+    type Item = Result<S::Item, TimedOut>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().merge.poll_next(cx)
+    }
+}
+
+/// The error yielded by [`MemberTimeout`] when one of its streams timed out.
+///
+/// The stream which timed out has been dropped from the merge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimedOut {
+    index: usize,
+}
+
+impl TimedOut {
+    /// Returns the index of the stream which timed out.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stream {} timed out", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TimedOut {}
+
+/// A stream which ends once it hasn't yielded an item before its deadline.
+///
+/// The deadline is created when the stream first returns `Pending` after
+/// yielding an item, and is polled with the same waker as the stream. A
+/// deadline which expires wakes this member in the merge like an item would.
+#[pin_project]
+pub(crate) struct Deadline<S, F, D> {
+    index: usize,
+    #[pin]
+    stream: Option<S>,
+    #[pin]
+    deadline: Option<D>,
+    timer: F,
+}
+
+impl<S, F, D> Deadline<S, F, D> {
+    pub(crate) fn new(index: usize, stream: S, timer: F) -> Self {
+        Self {
+            index,
+            stream: Some(stream),
+            deadline: None,
+            timer,
+        }
+    }
+}
+
+impl<S: fmt::Debug, F, D> fmt::Debug for Deadline<S, F, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.stream.fmt(f)
+    }
+}
+
+impl<S, F, D> Stream for Deadline<S, F, D>
+where
+    S: Stream,
+    F: FnMut() -> D,
+    D: Future,
+{
+    type Item = Result<S::Item, TimedOut>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let Some(stream) = this.stream.as_mut().as_pin_mut() else {
+            return Poll::Ready(None);
+        };
+        match stream.poll_next(cx) {
+            // Every item resets the deadline.
+            Poll::Ready(Some(item)) => {
+                this.deadline.set(None);
+                return Poll::Ready(Some(Ok(item)));
+            }
+            Poll::Ready(None) => {
+                this.stream.set(None);
+                this.deadline.set(None);
+                return Poll::Ready(None);
+            }
+            Poll::Pending => {}
+        }
+
+        if this.deadline.is_none() {
+            this.deadline.set(Some((this.timer)()));
+        }
+        let deadline = this.deadline.as_mut().as_pin_mut().unwrap();
+        match deadline.poll(cx) {
+            // Drop the stream right away, so its resources are released
+            // while the other streams keep being merged.
+            Poll::Ready(_) => {
+                this.stream.set(None);
+                this.deadline.set(None);
+                Poll::Ready(Some(Err(TimedOut { index: *this.index })))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+    use core::pin::pin;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+    use std::task::Waker;
+    use std::vec::Vec;
+
+    /// A clock which only moves when it's advanced by hand.
+    #[derive(Clone, Default)]
+    struct MockClock {
+        now: Rc<Cell<u64>>,
+        wakers: Rc<RefCell<Vec<Waker>>>,
+    }
+
+    impl MockClock {
+        fn sleep(&self, ticks: u64) -> Sleep {
+            Sleep {
+                until: self.now.get() + ticks,
+                clock: self.clone(),
+            }
+        }
+
+        fn advance(&self, ticks: u64) {
+            self.now.set(self.now.get() + ticks);
+            for waker in self.wakers.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    struct Sleep {
+        until: u64,
+        clock: MockClock,
+    }
+
+    impl Future for Sleep {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.clock.now.get() >= self.until {
+                return Poll::Ready(());
+            }
+            self.clock.wakers.borrow_mut().push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn drops_silent_member() {
+        let clock = MockClock::default();
+        // Every advance of the clock wakes all members, so both streams stay
+        // pending for longer than the test runs.
+        let silent = || core::iter::repeat(Step::Pending).take(8);
+        let (a, a_handle) = ScriptedStream::new(
+            [Step::WakeLater, Step::Ready(1)]
+                .into_iter()
+                .chain(silent()),
+        );
+        let (b, _) = ScriptedStream::new(silent());
+        let timer = {
+            let clock = clock.clone();
+            move || clock.sleep(10)
+        };
+        let mut s = pin!(vec![a, b].merge().member_timeout(timer));
+        let waker = CountingWaker::new();
+
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        clock.advance(5);
+        assert!(a_handle.wake());
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(Ok(1))));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+
+        // `b` has been silent for 10 ticks, while `a` yielded after 5, which
+        // reset its deadline.
+        clock.advance(5);
+        let timed_out = TimedOut { index: 1 };
+        assert_eq!(
+            step_stream(s.as_mut(), &waker),
+            Poll::Ready(Some(Err(timed_out)))
+        );
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert_eq!(s.pending_members(), 1);
+
+        // Once every member has timed out, the stream ends.
+        clock.advance(10);
+        let timed_out = TimedOut { index: 0 };
+        assert_eq!(
+            step_stream(s.as_mut(), &waker),
+            Poll::Ready(Some(Err(timed_out)))
+        );
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+    }
+
+    #[test]
+    fn ended_member_does_not_time_out() {
+        let clock = MockClock::default();
+        let (a, _) = ScriptedStream::new([Step::Ready(1)]);
+        let timer = {
+            let clock = clock.clone();
+            move || clock.sleep(1)
+        };
+        let mut s = pin!(vec![a].merge().member_timeout(timer));
+        let waker = CountingWaker::new();
+
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(Ok(1))));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+        assert!(clock.wakers.borrow().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add a member timeout to a `Merge` which has been polled")]
+    fn after_polling() {
+        let clock = MockClock::default();
+        let (a, _) = ScriptedStream::new([Step::Ready(1), Step::Ready(2)]);
+        let mut s = vec![a].merge();
+        let waker = CountingWaker::new();
+        assert_eq!(step_stream(Pin::new(&mut s), &waker), Poll::Ready(Some(1)));
+        let _ = s.member_timeout(move || clock.sleep(1));
+    }
+}
@@ -1,6 +1,16 @@
 use futures_core::Stream;
 
+use crate::private::IndexedStream;
+
+pub use idle::{IdleMarkers, Marked};
+pub use map::MergeMap;
+
 pub(crate) mod array;
+mod idle;
+mod map;
+#[cfg(feature = "alloc")]
+pub(crate) mod member_timeout;
+mod state;
 pub(crate) mod tuple;
 #[cfg(feature = "alloc")]
 pub(crate) mod vec;
@@ -30,6 +40,25 @@ pub(crate) mod vec;
 ///     assert_eq!(&buf, &[1, 2, 3]);
 /// })
 /// ```
+///
+/// # Panics
+///
+/// If one of the streams panics, the panic is propagated to the caller. The
+/// merged stream remains usable if the panic is caught: the streams which
+/// didn't panic keep being polled, and the stream which panicked is only
+/// polled again once it has been woken.
+///
+/// # Implementing for other containers
+///
+/// This trait is not sealed, and can be implemented for containers defined
+/// outside of this crate. The returned stream must yield every item of every
+/// stream in the container, and only complete once all of them have
+/// completed. Rather than writing a poll loop by hand, implementations can
+/// collect their streams into a `Vec` and return [`vec::Merge`] through
+/// [`vec::Merge::new`].
+///
+/// [`vec::Merge`]: crate::vec::Merge
+/// [`vec::Merge::new`]: crate::vec::Merge::new
 pub trait Merge {
     /// The resulting output type.
     type Item;
@@ -39,4 +68,62 @@ pub trait Merge {
 
     /// Combine multiple streams into a single stream.
     fn merge(self) -> Self::Stream;
+
+    /// Combine multiple streams into a single stream, mapping each item
+    /// together with the index of the stream which yielded it.
+    ///
+    /// This allows a single closure to handle the items of each stream
+    /// differently, without wrapping every stream in its own adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let primary = stream::iter([1, 2]);
+    /// let replica = stream::iter([3, 4]);
+    /// let s = vec![primary, replica].merge_map(|index, n| match index {
+    ///     0 => format!("primary: {n}"),
+    ///     _ => format!("replica: {n}"),
+    /// });
+    ///
+    /// let mut buf: Vec<_> = s.collect().await;
+    /// buf.sort_unstable();
+    /// assert_eq!(buf, ["primary: 1", "primary: 2", "replica: 3", "replica: 4"]);
+    /// # });
+    /// ```
+    fn merge_map<F, U>(self, f: F) -> MergeMap<Self::Stream, F>
+    where
+        Self: Sized,
+        Self::Stream: IndexedStream,
+        F: FnMut(usize, Self::Item) -> U,
+    {
+        MergeMap::new(self.merge(), f)
+    }
+}
+
+/// The order in which a [`vec::Merge`] polls the streams which are ready.
+///
+/// This is set through [`vec::Merge::strategy`].
+///
+/// [`vec::Merge`]: crate::vec::Merge
+/// [`vec::Merge::strategy`]: crate::vec::Merge::strategy
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Strategy {
+    /// Poll the streams round-robin, starting one stream further along on
+    /// every poll. This is the default.
+    #[default]
+    RoundRobin,
+    /// Poll the streams in the order in which their wakers were called.
+    ///
+    /// A stream which is woken while it's already waiting to be polled keeps
+    /// its place in line, and a stream which yielded an item moves to the
+    /// back. Items which became available in a known order across streams
+    /// are yielded in that order.
+    ///
+    /// Without the `std` or `critical-section` feature wakes can't be told
+    /// apart, and this behaves like [`Strategy::RoundRobin`].
+    Fifo,
 }
@@ -0,0 +1,332 @@
+use super::Strategy;
+use crate::utils::{Indexer, PollState, Readiness, Streak, Trace, Wakers};
+
+use core::ops::DerefMut;
+use core::task::{Context, Poll};
+
+/// The state of a merge, shared by the array and vector implementations.
+///
+/// This only leaves the storage of the streams to each implementation: the
+/// order in which streams are polled, how wakes are tracked, and when streams
+/// and the merge itself complete are all decided here, so the two can't
+/// diverge.
+pub(crate) struct MergeState<W, P> {
+    pub(crate) wakers: W,
+    /// The poll state of each stream.
+    pub(crate) members: P,
+    pub(crate) indexer: Indexer,
+    pub(crate) streak: Streak,
+    pub(crate) strategy: Strategy,
+    pub(crate) complete: usize,
+    /// The number of streams which are paused.
+    pub(crate) paused: usize,
+    pub(crate) trace: Trace,
+}
+
+impl<W, P> MergeState<W, P>
+where
+    W: Wakers,
+    P: DerefMut<Target = [PollState]>,
+{
+    /// Create the state for a merge of `members.len()` streams.
+    pub(crate) fn new(wakers: W, members: P) -> Self {
+        Self {
+            indexer: Indexer::new(members.len()),
+            trace: Trace::new("merge", members.len()),
+            wakers,
+            members,
+            streak: Streak::default(),
+            strategy: Strategy::default(),
+            complete: 0,
+            paused: 0,
+        }
+    }
+
+    /// Returns the number of streams, including the ones which have
+    /// completed.
+    pub(crate) fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns the number of streams which have not completed yet.
+    pub(crate) fn pending_members(&self) -> usize {
+        self.len() - self.complete
+    }
+
+    /// Returns `true` if the stream at `index` has completed.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    pub(crate) fn is_member_done(&self, index: usize) -> bool {
+        assert!(
+            index < self.len(),
+            "index {index} is out of bounds for a `Merge` of {} streams",
+            self.len()
+        );
+        self.members[index].is_none()
+    }
+
+    /// Poll the streams which are ready, until one of them yields an item.
+    ///
+    /// `poll_stream` polls the stream at the given index.
+    pub(crate) fn poll_next<T>(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut poll_stream: impl FnMut(usize, &mut Context<'_>) -> Poll<Option<T>>,
+    ) -> Poll<Option<(usize, T)>> {
+        let Self {
+            wakers,
+            members,
+            indexer,
+            streak,
+            strategy,
+            complete,
+            paused,
+            trace,
+        } = self;
+        let len = members.len();
+
+        // An empty merge has nothing to yield.
+        if *complete == len {
+            return Poll::Ready(None);
+        }
+
+        // A single stream doesn't need to be told apart from any others, so
+        // it's polled with the caller's waker directly, unless it's paused.
+        if len == 1 && *paused == 0 {
+            let poll = poll_stream(0, cx);
+            trace.polled(0, poll.is_ready());
+            return match poll {
+                Poll::Ready(Some(item)) => Poll::Ready(Some((0, item))),
+                Poll::Ready(None) => {
+                    *complete += 1;
+                    trace.completed(0, *complete);
+                    members[0].set_none();
+                    Poll::Ready(None)
+                }
+                Poll::Pending => Poll::Pending,
+            };
+        }
+
+        let mut readiness = wakers.readiness();
+        readiness.set_waker(cx.waker());
+        readiness.set_trace(trace);
+
+        // Iterate over our streams one-by-one. If a stream yields a value,
+        // we exit early. By default we'll return `Poll::Ready(None)`, but
+        // this changes if we encounter a `Poll::Pending`.
+        // A stream which has reached its maximum number of consecutive items
+        // is polled after all other streams.
+        let throttled = streak.throttled();
+        let mut indexes = indexer
+            .iter()
+            .filter(move |&i| Some(i) != throttled)
+            .chain(throttled);
+        loop {
+            let index = match strategy {
+                // Streams are taken in the order in which they were woken.
+                #[cfg(any(feature = "std", feature = "critical-section"))]
+                Strategy::Fifo => match readiness.pop_ready() {
+                    Some(index) => index,
+                    None => return Poll::Pending,
+                },
+                _ => match indexes.next() {
+                    Some(index) if readiness.any_ready() => {
+                        if !readiness.clear_ready(index) {
+                            continue;
+                        }
+                        index
+                    }
+                    // Nothing is ready yet
+                    _ => return Poll::Pending,
+                },
+            };
+            if members[index].is_none() {
+                // Completion latches per stream: a stream which has ended may still
+                // be woken by a stale waker, but is never polled or counted again.
+                continue;
+            }
+
+            // unlock readiness so we don't deadlock when polling
+            #[allow(clippy::drop_non_drop)]
+            drop(readiness);
+
+            // Obtain the intermediate waker.
+            let mut cx = Context::from_waker(wakers.get(index).unwrap());
+
+            let poll = poll_stream(index, &mut cx);
+            trace.polled(index, poll.is_ready());
+            match poll {
+                Poll::Ready(Some(item)) => {
+                    // Mark ourselves as ready again because we need to poll for the next item.
+                    wakers.readiness().set_ready(index);
+                    streak.record(index);
+                    return Poll::Ready(Some((index, item)));
+                }
+                Poll::Ready(None) => {
+                    *complete += 1;
+                    trace.completed(index, *complete);
+                    members[index].set_none();
+                    if *complete == len {
+                        return Poll::Ready(None);
+                    }
+                }
+                Poll::Pending => {}
+            }
+
+            // Lock readiness so we can use it again
+            readiness = wakers.readiness();
+        }
+    }
+}
+
+/// The same tests, run against the array and the vector implementations.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+    use crate::{array, vec};
+
+    use core::pin::pin;
+    use core::task::Poll;
+    use futures_core::Stream;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    fn merge_array<S: Stream, const N: usize>(streams: [S; N]) -> array::Merge<S, N> {
+        streams.merge()
+    }
+
+    fn merge_vec<S: Stream, const N: usize>(streams: [S; N]) -> vec::Merge<S> {
+        Vec::from(streams).merge()
+    }
+
+    macro_rules! merge_tests {
+        ($storage:ident, $merge:ident) => {
+            mod $storage {
+                use super::*;
+
+                #[test]
+                fn empty() {
+                    let streams: [stream::Once<u8>; 0] = [];
+                    let mut s = pin!($merge(streams));
+                    let waker = CountingWaker::new();
+                    assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+                    assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+                }
+
+                #[test]
+                fn round_robin() {
+                    block_on(async {
+                        let s = $merge([stream::repeat(0), stream::repeat(1), stream::repeat(2)]);
+                        let items: Vec<_> = s.take(9).collect().await;
+                        assert_eq!(items, [0, 1, 2, 0, 1, 2, 0, 1, 2]);
+                    })
+                }
+
+                #[test]
+                fn wakes_parent_once() {
+                    let (a, a_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+                    let (b, b_handle) = ScriptedStream::new([Step::WakeLater]);
+                    let mut s = pin!($merge([a, b]));
+                    let waker = CountingWaker::new();
+
+                    assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+                    assert!(a_handle.wake());
+                    assert_eq!(waker.wakes(), 1);
+                    assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(1)));
+                    // `b` wasn't woken, so only `a` was polled again.
+                    assert_eq!(a_handle.polls(), 2);
+                    assert_eq!(b_handle.polls(), 1);
+                }
+
+                #[test]
+                fn completion_latches() {
+                    // `a` ends right away, and keeps waking the merge after
+                    // it has ended.
+                    let (a, a_handle) = ScriptedStream::new(Vec::<Step<u8>>::new());
+                    let (b, _) = ScriptedStream::new([
+                        Step::Pending,
+                        Step::Ready(1),
+                        Step::Pending,
+                        Step::Ready(2),
+                    ]);
+                    let s = $merge([a.wake_on_end(), b]);
+                    let items: Vec<_> = block_on(s.collect());
+                    assert_eq!(items, [1, 2]);
+                    assert_eq!(a_handle.polls(), 1);
+                }
+
+                #[test]
+                fn member_counts() {
+                    let (a, _) = ScriptedStream::new([Step::Ready(1)]);
+                    let (b, _) = ScriptedStream::new([Step::WakeLater]);
+                    let mut s = pin!($merge([a, b]));
+                    let waker = CountingWaker::new();
+                    assert_eq!(s.len(), 2);
+                    assert_eq!(s.pending_members(), 2);
+
+                    assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(1)));
+                    assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+                    assert_eq!(s.pending_members(), 1);
+                    assert!(s.is_member_done(0));
+                    assert!(!s.is_member_done(1));
+                }
+
+                #[test]
+                fn single_stream() {
+                    // The stream is handed the caller's waker directly.
+                    let (a, a_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+                    let mut s = pin!($merge([a]));
+                    let waker = CountingWaker::new();
+                    assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+                    assert!(a_handle.wake());
+                    assert_eq!(waker.wakes(), 1);
+                    assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(1)));
+                    assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+                    assert!(s.is_member_done(0));
+                }
+
+                #[test]
+                fn max_consecutive() {
+                    /// Returns how many items the noisy stream yields after
+                    /// the slow stream has been woken, before the slow
+                    /// stream's item is yielded.
+                    fn delay(max: Option<usize>) -> usize {
+                        let (idle_a, _) = ScriptedStream::new([Step::WakeLater]);
+                        let (noisy, _) = ScriptedStream::new([Step::Ready(0); 16]);
+                        let (slow, slow_handle) =
+                            ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+                        let (idle_b, _) = ScriptedStream::new([Step::WakeLater]);
+                        let mut s = $merge([idle_a, noisy, slow, idle_b]);
+                        if let Some(max) = max {
+                            s = s.max_consecutive(max);
+                        }
+                        let mut s = pin!(s);
+                        let waker = CountingWaker::new();
+
+                        for _ in 0..3 {
+                            assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(0)));
+                        }
+                        assert!(slow_handle.wake());
+                        let mut delay = 0;
+                        while step_stream(s.as_mut(), &waker) == Poll::Ready(Some(0)) {
+                            delay += 1;
+                        }
+                        delay
+                    }
+
+                    assert_eq!(delay(None), 3);
+                    assert_eq!(delay(Some(1)), 0);
+                    assert_eq!(delay(Some(2)), 0);
+                    assert_eq!(delay(Some(8)), 3);
+                }
+            }
+        };
+    }
+
+    merge_tests!(array_storage, merge_array);
+    merge_tests!(vec_storage, merge_vec);
+}
@@ -1,6 +1,8 @@
 use super::Merge as MergeTrait;
+use crate::private::IndexedStream;
+use crate::private_impl;
 use crate::stream::IntoStream;
-use crate::utils::{self, PollArray, WakerArray};
+use crate::utils::{self, PollArray, Trace, WakerArray, WakerPair};
 
 use core::fmt;
 use core::pin::Pin;
@@ -10,14 +12,20 @@ use futures_core::Stream;
 macro_rules! poll_stream {
     ($stream_idx:tt, $iteration:ident, $this:ident, $streams:ident . $stream_member:ident, $cx:ident, $len_streams:ident) => {
         if $stream_idx == $iteration {
-            match unsafe { Pin::new_unchecked(&mut $streams.$stream_member) }.poll_next(&mut $cx) {
+            let poll =
+                unsafe { Pin::new_unchecked(&mut $streams.$stream_member) }.poll_next(&mut $cx);
+            $this.trace.polled($stream_idx, poll.is_ready());
+            match poll {
                 Poll::Ready(Some(item)) => {
                     // Mark ourselves as ready again because we need to poll for the next item.
                     $this.wakers.readiness().set_ready($stream_idx);
-                    return Poll::Ready(Some(item));
+                    return Poll::Ready(Some(($stream_idx, item)));
                 }
                 Poll::Ready(None) => {
                     *$this.completed += 1;
+                    $this
+                        .trace
+                        .completed($stream_idx, *$this.completed as usize);
                     $this.state[$stream_idx].set_none();
                     if *$this.completed == $len_streams {
                         return Poll::Ready(None);
@@ -54,6 +62,30 @@ macro_rules! impl_merge_tuple {
             }
         }
 
+        #[cfg(feature = "unstable-async-iter")]
+        impl core::async_iter::AsyncIterator for $StructName {
+            type Item = core::convert::Infallible;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                Stream::poll_next(self, cx)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                Stream::size_hint(self)
+            }
+        }
+
+        impl IndexedStream for $StructName {
+            private_impl! {}
+
+            fn poll_next_indexed(
+                self: Pin<&mut Self>,
+                _cx: &mut Context<'_>,
+            ) -> Poll<Option<(usize, Self::Item)>> {
+                Poll::Ready(None)
+            }
+        }
+
         impl MergeTrait for () {
             type Item = core::convert::Infallible; // TODO: convert to `never` type in the stdlib
             type Stream = $StructName;
@@ -91,6 +123,22 @@ macro_rules! impl_merge_tuple {
             wakers: WakerArray<{$mod_name::LEN}>,
             state: PollArray<{$mod_name::LEN}>,
             completed: u8,
+            trace: utils::Trace,
+        }
+
+        impl<T, $($F),*> $StructName<T, $($F),*>
+        where $(
+            $F: Stream<Item = T>,
+        )* {
+            /// Name the span in which this merge is traced, so its events can
+            /// be told apart from those of other merges.
+            ///
+            /// This requires the `tracing` feature.
+            #[cfg(feature = "tracing")]
+            pub fn named(self, name: &str) -> Self {
+                self.trace.set_name(name);
+                self
+            }
         }
 
         impl<T, $($F),*> fmt::Debug for $StructName<T, $($F),*>
@@ -104,6 +152,24 @@ macro_rules! impl_merge_tuple {
             }
         }
 
+        /// Cloning creates a new, unpolled merge from clones of the streams.
+        /// Streams which have completed are completed in the clone as well.
+        impl<T, $($F),*> Clone for $StructName<T, $($F),*>
+        where $(
+            $F: Stream<Item = T> + Clone,
+        )* {
+            fn clone(&self) -> Self {
+                let mut merge = ($(self.streams.$F.clone(),)*).merge();
+                for index in 0..$mod_name::LEN {
+                    if self.state[index].is_none() {
+                        merge.state[index].set_none();
+                        merge.completed += 1;
+                    }
+                }
+                merge
+            }
+        }
+
         impl<T, $($F),*> Stream for $StructName<T, $($F),*>
         where $(
             $F: Stream<Item = T>,
@@ -111,10 +177,26 @@ macro_rules! impl_merge_tuple {
             type Item = T;
 
             fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                self.poll_next_indexed(cx)
+                    .map(|item| item.map(|(_, item)| item))
+            }
+        }
+
+        impl<T, $($F),*> IndexedStream for $StructName<T, $($F),*>
+        where $(
+            $F: Stream<Item = T>,
+        )* {
+            private_impl! {}
+
+            fn poll_next_indexed(
+                self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+            ) -> Poll<Option<(usize, Self::Item)>> {
                 let this = self.project();
 
                 let mut readiness = this.wakers.readiness();
                 readiness.set_waker(cx.waker());
+                readiness.set_trace(this.trace);
 
                 const LEN: u8 = $mod_name::LEN as u8;
 
@@ -128,6 +210,8 @@ macro_rules! impl_merge_tuple {
                         // Nothing is ready yet
                         return Poll::Pending;
                     } else if !readiness.clear_ready(index) || this.state[index].is_none() {
+                        // Completion latches per stream: a stream which has ended may still
+                        // be woken by a stale waker, but is never polled or counted again.
                         continue;
                     }
 
@@ -158,6 +242,22 @@ macro_rules! impl_merge_tuple {
             }
         }
 
+        #[cfg(feature = "unstable-async-iter")]
+        impl<T, $($F),*> core::async_iter::AsyncIterator for $StructName<T, $($F),*>
+        where
+            $($F: Stream<Item = T>,)*
+        {
+            type Item = T;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                Stream::poll_next(self, cx)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                Stream::size_hint(self)
+            }
+        }
+
         impl<T, $($F),*> MergeTrait for ($($F,)*)
         where $(
             $F: IntoStream<Item = T>,
@@ -173,6 +273,7 @@ macro_rules! impl_merge_tuple {
                     wakers: WakerArray::new(),
                     state: PollArray::new_pending(),
                     completed: 0,
+                    trace: utils::Trace::new("merge", $mod_name::LEN),
                 }
             }
         }
@@ -181,7 +282,6 @@ macro_rules! impl_merge_tuple {
 
 impl_merge_tuple! { merge0 Merge0  }
 impl_merge_tuple! { merge1 Merge1  A }
-impl_merge_tuple! { merge2 Merge2  A B }
 impl_merge_tuple! { merge3 Merge3  A B C }
 impl_merge_tuple! { merge4 Merge4  A B C D }
 impl_merge_tuple! { merge5 Merge5  A B C D E }
@@ -192,6 +292,209 @@ impl_merge_tuple! { merge9 Merge9  A B C D E F G H I }
 impl_merge_tuple! { merge10 Merge10 A B C D E F G H I J }
 impl_merge_tuple! { merge11 Merge11 A B C D E F G H I J K }
 impl_merge_tuple! { merge12 Merge12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge13 Merge13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge14 Merge14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge15 Merge15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge16 Merge16 A B C D E F G H I J K L M N O P }
+
+/// A stream that merges multiple streams into a single stream.
+///
+/// This `struct` is created by the [`merge`] method on the [`Merge`] trait. See its
+/// documentation for more.
+///
+/// [`merge`]: trait.Merge.html#method.merge
+/// [`Merge`]: trait.Merge.html
+//
+// Merging two streams is by far the most common case, which is why it's
+// specialized: instead of an `Indexer` and a `WakerArray` this alternates
+// between the two streams, and tracks their readiness in a `WakerPair`.
+#[pin_project::pin_project]
+pub struct Merge2<T, A, B>
+where
+    A: Stream<Item = T>,
+    B: Stream<Item = T>,
+{
+    #[pin]
+    a: A,
+    #[pin]
+    b: B,
+    wakers: WakerPair,
+    /// The index of the stream which is polled first on the next poll.
+    next: u8,
+    /// A bitmask of the streams which have completed.
+    done: u8,
+    trace: Trace,
+}
+
+impl<T, A, B> Merge2<T, A, B>
+where
+    A: Stream<Item = T>,
+    B: Stream<Item = T>,
+{
+    /// Name the span in which this merge is traced, so its events can be told
+    /// apart from those of other merges.
+    ///
+    /// This requires the `tracing` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let s = (stream::once(1), stream::once(2)).merge().named("events");
+    /// assert_eq!(s.collect::<Vec<_>>().await.len(), 2);
+    /// # });
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn named(self, name: &str) -> Self {
+        self.trace.set_name(name);
+        self
+    }
+}
+
+impl<T, A, B> fmt::Debug for Merge2<T, A, B>
+where
+    A: Stream<Item = T> + fmt::Debug,
+    B: Stream<Item = T> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Merge")
+            .field(&self.a)
+            .field(&self.b)
+            .finish()
+    }
+}
+
+/// Cloning creates a new, unpolled merge from clones of the streams. Streams
+/// which have completed are completed in the clone as well.
+impl<T, A, B> Clone for Merge2<T, A, B>
+where
+    A: Stream<Item = T> + Clone,
+    B: Stream<Item = T> + Clone,
+{
+    fn clone(&self) -> Self {
+        let mut merge = (self.a.clone(), self.b.clone()).merge();
+        merge.done = self.done;
+        merge
+    }
+}
+
+impl<T, A, B> Stream for Merge2<T, A, B>
+where
+    A: Stream<Item = T>,
+    B: Stream<Item = T>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_next_indexed(cx)
+            .map(|item| item.map(|(_, item)| item))
+    }
+}
+
+impl<T, A, B> IndexedStream for Merge2<T, A, B>
+where
+    A: Stream<Item = T>,
+    B: Stream<Item = T>,
+{
+    private_impl! {}
+
+    fn poll_next_indexed(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<(usize, Self::Item)>> {
+        let mut this = self.project();
+        this.wakers.set_waker(cx.waker());
+
+        // Alternate which stream is polled first, so that neither of them can
+        // starve the other.
+        let first = *this.next as usize;
+        *this.next ^= 1;
+
+        for index in [first, first ^ 1] {
+            if !this.wakers.any_ready() {
+                // Nothing is ready yet
+                return Poll::Pending;
+            } else if !this.wakers.clear_ready(index) || *this.done & (1 << index) != 0 {
+                // Completion latches per stream: a stream which has ended may still
+                // be woken by a stale waker, but is never polled or counted again.
+                continue;
+            }
+
+            // Obtain the intermediate waker.
+            let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+
+            let poll = match index {
+                0 => this.a.as_mut().poll_next(&mut cx),
+                _ => this.b.as_mut().poll_next(&mut cx),
+            };
+            this.trace.polled(index, poll.is_ready());
+            match poll {
+                Poll::Ready(Some(item)) => {
+                    // Mark ourselves as ready again because we need to poll for the next item.
+                    this.wakers.set_ready(index);
+                    return Poll::Ready(Some((index, item)));
+                }
+                Poll::Ready(None) => {
+                    *this.done |= 1 << index;
+                    this.trace.completed(index, this.done.count_ones() as usize);
+                    if *this.done == 0b11 {
+                        return Poll::Ready(None);
+                    }
+                }
+                Poll::Pending => {}
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(feature = "unstable-async-iter")]
+impl<T, A, B> core::async_iter::AsyncIterator for Merge2<T, A, B>
+where
+    A: Stream<Item = T>,
+    B: Stream<Item = T>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Stream::poll_next(self, cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Stream::size_hint(self)
+    }
+}
+
+impl<T, A, B> MergeTrait for (A, B)
+where
+    A: IntoStream<Item = T>,
+    B: IntoStream<Item = T>,
+{
+    type Item = T;
+    type Stream = Merge2<T, A::IntoStream, B::IntoStream>;
+
+    fn merge(self) -> Self::Stream {
+        let (a, b) = self;
+        let trace = Trace::new("merge", 2);
+        Merge2 {
+            a: a.into_stream(),
+            b: b.into_stream(),
+            wakers: WakerPair::new(trace.clone()),
+            next: 0,
+            done: 0,
+            trace,
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -332,4 +635,162 @@ mod tests {
             pool.run_until_stalled()
         }
     }
+
+    #[test]
+    fn clone_runs_independently() {
+        block_on(async {
+            let s = (stream::iter(0..2), stream::iter(2..4)).merge();
+            let clone = s.clone();
+            assert_eq!(s.fold(0, |a, b| a + b).await, 6);
+            assert_eq!(clone.fold(0, |a, b| a + b).await, 6);
+        })
+    }
+
+    /// Clones of both the two-member specialization and the general tuple
+    /// implementation never poll a stream which has ended.
+    #[cfg(feature = "std")]
+    #[test]
+    fn clone_after_member_completed() {
+        use crate::test_utils::{ScriptedStream, Step};
+        use futures_lite::future::poll_once;
+
+        block_on(async {
+            let (a, a_handle) = ScriptedStream::new(Vec::<Step<u8>>::new());
+            let (b, _) = ScriptedStream::new([Step::Pending, Step::Ready(1)]);
+            let mut s = (a, b).merge();
+            assert_eq!(poll_once(s.next()).await, None);
+            assert_eq!(s.clone().collect::<Vec<_>>().await, [1]);
+            assert_eq!(a_handle.polls(), 1);
+
+            let (a, a_handle) = ScriptedStream::new(Vec::<Step<u8>>::new());
+            let (b, _) = ScriptedStream::new([Step::Pending, Step::Ready(1)]);
+            let (c, _) = ScriptedStream::new([Step::Pending, Step::Ready(2)]);
+            let mut s = (a, b, c).merge();
+            assert_eq!(poll_once(s.next()).await, None);
+            assert_eq!(s.clone().collect::<Vec<_>>().await, [1, 2]);
+            assert_eq!(a_handle.polls(), 1);
+        })
+    }
+
+    /// Both the two-member specialization and the general tuple
+    /// implementation count each member as completed only once.
+    #[cfg(feature = "std")]
+    #[test]
+    fn merge_tuple_woken_after_end() {
+        use crate::test_utils::{ScriptedStream, Step};
+
+        block_on(async {
+            // `a` ends right away, and keeps waking the merge after it has ended.
+            let (a, a_handle) = ScriptedStream::new(Vec::<Step<u8>>::new());
+            let (b, _) = ScriptedStream::new([Step::Pending, Step::Ready(1)]);
+            let out: Vec<_> = (a.wake_on_end(), b).merge().collect().await;
+            assert_eq!(out, vec![1]);
+            assert_eq!(a_handle.polls(), 1);
+
+            let (b, b_handle) = ScriptedStream::new(Vec::<Step<u8>>::new());
+            let (c, _) = ScriptedStream::new([Step::Pending, Step::Pending, Step::Ready(2)]);
+            let s = (stream::once(1), b.wake_on_end(), c).merge();
+            let out: Vec<_> = s.collect().await;
+            assert_eq!(out, vec![1, 2]);
+            assert_eq!(b_handle.polls(), 1);
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn merge_tuple_parent_waker_migration() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        let (a, a_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, _) = ScriptedStream::new([Step::WakeLater]);
+        let mut s = pin!((a, b).merge());
+
+        // The merge keeps a single clone of the waker, no matter how often
+        // it's polled with the same waker.
+        let first = CountingWaker::new();
+        assert_eq!(step_stream(s.as_mut(), &first), Poll::Pending);
+        assert_eq!(step_stream(s.as_mut(), &first), Poll::Pending);
+        assert_eq!(first.clones(), 1);
+
+        // The task migrates, and is polled with a different waker. The clone
+        // of the previous waker is released right away.
+        let second = CountingWaker::new();
+        assert_eq!(step_stream(s.as_mut(), &second), Poll::Pending);
+        assert_eq!(first.clones(), 0);
+        assert_eq!(second.clones(), 1);
+
+        // `a` still holds the waker it registered before the migration, which
+        // wakes the current parent waker.
+        assert!(a_handle.wake());
+        assert_eq!(first.wakes(), 0);
+        assert_eq!(second.wakes(), 1);
+        assert_eq!(step_stream(s.as_mut(), &second), Poll::Ready(Some(1)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn merge_tuple_2_only_polls_woken() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        let (a, a_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, b_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(2)]);
+        let mut s = pin!((a, b).merge());
+        let waker = CountingWaker::new();
+
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert_eq!((a_handle.polls(), b_handle.polls()), (1, 1));
+
+        // Polling again without any wakes doesn't poll either stream.
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert_eq!((a_handle.polls(), b_handle.polls()), (1, 1));
+
+        assert!(b_handle.wake());
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(2)));
+        assert_eq!((a_handle.polls(), b_handle.polls()), (1, 2));
+    }
+
+    #[test]
+    fn merge_tuple_2_alternates() {
+        use alloc::string::String;
+
+        block_on(async {
+            let a = stream::repeat('a');
+            let b = stream::repeat('b');
+            let out: String = (a, b).merge().take(6).collect().await;
+            assert_eq!(out, "ababab");
+        })
+    }
+
+    #[cfg(feature = "large-tuples")]
+    #[test]
+    fn merge_tuple_16() {
+        block_on(async {
+            let s = stream::once;
+            let s = (
+                s(0),
+                s(1),
+                s(2),
+                s(3),
+                s(4),
+                s(5),
+                s(6),
+                s(7),
+                s(8),
+                s(9),
+                s(10),
+                s(11),
+                s(12),
+                s(13),
+                s(14),
+                s(15),
+            )
+                .merge();
+            let mut out: Vec<_> = s.collect().await;
+            out.sort_unstable();
+            assert_eq!(out, (0..16).collect::<Vec<_>>());
+        })
+    }
 }
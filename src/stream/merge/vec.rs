@@ -1,11 +1,18 @@
+use super::idle::IdleMarkers;
+use super::member_timeout::{Deadline, MemberTimeout};
+use super::state::MergeState;
 use super::Merge as MergeTrait;
+use super::Strategy;
+use crate::private::IndexedStream;
+use crate::private_impl;
 use crate::stream::IntoStream;
-use crate::utils::{self, Indexer, PollVec, WakerVec};
+use crate::utils::{PinnedVec, PollVec, WakerVec};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 
 use core::fmt;
+use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::Stream;
@@ -22,29 +29,434 @@ pub struct Merge<S>
 where
     S: Stream,
 {
-    #[pin]
-    streams: Vec<S>,
-    indexer: Indexer,
-    complete: usize,
-    wakers: WakerVec,
-    state: PollVec,
-    done: bool,
+    streams: PinnedVec<S>,
+    state: MergeState<WakerVec, PollVec>,
 }
 
 impl<S> Merge<S>
 where
     S: Stream,
 {
-    pub(crate) fn new(streams: Vec<S>) -> Self {
+    /// Create a new stream which merges the items of all `streams`.
+    ///
+    /// This is equivalent to calling [`merge`] on the vector, and is intended
+    /// for implementing [`Merge`] for other containers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::vec::Merge;
+    /// use futures_lite::{stream, StreamExt};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let s = Merge::new(vec![stream::once(1), stream::once(2)]);
+    /// assert_eq!(s.fold(0, |acc, n| acc + n).await, 3);
+    /// # });
+    /// ```
+    ///
+    /// [`merge`]: crate::stream::Merge::merge
+    /// [`Merge`]: crate::stream::Merge
+    pub fn new(streams: Vec<S>) -> Self {
         let len = streams.len();
+        // A single stream is polled with the caller's waker directly, so its
+        // wakers are never allocated.
+        let wakers = match len {
+            1 => WakerVec::lazy(len),
+            _ => WakerVec::new(len),
+        };
         Self {
-            wakers: WakerVec::new(len),
-            state: PollVec::new_pending(len),
-            indexer: Indexer::new(len),
-            streams,
-            complete: 0,
-            done: false,
+            streams: PinnedVec::new(streams),
+            state: MergeState::new(wakers, PollVec::new_pending(len)),
+        }
+    }
+
+    /// Limit how many items in a row are taken from the same stream.
+    ///
+    /// Once a stream has yielded `max` consecutive items, all other streams
+    /// which are ready are polled before it is polled again. By default a
+    /// stream may yield up to one item less than there are streams in a row,
+    /// while other streams are ready.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `max` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let noisy = stream::iter(vec!["noisy"; 3]);
+    /// let quiet = stream::iter(vec!["quiet"]);
+    /// let s = vec![noisy, quiet].merge().max_consecutive(1);
+    /// let items: Vec<_> = s.collect().await;
+    /// assert_eq!(items.iter().filter(|s| **s == "quiet").count(), 1);
+    /// # });
+    /// ```
+    pub fn max_consecutive(mut self, max: usize) -> Self {
+        self.state.streak.set_max(max);
+        self
+    }
+
+    /// Name the span in which this merge is traced, so its events can be told
+    /// apart from those of other merges.
+    ///
+    /// This requires the `tracing` feature.
+    #[cfg(feature = "tracing")]
+    pub fn named(self, name: &str) -> Self {
+        self.state.trace.set_name(name);
+        self
+    }
+
+    /// Set the order in which streams which are ready are polled.
+    ///
+    /// With [`Strategy::Fifo`], streams are polled in the order in which they
+    /// were woken, so items which became available in a known order across
+    /// streams are yielded in that order. `max_consecutive` has no effect
+    /// with this strategy, since a stream which yielded an item is always
+    /// polled after the streams which were already waiting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_concurrency::stream::Strategy;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let a = stream::iter(vec![1, 2]);
+    /// let b = stream::iter(vec![3]);
+    /// let s = vec![a, b].merge().strategy(Strategy::Fifo);
+    /// let items: Vec<_> = s.collect().await;
+    /// assert_eq!(items, [1, 3, 2]);
+    /// # });
+    /// ```
+    pub fn strategy(mut self, strategy: Strategy) -> Self {
+        if strategy == Strategy::Fifo {
+            self.state.wakers.readiness().track_order();
+        }
+        self.state.strategy = strategy;
+        self
+    }
+
+    /// Drop each stream which doesn't yield an item before its deadline.
+    ///
+    /// `timer` is called to create a new deadline whenever a stream starts
+    /// waiting for its next item, so every item resets the deadline of its
+    /// stream. When a deadline expires before its stream yields, the stream
+    /// is dropped from the merge and a [`TimedOut`] error with the index of
+    /// the stream is yielded in its place. The other streams keep being
+    /// merged, and the merged stream ends once every stream has either ended
+    /// or timed out.
+    ///
+    /// Any future can serve as a deadline, so this works with the timers of
+    /// any executor.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the merge has already been polled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(miri)]fn main() {}
+    /// # #[cfg(not(miri))]
+    /// # fn main() {
+    /// use async_io::Timer;
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    /// use std::time::Duration;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let heartbeat = stream::once(1);
+    /// let silent = stream::pending();
+    /// let s = vec![heartbeat.boxed(), silent.boxed()]
+    ///     .merge()
+    ///     .member_timeout(|| Timer::after(Duration::from_millis(10)));
+    /// let items: Vec<_> = s.collect().await;
+    /// assert_eq!(items.len(), 2);
+    /// assert_eq!(items[0], Ok(1));
+    /// assert_eq!(items[1].unwrap_err().index(), 1);
+    /// # });
+    /// # }
+    /// ```
+    ///
+    /// [`TimedOut`]: crate::stream::TimedOut
+    pub fn member_timeout<F, D>(self, timer: F) -> MemberTimeout<S, F, D>
+    where
+        F: FnMut() -> D + Clone,
+        D: Future,
+    {
+        let Ok(streams) = self.streams.try_into_inner() else {
+            panic!("cannot add a member timeout to a `Merge` which has been polled");
+        };
+        let streams = streams
+            .into_iter()
+            .enumerate()
+            .map(|(index, stream)| Deadline::new(index, stream, timer.clone()))
+            .collect();
+        let mut merge = Merge::new(streams).strategy(self.state.strategy);
+        merge.state.streak = self.state.streak;
+        MemberTimeout::new(merge)
+    }
+
+    /// Yield a [`Marked::Idle`] marker whenever none of the streams have an
+    /// item ready, after at least one item was yielded.
+    ///
+    /// Every item is wrapped in [`Marked::Item`]. A marker is yielded at most
+    /// once between two items, and never before the first item or after the
+    /// last, so consumers which batch items can flush on every marker.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_concurrency::stream::Marked;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let s = vec![stream::iter(vec![1, 2]), stream::iter(vec![3])].merge().with_idle_markers();
+    /// let items: Vec<_> = s.collect().await;
+    /// assert!(items.iter().all(|item| !item.is_idle()));
+    /// assert_eq!(items.len(), 3);
+    /// # });
+    /// ```
+    ///
+    /// [`Marked::Idle`]: crate::stream::Marked::Idle
+    /// [`Marked::Item`]: crate::stream::Marked::Item
+    pub fn with_idle_markers(self) -> IdleMarkers<Self> {
+        IdleMarkers::new(self)
+    }
+
+    /// Returns the number of streams in the merge, including the ones which
+    /// have completed.
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    /// Returns `true` if the merge contains no streams.
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+
+    /// Returns the number of streams which have not completed yet.
+    ///
+    /// Like all methods which take `&self`, this can be called on a pinned
+    /// merge as well, e.g. through `Pin::as_ref`.
+    pub fn pending_members(&self) -> usize {
+        self.state.pending_members()
+    }
+
+    /// Returns `true` if the stream at `index` has completed.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    pub fn is_member_done(&self, index: usize) -> bool {
+        self.state.is_member_done(index)
+    }
+
+    /// Stop polling the stream at `index` until it's resumed.
+    ///
+    /// The stream is kept in the merge, and the merge doesn't end while it's
+    /// paused. Wakes of the stream are recorded, but the merge isn't woken by
+    /// them until the stream is resumed. A stream which [replaces] a paused
+    /// stream stays paused. Pausing a stream which has completed, or which is
+    /// already paused, does nothing.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    ///
+    /// This requires the `std` or `critical-section` feature, which track
+    /// the wakes of each stream.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut s = vec![stream::repeat(1), stream::repeat(2)].merge();
+    /// s.pause(0);
+    /// let items: Vec<_> = (&mut s).take(3).collect().await;
+    /// assert_eq!(items, [2, 2, 2]);
+    ///
+    /// s.resume(0);
+    /// let items: Vec<_> = s.take(4).collect().await;
+    /// assert!(items.contains(&1));
+    /// # });
+    /// ```
+    ///
+    /// [replaces]: Merge::replace
+    #[cfg(any(feature = "std", feature = "critical-section"))]
+    pub fn pause(&mut self, index: usize) {
+        if self.is_member_done(index) {
+            return;
+        }
+        if self.state.wakers.readiness().pause(index) {
+            self.state.paused += 1;
+        }
+    }
+
+    /// Resume polling the stream at `index` after it was paused.
+    ///
+    /// If the stream was woken while it was paused, or was ready to be polled
+    /// when it was paused, the merge is woken so the stream is polled right
+    /// away. Resuming a stream which isn't paused does nothing.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    ///
+    /// This requires the `std` or `critical-section` feature.
+    #[cfg(any(feature = "std", feature = "critical-section"))]
+    pub fn resume(&mut self, index: usize) {
+        assert!(
+            index < self.len(),
+            "index {index} is out of bounds for a `Merge` of {} streams",
+            self.len()
+        );
+        if self.state.wakers.readiness().resume(index) {
+            self.state.paused -= 1;
+        }
+    }
+
+    /// Replace the stream at `index`, returning the previous stream.
+    ///
+    /// The new stream keeps the index of the stream it replaces, so the
+    /// indexes passed to [`merge_map`] stay stable. The new stream is polled
+    /// on the next poll of the merge, even if the previous stream has already
+    /// completed, or the merge itself has completed.
+    ///
+    /// Streams must be `Unpin` to be moved out of the merge. To use this with
+    /// streams which aren't `Unpin`, wrap them in `Box::pin` first.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut s = vec![stream::iter(vec![1, 2]), stream::iter(vec![3])].merge();
+    /// let mut items: Vec<_> = (&mut s).collect().await;
+    ///
+    /// // Reconnect the second stream after it has ended.
+    /// s.replace(1, stream::iter(vec![4, 5]));
+    /// items.extend(s.collect::<Vec<_>>().await);
+    /// items.sort_unstable();
+    /// assert_eq!(items, [1, 2, 3, 4, 5]);
+    /// # });
+    /// ```
+    ///
+    /// [`merge_map`]: crate::stream::Merge::merge_map
+    pub fn replace(&mut self, index: usize, stream: S) -> S
+    where
+        S: Unpin,
+    {
+        assert!(
+            index < self.len(),
+            "index {index} is out of bounds for a `Merge` of {} streams",
+            self.len()
+        );
+        let prev = self.streams.replace(index, stream);
+        if self.state.members[index].is_none() {
+            self.state.members[index].set_pending();
+            self.state.complete -= 1;
         }
+        self.state.wakers.readiness().set_ready(index);
+        prev
+    }
+
+    /// Combine several merges into a single merge of all of their streams.
+    ///
+    /// Merging merges with [`merge`] nests them, which means every wake of
+    /// an inner stream passes through the wakers of both the inner and the
+    /// outer merge. This instead moves all streams into a single merge, in
+    /// the order of `parts`. Streams which have completed in their previous
+    /// merge remain completed, and aren't polled again.
+    ///
+    /// Waker registrations of streams which were in flight are lost, so every
+    /// stream which hasn't completed will be polled once more by the new
+    /// merge. Options such as [`max_consecutive`] aren't carried over.
+    ///
+    /// Streams must be `Unpin` to be moved out of their merges. To use this
+    /// with streams which aren't `Unpin`, wrap them in `Box::pin` first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_concurrency::vec::Merge;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let a = vec![stream::iter(vec![1, 2]), stream::iter(vec![3])].merge();
+    /// let b = vec![stream::iter(vec![4])].merge();
+    /// let s = Merge::flatten_from(vec![a, b]);
+    /// assert_eq!(s.len(), 3);
+    /// assert_eq!(s.fold(0, |acc, n| acc + n).await, 10);
+    /// # });
+    /// ```
+    ///
+    /// [`merge`]: crate::stream::Merge::merge
+    /// [`max_consecutive`]: Merge::max_consecutive
+    pub fn flatten_from(parts: Vec<Merge<S>>) -> Self
+    where
+        S: Unpin,
+    {
+        let len = parts.iter().map(Merge::len).sum();
+        let mut streams = Vec::with_capacity(len);
+        let mut completed = Vec::new();
+        for part in parts {
+            let offset = streams.len();
+            completed.extend(
+                part.state
+                    .members
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, state)| state.is_none().then_some(offset + index)),
+            );
+            // SAFETY: the streams are `Unpin`, so they may be moved even
+            // though they may have been handed out as `Pin<&mut S>` before.
+            streams.extend(unsafe { part.streams.into_inner() });
+        }
+
+        let mut merge = Merge::new(streams);
+        for index in completed {
+            merge.state.members[index].set_none();
+            merge.state.complete += 1;
+        }
+        merge
+    }
+}
+
+/// Move the streams out of a `SelectAll` into a new merge.
+///
+/// Waker registrations of streams which were in flight are lost in the
+/// conversion, so every stream will be polled once more by the merge.
+#[cfg(feature = "futures")]
+impl<S> From<futures_util::stream::SelectAll<S>> for Merge<S>
+where
+    S: Stream + Unpin,
+{
+    fn from(streams: futures_util::stream::SelectAll<S>) -> Self {
+        Merge::new(streams.into_iter().collect())
     }
 }
 
@@ -53,7 +465,35 @@ where
     S: Stream + fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.streams.iter()).finish()
+        f.debug_struct("Merge")
+            .field("len", &self.len())
+            .field("pending_members", &self.pending_members())
+            .field("streams", &self.streams)
+            .finish()
+    }
+}
+
+/// Cloning creates a new, unpolled merge from clones of the streams, with the
+/// same strategy and streak limit. Streams which have completed are completed
+/// in the clone as well, so they're never polled again.
+impl<S> Clone for Merge<S>
+where
+    S: Stream + Clone,
+{
+    fn clone(&self) -> Self {
+        let merge =
+            Merge::new(self.streams.iter().cloned().collect()).strategy(self.state.strategy);
+        let mut merge = match self.state.streak.max() {
+            Some(max) => merge.max_consecutive(max),
+            None => merge,
+        };
+        for index in 0..self.len() {
+            if self.state.members[index].is_none() {
+                merge.state.members[index].set_none();
+                merge.state.complete += 1;
+            }
+        }
+        merge
     }
 }
 
@@ -64,51 +504,42 @@ where
     type Item = S::Item;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let mut this = self.project();
-
-        let mut readiness = this.wakers.readiness();
-        readiness.set_waker(cx.waker());
-
-        // Iterate over our streams one-by-one. If a stream yields a value,
-        // we exit early. By default we'll return `Poll::Ready(None)`, but
-        // this changes if we encounter a `Poll::Pending`.
-        for index in this.indexer.iter() {
-            if !readiness.any_ready() {
-                // Nothing is ready yet
-                return Poll::Pending;
-            } else if !readiness.clear_ready(index) || this.state[index].is_none() {
-                continue;
-            }
+        self.poll_next_indexed(cx)
+            .map(|item| item.map(|(_, item)| item))
+    }
+}
 
-            // unlock readiness so we don't deadlock when polling
-            #[allow(clippy::drop_non_drop)]
-            drop(readiness);
+impl<S> IndexedStream for Merge<S>
+where
+    S: Stream,
+{
+    private_impl! {}
 
-            // Obtain the intermediate waker.
-            let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+    fn poll_next_indexed(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<(usize, Self::Item)>> {
+        let this = self.project();
+        this.state.poll_next(cx, |index, cx| {
+            let stream = this.streams.get_pin_mut(index).unwrap();
+            stream.poll_next(cx)
+        })
+    }
+}
 
-            let stream = utils::get_pin_mut_from_vec(this.streams.as_mut(), index).unwrap();
-            match stream.poll_next(&mut cx) {
-                Poll::Ready(Some(item)) => {
-                    // Mark ourselves as ready again because we need to poll for the next item.
-                    this.wakers.readiness().set_ready(index);
-                    return Poll::Ready(Some(item));
-                }
-                Poll::Ready(None) => {
-                    *this.complete += 1;
-                    this.state[index].set_none();
-                    if *this.complete == this.streams.len() {
-                        return Poll::Ready(None);
-                    }
-                }
-                Poll::Pending => {}
-            }
+#[cfg(feature = "unstable-async-iter")]
+impl<S> core::async_iter::AsyncIterator for Merge<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
 
-            // Lock readiness so we can use it again
-            readiness = this.wakers.readiness();
-        }
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Stream::poll_next(self, cx)
+    }
 
-        Poll::Pending
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Stream::size_hint(self)
     }
 }
 
@@ -120,7 +551,71 @@ where
     type Stream = Merge<S::IntoStream>;
 
     fn merge(self) -> Self::Stream {
-        Merge::new(self.into_iter().map(|i| i.into_stream()).collect())
+        self.into_iter().collect()
+    }
+}
+
+/// Merge the streams of an iterator.
+///
+/// This behaves the same as collecting the streams into a `Vec` and merging
+/// it, but the streams are moved into the merge directly and the state of the
+/// merge is sized once, from the number of streams.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::vec::Merge;
+/// use futures_lite::{stream, StreamExt};
+///
+/// # futures_lite::future::block_on(async {
+/// let s: Merge<_> = (0..4).map(stream::once).collect();
+/// assert_eq!(s.fold(0, |acc, n| acc + n).await, 6);
+/// # });
+/// ```
+impl<S> FromIterator<S> for Merge<S::IntoStream>
+where
+    S: IntoStream,
+{
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        Merge::new(iter.into_iter().map(|s| s.into_stream()).collect())
+    }
+}
+
+/// Merge streams by reference.
+///
+/// The streams are only borrowed for as long as the merged stream is alive.
+/// Dropping the merged stream releases them unchanged: items which haven't
+/// been yielded yet remain in their streams, and streams which completed
+/// during the merge are left completed.
+///
+/// Because the streams aren't pinned in place, they must be `Unpin`. Streams
+/// which aren't can be pinned first, and merged as a `Vec<Pin<&mut S>>`.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::stream::{self, StreamExt};
+///
+/// # futures_lite::future::block_on(async {
+/// let mut streams = vec![stream::iter(0..3), stream::iter(3..6)];
+/// let first: Vec<_> = streams.as_mut_slice().merge().take(4).collect().await;
+/// assert_eq!(first.len(), 4);
+///
+/// // The remaining items are still in the streams.
+/// let rest: Vec<_> = streams.merge().collect().await;
+/// assert_eq!(rest.len(), 2);
+/// # });
+/// ```
+impl<'a, S> MergeTrait for &'a mut [S]
+where
+    S: Stream + Unpin,
+{
+    type Item = S::Item;
+    type Stream = Merge<&'a mut S>;
+
+    fn merge(self) -> Self::Stream {
+        Merge::new(self.iter_mut().collect())
     }
 }
 
@@ -157,6 +652,71 @@ mod tests {
         })
     }
 
+    #[test]
+    fn merge_from_iter() {
+        block_on(async {
+            let streams = || (0..4).map(|n| stream::iter(vec![n, n + 10]));
+            let expected: Vec<_> = streams().collect::<Vec<_>>().merge().collect().await;
+            let s: Merge<_> = streams().collect();
+            let items: Vec<_> = s.collect().await;
+            assert_eq!(items, expected);
+        })
+    }
+
+    #[test]
+    fn merge_vec_empty() {
+        block_on(async {
+            let mut s = Vec::<stream::Once<u8>>::new().merge();
+            assert_eq!(s.next().await, None);
+            assert_eq!(s.next().await, None);
+        })
+    }
+
+    #[test]
+    fn merge_by_reference() {
+        block_on(async {
+            let mut streams = vec![
+                stream::iter(0..4),
+                stream::iter(4..10),
+                stream::iter(10..11),
+            ];
+            let mut merged = streams.as_mut_slice().merge();
+            let mut seen = vec![];
+            for _ in 0..5 {
+                seen.push(merged.next().await.unwrap());
+            }
+            drop(merged);
+
+            // The streams yield the items which weren't consumed by the merge.
+            for stream in &mut streams {
+                while let Some(n) = stream.next().await {
+                    seen.push(n);
+                }
+            }
+            seen.sort_unstable();
+            assert_eq!(seen, (0..11).collect::<Vec<_>>());
+        })
+    }
+
+    #[test]
+    fn merge_by_reference_completed() {
+        block_on(async {
+            let mut streams = vec![
+                stream::iter(0..1).boxed_local(),
+                stream::pending().boxed_local(),
+            ];
+            let mut merged = streams.as_mut_slice().merge();
+            assert_eq!(merged.next().await, Some(0));
+            assert!(futures_lite::future::poll_once(merged.next())
+                .await
+                .is_none());
+            drop(merged);
+
+            // The first stream completed during the merge.
+            assert_eq!(streams[0].next().await, None);
+        })
+    }
+
     #[test]
     fn merge_vec_2x2() {
         block_on(async {
@@ -172,6 +732,20 @@ mod tests {
         })
     }
 
+    #[cfg(feature = "futures")]
+    #[test]
+    fn from_select_all() {
+        block_on(async {
+            let a = stream::iter(0..3);
+            let b = stream::iter(3..6);
+            let mut select = futures::stream::select_all([a, b]);
+            assert!(select.next().await.is_some());
+
+            let s = Merge::from(select);
+            assert_eq!(s.count().await, 5);
+        })
+    }
+
     /// This test case uses channels so we'll have streams that return Pending from time to time.
     ///
     /// The purpose of this test is to make sure we have the waking logic working.
@@ -219,4 +793,448 @@ mod tests {
             pool.run_until_stalled()
         }
     }
+
+    #[test]
+    fn clone_runs_independently() {
+        block_on(async {
+            let s = vec![stream::iter(0..2), stream::iter(2..4)].merge();
+            let clone = s.clone();
+            assert_eq!(s.fold(0, |a, b| a + b).await, 6);
+            assert_eq!(clone.fold(0, |a, b| a + b).await, 6);
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn clone_after_member_completed() {
+        use crate::test_utils::{ScriptedStream, Step};
+
+        block_on(async {
+            let (a, a_handle) = ScriptedStream::new(Vec::<Step<u8>>::new());
+            let (b, _) = ScriptedStream::new([Step::Pending, Step::Ready(1)]);
+            let mut s = vec![a, b].merge();
+            assert_eq!(futures_lite::future::poll_once(s.next()).await, None);
+            assert!(s.is_member_done(0));
+
+            // The clone picks up `b` where it was left, and never polls `a`
+            // again now that it has ended.
+            let clone = s.clone();
+            assert_eq!(clone.collect::<Vec<_>>().await, [1]);
+            assert_eq!(s.collect::<Vec<_>>().await, [1]);
+            assert_eq!(a_handle.polls(), 1);
+        })
+    }
+
+    #[cfg(feature = "std")]
+    /// The starting point rotates deterministically, so streams which are
+    /// always ready take turns.
+    #[test]
+    fn rotation_distribution() {
+        block_on(async {
+            let a = stream::repeat(0);
+            let b = stream::repeat(1);
+            let c = stream::repeat(2);
+            let mut counts = [0usize; 3];
+            let mut s = vec![a, b, c].merge().take(100);
+            while let Some(index) = s.next().await {
+                counts[index] += 1;
+            }
+            assert!(
+                counts.iter().all(|&n| n.abs_diff(100 / 3) <= 1),
+                "{counts:?}"
+            );
+        })
+    }
+
+    #[test]
+    fn member_counts() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        let (a, _) = ScriptedStream::new([Step::Ready(1)]);
+        let (b, _) = ScriptedStream::new([Step::WakeLater]);
+        let mut s = pin!(vec![a, b].merge());
+        let waker = CountingWaker::new();
+        assert_eq!(s.len(), 2);
+        assert_eq!(s.pending_members(), 2);
+
+        // `a` yields its item, then completes on the next poll.
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(1)));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert_eq!(s.pending_members(), 1);
+        assert!(s.is_member_done(0));
+        assert!(!s.is_member_done(1));
+
+        let debug = format!("{:?}", s.as_ref().get_ref());
+        assert!(
+            debug.starts_with("Merge { len: 2, pending_members: 1, streams: ["),
+            "{debug}"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn replace_members() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        let (a, _) = ScriptedStream::new([Step::Ready(1)]);
+        let (b, b_handle) = ScriptedStream::new([Step::WakeLater]);
+        let mut s = pin!(vec![a, b].merge());
+        let waker = CountingWaker::new();
+
+        let mut total = 0;
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(1)));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        total += 1;
+        assert!(s.is_member_done(0));
+
+        // Replace a live member which is waiting to be woken: the new stream
+        // is polled right away.
+        let (c, _) = ScriptedStream::new([Step::Ready(2), Step::Ready(3)]);
+        drop(s.replace(1, c));
+        // A stale wake from the replaced stream is harmless.
+        assert!(b_handle.wake());
+        // Replace a member which has already completed.
+        let (d, _) = ScriptedStream::new([Step::Ready(4)]);
+        drop(s.replace(0, d));
+        assert_eq!(s.pending_members(), 2);
+
+        while let Poll::Ready(Some(n)) = step_stream(s.as_mut(), &waker) {
+            total += n;
+        }
+        assert_eq!(total, 1 + 2 + 3 + 4);
+        assert_eq!(s.pending_members(), 0);
+
+        // A merge which has completed is revived by replacing a member.
+        let (e, _) = ScriptedStream::new([Step::Ready(5)]);
+        drop(s.replace(1, e));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(5)));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pause_members() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        let (chatty, chatty_handle) =
+            ScriptedStream::new([Step::Ready(1), Step::WakeLater, Step::Ready(2)]);
+        let (quiet, quiet_handle) =
+            ScriptedStream::new([Step::WakeLater, Step::Ready(10), Step::WakeLater]);
+        let mut s = pin!(vec![chatty, quiet].merge());
+        let waker = CountingWaker::new();
+
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(1)));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        s.pause(0);
+        s.pause(0);
+
+        // The wake of the paused stream is recorded, without waking the
+        // merge.
+        assert!(chatty_handle.wake());
+        assert_eq!(waker.wakes(), 0);
+        assert!(quiet_handle.wake());
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(10)));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert_eq!(chatty_handle.polls(), 2);
+
+        // Resuming wakes the merge, and the backlog is polled.
+        s.resume(0);
+        assert_eq!(waker.wakes(), 2);
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(2)));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+
+        // Pausing a stream which has completed does nothing.
+        assert!(s.is_member_done(0));
+        s.pause(0);
+        assert!(quiet_handle.wake());
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pause_single_stream() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        let (a, a_handle) = ScriptedStream::new([Step::Ready(1), Step::Ready(2)]);
+        let mut s = pin!(vec![a].merge());
+        let waker = CountingWaker::new();
+
+        s.pause(0);
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert_eq!(a_handle.polls(), 0);
+        s.resume(0);
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(1)));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(2)));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn max_consecutive() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        /// Returns how many items the noisy stream yields after the slow
+        /// stream has been woken, before the slow stream's item is yielded.
+        fn delay(max: Option<usize>) -> usize {
+            let (idle_a, _) = ScriptedStream::new([Step::WakeLater]);
+            let (noisy, _) = ScriptedStream::new([Step::Ready(0); 16]);
+            let (slow, slow_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+            let (idle_b, _) = ScriptedStream::new([Step::WakeLater]);
+            let mut s = vec![idle_a, noisy, slow, idle_b].merge();
+            if let Some(max) = max {
+                s = s.max_consecutive(max);
+            }
+            let mut s = pin!(s);
+            let waker = CountingWaker::new();
+
+            for _ in 0..3 {
+                assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(0)));
+            }
+            assert!(slow_handle.wake());
+            let mut delay = 0;
+            while step_stream(s.as_mut(), &waker) == Poll::Ready(Some(0)) {
+                delay += 1;
+            }
+            delay
+        }
+
+        // By default the rotation lets the noisy stream go first three times.
+        assert_eq!(delay(None), 3);
+        assert_eq!(delay(Some(1)), 0);
+        assert_eq!(delay(Some(2)), 0);
+        assert_eq!(delay(Some(8)), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fifo_strategy() {
+        use crate::stream::Strategy;
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        let script = |item| {
+            ScriptedStream::new([
+                Step::WakeLater,
+                Step::Ready(item),
+                Step::WakeLater,
+                Step::Ready(item),
+            ])
+        };
+        let (a, a_handle) = script("a");
+        let (b, b_handle) = script("b");
+        let (c, c_handle) = script("c");
+        let mut s = pin!(vec![a, b, c].merge().strategy(Strategy::Fifo));
+        let waker = CountingWaker::new();
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+
+        // The items are yielded in the order the streams were woken in.
+        assert!(c_handle.wake());
+        assert!(a_handle.wake());
+        assert!(b_handle.wake());
+        for item in ["c", "a", "b"] {
+            assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(item)));
+        }
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+
+        assert!(b_handle.wake());
+        assert!(c_handle.wake());
+        assert!(a_handle.wake());
+        for item in ["b", "c", "a"] {
+            assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(item)));
+        }
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn single_stream() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        // The stream is handed the caller's waker, so waking it wakes the
+        // caller directly.
+        let (a, a_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+        let mut s = pin!(vec![a].merge());
+        let waker = CountingWaker::new();
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert!(a_handle.wake());
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(1)));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+        assert_eq!(s.pending_members(), 0);
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+        assert_eq!(a_handle.polls(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn flatten_from() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+
+        let waker = CountingWaker::new();
+        let (a, a_handle) = ScriptedStream::new([Step::Ready(1)]);
+        let (b, _) = ScriptedStream::new([Step::WakeLater, Step::Ready(2)]);
+        let (c, c_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(3)]);
+
+        // `a` completes, and `b` is in flight, before the merges are flattened.
+        let mut inner = vec![a, b].merge();
+        assert_eq!(
+            step_stream(Pin::new(&mut inner), &waker),
+            Poll::Ready(Some(1))
+        );
+        assert_eq!(step_stream(Pin::new(&mut inner), &waker), Poll::Pending);
+        assert_eq!(a_handle.polls(), 2);
+
+        let mut s = Merge::flatten_from(vec![inner, vec![c].merge()]);
+        assert_eq!(s.len(), 3);
+        assert_eq!(s.pending_members(), 2);
+        assert!(s.is_member_done(0));
+
+        let waker = CountingWaker::new();
+        let next = |s: &mut Merge<_>| {
+            let mut cx = Context::from_waker(waker.waker());
+            Pin::new(s).poll_next_indexed(&mut cx)
+        };
+        // The stream which was in flight is polled once more.
+        assert_eq!(next(&mut s), Poll::Ready(Some((1, 2))));
+        assert_eq!(next(&mut s), Poll::Pending);
+
+        // The flattened merge registers a single layer of wakers: waking a
+        // stream wakes the caller directly, which is only cloned once.
+        assert!(c_handle.wake());
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(waker.clones(), 1);
+        assert_eq!(next(&mut s), Poll::Ready(Some((2, 3))));
+        assert_eq!(next(&mut s), Poll::Ready(None));
+        assert_eq!(a_handle.polls(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn merge_vec_parent_waker_migration() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        let (a, a_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, _) = ScriptedStream::new([Step::WakeLater]);
+        let mut s = pin!(vec![a, b].merge());
+
+        // The merge keeps a single clone of the waker, no matter how often
+        // it's polled with the same waker.
+        let first = CountingWaker::new();
+        assert_eq!(step_stream(s.as_mut(), &first), Poll::Pending);
+        assert_eq!(step_stream(s.as_mut(), &first), Poll::Pending);
+        assert_eq!(first.clones(), 1);
+
+        // The task migrates, and is polled with a different waker. The clone
+        // of the previous waker is released right away.
+        let second = CountingWaker::new();
+        assert_eq!(step_stream(s.as_mut(), &second), Poll::Pending);
+        assert_eq!(first.clones(), 0);
+        assert_eq!(second.clones(), 1);
+
+        // `a` still holds the waker it registered before the migration, which
+        // wakes the current parent waker.
+        assert!(a_handle.wake());
+        assert_eq!(first.wakes(), 0);
+        assert_eq!(second.wakes(), 1);
+        assert_eq!(step_stream(s.as_mut(), &second), Poll::Ready(Some(1)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn merge_vec_continues_after_panic() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use alloc::sync::Arc;
+        use alloc::task::Wake;
+        use core::pin::pin;
+        use core::task::Waker;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        struct PanickingWaker;
+        impl Wake for PanickingWaker {
+            fn wake(self: Arc<Self>) {
+                panic!("parent waker panicked");
+            }
+        }
+
+        // `a` wakes itself while it's being polled, which wakes the parent
+        // waker while the readiness is locked.
+        let (a, _) = ScriptedStream::new([Step::Pending, Step::Ready(1)]);
+        let (b, _) = ScriptedStream::new([Step::Ready(2)]);
+        let mut s = pin!(vec![a, b].merge());
+
+        let waker = Waker::from(Arc::new(PanickingWaker));
+        let mut cx = Context::from_waker(&waker);
+        let res = catch_unwind(AssertUnwindSafe(|| s.as_mut().poll_next(&mut cx)));
+        assert!(res.is_err());
+
+        // The merge keeps going with all of its streams.
+        let waker = CountingWaker::new();
+        let mut items = vec![];
+        loop {
+            match step_stream(s.as_mut(), &waker) {
+                Poll::Ready(Some(item)) => items.push(item),
+                Poll::Ready(None) => break,
+                Poll::Pending => panic!("all streams are ready"),
+            }
+        }
+        items.sort_unstable();
+        assert_eq!(items, [1, 2]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn merge_vec_continues_after_member_panic() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use alloc::boxed::Box;
+        use core::pin::pin;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        /// Panics on its first poll, after waking itself. Yields `1` after.
+        struct Panicking(u8);
+        impl Stream for Panicking {
+            type Item = u8;
+            fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u8>> {
+                self.0 += 1;
+                match self.0 {
+                    1 => {
+                        cx.waker().wake_by_ref();
+                        panic!("member stream panicked");
+                    }
+                    2 => Poll::Ready(Some(1)),
+                    _ => Poll::Ready(None),
+                }
+            }
+        }
+
+        let (b, _) = ScriptedStream::new([Step::Ready(2)]);
+        let streams: Vec<Pin<Box<dyn Stream<Item = u8>>>> =
+            vec![Box::pin(Panicking(0)), Box::pin(b)];
+        let mut s = pin!(streams.merge());
+
+        let waker = CountingWaker::new();
+        let res = catch_unwind(AssertUnwindSafe(|| step_stream(s.as_mut(), &waker)));
+        assert!(res.is_err());
+
+        // Polling again yields the items of both streams, including the one
+        // which panicked, since it woke itself before panicking.
+        let mut items = vec![];
+        loop {
+            match step_stream(s.as_mut(), &waker) {
+                Poll::Ready(Some(item)) => items.push(item),
+                Poll::Ready(None) => break,
+                Poll::Pending => panic!("all streams are ready"),
+            }
+        }
+        items.sort_unstable();
+        assert_eq!(items, [1, 2]);
+    }
 }
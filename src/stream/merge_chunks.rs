@@ -0,0 +1,178 @@
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+/// A stream which batches the items of the underlying stream.
+///
+/// This `struct` is created by the [`merge_chunks`] method on [`StreamExt`]. See its
+/// documentation for more.
+///
+/// [`merge_chunks`]: crate::stream::StreamExt::merge_chunks
+/// [`StreamExt`]: crate::stream::StreamExt
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct MergeChunks<S: Stream> {
+    #[pin]
+    stream: S,
+    items: Vec<S::Item>,
+    cap: usize,
+    done: bool,
+}
+
+impl<S: Stream> MergeChunks<S> {
+    pub(crate) fn new(stream: S, cap: usize) -> Self {
+        assert!(cap > 0, "the size of a chunk must be non-zero");
+        Self {
+            stream,
+            items: Vec::new(),
+            cap,
+            done: false,
+        }
+    }
+
+    /// Consume the `MergeChunks`, returning the inner stream.
+    ///
+    /// Any items which have been buffered but not yet yielded are dropped.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S: Stream> Stream for MergeChunks<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if this.items.capacity() == 0 {
+                        this.items.reserve_exact(*this.cap);
+                    }
+                    this.items.push(item);
+                    if this.items.len() == *this.cap {
+                        return Poll::Ready(Some(core::mem::take(this.items)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    // Emit the final partial batch before ending the stream.
+                    *this.done = true;
+                    return match this.items.is_empty() {
+                        true => Poll::Ready(None),
+                        false => Poll::Ready(Some(core::mem::take(this.items))),
+                    };
+                }
+                // The underlying stream has no more items right now: flush
+                // what we have rather than waiting for the batch to fill up.
+                Poll::Pending => {
+                    return match this.items.is_empty() {
+                        true => Poll::Pending,
+                        false => Poll::Ready(Some(core::mem::take(this.items))),
+                    };
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let buffered = self.items.len();
+        let (low, high) = self.stream.size_hint();
+        let low = match low.saturating_add(buffered) {
+            0 => 0,
+            // Every batch holds at most `cap` items.
+            n => n.div_ceil(self.cap),
+        };
+        // Every batch holds at least one item.
+        let high = high.and_then(|high| high.checked_add(buffered));
+        (low, high)
+    }
+}
+
+impl<S: Stream> FusedStream for MergeChunks<S> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream::StreamExt as _;
+    use futures_lite::future::block_on;
+    use futures_lite::stream;
+    use futures_lite::StreamExt as _;
+
+    #[test]
+    fn full_and_final_batches() {
+        block_on(async {
+            let out: Vec<_> = stream::iter(0..5).merge_chunks(2).collect().await;
+            assert_eq!(out, vec![vec![0, 1], vec![2, 3], vec![4]]);
+        })
+    }
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let out: Vec<_> = stream::empty::<u8>().merge_chunks(2).collect().await;
+            assert!(out.is_empty());
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn flush_on_pending() {
+        use crate::stream::Merge;
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+
+        let (a, a_handle) = ScriptedStream::new([Step::Ready(1), Step::Ready(2), Step::WakeLater]);
+        let (b, b_handle) = ScriptedStream::new([
+            Step::Ready(10),
+            Step::WakeLater,
+            Step::Ready(11),
+            Step::Ready(12),
+            Step::Ready(13),
+            Step::Ready(14),
+            Step::WakeLater,
+        ]);
+        let mut s = pin!([a, b].merge().merge_chunks(3));
+        let waker = CountingWaker::new();
+
+        // Items from both members fill up the first batch.
+        let mut first = match step_stream(s.as_mut(), &waker) {
+            Poll::Ready(Some(batch)) => batch,
+            poll => panic!("expected a batch, got {poll:?}"),
+        };
+        first.sort_unstable();
+        assert_eq!(first, vec![1, 2, 10]);
+
+        // Nothing is buffered, so there is nothing to flush.
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+
+        // `b` wakes up with more items than fit into a single batch.
+        assert!(b_handle.wake());
+        assert_eq!(
+            step_stream(s.as_mut(), &waker),
+            Poll::Ready(Some(vec![11, 12, 13]))
+        );
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(vec![14])));
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+
+        // Both members end without yielding any more items.
+        assert!(a_handle.wake());
+        assert!(b_handle.wake());
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(None));
+        assert!(s.is_terminated());
+    }
+}
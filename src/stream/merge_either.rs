@@ -0,0 +1,280 @@
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+use super::merge::tuple::Merge2;
+use super::Merge;
+
+/// A value which is one of two types.
+///
+/// This is the item of the stream created by the [`merge_either`] method on
+/// [`StreamExt`].
+///
+/// [`merge_either`]: crate::stream::StreamExt::merge_either
+/// [`StreamExt`]: crate::stream::StreamExt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+    /// A value of the left type.
+    Left(L),
+    /// A value of the right type.
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Returns `true` if this is a `Left` value.
+    pub fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+
+    /// Returns `true` if this is a `Right` value.
+    pub fn is_right(&self) -> bool {
+        matches!(self, Either::Right(_))
+    }
+
+    /// Returns the left value, if any.
+    pub fn left(self) -> Option<L> {
+        match self {
+            Either::Left(left) => Some(left),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Returns the right value, if any.
+    pub fn right(self) -> Option<R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(right) => Some(right),
+        }
+    }
+
+    /// Map the left value, leaving a right value untouched.
+    pub fn map_left<M, F>(self, f: F) -> Either<M, R>
+    where
+        F: FnOnce(L) -> M,
+    {
+        match self {
+            Either::Left(left) => Either::Left(f(left)),
+            Either::Right(right) => Either::Right(right),
+        }
+    }
+
+    /// Map the right value, leaving a left value untouched.
+    pub fn map_right<M, F>(self, f: F) -> Either<L, M>
+    where
+        F: FnOnce(R) -> M,
+    {
+        match self {
+            Either::Left(left) => Either::Left(left),
+            Either::Right(right) => Either::Right(f(right)),
+        }
+    }
+}
+
+impl<T> Either<T, T> {
+    /// Returns the value, if both sides are of the same type.
+    pub fn into_inner(self) -> T {
+        match self {
+            Either::Left(value) | Either::Right(value) => value,
+        }
+    }
+}
+
+mod side {
+    use core::fmt;
+    use core::marker::PhantomData;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+
+    use futures_core::Stream;
+    use pin_project::pin_project;
+
+    use super::Either;
+
+    /// Wraps the items of a stream in `Either::Left`.
+    #[pin_project]
+    pub struct Left<S, R>(#[pin] pub(super) S, pub(super) PhantomData<fn() -> R>);
+
+    /// Wraps the items of a stream in `Either::Right`.
+    #[pin_project]
+    pub struct Right<S, L>(#[pin] pub(super) S, pub(super) PhantomData<fn() -> L>);
+
+    impl<S: Stream, R> Stream for Left<S, R> {
+        type Item = Either<S::Item, R>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.project()
+                .0
+                .poll_next(cx)
+                .map(|item| item.map(Either::Left))
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.0.size_hint()
+        }
+    }
+
+    impl<S: Stream, L> Stream for Right<S, L> {
+        type Item = Either<L, S::Item>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.project()
+                .0
+                .poll_next(cx)
+                .map(|item| item.map(Either::Right))
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.0.size_hint()
+        }
+    }
+
+    impl<S: fmt::Debug, R> fmt::Debug for Left<S, R> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    impl<S: fmt::Debug, L> fmt::Debug for Right<S, L> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    impl<S: Clone, R> Clone for Left<S, R> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), PhantomData)
+        }
+    }
+
+    impl<S: Clone, L> Clone for Right<S, L> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), PhantomData)
+        }
+    }
+}
+
+type Inner<S1, S2> = Merge2<
+    Either<<S1 as Stream>::Item, <S2 as Stream>::Item>,
+    side::Left<S1, <S2 as Stream>::Item>,
+    side::Right<S2, <S1 as Stream>::Item>,
+>;
+
+/// A stream which merges two streams with different items.
+///
+/// This `struct` is created by the [`merge_either`] method on [`StreamExt`].
+/// See its documentation for more.
+///
+/// [`merge_either`]: crate::stream::StreamExt::merge_either
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct MergeEither<S1, S2>
+where
+    S1: Stream,
+    S2: Stream,
+{
+    #[pin]
+    inner: Inner<S1, S2>,
+}
+
+impl<S1, S2> MergeEither<S1, S2>
+where
+    S1: Stream,
+    S2: Stream,
+{
+    pub(crate) fn new(left: S1, right: S2) -> Self {
+        let left = side::Left(left, core::marker::PhantomData);
+        let right = side::Right(right, core::marker::PhantomData);
+        Self {
+            inner: (left, right).merge(),
+        }
+    }
+}
+
+impl<S1, S2> fmt::Debug for MergeEither<S1, S2>
+where
+    S1: Stream + fmt::Debug,
+    S2: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Cloning creates a new, unpolled merge from clones of the streams. Streams
+/// which have completed are completed in the clone as well.
+impl<S1, S2> Clone for MergeEither<S1, S2>
+where
+    S1: Stream + Clone,
+    S2: Stream + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<S1, S2> Stream for MergeEither<S1, S2>
+where
+    S1: Stream,
+    S2: Stream,
+{
+    type Item = Either<S1::Item, S2::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream::StreamExt;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use futures_lite::future::block_on;
+    use futures_lite::stream;
+
+    #[test]
+    fn strings_and_ints() {
+        block_on(async {
+            let words = stream::iter(["a", "b"]);
+            let numbers = stream::iter([1, 2, 3]);
+            let s = words.merge_either(numbers);
+            let items: Vec<_> = futures_lite::StreamExt::collect(s).await;
+
+            let mut words = vec![];
+            let mut sum = 0;
+            for item in &items {
+                match item {
+                    Either::Left(word) => words.push(*word),
+                    Either::Right(n) => sum += n,
+                }
+            }
+            assert_eq!(words, ["a", "b"]);
+            assert_eq!(sum, 6);
+
+            // Both sides are polled fairly.
+            assert!(items[0].is_left() != items[1].is_left());
+        })
+    }
+
+    #[test]
+    fn helpers() {
+        let left: Either<u8, &str> = Either::Left(1);
+        let right: Either<u8, &str> = Either::Right("two");
+        assert_eq!(left.map_left(|n| n + 1), Either::Left(2));
+        assert_eq!(right.map_right(str::len), Either::Right(3));
+        assert_eq!(left.left(), Some(1));
+        assert_eq!(right.left(), None);
+        assert_eq!(Either::<u8, u8>::Right(3).into_inner(), 3);
+    }
+}
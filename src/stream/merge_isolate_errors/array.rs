@@ -0,0 +1,120 @@
+use super::MergeIsolateErrors as MergeIsolateErrorsTrait;
+use super::UntilError;
+use crate::private::IndexedStream;
+use crate::stream::merge::array::Merge;
+use crate::stream::IntoStream;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+/// A stream which merges multiple fallible streams, dropping each stream
+/// after it yields an error.
+///
+/// This `struct` is created by the [`merge_isolate_errors`] method on the
+/// [`MergeIsolateErrors`] trait. See its documentation for more.
+///
+/// [`merge_isolate_errors`]: crate::stream::MergeIsolateErrors::merge_isolate_errors
+/// [`MergeIsolateErrors`]: crate::stream::MergeIsolateErrors
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct MergeIsolateErrors<S, T, E, const N: usize>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    #[pin]
+    merge: Merge<UntilError<S>, N>,
+}
+
+impl<S, T, E, const N: usize> MergeIsolateErrors<S, T, E, N>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    pub(crate) fn new(streams: [S; N]) -> Self {
+        Self {
+            merge: Merge::new(streams.map(UntilError::new)),
+        }
+    }
+}
+
+impl<S, T, E, const N: usize> fmt::Debug for MergeIsolateErrors<S, T, E, N>
+where
+    S: Stream<Item = Result<T, E>> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.merge.fmt(f)
+    }
+}
+
+impl<S, T, E, const N: usize> Stream for MergeIsolateErrors<S, T, E, N>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    type Item = (usize, Result<T, E>);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().merge.poll_next_indexed(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.merge.size_hint()
+    }
+}
+
+impl<S, T, E, const N: usize> MergeIsolateErrorsTrait for [S; N]
+where
+    S: IntoStream<Item = Result<T, E>>,
+{
+    type Item = T;
+    type Error = E;
+    type Stream = MergeIsolateErrors<S::IntoStream, T, E, N>;
+
+    fn merge_isolate_errors(self) -> Self::Stream {
+        MergeIsolateErrors::new(self.map(IntoStream::into_stream))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use futures_lite::future::block_on;
+    use futures_lite::{stream, StreamExt};
+
+    #[test]
+    fn isolates_failed_member() {
+        block_on(async {
+            let a = stream::iter([Ok(1), Ok(2), Ok(3), Ok(10)]);
+            let b = stream::iter([Ok(4), Ok(5), Err("oh no"), Ok(6)]);
+            let c = stream::iter([Ok(7), Ok(8), Ok(9), Ok(11)]);
+            let items: Vec<_> = [a, b, c].merge_isolate_errors().collect().await;
+
+            let errors: Vec<_> = items.iter().filter(|(_, item)| item.is_err()).collect();
+            assert_eq!(errors, [&(1, Err("oh no"))]);
+
+            let mut ok: Vec<_> = items
+                .into_iter()
+                .filter_map(|(index, item)| Some((index, item.ok()?)))
+                .collect();
+            ok.sort_unstable();
+            assert_eq!(
+                ok,
+                [
+                    (0, 1),
+                    (0, 2),
+                    (0, 3),
+                    (0, 10),
+                    (1, 4),
+                    (1, 5),
+                    (2, 7),
+                    (2, 8),
+                    (2, 9),
+                    (2, 11)
+                ]
+            );
+        })
+    }
+}
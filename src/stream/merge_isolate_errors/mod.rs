@@ -0,0 +1,99 @@
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+pub(crate) mod array;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Combines multiple fallible streams into a single stream, isolating the
+/// streams which fail from the others.
+///
+/// Every item is yielded together with the index of the stream which yielded
+/// it. When a stream yields an `Err`, the error is yielded and that stream is
+/// dropped, while the other streams keep being merged. The merged stream ends
+/// once every stream has either ended or failed.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::stream::{self, StreamExt};
+///
+/// # futures_lite::future::block_on(async {
+/// let a = stream::iter([Ok(1), Ok(2)]);
+/// let b = stream::iter([Err("oh no"), Ok(3)]);
+/// let mut buf: Vec<_> = [a, b].merge_isolate_errors().collect().await;
+/// buf.sort_unstable();
+///
+/// // The item after the error is never yielded.
+/// assert_eq!(buf, [(0, Ok(1)), (0, Ok(2)), (1, Err("oh no"))]);
+/// # });
+/// ```
+pub trait MergeIsolateErrors {
+    /// The items yielded by the streams.
+    type Item;
+
+    /// The resulting error type.
+    type Error;
+
+    /// What stream do we return?
+    type Stream: Stream<Item = (usize, Result<Self::Item, Self::Error>)>;
+
+    /// Combine multiple fallible streams into a single stream, dropping each
+    /// stream after it yields an error.
+    fn merge_isolate_errors(self) -> Self::Stream;
+}
+
+/// A stream which ends after yielding its first error.
+#[derive(Debug)]
+#[pin_project]
+pub(crate) struct UntilError<S> {
+    #[pin]
+    stream: Option<S>,
+}
+
+impl<S> UntilError<S> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream: Some(stream),
+        }
+    }
+}
+
+impl<S, T, E> Stream for UntilError<S>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let Some(stream) = this.stream.as_mut().as_pin_mut() else {
+            return Poll::Ready(None);
+        };
+        match ready!(stream.poll_next(cx)) {
+            Some(Ok(item)) => Poll::Ready(Some(Ok(item))),
+            // Drop the stream right away, so its resources are released
+            // while the other streams keep being merged.
+            Some(Err(err)) => {
+                this.stream.set(None);
+                Poll::Ready(Some(Err(err)))
+            }
+            None => {
+                this.stream.set(None);
+                Poll::Ready(None)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.stream {
+            // The stream may fail on its first item.
+            Some(stream) => (stream.size_hint().0.min(1), stream.size_hint().1),
+            None => (0, Some(0)),
+        }
+    }
+}
@@ -34,8 +34,12 @@
 //!   underlying iterators.
 //! - `zip`: combine multiple iterators into an iterator of pairs. The
 //!   underlying iterators will be awaited concurrently.
+//! - `try_zip`: like `zip`, but for iterators of `Result`s: the first error
+//!   ends the iterator.
 //! - `chain`: iterate over multiple iterators in sequence. The next iterator in
 //!   the sequence won't start until the previous iterator has finished.
+//! - `try_chain`: like `chain`, but for iterators of `Result`s: the first error
+//!   ends the iterator, and the iterators after it are never started.
 //!
 //! ## Futures
 //!
@@ -47,23 +51,53 @@
 //!
 //! See the [future concurrency][crate::future#concurrency] documentation for
 //! more on futures concurrency.
+#[cfg(feature = "unstable-async-iter")]
+pub use async_iter::{from_async_iter, FromAsyncIter};
 pub use chain::Chain;
+pub use chain_either::ChainEither;
+pub use from_iter::{from_iter, FromIter};
+pub use fuse::Fuse;
 pub use into_stream::IntoStream;
-pub use merge::Merge;
+#[cfg(feature = "alloc")]
+pub use merge::member_timeout::TimedOut;
+pub use merge::{IdleMarkers, Marked, Merge, MergeMap, Strategy};
+#[cfg(feature = "alloc")]
+pub use merge_chunks::MergeChunks;
+pub use merge_either::{Either, MergeEither};
+pub use merge_isolate_errors::MergeIsolateErrors;
+pub use stop_when::StopWhen;
 pub use stream_ext::StreamExt;
 #[doc(inline)]
 #[cfg(feature = "alloc")]
 pub use stream_group::StreamGroup;
+pub use try_chain::TryChain;
+#[cfg(feature = "alloc")]
+pub use try_merge_tolerating::TryMergeTolerating;
+pub use try_zip::TryZip;
 pub use wait_until::WaitUntil;
-pub use zip::Zip;
+pub use zip::{Zip, ZipWith};
 
 /// A growable group of streams which act as a single unit.
 #[cfg(feature = "alloc")]
 pub mod stream_group;
 
+#[cfg(feature = "unstable-async-iter")]
+mod async_iter;
 pub(crate) mod chain;
+mod chain_either;
+mod from_iter;
+mod fuse;
 mod into_stream;
 pub(crate) mod merge;
+#[cfg(feature = "alloc")]
+mod merge_chunks;
+mod merge_either;
+pub(crate) mod merge_isolate_errors;
+mod stop_when;
 mod stream_ext;
+pub(crate) mod try_chain;
+#[cfg(feature = "alloc")]
+pub(crate) mod try_merge_tolerating;
+pub(crate) mod try_zip;
 pub(crate) mod wait_until;
 pub(crate) mod zip;
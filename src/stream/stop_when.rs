@@ -0,0 +1,133 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+/// A stream which ends after the first item matching a predicate.
+///
+/// This `struct` is created by the [`stop_when`] method on [`StreamExt`]. See its
+/// documentation for more.
+///
+/// [`stop_when`]: crate::stream::StreamExt::stop_when
+/// [`StreamExt`]: crate::stream::StreamExt
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct StopWhen<S, F> {
+    #[pin]
+    stream: Option<S>,
+    predicate: F,
+}
+
+impl<S, F> StopWhen<S, F> {
+    pub(crate) fn new(stream: S, predicate: F) -> Self {
+        Self {
+            stream: Some(stream),
+            predicate,
+        }
+    }
+}
+
+impl<S, F> Stream for StopWhen<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let Some(stream) = this.stream.as_mut().as_pin_mut() else {
+            return Poll::Ready(None);
+        };
+
+        let item = core::task::ready!(stream.poll_next(cx));
+        match &item {
+            // Drop the stream right away, so that any resources it holds are
+            // freed before the item is handed out.
+            Some(item) if (this.predicate)(item) => this.stream.set(None),
+            Some(_) => {}
+            None => this.stream.set(None),
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.stream {
+            Some(stream) => (0, stream.size_hint().1),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<S, F> FusedStream for StopWhen<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> bool,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream::{Merge, StreamExt};
+    use alloc::vec;
+    use core::pin::pin;
+    use futures_lite::future::block_on;
+    use futures_lite::stream;
+
+    #[test]
+    fn yields_trigger_then_ends() {
+        block_on(async {
+            let mut s = pin!(StreamExt::stop_when(stream::iter(0..10), |n| *n == 3));
+            let mut out = vec![];
+            while let Some(n) = futures_lite::StreamExt::next(&mut s).await {
+                out.push(n);
+            }
+            assert_eq!(out, vec![0, 1, 2, 3]);
+            assert!(s.is_terminated());
+        })
+    }
+
+    #[test]
+    fn drops_members_after_trigger() {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+
+        struct Guard(Rc<Cell<usize>>);
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let dropped = Rc::new(Cell::new(0));
+        let member = |items: [&'static str; 2]| {
+            let guard = Guard(dropped.clone());
+            futures_lite::StreamExt::map(stream::iter(items), move |item| {
+                let _ = &guard;
+                item
+            })
+        };
+
+        block_on(async {
+            let merged = [member(["ok", "fatal"]), member(["ok", "ok"])].merge();
+            let mut s = pin!(merged.stop_when(|item| *item == "fatal"));
+            loop {
+                let item = futures_lite::StreamExt::next(&mut s).await.unwrap();
+                if item == "fatal" {
+                    break;
+                }
+                assert_eq!(dropped.get(), 0);
+            }
+
+            // Both members are gone as soon as the triggering item is yielded.
+            assert_eq!(dropped.get(), 2);
+            assert_eq!(futures_lite::StreamExt::next(&mut s).await, None);
+        })
+    }
+}
@@ -1,35 +1,192 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 use core::future::IntoFuture;
+#[cfg(feature = "alloc")]
+use core::pin::Pin;
 
 use crate::stream::{IntoStream, Merge};
 use futures_core::Stream;
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
 use crate::concurrent_stream::FromStream;
 
-use super::{chain::tuple::Chain2, merge::tuple::Merge2, zip::tuple::Zip2, Chain, WaitUntil, Zip};
+#[cfg(feature = "alloc")]
+use super::MergeChunks;
+use super::{
+    chain::tuple::Chain2, merge::tuple::Merge2, zip::tuple::Zip2, Chain, ChainEither, MergeEither,
+    StopWhen, WaitUntil, Zip,
+};
 
 /// An extension trait for the `Stream` trait.
 pub trait StreamExt: Stream {
     /// Combines two streams into a single stream of all their outputs.
+    ///
+    /// This is a shorthand for `(self, other).merge()`, and accepts any type
+    /// which implements [`IntoStream`] as its argument. Because it returns a
+    /// stream, calls can be chained to merge more than two streams.
+    ///
+    /// # Fairness
+    ///
+    /// Each call to `merge` fairly alternates between its own two members.
+    /// This means that `a.merge(b).merge(c)` does not treat all three streams
+    /// equally: `c` gets the same share as `a` and `b` combined. To merge
+    /// multiple streams with an equal share each, prefer `(a, b, c).merge()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::stream;
+    /// use futures_lite::prelude::*;
+    ///
+    /// block_on(async {
+    ///     let a = stream::once(1);
+    ///     let b = stream::once(2);
+    ///     let c = stream::once(3);
+    ///
+    ///     let mut buf: Vec<_> = a.merge(b).merge(c).collect().await;
+    ///     buf.sort_unstable();
+    ///     assert_eq!(buf, vec![1, 2, 3]);
+    /// });
+    /// ```
     fn merge<T, S2>(self, other: S2) -> Merge2<T, Self, S2::IntoStream>
     where
         Self: Stream<Item = T> + Sized,
         S2: IntoStream<Item = T>;
 
-    /// Takes two streams and creates a new stream over all in sequence
+    /// Combines two streams with different items into a single stream of
+    /// [`Either`] of their items.
+    ///
+    /// Items of `self` are yielded as [`Either::Left`], and items of `other`
+    /// as [`Either::Right`]. The two streams are polled as fairly as by
+    /// [`merge`].
+    ///
+    /// [`Either`]: crate::stream::Either
+    /// [`Either::Left`]: crate::stream::Either::Left
+    /// [`Either::Right`]: crate::stream::Either::Right
+    /// [`merge`]: StreamExt::merge
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_concurrency::stream::Either;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::stream;
+    /// use futures_lite::prelude::*;
+    ///
+    /// block_on(async {
+    ///     let names = stream::once("ferris");
+    ///     let ages = stream::once(8);
+    ///
+    ///     let mut s = names.merge_either(ages);
+    ///     while let Some(item) = s.next().await {
+    ///         match item {
+    ///             Either::Left(name) => assert_eq!(name, "ferris"),
+    ///             Either::Right(age) => assert_eq!(age, 8),
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    fn merge_either<S2>(self, other: S2) -> MergeEither<Self, S2::IntoStream>
+    where
+        Self: Sized,
+        S2: IntoStream;
+
+    /// Takes two streams and creates a new stream over all in sequence.
+    ///
+    /// This is a shorthand for `(self, other).chain()`, and accepts any type
+    /// which implements [`IntoStream`] as its argument.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::stream;
+    ///
+    /// block_on(async {
+    ///     let a = stream::once(1);
+    ///     let b = stream::once(2);
+    ///     let c = stream::once(3);
+    ///
+    ///     // `futures_lite` provides its own `chain`, so we call its `collect`
+    ///     // by path to avoid ambiguity between the two extension traits.
+    ///     let s = a.chain(b).chain(c);
+    ///     let buf: Vec<_> = futures_lite::StreamExt::collect(s).await;
+    ///     assert_eq!(buf, vec![1, 2, 3]);
+    /// });
+    /// ```
     fn chain<T, S2>(self, other: S2) -> Chain2<Self, S2::IntoStream>
     where
         Self: Stream<Item = T> + Sized,
         S2: IntoStream<Item = T>;
 
-    /// ‘Zips up’ multiple streams into a single stream of pairs.
+    /// Takes two streams with different items and creates a new stream over
+    /// all of their items in sequence, as [`Either`] of their items.
+    ///
+    /// Items of `self` are yielded as [`Either::Left`], followed by the items
+    /// of `other` as [`Either::Right`]. `other` is only converted into a
+    /// stream and polled once `self` has ended.
+    ///
+    /// [`Either`]: crate::stream::Either
+    /// [`Either::Left`]: crate::stream::Either::Left
+    /// [`Either::Right`]: crate::stream::Either::Right
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_concurrency::stream::Either;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::stream;
+    ///
+    /// block_on(async {
+    ///     let replay = stream::iter(["a", "b"]);
+    ///     let live = stream::once(3);
+    ///
+    ///     let s = replay.chain_either(live);
+    ///     let buf: Vec<_> = futures_lite::StreamExt::collect(s).await;
+    ///     assert_eq!(buf, [Either::Left("a"), Either::Left("b"), Either::Right(3)]);
+    /// });
+    /// ```
+    fn chain_either<S2>(self, other: S2) -> ChainEither<Self, S2>
+    where
+        Self: Sized,
+        S2: IntoStream;
+
+    /// ‘Zips up’ two streams into a single stream of pairs.
+    ///
+    /// This is a shorthand for `(self, other).zip()`, and accepts any type
+    /// which implements [`IntoStream`] as its argument. Unlike `merge` and
+    /// `chain`, the two streams may yield different item types. Chaining calls
+    /// produces nested pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::stream;
+    ///
+    /// block_on(async {
+    ///     let a = stream::iter([1, 2]);
+    ///     let b = stream::iter(["a", "b"]);
+    ///     let c = stream::iter([true, false]);
+    ///
+    ///     let s = a.zip(b).zip(c);
+    ///     let buf: Vec<_> = futures_lite::StreamExt::collect(s).await;
+    ///     assert_eq!(buf, vec![((1, "a"), true), ((2, "b"), false)]);
+    /// });
+    /// ```
     fn zip<T, S2>(self, other: S2) -> Zip2<Self, S2::IntoStream>
     where
         Self: Stream<Item = T> + Sized,
-        S2: IntoStream<Item = T>;
+        S2: IntoStream;
 
     /// Convert into a concurrent stream.
-    #[cfg(feature = "alloc")]
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
     fn co(self) -> FromStream<Self>
     where
         Self: Sized,
@@ -75,6 +232,137 @@ pub trait StreamExt: Stream {
     {
         WaitUntil::new(self, deadline.into_future())
     }
+
+    /// Batch the items of the stream into `Vec`s of up to `n` items.
+    ///
+    /// A batch is yielded as soon as it holds `n` items, or as soon as the
+    /// underlying stream returns `Poll::Pending` while items are buffered.
+    /// This bounds the latency of every item without the need for a timer,
+    /// which makes it a good fit for batching the output of a [`Merge`]. Once
+    /// the underlying stream ends, a final partial batch is yielded before
+    /// the stream ends too.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// block_on(async {
+    ///     let s = (stream::iter([1, 2, 3]), stream::iter([4, 5])).chain();
+    ///     let batches: Vec<_> = s.merge_chunks(2).collect().await;
+    ///     assert_eq!(batches, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    /// });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn merge_chunks(self, n: usize) -> MergeChunks<Self>
+    where
+        Self: Sized,
+    {
+        MergeChunks::new(self, n)
+    }
+
+    /// End the stream after the first item which matches the predicate.
+    ///
+    /// The matching item is yielded as the final item of the stream. The
+    /// underlying stream is dropped as soon as that item has been received,
+    /// which for a [`Merge`] means all of its member streams are dropped right
+    /// away rather than when the merged stream itself is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// block_on(async {
+    ///     let a = stream::iter(["ok", "fatal", "ok"]);
+    ///     let b = stream::iter(["ok"; 3]);
+    ///
+    ///     let events: Vec<_> = (a, b).merge().stop_when(|e| *e == "fatal").collect().await;
+    ///     assert_eq!(events.last(), Some(&"fatal"));
+    /// });
+    /// ```
+    fn stop_when<F>(self, predicate: F) -> StopWhen<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        StopWhen::new(self, predicate)
+    }
+
+    /// Box and pin the stream, erasing its type.
+    ///
+    /// This makes it possible to store streams of different types in a single
+    /// `Vec`, without having to cast each `Box::pin` to a trait object.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// block_on(async {
+    ///     let streams = vec![
+    ///         stream::once(1).boxed_concurrency(),
+    ///         stream::iter([2, 3]).boxed_concurrency(),
+    ///     ];
+    ///     let out: Vec<_> = streams.chain().collect().await;
+    ///     assert_eq!(out, vec![1, 2, 3]);
+    /// });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed_concurrency<'a>(self) -> Pin<Box<dyn Stream<Item = Self::Item> + Send + 'a>>
+    where
+        Self: Sized + Send + 'a,
+    {
+        Box::pin(self)
+    }
+
+    /// Box and pin the stream, erasing its type, without requiring it to be
+    /// `Send`.
+    ///
+    /// This is the counterpart of [`boxed_concurrency`] for streams which
+    /// hold `!Send` state, such as an `Rc`, and run on a single-threaded
+    /// executor.
+    ///
+    /// [`boxed_concurrency`]: StreamExt::boxed_concurrency
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::prelude::*;
+    /// use futures_lite::stream;
+    /// use std::rc::Rc;
+    ///
+    /// block_on(async {
+    ///     let streams = vec![
+    ///         stream::once(Rc::new(1)).boxed_concurrency_local(),
+    ///         stream::iter([Rc::new(2), Rc::new(3)]).boxed_concurrency_local(),
+    ///     ];
+    ///     let out: Vec<_> = streams.chain().map(|n| *n).collect().await;
+    ///     assert_eq!(out, vec![1, 2, 3]);
+    /// });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed_concurrency_local<'a>(self) -> Pin<Box<dyn Stream<Item = Self::Item> + 'a>>
+    where
+        Self: Sized + 'a,
+    {
+        Box::pin(self)
+    }
 }
 
 impl<S1> StreamExt for S1
@@ -89,6 +377,13 @@ where
         Merge::merge((self, other))
     }
 
+    fn merge_either<S2>(self, other: S2) -> MergeEither<Self, S2::IntoStream>
+    where
+        S2: IntoStream,
+    {
+        MergeEither::new(self, other.into_stream())
+    }
+
     fn chain<T, S2>(self, other: S2) -> Chain2<Self, S2::IntoStream>
     where
         Self: Stream<Item = T> + Sized,
@@ -98,12 +393,18 @@ where
         Chain::chain((self, other.into_stream()))
     }
 
+    fn chain_either<S2>(self, other: S2) -> ChainEither<Self, S2>
+    where
+        S2: IntoStream,
+    {
+        ChainEither::new(self, other)
+    }
+
     fn zip<T, S2>(self, other: S2) -> Zip2<Self, S2::IntoStream>
     where
         Self: Stream<Item = T> + Sized,
-        S2: IntoStream<Item = T>,
+        S2: IntoStream,
     {
-        // TODO(yosh): fix the bounds on the tuple impl
         Zip::zip((self, other.into_stream()))
     }
 }
@@ -42,7 +42,7 @@ use crate::utils::{PollState, PollVec, WakerVec};
 /// # futures_lite::future::block_on(async {
 /// let mut group = StreamGroup::new();
 /// group.insert(stream::once(4));
-
+///
 /// let mut index = 3;
 /// let mut out = 0;
 /// let mut group = group.lend_mut();
@@ -187,10 +187,61 @@ impl<S> StreamGroup<S> {
         if is_present {
             self.states[key.0].set_none();
             self.streams.remove(key.0);
+            // A stream inserted with the same key isn't paused.
+            #[cfg(any(feature = "std", feature = "critical-section"))]
+            self.wakers.readiness().resume(key.0);
         }
         is_present
     }
 
+    /// Stop polling the stream with the given key until it's resumed.
+    ///
+    /// The stream is kept in the group, and the group doesn't end while it's
+    /// paused. Wakes of the stream are recorded, but the group isn't woken by
+    /// them until the stream is resumed. Pausing a stream which has completed
+    /// or been removed, or which is already paused, does nothing.
+    ///
+    /// This requires the `std` or `critical-section` feature, which track
+    /// the wakes of each stream.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_lite::{stream, StreamExt};
+    /// use futures_concurrency::stream::StreamGroup;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = StreamGroup::new();
+    /// let key = group.insert(stream::iter(vec![1, 2]));
+    /// group.insert(stream::iter(vec![3]));
+    ///
+    /// group.pause(key);
+    /// assert_eq!(group.next().await, Some(3));
+    /// group.resume(key);
+    /// assert_eq!(group.next().await, Some(1));
+    /// # })
+    /// ```
+    #[cfg(any(feature = "std", feature = "critical-section"))]
+    pub fn pause(&mut self, key: Key) {
+        if self.keys.contains(&key.0) {
+            self.wakers.readiness().pause(key.0);
+        }
+    }
+
+    /// Resume polling the stream with the given key after it was paused.
+    ///
+    /// If the stream was woken while it was paused, or was ready to be polled
+    /// when it was paused, the group is woken so the stream is polled right
+    /// away. Resuming a stream which isn't paused does nothing.
+    ///
+    /// This requires the `std` or `critical-section` feature.
+    #[cfg(any(feature = "std", feature = "critical-section"))]
+    pub fn resume(&mut self, key: Key) {
+        if self.keys.contains(&key.0) {
+            self.wakers.readiness().resume(key.0);
+        }
+    }
+
     /// Returns `true` if the `StreamGroup` contains a value for the specified key.
     ///
     /// # Example
@@ -242,6 +293,44 @@ impl<S> StreamGroup<S> {
     }
 }
 
+impl<S: Unpin> StreamGroup<S> {
+    /// Shrinks the capacity of the group as much as possible.
+    ///
+    /// Keys of streams which are still in the group remain valid. Because
+    /// keys are stable, the capacity can't shrink below the highest key still
+    /// in use.
+    ///
+    /// Shrinking may move streams around in memory, which is why this
+    /// requires streams to be `Unpin`. To use this with streams which aren't
+    /// `Unpin`, wrap them in `Box::pin` first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::StreamGroup;
+    /// use futures_lite::{stream, StreamExt};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = StreamGroup::new();
+    /// for n in 0..100 {
+    ///     group.insert(stream::once(n));
+    /// }
+    /// while group.next().await.is_some() {}
+    /// assert!(group.capacity() >= 100);
+    ///
+    /// group.shrink_to_fit();
+    /// assert_eq!(group.capacity(), 0);
+    /// # });
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.streams.shrink_to_fit();
+        let new_cap = self.streams.capacity();
+        self.wakers.resize(new_cap);
+        self.states.resize(new_cap);
+        self.capacity = new_cap;
+    }
+}
+
 impl<S: Stream> StreamGroup<S> {
     /// Insert a new future into the group.
     ///
@@ -267,7 +356,7 @@ impl<S: Stream> StreamGroup<S> {
 
         // Set the corresponding state
         self.states[index].set_pending();
-        self.wakers.readiness().set_ready(index);
+        self.wakers.readiness().set_ready_and_wake(index);
 
         Key(index)
     }
@@ -403,14 +492,22 @@ impl<S: Stream> Stream for StreamGroup<S> {
     }
 }
 
-impl<S: Stream> FromIterator<S> for StreamGroup<S> {
-    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+impl<S: Stream> Extend<S> for StreamGroup<S> {
+    fn extend<T: IntoIterator<Item = S>>(&mut self, iter: T) {
         let iter = iter.into_iter();
-        let len = iter.size_hint().1.unwrap_or_default();
-        let mut this = Self::with_capacity(len);
+        let len = iter.size_hint().0;
+        self.reserve(len);
+
         for stream in iter {
-            this.insert(stream);
+            self.insert(stream);
         }
+    }
+}
+
+impl<S: Stream> FromIterator<S> for StreamGroup<S> {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        let mut this = Self::new();
+        this.extend(iter);
         this
     }
 }
@@ -472,6 +569,50 @@ mod test {
         });
     }
 
+    #[test]
+    fn extend_mid_consumption() {
+        futures_lite::future::block_on(async {
+            let group: StreamGroup<_> = vec![stream::iter(vec![1, 2])].into_iter().collect();
+            let mut group = group.keyed();
+            let (first_key, _) = group.next().await.unwrap();
+
+            // The first stream is still active; new keys must not collide with it.
+            group.extend([stream::iter(vec![3]), stream::iter(vec![4])]);
+            assert_eq!(group.len(), 3);
+
+            let mut out = 0;
+            while let Some((key, num)) = group.next().await {
+                if num > 2 {
+                    assert_ne!(key, first_key);
+                }
+                out += num;
+            }
+            assert_eq!(out, 9);
+        });
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::new();
+            let key = group.insert(stream::once(1));
+            let keys: Vec<_> = (0..100_000)
+                .map(|n| group.insert(stream::once(n)))
+                .collect();
+            for key in keys {
+                group.remove(key);
+            }
+            assert!(group.capacity() >= 100_000);
+
+            // Keys of members which are still present remain valid.
+            group.shrink_to_fit();
+            assert!(group.capacity() < 100_000);
+            assert!(group.contains_key(key));
+            assert_eq!(group.next().await, Some(1));
+            assert_eq!(group.next().await, None);
+        });
+    }
+
     #[test]
     fn capacity_grow_on_insert() {
         futures_lite::future::block_on(async {
@@ -483,4 +624,71 @@ mod test {
             assert!(group.capacity() > cap);
         });
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn insert_wakes_waiting_task() {
+        use crate::test_utils::{step_stream, CountingWaker};
+        use core::pin::Pin;
+        use core::task::Poll;
+
+        let mut group = StreamGroup::new();
+        group.insert(stream::pending().boxed());
+        let waker = CountingWaker::new();
+        assert_eq!(step_stream(Pin::new(&mut group), &waker), Poll::Pending);
+
+        // The new stream lands in a slot created by growing the group, which
+        // must still wake the task waiting on the group.
+        group.insert(stream::once(1).boxed());
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(
+            step_stream(Pin::new(&mut group), &waker),
+            Poll::Ready(Some(1))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pause_members() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::Pin;
+        use core::task::Poll;
+
+        let (chatty, chatty_handle) =
+            ScriptedStream::new([Step::Ready(1), Step::WakeLater, Step::Ready(2)]);
+        let (quiet, quiet_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(10)]);
+        let mut group = StreamGroup::new();
+        let chatty_key = group.insert(chatty);
+        group.insert(quiet);
+        let waker = CountingWaker::new();
+
+        assert_eq!(
+            step_stream(Pin::new(&mut group), &waker),
+            Poll::Ready(Some(1))
+        );
+        assert_eq!(step_stream(Pin::new(&mut group), &waker), Poll::Pending);
+        group.pause(chatty_key);
+        assert_eq!(chatty_handle.polls(), 2);
+
+        // The wake of the paused stream is recorded, without waking the
+        // group.
+        let wakes = waker.wakes();
+        assert!(chatty_handle.wake());
+        assert_eq!(waker.wakes(), wakes);
+        assert!(quiet_handle.wake());
+        assert_eq!(
+            step_stream(Pin::new(&mut group), &waker),
+            Poll::Ready(Some(10))
+        );
+        assert_eq!(chatty_handle.polls(), 2);
+
+        // Resuming wakes the group, and the backlog is polled.
+        group.resume(chatty_key);
+        assert_eq!(waker.wakes(), wakes + 2);
+        assert_eq!(
+            step_stream(Pin::new(&mut group), &waker),
+            Poll::Ready(Some(2))
+        );
+        assert_eq!(step_stream(Pin::new(&mut group), &waker), Poll::Ready(None));
+    }
 }
@@ -0,0 +1,61 @@
+use futures_core::Stream;
+
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Takes multiple fallible streams and creates a new stream over all in
+/// sequence, or aborts early on error.
+///
+/// `Ok` items are yielded in the same order as with `chain`. As soon as a
+/// stream yields an `Err`, the error is yielded and the stream ends: the
+/// erroring stream is dropped, and the streams after it are dropped without
+/// ever being converted into streams or polled.
+pub trait TryChain {
+    /// The items yielded by the stream.
+    type Item;
+
+    /// The resulting error type.
+    type Error;
+
+    /// What stream do we return?
+    type Stream: Stream<Item = Result<Self::Item, Self::Error>>;
+
+    /// Combine multiple fallible streams into a single stream.
+    fn try_chain(self) -> Self::Stream;
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    use crate::stream::IntoStream;
+
+    /// A member which records whether it has been converted into a stream.
+    pub(crate) struct Lazy<S> {
+        stream: S,
+        converted: Rc<Cell<bool>>,
+    }
+
+    impl<S> Lazy<S> {
+        pub(crate) fn new(stream: S) -> (Self, Rc<Cell<bool>>) {
+            let converted = Rc::new(Cell::new(false));
+            let lazy = Self {
+                stream,
+                converted: converted.clone(),
+            };
+            (lazy, converted)
+        }
+    }
+
+    impl<S: futures_core::Stream> IntoStream for Lazy<S> {
+        type Item = S::Item;
+        type IntoStream = S;
+
+        fn into_stream(self) -> S {
+            self.converted.set(true);
+            self.stream
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use core::fmt;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+use super::TryChain;
+use crate::stream::IntoStream;
+
+/// A member of the chain, which is only converted into a stream once it's its
+/// turn.
+#[pin_project(project = SlotProj, project_replace = SlotReplace)]
+enum Slot<I: IntoStream> {
+    Idle(I),
+    Active(#[pin] I::IntoStream),
+    Done,
+}
+
+impl<I> fmt::Debug for Slot<I>
+where
+    I: IntoStream + fmt::Debug,
+    I::IntoStream: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Slot::Idle(member) => f.debug_tuple("Idle").field(member).finish(),
+            Slot::Active(stream) => f.debug_tuple("Active").field(stream).finish(),
+            Slot::Done => f.write_str("Done"),
+        }
+    }
+}
+
+impl<I: IntoStream> Slot<I> {
+    /// Poll the member for its next item, converting it into a stream first
+    /// if needed. The stream is dropped once it ends.
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        if let SlotProj::Idle(_) = self.as_mut().project() {
+            let SlotReplace::Idle(member) = self.as_mut().project_replace(Slot::Done) else {
+                unreachable!()
+            };
+            self.set(Slot::Active(member.into_stream()));
+        }
+
+        let item = match self.as_mut().project() {
+            SlotProj::Active(stream) => ready!(stream.poll_next(cx)),
+            SlotProj::Idle(_) | SlotProj::Done => None,
+        };
+        if item.is_none() {
+            self.set(Slot::Done);
+        }
+        Poll::Ready(item)
+    }
+}
+
+macro_rules! impl_try_chain_for_tuple {
+    ($mod_name: ident $StructName:ident $($F:ident)+) => {
+        mod $mod_name {
+            #[repr(usize)]
+            enum Indexes {
+                $($F,)+
+            }
+
+            $(
+                pub(super) const $F: usize = Indexes::$F as usize;
+            )+
+
+            pub(super) const LEN: usize = [$(Indexes::$F,)+].len();
+        }
+
+        /// A stream that chains multiple fallible streams one after another,
+        /// or aborts early on error.
+        ///
+        /// This `struct` is created by the [`try_chain`] method on the [`TryChain`] trait. See its
+        /// documentation for more.
+        ///
+        /// [`try_chain`]: crate::stream::TryChain::try_chain
+        /// [`TryChain`]: crate::stream::TryChain
+        #[pin_project]
+        #[must_use = "streams do nothing unless polled or .awaited"]
+        pub struct $StructName<$($F,)+>
+        where
+            $($F: IntoStream,)+
+        {
+            index: usize,
+            $( #[pin] $F: Slot<$F>,)+
+        }
+
+        impl<T, Err, $($F,)+> Stream for $StructName<$($F,)+>
+        where
+            $($F: IntoStream<Item = Result<T, Err>>,)+
+        {
+            type Item = Result<T, Err>;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                let err = loop {
+                    let item = match *this.index {
+                        $(
+                            $mod_name::$F => ready!(this.$F.as_mut().poll_next(cx)),
+                        )+
+                        _ => return Poll::Ready(None),
+                    };
+                    match item {
+                        Some(Ok(item)) => return Poll::Ready(Some(Ok(item))),
+                        Some(Err(err)) => break err,
+                        None => *this.index += 1,
+                    }
+                };
+
+                // Drop the member which errored, and the members which were
+                // never started.
+                $( this.$F.set(Slot::Done); )+
+                *this.index = $mod_name::LEN;
+                Poll::Ready(Some(Err(err)))
+            }
+        }
+
+        impl<T, Err, $($F,)+> FusedStream for $StructName<$($F,)+>
+        where
+            $($F: IntoStream<Item = Result<T, Err>>,)+
+        {
+            fn is_terminated(&self) -> bool {
+                self.index == $mod_name::LEN
+            }
+        }
+
+        impl<$($F,)+> fmt::Debug for $StructName<$($F,)+>
+        where
+            $($F: IntoStream + fmt::Debug, $F::IntoStream: fmt::Debug,)+
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("TryChain")
+                    $(.field(&self.$F))+
+                    .finish()
+            }
+        }
+
+        impl<T, Err, $($F,)+> TryChain for ($($F,)+)
+        where
+            $($F: IntoStream<Item = Result<T, Err>>,)+
+        {
+            type Item = T;
+            type Error = Err;
+            type Stream = $StructName<$($F,)+>;
+
+            fn try_chain(self) -> Self::Stream {
+                let ($($F,)*): ($($F,)*) = self;
+                $StructName {
+                    index: 0,
+                    $($F: Slot::Idle($F),)+
+                }
+            }
+        }
+    }
+}
+
+impl_try_chain_for_tuple! { try_chain_1 TryChain1 A }
+impl_try_chain_for_tuple! { try_chain_2 TryChain2 A B }
+impl_try_chain_for_tuple! { try_chain_3 TryChain3 A B C }
+impl_try_chain_for_tuple! { try_chain_4 TryChain4 A B C D }
+impl_try_chain_for_tuple! { try_chain_5 TryChain5 A B C D E }
+impl_try_chain_for_tuple! { try_chain_6 TryChain6 A B C D E F }
+impl_try_chain_for_tuple! { try_chain_7 TryChain7 A B C D E F G }
+impl_try_chain_for_tuple! { try_chain_8 TryChain8 A B C D E F G H }
+impl_try_chain_for_tuple! { try_chain_9 TryChain9 A B C D E F G H I }
+impl_try_chain_for_tuple! { try_chain_10 TryChain10 A B C D E F G H I J }
+impl_try_chain_for_tuple! { try_chain_11 TryChain11 A B C D E F G H I J K }
+impl_try_chain_for_tuple! { try_chain_12 TryChain12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_try_chain_for_tuple! { try_chain_13 TryChain13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_try_chain_for_tuple! { try_chain_14 TryChain14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_try_chain_for_tuple! { try_chain_15 TryChain15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_try_chain_for_tuple! { try_chain_16 TryChain16 A B C D E F G H I J K L M N O P }
+
+#[cfg(test)]
+mod tests {
+    use super::super::test::Lazy;
+    use crate::stream::TryChain;
+    use futures_core::stream::FusedStream;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn try_chain_tuple_3() {
+        block_on(async {
+            let a = stream::iter([Ok::<_, ()>(1), Ok(2)]);
+            let b = stream::once(Ok(3));
+            let c = stream::empty();
+            let out: Vec<_> = (a, b, c).try_chain().collect().await;
+            assert_eq!(out, vec![Ok(1), Ok(2), Ok(3)]);
+        })
+    }
+
+    #[test]
+    fn err_skips_remaining_members() {
+        block_on(async {
+            let (a, a_converted) = Lazy::new(stream::iter([Ok(1), Ok(2)]));
+            let (b, b_converted) = Lazy::new(stream::iter([Ok(3), Err("oh no"), Ok(4)]));
+            let (c, c_converted) = Lazy::new(stream::once(Ok(5)));
+            let mut s = (a, b, c).try_chain();
+
+            assert!(!a_converted.get());
+            let mut out = vec![];
+            while let Some(item) = s.next().await {
+                out.push(item);
+            }
+            assert_eq!(out, vec![Ok(1), Ok(2), Ok(3), Err("oh no")]);
+            assert!(s.is_terminated());
+            assert!(a_converted.get() && b_converted.get());
+            assert!(!c_converted.get());
+        })
+    }
+
+    #[cfg(feature = "large-tuples")]
+    #[test]
+    fn try_chain_tuple_16() {
+        block_on(async {
+            let s = |n| stream::once(Ok::<_, ()>(n));
+            let out: Vec<_> = (
+                s(0),
+                s(1),
+                s(2),
+                s(3),
+                s(4),
+                s(5),
+                s(6),
+                s(7),
+                s(8),
+                s(9),
+                s(10),
+                s(11),
+                s(12),
+                s(13),
+                s(14),
+                s(15),
+            )
+                .try_chain()
+                .collect()
+                .await;
+            assert_eq!(out, (0..16).map(Ok).collect::<Vec<_>>());
+        })
+    }
+}
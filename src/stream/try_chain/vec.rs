@@ -0,0 +1,158 @@
+use super::TryChain as TryChainTrait;
+use crate::stream::IntoStream;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::vec;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+/// A stream that chains multiple fallible streams one after another, or
+/// aborts early on error.
+///
+/// This `struct` is created by the [`try_chain`] method on the [`TryChain`] trait. See its
+/// documentation for more.
+///
+/// [`try_chain`]: crate::stream::TryChain::try_chain
+/// [`TryChain`]: crate::stream::TryChain
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct TryChain<S, T, E>
+where
+    S: IntoStream<Item = Result<T, E>>,
+{
+    /// The member which is currently being iterated over.
+    #[pin]
+    stream: Option<S::IntoStream>,
+    /// The members which haven't been started yet.
+    remaining: vec::IntoIter<S>,
+    done: bool,
+}
+
+impl<S, T, E> fmt::Debug for TryChain<S, T, E>
+where
+    S: IntoStream<Item = Result<T, E>>,
+    S::IntoStream: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryChain")
+            .field("stream", &self.stream)
+            .field("remaining", &self.remaining.len())
+            .finish()
+    }
+}
+
+impl<S, T, E> Stream for TryChain<S, T, E>
+where
+    S: IntoStream<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let Some(stream) = this.stream.as_mut().as_pin_mut() else {
+                // Only convert the next member once it's its turn.
+                match this.remaining.next() {
+                    Some(member) => this.stream.set(Some(member.into_stream())),
+                    None => {
+                        *this.done = true;
+                        return Poll::Ready(None);
+                    }
+                }
+                continue;
+            };
+
+            match ready!(stream.poll_next(cx)) {
+                Some(Ok(item)) => return Poll::Ready(Some(Ok(item))),
+                Some(Err(err)) => {
+                    // Drop the member which errored, and the members which
+                    // were never started.
+                    this.stream.set(None);
+                    *this.remaining = Vec::new().into_iter();
+                    *this.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+                None => this.stream.set(None),
+            }
+        }
+    }
+}
+
+impl<S, T, E> FusedStream for TryChain<S, T, E>
+where
+    S: IntoStream<Item = Result<T, E>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<S, T, E> TryChainTrait for Vec<S>
+where
+    S: IntoStream<Item = Result<T, E>>,
+{
+    type Item = T;
+    type Error = E;
+    type Stream = TryChain<S, T, E>;
+
+    fn try_chain(self) -> Self::Stream {
+        TryChain {
+            stream: None,
+            remaining: self.into_iter(),
+            done: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::super::test::Lazy;
+    use crate::stream::TryChain;
+    use futures_core::stream::FusedStream;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn try_chain_vec_3() {
+        block_on(async {
+            let a = stream::iter(vec![Ok::<_, ()>(1), Ok(2)]);
+            let b = stream::iter(vec![Ok(3)]);
+            let c = stream::iter(vec![Ok(4), Ok(5)]);
+            let out: Vec<_> = vec![a, b, c].try_chain().collect().await;
+            assert_eq!(out, vec![Ok(1), Ok(2), Ok(3), Ok(4), Ok(5)]);
+        })
+    }
+
+    #[test]
+    fn err_skips_remaining_members() {
+        block_on(async {
+            let (a, a_converted) = Lazy::new(stream::iter(vec![Ok(1), Ok(2)]));
+            let (b, b_converted) = Lazy::new(stream::iter(vec![Ok(3), Err("oh no"), Ok(4)]));
+            let (c, c_converted) = Lazy::new(stream::iter(vec![Ok(5)]));
+            let mut s = vec![a, b, c].try_chain();
+
+            assert!(!a_converted.get());
+            let mut out = vec![];
+            while let Some(item) = s.next().await {
+                out.push(item);
+            }
+            assert_eq!(out, vec![Ok(1), Ok(2), Ok(3), Err("oh no")]);
+            assert!(s.is_terminated());
+            assert!(a_converted.get() && b_converted.get());
+            assert!(!c_converted.get());
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use crate::future::Failure;
+
+use alloc::vec::Vec;
+use futures_core::Stream;
+
+pub(crate) mod vec;
+
+/// Combines multiple fallible streams into a single stream, tolerating a
+/// limited number of streams which fail.
+///
+/// When a stream yields an `Err`, that stream is dropped and its error is
+/// kept, while the other streams keep being merged. Once more than
+/// `max_errors` streams have failed, the merged stream yields every error
+/// which was kept, together with the index of its stream, and ends.
+///
+/// This sits between failing on the first error, which is what a
+/// `max_errors` of zero does, and isolating every error through
+/// [`MergeIsolateErrors`].
+///
+/// [`MergeIsolateErrors`]: crate::stream::MergeIsolateErrors
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::future::Failure;
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::stream::{self, StreamExt};
+///
+/// # futures_lite::future::block_on(async {
+/// let a = stream::iter([Ok(1)]);
+/// let b = stream::iter([Err::<i32, _>("oh no")]);
+/// let items: Vec<_> = vec![a, b].try_merge_tolerating(1).collect().await;
+///
+/// // A single failed stream is tolerated.
+/// assert_eq!(items, [Ok(1)]);
+///
+/// let a = stream::iter([Err::<i32, _>("oh no")]);
+/// let b = stream::iter([Err("oops")]);
+/// let items: Vec<_> = vec![a, b].try_merge_tolerating(1).collect().await;
+/// assert_eq!(items, [Err(vec![Failure::new(0, "oh no"), Failure::new(1, "oops")])]);
+/// # });
+/// ```
+pub trait TryMergeTolerating {
+    /// The items yielded by the streams.
+    type Item;
+
+    /// The resulting error type.
+    type Error;
+
+    /// What stream do we return?
+    type Stream: Stream<Item = Result<Self::Item, Vec<Failure<Self::Error>>>>;
+
+    /// Combine multiple fallible streams into a single stream, which fails
+    /// once more than `max_errors` of the streams have failed.
+    fn try_merge_tolerating(self, max_errors: usize) -> Self::Stream;
+}
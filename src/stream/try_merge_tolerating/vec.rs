@@ -0,0 +1,190 @@
+use super::TryMergeTolerating as TryMergeToleratingTrait;
+use crate::future::Failure;
+use crate::private::IndexedStream;
+use crate::stream::merge::vec::Merge;
+use crate::stream::merge_isolate_errors::UntilError;
+use crate::stream::IntoStream;
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+/// A stream which merges multiple fallible streams, failing once too many
+/// of them have failed.
+///
+/// This `struct` is created by the [`try_merge_tolerating`] method on the
+/// [`TryMergeTolerating`] trait. See its documentation for more.
+///
+/// [`try_merge_tolerating`]: crate::stream::TryMergeTolerating::try_merge_tolerating
+/// [`TryMergeTolerating`]: crate::stream::TryMergeTolerating
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct TryMergeTolerating<S, T, E>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    /// The streams, which are dropped as soon as the merge fails.
+    #[pin]
+    merge: Option<Merge<UntilError<S>>>,
+    errors: Vec<Failure<E>>,
+    max_errors: usize,
+}
+
+impl<S, T, E> TryMergeTolerating<S, T, E>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    pub(crate) fn new(streams: Vec<S>, max_errors: usize) -> Self {
+        Self {
+            merge: Some(Merge::new(
+                streams.into_iter().map(UntilError::new).collect(),
+            )),
+            errors: Vec::new(),
+            max_errors,
+        }
+    }
+
+    /// Returns the errors of the streams which have failed so far.
+    ///
+    /// Once the merge has failed, the errors have been yielded and this is
+    /// empty.
+    pub fn errors(&self) -> &[Failure<E>] {
+        &self.errors
+    }
+}
+
+impl<S, T, E> fmt::Debug for TryMergeTolerating<S, T, E>
+where
+    S: Stream<Item = Result<T, E>> + fmt::Debug,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryMergeTolerating")
+            .field("merge", &self.merge)
+            .field("errors", &self.errors)
+            .field("max_errors", &self.max_errors)
+            .finish()
+    }
+}
+
+impl<S, T, E> Stream for TryMergeTolerating<S, T, E>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    type Item = Result<T, Vec<Failure<E>>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(merge) = this.merge.as_mut().as_pin_mut() else {
+                return Poll::Ready(None);
+            };
+            match ready!(merge.poll_next_indexed(cx)) {
+                Some((_, Ok(item))) => return Poll::Ready(Some(Ok(item))),
+                Some((index, Err(error))) => {
+                    this.errors.push(Failure::new(index, error));
+                    if this.errors.len() > *this.max_errors {
+                        this.merge.set(None);
+                        return Poll::Ready(Some(Err(mem::take(this.errors))));
+                    }
+                }
+                None => {
+                    this.merge.set(None);
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.merge {
+            // Every item may be an error which ends the merge.
+            Some(merge) => (0, merge.size_hint().1),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<S, T, E> TryMergeToleratingTrait for Vec<S>
+where
+    S: IntoStream<Item = Result<T, E>>,
+{
+    type Item = T;
+    type Error = E;
+    type Stream = TryMergeTolerating<S::IntoStream, T, E>;
+
+    fn try_merge_tolerating(self, max_errors: usize) -> Self::Stream {
+        TryMergeTolerating::new(
+            self.into_iter().map(IntoStream::into_stream).collect(),
+            max_errors,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use futures_lite::future::block_on;
+    use futures_lite::{stream, StreamExt};
+
+    #[test]
+    fn fails_after_threshold() {
+        block_on(async {
+            let a = stream::iter(vec![Ok(1), Ok(2)]);
+            let b = stream::iter(vec![Ok(3), Err("b failed"), Ok(4)]);
+            let c = stream::iter(vec![Ok(5), Ok(6), Err("c failed"), Ok(7)]);
+            let d = stream::iter(vec![Ok(8), Ok(9), Ok(10), Ok(11)]);
+            let mut s = vec![a, b, c, d].try_merge_tolerating(1);
+
+            let mut items = Vec::new();
+            let errors = loop {
+                match s.next().await.unwrap() {
+                    Ok(item) => items.push(item),
+                    Err(errors) => break errors,
+                }
+            };
+            // The stream ends at the second failure, with both errors.
+            assert_eq!(
+                errors,
+                [Failure::new(1, "b failed"), Failure::new(2, "c failed")]
+            );
+            assert_eq!(s.next().await, None);
+            // Items after the failure of a stream are never yielded.
+            assert!(!items.contains(&4));
+            assert!(!items.contains(&7));
+        })
+    }
+
+    #[test]
+    fn tolerated_errors() {
+        block_on(async {
+            let a = stream::iter([Ok(1), Ok(2)]);
+            let b = stream::iter([Err("oh no"), Ok(3)]);
+            let mut s = vec![a, b].try_merge_tolerating(1);
+            let mut items = Vec::new();
+            while let Some(item) = s.next().await {
+                items.push(item.unwrap());
+            }
+            items.sort_unstable();
+            assert_eq!(items, [1, 2]);
+            assert_eq!(s.errors(), [Failure::new(1, "oh no")]);
+        })
+    }
+
+    #[test]
+    fn zero_fails_on_first_error() {
+        block_on(async {
+            let a = stream::iter([Err("oh no")]);
+            let b = stream::pending::<Result<u8, &str>>();
+            let s = vec![a.boxed(), b.boxed()].try_merge_tolerating(0);
+            let items: Vec<_> = s.collect().await;
+            assert_eq!(items, [Err(vec![Failure::new(0, "oh no")])]);
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use futures_core::Stream;
+
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// ‘Zips up’ multiple fallible streams into a single stream of rows, or
+/// aborts early on error.
+///
+/// A row is yielded once every stream has yielded an `Ok` item. As soon as any
+/// stream yields an `Err`, the error is yielded and the stream ends: items
+/// which were already received from the other streams are discarded. If any
+/// stream ends before a row is complete, the zipped stream ends without an
+/// error.
+pub trait TryZip {
+    /// The rows yielded by the stream.
+    type Item;
+
+    /// The resulting error type.
+    type Error;
+
+    /// What stream do we return?
+    type Stream: Stream<Item = Result<Self::Item, Self::Error>>;
+
+    /// Combine multiple fallible streams into a single stream.
+    fn try_zip(self) -> Self::Stream;
+}
@@ -0,0 +1,295 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use super::TryZip;
+use crate::stream::IntoStream;
+use crate::utils::{PollArray, WakerArray};
+
+macro_rules! impl_try_zip_for_tuple {
+    ($mod_name: ident $StructName: ident $(($F: ident $T: ident))+) => {
+        mod $mod_name {
+            pub(super) struct Output<$($T,)+> {
+                $(pub(super) $F: core::mem::MaybeUninit<$T>,)+
+            }
+
+            impl<$($T,)+> Default for Output<$($T,)+> {
+                fn default() -> Self {
+                    Self {
+                        $($F: core::mem::MaybeUninit::uninit(),)+
+                    }
+                }
+            }
+
+            #[repr(usize)]
+            enum Indexes {
+                $($F,)+
+            }
+
+            $(
+                pub(super) const $F: usize = Indexes::$F as usize;
+            )+
+
+            pub(super) const LEN: usize = [$(Indexes::$F,)+].len();
+        }
+
+        #[pin_project::pin_project(PinnedDrop)]
+        #[must_use = "streams do nothing unless polled or .awaited"]
+        pub struct $StructName<$($F, $T,)+ Err>
+        where
+            $($F: Stream<Item = Result<$T, Err>>,)+
+        {
+            done: bool,
+            output: $mod_name::Output<$($T,)+>,
+            state: PollArray<{ $mod_name::LEN }>,
+            wakers: WakerArray<{ $mod_name::LEN }>,
+            $( #[pin] $F: $F,)+
+            _phantom: PhantomData<Err>,
+        }
+
+        impl<$($F, $T,)+ Err> fmt::Debug for $StructName<$($F, $T,)+ Err>
+        where
+            $($F: Stream<Item = Result<$T, Err>> + fmt::Debug,)+
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("TryZip")
+                    $(.field(&self.$F))+
+                    .finish()
+            }
+        }
+
+        impl<$($F, $T,)+ Err> $StructName<$($F, $T,)+ Err>
+        where
+            $($F: Stream<Item = Result<$T, Err>>,)+
+        {
+            /// Drop the items which have been buffered, and mark their slots
+            /// as pending.
+            fn drop_initialized(
+                state: &mut PollArray<{ $mod_name::LEN }>,
+                output: &mut $mod_name::Output<$($T,)+>,
+            ) {
+                $(
+                    if state[$mod_name::$F].is_ready() {
+                        // SAFETY: we've just filtered down to *only* the initialized values.
+                        unsafe { output.$F.assume_init_drop() };
+                    }
+                )+
+                state.set_all_pending();
+            }
+        }
+
+        impl<$($F, $T,)+ Err> Stream for $StructName<$($F, $T,)+ Err>
+        where
+            $($F: Stream<Item = Result<$T, Err>>,)+
+        {
+            type Item = Result<($($T,)+), Err>;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                const LEN: usize = $mod_name::LEN;
+
+                if *this.done {
+                    return Poll::Ready(None);
+                }
+
+                let mut readiness = this.wakers.readiness();
+                readiness.set_waker(cx.waker());
+
+                for index in 0..LEN {
+                    if !readiness.any_ready() {
+                        // Nothing is ready yet
+                        return Poll::Pending;
+                    } else if this.state[index].is_ready() || !readiness.clear_ready(index) {
+                        // We already have data stored for this stream,
+                        // Or this waker isn't ready yet
+                        continue;
+                    }
+
+                    // unlock readiness so we don't deadlock when polling
+                    #[allow(clippy::drop_non_drop)]
+                    drop(readiness);
+
+                    // Obtain the intermediate waker.
+                    let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+
+                    let all_ready = match index {
+                        $(
+                            $mod_name::$F => {
+                                let stream = unsafe { Pin::new_unchecked(&mut this.$F) };
+
+                                match stream.poll_next(&mut cx) {
+                                    Poll::Pending => false,
+                                    Poll::Ready(None) => {
+                                        // If one stream returns `None`, we can no longer return
+                                        // rows - meaning the stream is over.
+                                        Self::drop_initialized(this.state, this.output);
+                                        *this.done = true;
+                                        return Poll::Ready(None);
+                                    }
+                                    Poll::Ready(Some(Err(err))) => {
+                                        // Discard the items we've buffered for the current
+                                        // row, and end the stream after yielding the error.
+                                        Self::drop_initialized(this.state, this.output);
+                                        *this.done = true;
+                                        return Poll::Ready(Some(Err(err)));
+                                    }
+                                    Poll::Ready(Some(Ok(item))) => {
+                                        this.output.$F = MaybeUninit::new(item);
+                                        this.state[$mod_name::$F].set_ready();
+
+                                        this.state.iter().all(|state| state.is_ready())
+                                    }
+                                }
+                            },
+                        )+
+                        _ => unreachable!(),
+                    };
+
+                    if all_ready {
+                        // Reset the stream's state.
+                        readiness = this.wakers.readiness();
+                        readiness.set_all_ready();
+                        this.state.set_all_pending();
+
+                        // Take the output
+                        //
+                        // SAFETY: we just validated all our data is populated, meaning
+                        // we can assume this is initialized.
+                        let mut output = $mod_name::Output::default();
+                        core::mem::swap(this.output, &mut output);
+
+                        match output {
+                            $mod_name::Output {
+                                $($F,)+
+                            } => return Poll::Ready(Some(Ok((
+                                $(unsafe { $F.assume_init() },)+
+                            ))))
+                        }
+                    }
+
+                    // Lock readiness so we can use it again
+                    readiness = this.wakers.readiness();
+                }
+
+                Poll::Pending
+            }
+        }
+
+        impl<$($F, $T,)+ Err> TryZip for ($($F,)+)
+        where
+            $($F: IntoStream<Item = Result<$T, Err>>,)+
+        {
+            type Item = ($($T,)+);
+            type Error = Err;
+            type Stream = $StructName<$($F::IntoStream, $T,)+ Err>;
+
+            fn try_zip(self) -> Self::Stream {
+                let ($($F,)*): ($($F,)*) = self;
+                $StructName {
+                    done: false,
+                    output: Default::default(),
+                    state: PollArray::new_pending(),
+                    wakers: WakerArray::new(),
+                    $($F: $F.into_stream(),)+
+                    _phantom: PhantomData,
+                }
+            }
+        }
+
+        #[pin_project::pinned_drop]
+        impl<$($F, $T,)+ Err> PinnedDrop for $StructName<$($F, $T,)+ Err>
+        where
+            $($F: Stream<Item = Result<$T, Err>>,)+
+        {
+            fn drop(self: Pin<&mut Self>) {
+                let this = self.project();
+                Self::drop_initialized(this.state, this.output);
+            }
+        }
+    };
+}
+
+impl_try_zip_for_tuple! { try_zip_1 TryZip1 (A ResA) }
+impl_try_zip_for_tuple! { try_zip_2 TryZip2 (A ResA) (B ResB) }
+impl_try_zip_for_tuple! { try_zip_3 TryZip3 (A ResA) (B ResB) (C ResC) }
+impl_try_zip_for_tuple! { try_zip_4 TryZip4 (A ResA) (B ResB) (C ResC) (D ResD) }
+impl_try_zip_for_tuple! { try_zip_5 TryZip5 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) }
+impl_try_zip_for_tuple! { try_zip_6 TryZip6 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) }
+impl_try_zip_for_tuple! { try_zip_7 TryZip7 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) }
+impl_try_zip_for_tuple! { try_zip_8 TryZip8 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) }
+impl_try_zip_for_tuple! { try_zip_9 TryZip9 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) }
+impl_try_zip_for_tuple! { try_zip_10 TryZip10 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) }
+impl_try_zip_for_tuple! { try_zip_11 TryZip11 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) }
+impl_try_zip_for_tuple! { try_zip_12 TryZip12 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) }
+#[cfg(feature = "large-tuples")]
+impl_try_zip_for_tuple! { try_zip_13 TryZip13 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) }
+#[cfg(feature = "large-tuples")]
+impl_try_zip_for_tuple! { try_zip_14 TryZip14 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) }
+#[cfg(feature = "large-tuples")]
+impl_try_zip_for_tuple! { try_zip_15 TryZip15 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) }
+#[cfg(feature = "large-tuples")]
+impl_try_zip_for_tuple! { try_zip_16 TryZip16 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) }
+
+#[cfg(test)]
+mod tests {
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    use crate::stream::TryZip;
+
+    #[test]
+    fn try_zip_tuple_3() {
+        block_on(async {
+            let a = stream::iter([Ok::<_, ()>(1), Ok(2)]);
+            let b = stream::iter([Ok("a"), Ok("b")]);
+            let c = stream::iter([Ok(true), Ok(false)]);
+            let out: Vec<_> = (a, b, c).try_zip().collect().await;
+            assert_eq!(out, vec![Ok((1, "a", true)), Ok((2, "b", false))]);
+        })
+    }
+
+    #[test]
+    fn try_zip_tuple_err() {
+        block_on(async {
+            let a = stream::iter([Ok(1), Ok(2), Ok(3)]);
+            let b = stream::iter([Ok("a"), Err("oh no"), Ok("c")]);
+            let out: Vec<_> = (a, b).try_zip().collect().await;
+            assert_eq!(out, vec![Ok((1, "a")), Err("oh no")]);
+        })
+    }
+
+    #[cfg(feature = "large-tuples")]
+    #[test]
+    fn try_zip_tuple_16() {
+        block_on(async {
+            let s = |n| stream::once(if n == 15 { Err("oh no") } else { Ok(n) });
+            let mut s = (
+                s(0),
+                s(1),
+                s(2),
+                s(3),
+                s(4),
+                s(5),
+                s(6),
+                s(7),
+                s(8),
+                s(9),
+                s(10),
+                s(11),
+                s(12),
+                s(13),
+                s(14),
+                s(15),
+            )
+                .try_zip();
+            assert!(matches!(s.next().await, Some(Err("oh no"))));
+            assert!(s.next().await.is_none());
+        })
+    }
+}
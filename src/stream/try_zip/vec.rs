@@ -0,0 +1,231 @@
+use super::TryZip as TryZipTrait;
+use crate::stream::IntoStream;
+use crate::utils::{OutputVec, PinnedVec, PollVec, WakerVec};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::{pin_project, pinned_drop};
+
+/// A stream that ‘zips up’ multiple fallible streams into a single stream of
+/// rows, or aborts early on error.
+///
+/// This `struct` is created by the [`try_zip`] method on the [`TryZip`] trait. See its
+/// documentation for more.
+///
+/// [`try_zip`]: crate::stream::TryZip::try_zip
+/// [`TryZip`]: crate::stream::TryZip
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project(PinnedDrop)]
+pub struct TryZip<S, T, E>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    streams: PinnedVec<S>,
+    output: OutputVec<T>,
+    wakers: WakerVec,
+    state: PollVec,
+    done: bool,
+    len: usize,
+}
+
+impl<S, T, E> TryZip<S, T, E>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    pub(crate) fn new(streams: Vec<S>) -> Self {
+        let len = streams.len();
+        Self {
+            len,
+            streams: PinnedVec::new(streams),
+            wakers: WakerVec::new(len),
+            output: OutputVec::uninit(len),
+            state: PollVec::new_pending(len),
+            done: false,
+        }
+    }
+}
+
+impl<S, T, E> fmt::Debug for TryZip<S, T, E>
+where
+    S: Stream<Item = Result<T, E>> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.streams.iter()).finish()
+    }
+}
+
+impl<S, T, E> Stream for TryZip<S, T, E>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    type Item = Result<Vec<T>, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        let mut readiness = this.wakers.readiness();
+        readiness.set_waker(cx.waker());
+        for index in 0..*this.len {
+            if !readiness.any_ready() {
+                // Nothing is ready yet
+                return Poll::Pending;
+            } else if this.state[index].is_ready() || !readiness.clear_ready(index) {
+                // We already have data stored for this stream,
+                // Or this waker isn't ready yet
+                continue;
+            }
+
+            // unlock readiness so we don't deadlock when polling
+            #[allow(clippy::drop_non_drop)]
+            drop(readiness);
+
+            // Obtain the intermediate waker.
+            let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+
+            let stream = this.streams.get_pin_mut(index).unwrap();
+            match stream.poll_next(&mut cx) {
+                Poll::Ready(Some(Ok(item))) => {
+                    this.output.write(index, item);
+                    this.state[index].set_ready();
+
+                    let all_ready = this.state.iter().all(|state| state.is_ready());
+                    if all_ready {
+                        // Reset the stream's state.
+                        readiness = this.wakers.readiness();
+                        readiness.set_all_ready();
+                        this.state.set_all_pending();
+
+                        // Take the output
+                        //
+                        // SAFETY: we just validated all our data is populated, meaning
+                        // we can assume this is initialized.
+                        let mut output = OutputVec::uninit(*this.len);
+                        mem::swap(this.output, &mut output);
+                        let output = unsafe { output.take() };
+                        return Poll::Ready(Some(Ok(output)));
+                    }
+                }
+                Poll::Ready(Some(Err(err))) => {
+                    // Discard the items we've buffered for the current row,
+                    // and end the stream after yielding the error.
+                    drop_initialized(this.state, this.output);
+                    *this.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+                Poll::Ready(None) => {
+                    // If one stream returns `None`, we can no longer return
+                    // rows - meaning the stream is over.
+                    drop_initialized(this.state, this.output);
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => {}
+            }
+
+            // Lock readiness so we can use it again
+            readiness = this.wakers.readiness();
+        }
+        Poll::Pending
+    }
+}
+
+/// Drop the items which have been buffered, and mark their slots as pending.
+fn drop_initialized<T>(state: &mut PollVec, output: &mut OutputVec<T>) {
+    for index in state.ready_indexes() {
+        // SAFETY: we've just filtered down to *only* the initialized values.
+        unsafe { output.drop(index) };
+    }
+    state.set_all_pending();
+}
+
+/// Drop the already initialized values on cancellation.
+#[pinned_drop]
+impl<S, T, E> PinnedDrop for TryZip<S, T, E>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    fn drop(self: Pin<&mut Self>) {
+        let this = self.project();
+        drop_initialized(this.state, this.output);
+    }
+}
+
+impl<S, T, E> TryZipTrait for Vec<S>
+where
+    S: IntoStream<Item = Result<T, E>>,
+{
+    type Item = Vec<T>;
+    type Error = E;
+    type Stream = TryZip<S::IntoStream, T, E>;
+
+    fn try_zip(self) -> Self::Stream {
+        TryZip::new(self.into_iter().map(|i| i.into_stream()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::rc::Rc;
+    use alloc::vec;
+
+    use crate::stream::TryZip;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn try_zip_vec_3() {
+        block_on(async {
+            let a = stream::iter([Ok::<_, ()>(1), Ok(4)]);
+            let b = stream::iter([Ok(2), Ok(5)]);
+            let c = stream::iter([Ok(3), Ok(6)]);
+            let out: Vec<_> = vec![a, b, c].try_zip().collect().await;
+            assert_eq!(out, vec![Ok(vec![1, 2, 3]), Ok(vec![4, 5, 6])]);
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn err_discards_buffered_items() {
+        use crate::test_utils::{ScriptedStream, Step};
+
+        block_on(async {
+            let item = Rc::new(());
+            let (a, _) = ScriptedStream::new([Step::Ready(Ok(item.clone())), Step::WakeLater]);
+            let (b, b_handle) =
+                ScriptedStream::new([Step::Pending, Step::Ready(Err("oh no")), Step::WakeLater]);
+            let mut s = vec![a, b].try_zip();
+
+            // `b` errors while `a` is waiting for its next item.
+            assert_eq!(s.next().await, Some(Err("oh no")));
+            assert_eq!(Rc::strong_count(&item), 1);
+            assert_eq!(s.next().await, None);
+            assert_eq!(b_handle.polls(), 2);
+        })
+    }
+
+    #[test]
+    fn end_with_buffered_items() {
+        block_on(async {
+            let item = Rc::new(());
+            let a = stream::iter(vec![Ok::<_, ()>(item.clone())]);
+            let b = stream::iter(vec![]);
+            let mut s = vec![a, b].try_zip();
+
+            // `a` yields an item, but `b` ends before the row is complete.
+            assert_eq!(s.next().await, None);
+            assert_eq!(Rc::strong_count(&item), 1);
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use super::Zip as ZipTrait;
+use super::{PendingSlots, Zip as ZipTrait, ZipWith};
 use crate::stream::IntoStream;
 use crate::utils::{self, PollArray, WakerArray};
 
@@ -44,6 +44,38 @@ where
             done: false,
         }
     }
+
+    /// Returns the indexes of the streams which haven't yielded an item for
+    /// the current row yet.
+    ///
+    /// Streams which have already yielded their item for the row are not
+    /// polled again until the row is complete, so a stream which stays in
+    /// this list while the others don't is the one holding the zip back.
+    pub fn pending_slots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.state.pending_indexes()
+    }
+
+    /// Pass the items of each row to `f`, and yield its output instead of
+    /// the row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let s = [stream::iter([1, 2]), stream::iter([3, 4])].zip();
+    /// let s = s.with(|[a, b]| a * b);
+    /// assert_eq!(s.collect::<Vec<_>>().await, [3, 8]);
+    /// # });
+    /// ```
+    pub fn with<F, U>(self, f: F) -> ZipWith<Self, F>
+    where
+        F: FnMut([S::Item; N]) -> U,
+    {
+        ZipWith::new(self, f)
+    }
 }
 
 impl<S, const N: usize> fmt::Debug for Zip<S, N>
@@ -51,7 +83,10 @@ where
     S: Stream + fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.streams.iter()).finish()
+        f.debug_struct("Zip")
+            .field("streams", &self.streams)
+            .field("pending_slots", &PendingSlots(&self.state))
+            .finish()
     }
 }
 
@@ -124,6 +159,23 @@ where
     }
 }
 
+impl<S, F, U, const N: usize> Stream for ZipWith<Zip<S, N>, F>
+where
+    S: Stream,
+    F: FnMut([S::Item; N]) -> U,
+{
+    type Item = U;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.zip.poll_next(cx).map(|row| row.map(this.f))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.zip.size_hint()
+    }
+}
+
 /// Drop the already initialized values on cancellation.
 #[pinned_drop]
 impl<S, const N: usize> PinnedDrop for Zip<S, N>
@@ -143,6 +195,22 @@ where
     }
 }
 
+#[cfg(feature = "unstable-async-iter")]
+impl<S, const N: usize> core::async_iter::AsyncIterator for Zip<S, N>
+where
+    S: Stream,
+{
+    type Item = [S::Item; N];
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Stream::poll_next(self, cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Stream::size_hint(self)
+    }
+}
+
 impl<S, const N: usize> ZipTrait for [S; N]
 where
     S: IntoStream,
@@ -189,4 +257,34 @@ mod tests {
             assert_eq!(s.next().await, None);
         })
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pending_slots() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+        use core::task::Poll;
+
+        let (a, a_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, _) = ScriptedStream::new([Step::Ready(2)]);
+        let (c, c_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(3)]);
+        let mut s = pin!([a, b, c].zip());
+        let waker = CountingWaker::new();
+
+        // Both stalled slots are reported, in index order.
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert!(s.pending_slots().eq([0, 2]));
+        assert!(format!("{s:?}").contains("pending_slots: [0, 2]"));
+
+        // Waking one of them only fills its own slot.
+        assert!(c_handle.wake());
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert!(s.pending_slots().eq([0]));
+
+        assert!(a_handle.wake());
+        assert_eq!(
+            step_stream(s.as_mut(), &waker),
+            Poll::Ready(Some([1, 2, 3]))
+        );
+    }
 }
@@ -1,9 +1,16 @@
+use crate::utils::PollState;
+
+use core::fmt;
+
 use futures_core::Stream;
 
+pub use with::ZipWith;
+
 pub(crate) mod array;
 pub(crate) mod tuple;
 #[cfg(feature = "alloc")]
 pub(crate) mod vec;
+mod with;
 
 /// ‘Zips up’ multiple streams into a single stream of pairs.
 pub trait Zip {
@@ -16,3 +23,18 @@ pub trait Zip {
     /// Combine multiple streams into a single stream.
     fn zip(self) -> Self::Stream;
 }
+
+/// Formats the indexes of the slots of a zip which are still awaiting an
+/// item for the current row.
+pub(crate) struct PendingSlots<'a>(pub(crate) &'a [PollState]);
+
+impl fmt::Debug for PendingSlots<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pending = self
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(_, state)| state.is_pending());
+        f.debug_list().entries(pending.map(|(i, _)| i)).finish()
+    }
+}
@@ -5,7 +5,7 @@ use core::task::{Context, Poll};
 
 use futures_core::Stream;
 
-use super::Zip;
+use super::{PendingSlots, Zip, ZipWith};
 use crate::utils::{PollArray, WakerArray};
 
 macro_rules! impl_zip_for_tuple {
@@ -54,13 +54,38 @@ macro_rules! impl_zip_for_tuple {
 
         }
 
+        impl<$($F,)+> $StructName<$($F,)+>
+        where
+            $($F: Stream,)+
+        {
+            /// Returns the indexes of the streams which haven't yielded an item for
+            /// the current row yet.
+            ///
+            /// Streams which have already yielded their item for the row are not
+            /// polled again until the row is complete, so a stream which stays in
+            /// this list while the others don't is the one holding the zip back.
+            pub fn pending_slots(&self) -> impl Iterator<Item = usize> + '_ {
+                self.state.pending_indexes()
+            }
+
+            /// Pass the items of each row to `f` as separate arguments, and
+            /// yield its output instead of the row.
+            pub fn with<Func, U>(self, f: Func) -> ZipWith<Self, Func>
+            where
+                Func: FnMut($($F::Item),+) -> U,
+            {
+                ZipWith::new(self, f)
+            }
+        }
+
         impl<$($F,)+> fmt::Debug for $StructName<$($F,)+>
         where
             $($F: Stream + fmt::Debug,)+
         {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.debug_tuple("Zip")
-                    $(.field(&self.$F))+
+                f.debug_struct("Zip")
+                    .field("streams", &[$(&self.$F as &dyn fmt::Debug,)+])
+                    .field("pending_slots", &PendingSlots(&self.state))
                     .finish()
             }
         }
@@ -155,6 +180,44 @@ macro_rules! impl_zip_for_tuple {
             }
         }
 
+        impl<$($F,)+ Func, U> Stream for ZipWith<$StructName<$($F,)+>, Func>
+        where
+            $($F: Stream,)+
+            Func: FnMut($($F::Item),+) -> U,
+        {
+            type Item = U;
+
+            #[allow(non_snake_case)]
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let this = self.project();
+                this.zip.poll_next(cx).map(|row| {
+                    row.map(|($($F,)+)| (this.f)($($F),+))
+                })
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.zip.size_hint()
+            }
+        }
+
+        #[cfg(feature = "unstable-async-iter")]
+        impl<$($F,)+> core::async_iter::AsyncIterator for $StructName<$($F,)+>
+        where
+            $($F: Stream,)+
+        {
+            type Item = (
+                $(<$F as Stream>::Item,)+
+            );
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                Stream::poll_next(self, cx)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                Stream::size_hint(self)
+            }
+        }
+
         impl<$($F,)+> Zip for ($($F,)+)
         where
             $($F: Stream,)+
@@ -208,6 +271,14 @@ impl_zip_for_tuple! { zip_9 Zip9 A B C D E F G H I }
 impl_zip_for_tuple! { zip_10 Zip10 A B C D E F G H I J }
 impl_zip_for_tuple! { zip_11 Zip11 A B C D E F G H I J K }
 impl_zip_for_tuple! { zip_12 Zip12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_13 Zip13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_14 Zip14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_15 Zip15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_16 Zip16 A B C D E F G H I J K L M N O P }
 
 #[cfg(test)]
 mod tests {
@@ -230,4 +301,71 @@ mod tests {
             assert_eq!(s.next().await, None);
         })
     }
+
+    #[cfg(feature = "large-tuples")]
+    #[test]
+    fn zip_tuple_16() {
+        block_on(async {
+            let s = stream::once;
+            let mut s = Zip::zip((
+                s(0),
+                s(1),
+                s(2),
+                s(3),
+                s(4),
+                s(5),
+                s(6),
+                s(7),
+                s(8),
+                s(9),
+                s(10),
+                s(11),
+                s(12),
+                s(13),
+                s(14),
+                s(15),
+            ));
+
+            // Tuples of 16 members don't implement `PartialEq`.
+            let (a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p) = s.next().await.unwrap();
+            assert_eq!((a, b, c, d, e, f, g, h), (0, 1, 2, 3, 4, 5, 6, 7));
+            assert_eq!((i, j, k, l, m, n, o, p), (8, 9, 10, 11, 12, 13, 14, 15));
+            assert!(s.next().await.is_none());
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pending_slots() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+        use core::task::Poll;
+
+        // The members may have different item types.
+        let (a, _) = ScriptedStream::new([Step::Ready(1), Step::Ready(3)]);
+        let (b, b_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready("b")]);
+        let mut s = pin!((a, b).zip());
+        let waker = CountingWaker::new();
+        assert!(s.pending_slots().eq([0, 1]));
+
+        // `a` has filled its slot, while `b` is stalled.
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert!(s.pending_slots().eq([1]));
+        assert!(format!("{s:?}").contains("pending_slots: [1]"));
+
+        assert!(b_handle.wake());
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some((1, "b"))));
+        assert!(s.pending_slots().eq([0, 1]));
+    }
+
+    #[test]
+    fn with() {
+        block_on(async {
+            let a = stream::iter([1, 2]);
+            let b = stream::iter([10, 20]);
+            let c = stream::iter([100, 200]);
+            let s = (a, b, c).zip().with(|a, b, c| a + b + c);
+            assert_eq!(s.collect::<Vec<_>>().await, [111, 222]);
+        })
+    }
 }
@@ -1,6 +1,7 @@
-use super::Zip as ZipTrait;
+use super::{PendingSlots, Zip as ZipTrait, ZipWith};
 use crate::stream::IntoStream;
-use crate::utils::{self, PollVec, WakerVec};
+use crate::utils::{PinnedVec, PollVec, WakerVec};
+use alloc::vec::Drain;
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 
@@ -8,7 +9,7 @@ use core::fmt;
 use core::mem;
 use core::mem::MaybeUninit;
 use core::pin::Pin;
-use core::task::{Context, Poll};
+use core::task::{ready, Context, Poll};
 
 use futures_core::Stream;
 use pin_project::{pin_project, pinned_drop};
@@ -25,9 +26,11 @@ pub struct Zip<S>
 where
     S: Stream,
 {
-    #[pin]
-    streams: Vec<S>,
+    streams: PinnedVec<S>,
     output: Vec<MaybeUninit<<S as Stream>::Item>>,
+    /// The row passed to the closure of `zip_with`, which is only allocated
+    /// once and reused for every row.
+    row: Vec<S::Item>,
     wakers: WakerVec,
     state: PollVec,
     done: bool,
@@ -42,13 +45,50 @@ where
         let len = streams.len();
         Self {
             len,
-            streams,
+            streams: PinnedVec::new(streams),
             wakers: WakerVec::new(len),
             output: (0..len).map(|_| MaybeUninit::uninit()).collect(),
+            row: Vec::new(),
             state: PollVec::new_pending(len),
             done: false,
         }
     }
+
+    /// Returns the indexes of the streams which haven't yielded an item for
+    /// the current row yet.
+    ///
+    /// Streams which have already yielded their item for the row are not
+    /// polled again until the row is complete, so a stream which stays in
+    /// this list while the others don't is the one holding the zip back.
+    pub fn pending_slots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.state.pending_indexes()
+    }
+
+    /// Pass the items of each row to `f`, and yield its output instead of
+    /// the row.
+    ///
+    /// `f` receives a [`Drain`] over the row. The row is stored in a buffer
+    /// which is reused for every row, so unlike yielding each row as a new
+    /// `Vec`, this only allocates once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let streams = vec![stream::iter([1, 2]), stream::iter([3, 4]), stream::iter([5, 6])];
+    /// let s = streams.zip().with(|row| row.sum::<i32>());
+    /// assert_eq!(s.collect::<Vec<_>>().await, [9, 12]);
+    /// # });
+    /// ```
+    pub fn with<F, U>(self, f: F) -> ZipWith<Self, F>
+    where
+        F: FnMut(Drain<'_, S::Item>) -> U,
+    {
+        ZipWith::new(self, f)
+    }
 }
 
 impl<S> fmt::Debug for Zip<S>
@@ -56,18 +96,25 @@ where
     S: Stream + fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.streams.iter()).finish()
+        f.debug_struct("Zip")
+            .field("streams", &self.streams)
+            .field("pending_slots", &PendingSlots(&self.state))
+            .finish()
     }
 }
 
-impl<S> Stream for Zip<S>
+impl<S> Zip<S>
 where
     S: Stream,
 {
-    type Item = Vec<S::Item>;
-
-    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let mut this = self.project();
+    /// Poll the streams until each of them has yielded an item for the
+    /// current row.
+    ///
+    /// Once this returns `Poll::Ready(Some(()))`, `output` holds the items
+    /// of the row, and the state has been reset for the next row. The caller
+    /// must take the items out of `output` before polling again.
+    fn poll_row(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        let this = self.project();
 
         assert!(!*this.done, "Stream should not be polled after completion");
 
@@ -90,7 +137,7 @@ where
             // Obtain the intermediate waker.
             let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
 
-            let stream = utils::get_pin_mut_from_vec(this.streams.as_mut(), index).unwrap();
+            let stream = this.streams.get_pin_mut(index).unwrap();
             match stream.poll_next(&mut cx) {
                 Poll::Ready(Some(item)) => {
                     this.output[index] = MaybeUninit::new(item);
@@ -102,15 +149,7 @@ where
                         readiness = this.wakers.readiness();
                         readiness.set_all_ready();
                         this.state.set_all_pending();
-
-                        // Take the output
-                        //
-                        // SAFETY: we just validated all our data is populated, meaning
-                        // we can assume this is initialized.
-                        let mut output = (0..*this.len).map(|_| MaybeUninit::uninit()).collect();
-                        mem::swap(this.output, &mut output);
-                        let output = unsafe { vec_assume_init(output) };
-                        return Poll::Ready(Some(output));
+                        return Poll::Ready(Some(()));
                     }
                 }
                 Poll::Ready(None) => {
@@ -129,6 +168,62 @@ where
     }
 }
 
+impl<S> Stream for Zip<S>
+where
+    S: Stream,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if ready!(self.as_mut().poll_row(cx)).is_none() {
+            return Poll::Ready(None);
+        }
+        let this = self.project();
+
+        // Take the output
+        //
+        // SAFETY: `poll_row` just validated all our data is populated,
+        // meaning we can assume this is initialized.
+        let mut output = (0..*this.len).map(|_| MaybeUninit::uninit()).collect();
+        mem::swap(this.output, &mut output);
+        let output = unsafe { vec_assume_init(output) };
+        Poll::Ready(Some(output))
+    }
+}
+
+impl<S, F, U> Stream for ZipWith<Zip<S>, F>
+where
+    S: Stream,
+    F: FnMut(Drain<'_, S::Item>) -> U,
+{
+    type Item = U;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if ready!(this.zip.as_mut().poll_row(cx)).is_none() {
+            return Poll::Ready(None);
+        }
+        let zip = this.zip.project();
+
+        // Move the items into the row, which only allocates for the first
+        // row. Items which the closure doesn't consume are dropped by the
+        // `Drain`.
+        //
+        // SAFETY: `poll_row` just validated all our data is populated, and
+        // the state has been reset, so every item is read exactly once.
+        zip.row.extend(
+            zip.output
+                .iter()
+                .map(|item| unsafe { item.assume_init_read() }),
+        );
+        Poll::Ready(Some((this.f)(zip.row.drain(..))))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.zip.size_hint()
+    }
+}
+
 /// Drop the already initialized values on cancellation.
 #[pinned_drop]
 impl<S> PinnedDrop for Zip<S>
@@ -148,6 +243,22 @@ where
     }
 }
 
+#[cfg(feature = "unstable-async-iter")]
+impl<S> core::async_iter::AsyncIterator for Zip<S>
+where
+    S: Stream,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Stream::poll_next(self, cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Stream::size_hint(self)
+    }
+}
+
 impl<S> ZipTrait for Vec<S>
 where
     S: IntoStream,
@@ -195,4 +306,58 @@ mod tests {
             assert_eq!(s.next().await, None);
         })
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pending_slots() {
+        use crate::test_utils::{step_stream, CountingWaker, ScriptedStream, Step};
+        use core::pin::pin;
+        use core::task::Poll;
+
+        // A single stalled stream is found among many members.
+        let mut handles = Vec::new();
+        let streams: Vec<_> = (0..20)
+            .map(|i| {
+                let script = match i {
+                    13 => vec![Step::WakeLater, Step::Ready(i)],
+                    _ => vec![Step::Ready(i)],
+                };
+                let (stream, handle) = ScriptedStream::new(script);
+                handles.push(handle);
+                stream
+            })
+            .collect();
+        let mut s = pin!(streams.zip());
+        let waker = CountingWaker::new();
+        assert_eq!(s.pending_slots().count(), 20);
+
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+        assert!(s.pending_slots().eq([13]));
+        assert!(format!("{s:?}").contains("pending_slots: [13]"));
+
+        assert!(handles[13].wake());
+        let row = (0..20).collect::<Vec<_>>();
+        assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(row)));
+        assert!(s.pending_slots().eq(0..20));
+    }
+
+    #[test]
+    fn with_drops_unconsumed_items() {
+        use alloc::rc::Rc;
+
+        block_on(async {
+            let item = Rc::new(());
+            let streams = vec![stream::repeat(item.clone()).take(2); 3];
+            // Only the first item of each row is consumed, the others are
+            // dropped right away: only the streams and `first` hold a clone.
+            let mut s = streams.zip().with(|mut row| row.next());
+            let first = s.next().await.flatten();
+            assert!(first.is_some());
+            assert_eq!(Rc::strong_count(&item), 1 + 3 + 1);
+            assert!(s.next().await.is_some());
+            assert!(s.next().await.is_none());
+            drop((s, first));
+            assert_eq!(Rc::strong_count(&item), 1);
+        })
+    }
 }
@@ -0,0 +1,24 @@
+use pin_project::pin_project;
+
+/// A stream which zips multiple streams, and passes the items of each row to
+/// a closure.
+///
+/// This `struct` is created by the `with` method on the streams returned by
+/// [`Zip::zip`], such as [`vec::Zip::with`]. See its documentation for more.
+///
+/// [`Zip::zip`]: crate::stream::Zip::zip
+/// [`vec::Zip::with`]: crate::vec::Zip::with
+#[derive(Debug, Clone)]
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct ZipWith<Z, F> {
+    #[pin]
+    pub(crate) zip: Z,
+    pub(crate) f: F,
+}
+
+impl<Z, F> ZipWith<Z, F> {
+    pub(crate) fn new(zip: Z, f: F) -> Self {
+        Self { zip, f }
+    }
+}
@@ -0,0 +1,371 @@
+//! Scripted futures and streams for testing concurrency operations.
+//!
+//! This module is only available with the `test-utils` feature flag, and is
+//! not covered by the semver guarantees of this crate.
+//!
+//! # Example
+//!
+//! ```
+//! use futures_concurrency::prelude::*;
+//! use futures_concurrency::test_utils::{step, CountingWaker, ScriptedFuture};
+//! use std::pin::pin;
+//! use std::task::Poll;
+//!
+//! let (a, a_handle) = ScriptedFuture::new([Poll::Pending, Poll::Ready(1)]);
+//! let (b, _) = ScriptedFuture::new([Poll::Ready(2)]);
+//! let mut fut = pin!((a, b).join());
+//!
+//! let waker = CountingWaker::new();
+//! assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+//! assert_eq!(waker.wakes(), 1);
+//! assert_eq!(step(fut.as_mut(), &waker), Poll::Ready((1, 2)));
+//! assert_eq!(a_handle.polls(), 2);
+//! ```
+
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::task::{Context, Poll, Waker};
+use std::sync::Mutex;
+
+use futures_core::Stream;
+
+#[cfg(any(test, feature = "interleaving-harness"))]
+pub mod harness;
+
+/// A single step in the script of a [`ScriptedFuture`] or [`ScriptedStream`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step<T> {
+    /// Resolve with the value.
+    Ready(T),
+    /// Return `Pending`, and wake the task right away.
+    Pending,
+    /// Return `Pending` without waking the task. The task is only woken once
+    /// [`ScriptHandle::wake`] is called.
+    WakeLater,
+}
+
+impl<T> From<Poll<T>> for Step<T> {
+    fn from(poll: Poll<T>) -> Self {
+        match poll {
+            Poll::Ready(value) => Step::Ready(value),
+            Poll::Pending => Step::Pending,
+        }
+    }
+}
+
+/// A handle to observe and wake a scripted future or stream after it has been
+/// moved into a concurrency operation.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptHandle {
+    shared: Arc<Shared>,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    polls: AtomicUsize,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl ScriptHandle {
+    /// Returns how many times the future or stream has been polled.
+    pub fn polls(&self) -> usize {
+        self.shared.polls.load(Ordering::SeqCst)
+    }
+
+    /// Wake the task which received the last [`Step::WakeLater`]. Returns
+    /// whether there was a task to wake.
+    pub fn wake(&self) -> bool {
+        match self.shared.waker.lock().unwrap().take() {
+            Some(waker) => {
+                waker.wake();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Count a poll, and perform the step.
+    fn step<T>(&self, step: Step<T>, cx: &Context<'_>) -> Poll<T> {
+        self.shared.polls.fetch_add(1, Ordering::SeqCst);
+        match step {
+            Step::Ready(value) => Poll::Ready(value),
+            Step::Pending => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            Step::WakeLater => {
+                *self.shared.waker.lock().unwrap() = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// A future which follows a script of steps, one step per poll.
+///
+/// # Panics
+///
+/// Polling the future after it has resolved, or after its script has run
+/// out, panics. See [`ScriptedFuture::resolve_repeatedly`] to resolve again
+/// instead.
+///
+/// A clone continues from the same step of the script, and shares the
+/// handle of the future it was cloned from.
+#[derive(Debug, Clone)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ScriptedFuture<T> {
+    script: VecDeque<Step<T>>,
+    handle: ScriptHandle,
+    repeat: Option<fn(&T) -> T>,
+}
+
+impl<T> ScriptedFuture<T> {
+    /// Create a new scripted future, along with a handle to observe it.
+    ///
+    /// The script accepts both [`Step`]s and `Poll`s.
+    pub fn new<I>(script: I) -> (Self, ScriptHandle)
+    where
+        I: IntoIterator,
+        I::Item: Into<Step<T>>,
+    {
+        let handle = ScriptHandle::default();
+        let script = script.into_iter().map(Into::into).collect();
+        let this = Self {
+            script,
+            handle: handle.clone(),
+            repeat: None,
+        };
+        (this, handle)
+    }
+}
+
+impl<T: Clone> ScriptedFuture<T> {
+    /// Resolve with the same value again every time the future is polled
+    /// after it has resolved.
+    ///
+    /// This mimics a misbehaving future which doesn't track whether it has
+    /// completed.
+    pub fn resolve_repeatedly(mut self) -> Self {
+        self.repeat = Some(T::clone);
+        self
+    }
+}
+
+impl<T> Unpin for ScriptedFuture<T> {}
+
+impl<T> Future for ScriptedFuture<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let step = self
+            .script
+            .pop_front()
+            .expect("`ScriptedFuture` polled after its script ran out");
+        let poll = self.handle.step(step, cx);
+        if let Poll::Ready(value) = &poll {
+            // Any steps after the first `Ready` are never reached.
+            self.script.clear();
+            if let Some(repeat) = self.repeat {
+                let step = Step::Ready(repeat(value));
+                self.script.push_back(step);
+            }
+        }
+        poll
+    }
+}
+
+/// A stream which follows a script of steps, one step per poll.
+///
+/// The stream yields an item for every [`Step::Ready`], and ends once its
+/// script has run out.
+///
+/// A clone continues from the same step of the script, and shares the
+/// handle of the stream it was cloned from.
+#[derive(Debug, Clone)]
+#[must_use = "streams do nothing unless polled or .awaited"]
+pub struct ScriptedStream<T> {
+    script: VecDeque<Step<T>>,
+    handle: ScriptHandle,
+    wake_on_end: bool,
+}
+
+impl<T> ScriptedStream<T> {
+    /// Create a new scripted stream, along with a handle to observe it.
+    ///
+    /// The script accepts both [`Step`]s and `Poll`s.
+    pub fn new<I>(script: I) -> (Self, ScriptHandle)
+    where
+        I: IntoIterator,
+        I::Item: Into<Step<T>>,
+    {
+        let handle = ScriptHandle::default();
+        let script = script.into_iter().map(Into::into).collect();
+        let this = Self {
+            script,
+            handle: handle.clone(),
+            wake_on_end: false,
+        };
+        (this, handle)
+    }
+
+    /// Wake the task every time the stream returns `Ready(None)`.
+    ///
+    /// This mimics a misbehaving stream which holds on to a stale waker after
+    /// it has ended.
+    pub fn wake_on_end(mut self) -> Self {
+        self.wake_on_end = true;
+        self
+    }
+}
+
+impl<T> Unpin for ScriptedStream<T> {}
+
+impl<T> Stream for ScriptedStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        match self.script.pop_front() {
+            Some(step) => self.handle.step(step, cx).map(Some),
+            None => {
+                self.handle.shared.polls.fetch_add(1, Ordering::SeqCst);
+                if self.wake_on_end {
+                    cx.waker().wake_by_ref();
+                }
+                Poll::Ready(None)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let ready = self
+            .script
+            .iter()
+            .filter(|step| matches!(step, Step::Ready(_)))
+            .count();
+        (ready, Some(ready))
+    }
+}
+
+/// A waker which counts how often it has been woken.
+#[derive(Debug, Clone)]
+pub struct CountingWaker {
+    count: Arc<Counter>,
+    waker: Waker,
+}
+
+#[derive(Debug, Default)]
+struct Counter(AtomicUsize);
+
+impl Wake for Counter {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl CountingWaker {
+    /// Create a new waker, which hasn't been woken yet.
+    pub fn new() -> Self {
+        let count = Arc::new(Counter::default());
+        let waker = Waker::from(count.clone());
+        Self { count, waker }
+    }
+
+    /// Returns how often the waker, or any of its clones, has been woken.
+    pub fn wakes(&self) -> usize {
+        self.count.0.load(Ordering::SeqCst)
+    }
+
+    /// Returns how many clones of the waker are currently alive, not
+    /// counting the waker owned by `self`.
+    pub fn clones(&self) -> usize {
+        // One reference is held by `self.count`, and one by `self.waker`.
+        Arc::strong_count(&self.count) - 2
+    }
+
+    /// Returns the underlying `Waker`.
+    pub fn waker(&self) -> &Waker {
+        &self.waker
+    }
+}
+
+impl Default for CountingWaker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Poll a future exactly once, using `waker` as its waker.
+///
+/// Unlike an executor, this never polls the future again by itself, which
+/// makes the order of polls fully deterministic.
+pub fn step<F: Future + ?Sized>(fut: Pin<&mut F>, waker: &CountingWaker) -> Poll<F::Output> {
+    fut.poll(&mut Context::from_waker(waker.waker()))
+}
+
+/// Poll a stream for its next item exactly once, using `waker` as its waker.
+///
+/// See [`step`] for more.
+pub fn step_stream<S: Stream + ?Sized>(
+    stream: Pin<&mut S>,
+    waker: &CountingWaker,
+) -> Poll<Option<S::Item>> {
+    stream.poll_next(&mut Context::from_waker(waker.waker()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::pin::pin;
+
+    #[test]
+    fn scripted_future() {
+        let (fut, handle) = ScriptedFuture::new([Step::Pending, Step::WakeLater, Step::Ready(1)]);
+        let mut fut = pin!(fut);
+        let waker = CountingWaker::new();
+
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert_eq!(waker.wakes(), 1);
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert_eq!(waker.wakes(), 1);
+        assert!(handle.wake());
+        assert_eq!(waker.wakes(), 2);
+        assert!(!handle.wake());
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(1));
+        assert_eq!(handle.polls(), 3);
+    }
+
+    #[test]
+    fn resolve_repeatedly() {
+        let (fut, handle) = ScriptedFuture::new([Step::Pending, Step::Ready(1)]);
+        let mut fut = pin!(fut.resolve_repeatedly());
+        let waker = CountingWaker::new();
+
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(1));
+        assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(1));
+        assert_eq!(handle.polls(), 3);
+    }
+
+    #[test]
+    fn scripted_stream() {
+        let (stream, handle) = ScriptedStream::new([Poll::Ready(1), Poll::Pending, Poll::Ready(2)]);
+        let mut stream = pin!(stream);
+        let waker = CountingWaker::new();
+
+        assert_eq!(stream.size_hint(), (2, Some(2)));
+        assert_eq!(step_stream(stream.as_mut(), &waker), Poll::Ready(Some(1)));
+        assert_eq!(step_stream(stream.as_mut(), &waker), Poll::Pending);
+        assert_eq!(step_stream(stream.as_mut(), &waker), Poll::Ready(Some(2)));
+        assert_eq!(step_stream(stream.as_mut(), &waker), Poll::Ready(None));
+        assert_eq!(handle.polls(), 4);
+        assert_eq!(waker.wakes(), 1);
+    }
+}
@@ -0,0 +1,512 @@
+//! A deterministic executor which drives concurrency operations through
+//! random interleavings of wakes.
+//!
+//! This module is only available with the `interleaving-harness` feature flag,
+//! and is not covered by the semver guarantees of this crate.
+//!
+//! A [`Harness`] hands out members which only make progress when the harness
+//! releases their next event, and then drives the operation built from them.
+//! Between polls it releases events of random members, wakes members without
+//! making them ready, polls the operation without it having been woken, and
+//! replaces the waker the operation is polled with. Every run is determined
+//! by its seed, so a failing seed reproduces the same interleaving.
+//!
+//! The harness panics if:
+//!
+//! - a member is polled after it has completed,
+//! - the operation stops making progress while all members are ready, which
+//!   means a wake was lost or delivered to a stale waker,
+//! - the operation doesn't complete within [`Harness::max_steps`] steps, or
+//! - a stream yields an item which none of its members produced, or doesn't
+//!   yield an item which one of its members produced.
+//!
+//! # Example
+//!
+//! ```
+//! use futures_concurrency::prelude::*;
+//! use futures_concurrency::test_utils::harness::Harness;
+//!
+//! for seed in 0..64 {
+//!     let report = Harness::new(seed).run_future(vec![1, 2, 3], |futures| futures.join());
+//!     assert_eq!(report.output, [1, 2, 3]);
+//!     assert!(report.finished.iter().all(|finished| *finished));
+//! }
+//! ```
+
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::pin::{pin, Pin};
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use futures_core::Stream;
+
+/// A deterministic executor which drives an operation through a random
+/// interleaving of wakes.
+///
+/// See the [module documentation](self) for more.
+#[derive(Debug)]
+pub struct Harness {
+    seed: u64,
+    rng: Rng,
+    max_steps: usize,
+}
+
+/// The outcome of a run of the [`Harness`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report<T> {
+    /// The output of the future, or the items of the stream in the order
+    /// they were yielded.
+    pub output: T,
+    /// Whether each member had completed when the operation completed.
+    pub finished: Vec<bool>,
+    /// How many times the operation was polled.
+    pub polls: usize,
+}
+
+impl Harness {
+    /// Create a new harness, whose interleaving is determined by `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: Rng::new(seed),
+            max_steps: 10_000,
+        }
+    }
+
+    /// Set how many steps the operation may take before it's considered not
+    /// to terminate. Defaults to 10 000.
+    pub fn max_steps(mut self, max: usize) -> Self {
+        self.max_steps = max;
+        self
+    }
+
+    /// Returns the seed of the harness.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns a random number in `0..n`, determined by the seed.
+    ///
+    /// This can be used to derive the inputs of a run from the same seed.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `n` is zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        self.rng.below(n)
+    }
+
+    /// Drive the future built from one member per output, until it
+    /// completes.
+    ///
+    /// Each member resolves to its output once the harness releases it.
+    pub fn run_future<T, F, B>(&mut self, outputs: Vec<T>, build: B) -> Report<F::Output>
+    where
+        F: Future,
+        B: FnOnce(Vec<HarnessFuture<T>>) -> F,
+    {
+        let scripts: Vec<_> = outputs.into_iter().map(|output| [output]).collect();
+        let (members, shared) = self.members(scripts, false, |events| HarnessFuture { events });
+        let mut fut = pin!(build(members));
+
+        let mut output = None;
+        let polls = self.drive(&shared, |cx| match fut.as_mut().poll(cx) {
+            Poll::Ready(out) => {
+                output = Some(out);
+                true
+            }
+            Poll::Pending => false,
+        });
+        Report {
+            output: output.unwrap(),
+            finished: finished(&shared),
+            polls,
+        }
+    }
+
+    /// Drive the stream built from one member per list of items, until it
+    /// ends.
+    ///
+    /// Each member yields its items one by one as the harness releases them,
+    /// after which it ends once that is released as well. Every item which a
+    /// member yielded must be yielded by the stream exactly once.
+    pub fn run_stream<T, S, B>(&mut self, items: Vec<Vec<T>>, build: B) -> Report<Vec<T>>
+    where
+        T: Clone + PartialEq + fmt::Debug,
+        S: Stream<Item = T>,
+        B: FnOnce(Vec<HarnessStream<T>>) -> S,
+    {
+        let expected = items.clone();
+        let (members, shared) = self.members(items, true, |events| HarnessStream { events });
+        let mut stream = pin!(build(members));
+
+        let mut output = Vec::new();
+        let polls = self.drive(&shared, |cx| loop {
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => output.push(item),
+                Poll::Ready(None) => return true,
+                Poll::Pending => return false,
+            }
+        });
+
+        // Every item which was produced has been yielded exactly once.
+        let mut produced: Vec<_> = shared
+            .iter()
+            .zip(&expected)
+            .flat_map(|(shared, items)| &items[..lock(shared).produced.min(items.len())])
+            .collect();
+        for item in &output {
+            let Some(index) = produced.iter().position(|produced| *produced == item) else {
+                panic!(
+                    "the stream yielded {item:?}, which wasn't produced by any of its members (seed {})",
+                    self.seed
+                );
+            };
+            produced.swap_remove(index);
+        }
+        assert!(
+            produced.is_empty(),
+            "the stream never yielded {produced:?}, which its members produced (seed {})",
+            self.seed
+        );
+
+        Report {
+            output,
+            finished: finished(&shared),
+            polls,
+        }
+    }
+
+    /// Create the members for the given scripts, which end after their last
+    /// item has been handed out if `end` is set. Some members are randomly
+    /// made ready before they're first polled.
+    fn members<T, M, I>(
+        &mut self,
+        scripts: Vec<I>,
+        end: bool,
+        wrap: impl Fn(Events<T>) -> M,
+    ) -> (Vec<M>, Vec<Arc<Mutex<Member>>>)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut members = Vec::with_capacity(scripts.len());
+        let mut shared = Vec::with_capacity(scripts.len());
+        for (index, script) in scripts.into_iter().enumerate() {
+            let events: VecDeque<T> = script.into_iter().collect();
+            let member = Arc::new(Mutex::new(Member {
+                index,
+                seed: self.seed,
+                unreleased: events.len() + usize::from(end),
+                released: 0,
+                produced: 0,
+                finished: false,
+                waker: None,
+            }));
+            if self.rng.below(4) == 0 {
+                release(&member);
+            }
+            members.push(wrap(Events {
+                items: events,
+                shared: member.clone(),
+            }));
+            shared.push(member);
+        }
+        (members, shared)
+    }
+
+    /// Run the executor until `poll` reports that the operation completed,
+    /// returning the number of polls.
+    fn drive(
+        &mut self,
+        members: &[Arc<Mutex<Member>>],
+        mut poll: impl FnMut(&mut Context<'_>) -> bool,
+    ) -> usize {
+        let mut parent = Arc::new(Flag::default());
+        // The operation is woken when it's created.
+        parent.0.store(true, Ordering::SeqCst);
+        let mut polls = 0;
+
+        for _ in 0..self.max_steps {
+            let releasable: Vec<_> = members
+                .iter()
+                .filter(|member| lock(member).unreleased > 0)
+                .collect();
+            let woken = parent.0.load(Ordering::SeqCst);
+            if releasable.is_empty() && !woken {
+                panic!(
+                    "lost wake: every member is ready, but the operation wasn't woken (seed {})",
+                    self.seed
+                );
+            }
+
+            match self.rng.below(8) {
+                // Release the next event of a member.
+                0..=2 if !releasable.is_empty() => {
+                    release(releasable[self.rng.below(releasable.len())]);
+                }
+                // Wake a member without making it ready.
+                3 if !members.is_empty() => {
+                    let member = &members[self.rng.below(members.len())];
+                    let waker = lock(member).waker.clone();
+                    if let Some(waker) = waker {
+                        waker.wake();
+                    }
+                }
+                // Poll the operation, either because it was woken, or
+                // spuriously.
+                action if woken || action == 4 => {
+                    if self.rng.below(4) == 0 {
+                        // The task has moved, and is polled with a new waker.
+                        // Wakes of the previous waker are no longer seen.
+                        parent = Arc::new(Flag::default());
+                    }
+                    parent.0.store(false, Ordering::SeqCst);
+                    let waker = Waker::from(parent.clone());
+                    polls += 1;
+                    if poll(&mut Context::from_waker(&waker)) {
+                        return polls;
+                    }
+                }
+                _ => {}
+            }
+        }
+        panic!(
+            "the operation didn't complete within {} steps (seed {})",
+            self.max_steps, self.seed
+        );
+    }
+}
+
+/// A future member of a [`Harness`] run.
+///
+/// This `struct` is created by [`Harness::run_future`]. See its
+/// documentation for more.
+#[derive(Debug)]
+pub struct HarnessFuture<T> {
+    events: Events<T>,
+}
+
+impl<T> Unpin for HarnessFuture<T> {}
+
+impl<T> Future for HarnessFuture<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let item = core::task::ready!(self.events.poll_next(cx));
+        // A future completes with its single output.
+        lock(&self.events.shared).finished = true;
+        Poll::Ready(item.expect("a future member always has an output"))
+    }
+}
+
+/// A stream member of a [`Harness`] run.
+///
+/// This `struct` is created by [`Harness::run_stream`]. See its
+/// documentation for more.
+#[derive(Debug)]
+pub struct HarnessStream<T> {
+    events: Events<T>,
+}
+
+impl<T> Unpin for HarnessStream<T> {}
+
+impl<T> Stream for HarnessStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.events.poll_next(cx)
+    }
+}
+
+/// The items of a member, which are handed out as they're released.
+#[derive(Debug)]
+struct Events<T> {
+    items: VecDeque<T>,
+    shared: Arc<Mutex<Member>>,
+}
+
+impl<T> Events<T> {
+    /// Returns the next released item, or `None` once the end has been
+    /// released after the last item.
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut member = lock(&self.shared);
+        assert!(
+            !member.finished,
+            "member {} was polled after it completed (seed {})",
+            member.index, member.seed
+        );
+        if member.released == 0 {
+            member.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        member.released -= 1;
+        let item = self.items.pop_front();
+        match item {
+            Some(_) => member.produced += 1,
+            None => member.finished = true,
+        }
+        Poll::Ready(item)
+    }
+}
+
+/// The state of a member which is shared with the harness.
+#[derive(Debug)]
+struct Member {
+    index: usize,
+    seed: u64,
+    /// The number of events which haven't been released yet, including the
+    /// end of a stream.
+    unreleased: usize,
+    /// The number of events which have been released, but not handed out.
+    released: usize,
+    /// The number of items which have been handed out.
+    produced: usize,
+    finished: bool,
+    waker: Option<Waker>,
+}
+
+fn lock(member: &Mutex<Member>) -> MutexGuard<'_, Member> {
+    member.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Release the next event of a member, and wake it if it's waiting.
+fn release(member: &Mutex<Member>) {
+    let waker = {
+        let mut member = lock(member);
+        member.unreleased -= 1;
+        member.released += 1;
+        member.waker.take()
+    };
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+fn finished(members: &[Arc<Mutex<Member>>]) -> Vec<bool> {
+    members.iter().map(|member| lock(member).finished).collect()
+}
+
+/// The parent waker, which records whether it has been woken.
+#[derive(Debug, Default)]
+struct Flag(AtomicBool);
+
+impl Wake for Flag {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+/// A small deterministic random number generator (xorshift64*).
+#[derive(Debug)]
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // Scramble the seed with splitmix64, so that adjacent seeds diverge
+        // right away, and the state is never zero.
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        Self((z ^ (z >> 31)) | 1)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "cannot pick a number below zero");
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        let n = n as u64;
+        (self.0.wrapping_mul(0x2545_F491_4F6C_DD1D) % n) as usize
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::stream::Merge;
+    use alloc::vec;
+    use core::future::poll_fn;
+    use core::task::ready;
+
+    /// A broken join of two futures, which polls its futures with a waker
+    /// that never wakes the task.
+    struct LosesWakes<F>(Option<F>, Option<F>);
+
+    impl<F: Future + Unpin> Future for LosesWakes<F> {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+            let waker = Waker::from(Arc::new(Flag::default()));
+            let mut cx = Context::from_waker(&waker);
+            let this = &mut *self;
+            for slot in [&mut this.0, &mut this.1] {
+                if let Some(fut) = slot {
+                    if Pin::new(fut).poll(&mut cx).is_ready() {
+                        *slot = None;
+                    }
+                }
+            }
+            match (&self.0, &self.1) {
+                (None, None) => Poll::Ready(()),
+                _ => Poll::Pending,
+            }
+        }
+    }
+
+    /// A broken future, which polls its inner future again after it has
+    /// completed.
+    struct PollsTwice<F>(F);
+
+    impl<F: Future + Unpin> Future for PollsTwice<F> {
+        type Output = F::Output;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+            let out = ready!(Pin::new(&mut self.0).poll(cx));
+            let _ = Pin::new(&mut self.0).poll(cx);
+            Poll::Ready(out)
+        }
+    }
+
+    #[test]
+    fn deterministic() {
+        let run = |seed| Harness::new(seed).run_stream(vec![vec![1, 2], vec![3]], Merge::merge);
+        assert_eq!(run(7), run(7));
+        assert_eq!(Harness::new(7).below(1000), Harness::new(7).below(1000));
+    }
+
+    #[test]
+    #[should_panic(expected = "lost wake")]
+    fn detects_lost_wakes() {
+        for seed in 0..64 {
+            Harness::new(seed).run_future(vec![1, 2], |mut futures| {
+                let b = futures.pop();
+                let a = futures.pop();
+                LosesWakes(a, b)
+            });
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "was polled after it completed")]
+    fn detects_poll_after_completion() {
+        Harness::new(0).run_future(vec![1], |mut futures| PollsTwice(futures.pop().unwrap()));
+    }
+
+    #[test]
+    #[should_panic(expected = "didn't complete within 10 steps")]
+    fn detects_non_termination() {
+        // The future keeps waking itself, but never completes.
+        Harness::new(0).max_steps(10).run_future(vec![1], |_| {
+            poll_fn(|cx| {
+                cx.waker().wake_by_ref();
+                Poll::<()>::Pending
+            })
+        });
+    }
+}
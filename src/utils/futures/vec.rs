@@ -1,36 +1,30 @@
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 
-use core::{
-    mem::{self, ManuallyDrop, MaybeUninit},
-    pin::Pin,
-};
+use core::{mem::ManuallyDrop, pin::Pin};
+
+use crate::utils::PinnedVec;
 
 /// An array of futures which can be dropped in-place, intended to be
 /// constructed once and then accessed through pin projections.
 pub(crate) struct FutureVec<T> {
-    futures: Vec<ManuallyDrop<T>>,
+    futures: PinnedVec<ManuallyDrop<T>>,
 }
 
 impl<T> FutureVec<T> {
     /// Create a new instance of `FutureVec`
     pub(crate) fn new(futures: Vec<T>) -> Self {
-        // Implementation copied from: https://doc.rust-lang.org/src/core/mem/maybe_uninit.rs.html#1292
-        let futures = MaybeUninit::new(futures);
-        // SAFETY: T and MaybeUninit<T> have the same layout
-        let futures = unsafe { mem::transmute_copy(&mem::ManuallyDrop::new(futures)) };
-        Self { futures }
+        // NOTE: `ManuallyDrop<T>` has the same layout as `T`, so this reuses
+        // the existing allocation.
+        let futures = futures.into_iter().map(ManuallyDrop::new).collect();
+        Self {
+            futures: PinnedVec::new(futures),
+        }
     }
 
     /// Create an iterator of pinned references.
     pub(crate) fn iter(self: Pin<&mut Self>) -> impl Iterator<Item = Pin<&mut ManuallyDrop<T>>> {
-        // SAFETY: `std` _could_ make this unsound if it were to decide Pin's
-        // invariants aren't required to transmit through slices. Otherwise this has
-        // the same safety as a normal field pin projection.
-        unsafe { self.get_unchecked_mut() }
-            .futures
-            .iter_mut()
-            .map(|t| unsafe { Pin::new_unchecked(t) })
+        self.get_mut().futures.iter_pin_mut()
     }
 
     /// Drop a future at the given index.
@@ -38,10 +32,22 @@ impl<T> FutureVec<T> {
     /// # Safety
     ///
     /// The future is held in a `ManuallyDrop`, so no double-dropping, etc
-    pub(crate) unsafe fn drop(mut self: Pin<&mut Self>, idx: usize) {
-        unsafe {
-            let futures = self.as_mut().get_unchecked_mut().futures.as_mut_slice();
-            ManuallyDrop::drop(&mut futures[idx]);
-        };
+    pub(crate) unsafe fn drop(self: Pin<&mut Self>, idx: usize) {
+        let future = self.get_mut().futures.get_pin_mut(idx).unwrap();
+        unsafe { ManuallyDrop::drop(future.get_unchecked_mut()) };
+    }
+
+    /// Return the emptied allocation of the futures, to be reused.
+    ///
+    /// # Safety
+    ///
+    /// All of the futures must have been dropped.
+    pub(crate) unsafe fn into_empty_vec(self) -> Vec<T> {
+        let mut futures = ManuallyDrop::new(unsafe { self.futures.into_inner() });
+        futures.clear();
+        let (ptr, capacity) = (futures.as_mut_ptr(), futures.capacity());
+        // SAFETY: `ManuallyDrop<T>` has the same layout as `T`, and the
+        // vector is empty.
+        unsafe { Vec::from_raw_parts(ptr.cast::<T>(), 0, capacity) }
     }
 }
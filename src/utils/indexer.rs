@@ -2,6 +2,11 @@ use core::ops;
 
 /// Generate an iteration sequence. This provides *fair* iteration when multiple
 /// futures need to be polled concurrently.
+///
+/// The sequence is a deterministic round-robin: the first call starts at `0`,
+/// and every subsequent call starts one position further along. No source of
+/// entropy is involved, so the polling order is reproducible across runs and
+/// targets.
 pub(crate) struct Indexer {
     offset: usize,
     max: usize,
@@ -17,7 +22,9 @@ impl Indexer {
     pub(crate) fn iter(&mut self) -> IndexIter {
         // Increment the starting point for next time.
         let offset = self.offset;
-        self.offset = (self.offset + 1).wrapping_rem(self.max);
+        if self.max != 0 {
+            self.offset = (self.offset + 1) % self.max;
+        }
 
         IndexIter {
             iter: (0..self.max),
@@ -40,3 +47,124 @@ impl Iterator for IndexIter {
             .map(|pos| (pos + self.offset).wrapping_rem(self.iter.end))
     }
 }
+
+/// Tracks how many items in a row were yielded by the same member, so that
+/// the number of consecutive items can be capped.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Streak {
+    max: Option<usize>,
+    last: usize,
+    len: usize,
+}
+
+impl Streak {
+    /// Set the maximum number of consecutive items per member.
+    pub(crate) fn set_max(&mut self, max: usize) {
+        assert!(max > 0, "the number of consecutive items must be non-zero");
+        self.max = Some(max);
+    }
+
+    /// Returns the maximum number of consecutive items per member, if set.
+    pub(crate) fn max(&self) -> Option<usize> {
+        self.max
+    }
+
+    /// Record an item yielded by the member at `index`.
+    pub(crate) fn record(&mut self, index: usize) {
+        if self.len > 0 && self.last == index {
+            self.len = self.len.saturating_add(1);
+        } else {
+            self.last = index;
+            self.len = 1;
+        }
+    }
+
+    /// Returns the member which has reached the maximum number of consecutive
+    /// items, if any. It should only be polled after all other members.
+    pub(crate) fn throttled(&self) -> Option<usize> {
+        match self.max {
+            Some(max) if self.len >= max => Some(self.last),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rotates_starting_point() {
+        let mut indexer = Indexer::new(3);
+        assert_eq!(indexer.iter().collect::<Vec<_>>(), [0, 1, 2]);
+        assert_eq!(indexer.iter().collect::<Vec<_>>(), [1, 2, 0]);
+        assert_eq!(indexer.iter().collect::<Vec<_>>(), [2, 0, 1]);
+        assert_eq!(indexer.iter().collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn visits_every_index_once() {
+        for max in 1..8 {
+            let mut indexer = Indexer::new(max);
+            for _ in 0..3 * max {
+                let mut seen: Vec<_> = indexer.iter().collect();
+                seen.sort_unstable();
+                assert_eq!(seen, (0..max).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn wraps_around_from_start() {
+        let mut indexer = Indexer::new(5);
+        for _ in 0..10 {
+            let order: Vec<_> = indexer.iter().collect();
+            for pair in order.windows(2) {
+                assert_eq!(pair[1], (pair[0] + 1) % 5);
+            }
+        }
+    }
+
+    #[test]
+    fn uniform_start() {
+        let mut indexer = Indexer::new(4);
+        let mut starts = [0; 4];
+        for _ in 0..4 * 25 {
+            starts[indexer.iter().next().unwrap()] += 1;
+        }
+        assert_eq!(starts, [25; 4]);
+    }
+
+    #[test]
+    fn stable_within_iteration() {
+        // Starting the next iteration doesn't affect one in progress.
+        let mut indexer = Indexer::new(3);
+        let mut first = indexer.iter();
+        assert_eq!(first.next(), Some(0));
+        let second: Vec<_> = indexer.iter().collect();
+        assert_eq!(first.collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(second, [1, 2, 0]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut indexer = Indexer::new(0);
+        assert_eq!(indexer.iter().next(), None);
+        assert_eq!(indexer.iter().next(), None);
+    }
+
+    #[test]
+    fn streak() {
+        let mut streak = Streak::default();
+        streak.record(1);
+        streak.record(1);
+        assert_eq!(streak.throttled(), None);
+
+        streak.set_max(2);
+        assert_eq!(streak.throttled(), Some(1));
+        streak.record(0);
+        assert_eq!(streak.throttled(), None);
+        streak.record(0);
+        assert_eq!(streak.throttled(), Some(0));
+    }
+}
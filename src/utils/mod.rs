@@ -7,8 +7,10 @@ mod futures;
 mod indexer;
 mod output;
 mod pin;
+#[cfg(feature = "alloc")]
+mod pinned_vec;
 mod poll_state;
-mod stream;
+mod trace;
 mod tuple;
 mod wakers;
 
@@ -19,26 +21,22 @@ pub(crate) use self::futures::FutureArray;
 #[cfg(feature = "alloc")]
 pub(crate) use self::futures::FutureVec;
 pub(crate) use array::array_assume_init;
-pub(crate) use indexer::Indexer;
+pub(crate) use indexer::{Indexer, Streak};
 pub(crate) use output::OutputArray;
 #[cfg(feature = "alloc")]
 pub(crate) use output::OutputVec;
 pub(crate) use pin::{get_pin_mut, iter_pin_mut};
 #[cfg(feature = "alloc")]
-pub(crate) use pin::{get_pin_mut_from_vec, iter_pin_mut_vec};
-pub(crate) use poll_state::PollArray;
+pub(crate) use pinned_vec::PinnedVec;
 #[cfg(feature = "alloc")]
-pub(crate) use poll_state::{MaybeDone, PollState, PollVec};
-pub(crate) use tuple::{gen_conditions, tuple_len};
-pub(crate) use wakers::WakerArray;
+pub(crate) use poll_state::{MaybeDone, PollVec};
+pub(crate) use poll_state::{PollArray, PollState};
+pub(crate) use trace::Trace;
+pub(crate) use tuple::{gen_conditions, tuple_first, tuple_len};
+pub(crate) use wakers::WakerPair;
 #[cfg(feature = "alloc")]
 pub(crate) use wakers::WakerVec;
-
-#[cfg(all(test, feature = "alloc"))]
-pub(crate) use wakers::DummyWaker;
+pub(crate) use wakers::{Readiness, WakerArray, Wakers};
 
 #[cfg(all(test, feature = "alloc"))]
 pub(crate) mod channel;
-
-#[cfg(feature = "alloc")]
-pub(crate) use stream::{from_iter, FromIter};
@@ -0,0 +1,189 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::pin::Pin;
+use core::slice;
+
+/// A fixed-length sequence of heap-allocated values which can be accessed
+/// through pinned references.
+///
+/// The values live in a single heap allocation which is never resized, and
+/// there is no way to obtain an unpinned mutable reference to a value or to
+/// move one out. That means once a value has been handed out as `Pin<&mut T>`
+/// it stays at the same address until it is dropped in place, even when the
+/// `PinnedVec` itself is moved. Because of that, pinned access does not
+/// require the `PinnedVec` to be pinned.
+pub(crate) struct PinnedVec<T> {
+    // NOTE: this must never be grown, shrunk, reordered, or drained.
+    items: Vec<T>,
+    /// Whether any value has been handed out as `Pin<&mut T>`.
+    pinned: bool,
+}
+
+// Moving a `PinnedVec` never moves the values it holds.
+impl<T> Unpin for PinnedVec<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for PinnedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.items.iter()).finish()
+    }
+}
+
+impl<T> PinnedVec<T> {
+    /// Take ownership of the values in a `Vec`.
+    pub(crate) fn new(items: Vec<T>) -> Self {
+        Self {
+            items,
+            pinned: false,
+        }
+    }
+
+    /// Return the underlying `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The values which have been handed out as `Pin<&mut T>` must not be
+    /// moved out of the `Vec`.
+    pub(crate) unsafe fn into_inner(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Return the underlying `Vec` if none of the values have been handed out
+    /// as `Pin<&mut T>` yet, or `self` otherwise.
+    pub(crate) fn try_into_inner(self) -> Result<Vec<T>, Self> {
+        match self.pinned {
+            true => Err(self),
+            false => Ok(self.items),
+        }
+    }
+
+    /// Returns the number of values.
+    pub(crate) fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no values.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Iterate over shared references to the values.
+    pub(crate) fn iter(&self) -> slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Returns a pinned reference to the value at `index`, or `None` if the
+    /// index is out of bounds.
+    pub(crate) fn get_pin_mut(&mut self, index: usize) -> Option<Pin<&mut T>> {
+        // SAFETY: values are never moved out of the allocation, and the
+        // allocation is never resized. Values are dropped in place when the
+        // `Vec` is dropped.
+        self.pinned = true;
+        self.items
+            .get_mut(index)
+            .map(|item| unsafe { Pin::new_unchecked(item) })
+    }
+
+    /// Replace the value at `index`, returning the previous value.
+    ///
+    /// Values must be `Unpin` to be moved out, since they may have been
+    /// handed out as `Pin<&mut T>` before.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    pub(crate) fn replace(&mut self, index: usize, value: T) -> T
+    where
+        T: Unpin,
+    {
+        core::mem::replace(&mut self.items[index], value)
+    }
+
+    /// Iterate over pinned references to the values.
+    pub(crate) fn iter_pin_mut(&mut self) -> impl Iterator<Item = Pin<&mut T>> {
+        // SAFETY: see `get_pin_mut`.
+        self.pinned = true;
+        self.items
+            .iter_mut()
+            .map(|item| unsafe { Pin::new_unchecked(item) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use core::future::Future;
+    use core::marker::PhantomPinned;
+    use core::task::{Context, Poll};
+
+    use crate::test_utils::CountingWaker;
+    use alloc::boxed::Box;
+    use alloc::sync::Arc;
+
+    /// A future which asserts it hasn't moved between polls.
+    #[derive(Default)]
+    struct AddressCheck {
+        address: Option<usize>,
+        _pinned: PhantomPinned,
+    }
+
+    impl Future for AddressCheck {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+            let address = &*self as *const Self as usize;
+            // SAFETY: we don't move out of `self`.
+            let this = unsafe { self.get_unchecked_mut() };
+            match this.address {
+                Some(prev) => assert_eq!(prev, address, "future moved after pinning"),
+                None => this.address = Some(address),
+            }
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn addresses_are_stable() {
+        let waker = CountingWaker::new();
+        let mut cx = Context::from_waker(waker.waker());
+
+        let mut futures = PinnedVec::new((0..16).map(|_| AddressCheck::default()).collect());
+        for fut in futures.iter_pin_mut() {
+            assert!(fut.poll(&mut cx).is_pending());
+        }
+
+        // Moving the container must not move the values.
+        let mut futures = Box::new(futures);
+        for index in (0..16).rev() {
+            let fut = futures.get_pin_mut(index).unwrap();
+            assert!(fut.poll(&mut cx).is_pending());
+        }
+        assert!(futures.get_pin_mut(16).is_none());
+        assert_eq!(futures.len(), 16);
+    }
+
+    #[test]
+    fn into_inner_before_pinning() {
+        let waker = CountingWaker::new();
+        let mut cx = Context::from_waker(waker.waker());
+
+        // Nothing has been pinned yet, so the values may still be moved.
+        let futures = PinnedVec::new(vec![AddressCheck::default()]);
+        let mut futures = PinnedVec::new(futures.try_into_inner().ok().unwrap());
+
+        let fut = futures.get_pin_mut(0).unwrap();
+        assert!(fut.poll(&mut cx).is_pending());
+        assert!(futures.try_into_inner().is_err());
+    }
+
+    #[test]
+    fn drops_every_value() {
+        let counter = Arc::new(());
+        let futures = PinnedVec::new(vec![counter.clone(), counter.clone()]);
+        assert_eq!(Arc::strong_count(&counter), 3);
+        drop(futures);
+        assert_eq!(Arc::strong_count(&counter), 1);
+    }
+}
@@ -3,6 +3,8 @@ use core::mem;
 use core::pin::Pin;
 use core::task::{ready, Context, Poll};
 
+use crate::utils::private::TryOutput;
+
 /// A future that may have completed.
 #[derive(Debug)]
 pub(crate) enum MaybeDone<Fut: Future> {
@@ -24,39 +26,48 @@ impl<Fut: Future> MaybeDone<Fut> {
     }
 }
 
-impl<T, E, Fut> MaybeDone<Fut>
+impl<Fut> MaybeDone<Fut>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryOutput,
 {
     /// Attempt to take the `Ok(output)` of a `MaybeDone` without driving it towards completion.
     /// If the future is done but is an `Err(_)`, this will return `None`.
     #[inline]
-    pub(crate) fn take_ok(self: Pin<&mut Self>) -> Option<T> {
+    pub(crate) fn take_ok(self: Pin<&mut Self>) -> Option<<Fut::Output as TryOutput>::Ok> {
         let this = unsafe { self.get_unchecked_mut() };
-        match this {
-            MaybeDone::Done(Ok(_)) => {}
-            MaybeDone::Done(Err(_)) | MaybeDone::Future(_) | MaybeDone::Gone => return None,
+        if !matches!(this, MaybeDone::Done(_)) {
+            return None;
         }
-        if let MaybeDone::Done(Ok(output)) = mem::replace(this, MaybeDone::Gone) {
-            Some(output)
-        } else {
+        let MaybeDone::Done(output) = mem::replace(this, MaybeDone::Gone) else {
             unreachable!()
+        };
+        match output.into_result() {
+            Ok(output) => Some(output),
+            Err(err) => {
+                *this = MaybeDone::Done(TryOutput::from_error(err));
+                None
+            }
         }
     }
 
     /// Attempt to take the `Err(output)` of a `MaybeDone` without driving it towards completion.
     /// If the future is done but is an `Ok(_)`, this will return `None`.
     #[inline]
-    pub(crate) fn take_err(self: Pin<&mut Self>) -> Option<E> {
+    pub(crate) fn take_err(self: Pin<&mut Self>) -> Option<<Fut::Output as TryOutput>::Error> {
         let this = unsafe { self.get_unchecked_mut() };
-        match this {
-            MaybeDone::Done(Err(_)) => {}
-            MaybeDone::Done(Ok(_)) | MaybeDone::Future(_) | MaybeDone::Gone => return None,
+        if !matches!(this, MaybeDone::Done(_)) {
+            return None;
         }
-        if let MaybeDone::Done(Err(output)) = mem::replace(this, MaybeDone::Gone) {
-            Some(output)
-        } else {
+        let MaybeDone::Done(output) = mem::replace(this, MaybeDone::Gone) else {
             unreachable!()
+        };
+        match output.into_result() {
+            Err(err) => Some(err),
+            Ok(output) => {
+                *this = MaybeDone::Done(TryOutput::from_ok(output));
+                None
+            }
         }
     }
 }
@@ -48,7 +48,6 @@ impl PollVec {
     }
 
     /// Get an iterator of indexes of all items which are "pending".
-    #[allow(unused)]
     pub(crate) fn pending_indexes(&self) -> impl Iterator<Item = usize> + '_ {
         self.iter()
             .cloned()
@@ -82,7 +81,11 @@ impl PollVec {
 
     /// Resize the `PollVec`
     pub(crate) fn resize(&mut self, len: usize) {
-        self.0.resize_with(len, || PollState::None)
+        let shrink = len < self.0.len();
+        self.0.resize_with(len, || PollState::None);
+        if shrink {
+            self.0.shrink_to_fit();
+        }
     }
 }
 
@@ -154,9 +154,126 @@ impl<T, E> Try for Poll<Option<Result<T, E>>> {
     }
 }
 
+/// The output of a fallible future: either `Result<T, E>` or `Option<T>`.
+///
+/// The futures of `TryJoin` and `RaceOk` are generic over this, so they can
+/// be shared with `JoinSome` and `RaceSome`. `Result` is short-circuited on
+/// `Err(E)`, and `Option` on `None`, which is represented as `NoneError`. The
+/// output of the combinator is of the same kind as the output of the futures
+/// passed to it.
+///
+/// Implementing this trait is not permitted outside of `futures_concurrency`.
+pub trait TryOutput: Sized {
+    private_decl! {}
+
+    /// The value on success.
+    type Ok;
+
+    /// The value on failure.
+    type Error;
+
+    /// The same kind of output, with a different success value.
+    type WithOk<U>: TryOutput<Ok = U, Error = Self::Error>;
+
+    /// The same kind of output, with a different success value and an
+    /// aggregate of errors. For `Option` this remains an `Option`.
+    type WithErrors<U, A>;
+
+    fn into_result(self) -> Result<Self::Ok, Self::Error>;
+
+    fn from_ok(ok: Self::Ok) -> Self;
+
+    fn from_error(error: Self::Error) -> Self;
+
+    fn from_errors<U, A>(result: Result<U, A>) -> Self::WithErrors<U, A>;
+}
+
+impl<T, E> TryOutput for Result<T, E> {
+    private_impl! {}
+
+    type Ok = T;
+    type Error = E;
+    type WithOk<U> = Result<U, E>;
+    type WithErrors<U, A> = Result<U, A>;
+
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+
+    fn from_ok(ok: T) -> Self {
+        Ok(ok)
+    }
+
+    fn from_error(error: E) -> Self {
+        Err(error)
+    }
+
+    fn from_errors<U, A>(result: Result<U, A>) -> Self::WithErrors<U, A> {
+        result
+    }
+}
+
+impl<T> TryOutput for Option<T> {
+    private_impl! {}
+
+    type Ok = T;
+    type Error = NoneError;
+    type WithOk<U> = Option<U>;
+    type WithErrors<U, A> = Option<U>;
+
+    fn into_result(self) -> Result<T, NoneError> {
+        self.ok_or(NoneError)
+    }
+
+    fn from_ok(ok: T) -> Self {
+        Some(ok)
+    }
+
+    fn from_error(_: NoneError) -> Self {
+        None
+    }
+
+    fn from_errors<U, A>(result: Result<U, A>) -> Self::WithErrors<U, A> {
+        result.ok()
+    }
+}
+
+/// The error of a future which resolved to `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoneError;
+
 #[allow(missing_debug_implementations)]
 pub struct PrivateMarker;
 
+/// The error of a member of a nested `race_ok!`: either the error of one of
+/// the futures, or the aggregate error of a nested race.
+pub enum NestedError<E, A> {
+    Leaf(E),
+    Node(A),
+}
+
+/// `RaceOk` requires errors to implement `Debug`, but these errors are
+/// flattened before they reach the user.
+impl<E, A> core::fmt::Debug for NestedError<E, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("NestedError")
+    }
+}
+
+/// A merged stream which reports which of its members yielded each item.
+///
+/// Implementing this trait is not permitted outside of `futures_concurrency`.
+pub trait IndexedStream: futures_core::Stream {
+    private_decl! {}
+
+    /// Attempt to pull out the next item of the stream, along with the index
+    /// of the member which yielded it.
+    fn poll_next_indexed(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> Poll<Option<(usize, Self::Item)>>;
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! private_impl {
@@ -0,0 +1,339 @@
+//! Instrumentation of operations through `tracing`.
+
+/// The span of a single operation, and the events it emits for its members.
+///
+/// Without the `tracing` feature this is zero-sized, and all of its methods
+/// compile to nothing.
+#[derive(Debug, Clone)]
+pub(crate) struct Trace {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl Default for Trace {
+    /// A trace which isn't part of any span, for wakers which haven't been
+    /// polled yet.
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "tracing")]
+            span: tracing::Span::none(),
+        }
+    }
+}
+
+impl Trace {
+    /// Create the span of an operation `op` over `len` members.
+    #[inline]
+    pub(crate) fn new(op: &'static str, len: usize) -> Self {
+        #[cfg(not(feature = "tracing"))]
+        let _ = (op, len);
+        Self {
+            #[cfg(feature = "tracing")]
+            span: tracing::trace_span!(
+                "futures_concurrency",
+                op,
+                len,
+                name = tracing::field::Empty
+            ),
+        }
+    }
+
+    /// Record the user-assigned name of the operation on its span.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn set_name(&self, name: &str) {
+        self.span.record("name", name);
+    }
+
+    /// Share the span of `other`, unless it's already shared.
+    ///
+    /// This is used by the readiness of child wakers, which only learns
+    /// which operation it belongs to once it's polled.
+    #[inline]
+    pub(crate) fn follow(&mut self, other: &Trace) {
+        #[cfg(feature = "tracing")]
+        if self.span.id() != other.span.id() {
+            self.span = other.span.clone();
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = other;
+    }
+
+    /// The member at `index` was polled.
+    #[inline]
+    pub(crate) fn polled(&self, index: usize, ready: bool) {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(parent: &self.span, index, ready, "polled");
+        #[cfg(not(feature = "tracing"))]
+        let _ = (index, ready);
+    }
+
+    /// The member at `index` was woken while it wasn't ready yet.
+    #[inline]
+    pub(crate) fn woken(&self, index: usize) {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(parent: &self.span, index, "woken");
+        #[cfg(not(feature = "tracing"))]
+        let _ = index;
+    }
+
+    /// The member at `index` completed, as the `completed`th member.
+    #[inline]
+    pub(crate) fn completed(&self, index: usize, completed: usize) {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(parent: &self.span, index, completed, "completed");
+        #[cfg(not(feature = "tracing"))]
+        let _ = (index, completed);
+    }
+}
+
+/// A subscriber which captures events, for use in tests.
+#[cfg(all(test, feature = "tracing"))]
+pub(crate) mod capture {
+    use alloc::string::{String, ToString};
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+    use core::fmt::Debug;
+    use std::sync::Mutex;
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Run `f`, and return the events it emitted.
+    ///
+    /// Each event is formatted as the name of its span, or the operation if
+    /// the span isn't named, followed by the message and the fields of the
+    /// event.
+    pub(crate) fn capture(f: impl FnOnce()) -> Vec<String> {
+        let subscriber = Capture::default();
+        let events = subscriber.events.clone();
+        tracing::subscriber::with_default(subscriber, f);
+        let events = events.lock().unwrap();
+        events.clone()
+    }
+
+    /// Run `f`, and return the labels of the spans it left open.
+    pub(crate) fn open_spans(f: impl FnOnce()) -> Vec<String> {
+        let subscriber = Capture::default();
+        let spans = subscriber.spans.clone();
+        let refs = subscriber.refs.clone();
+        tracing::subscriber::with_default(subscriber, f);
+        let spans = spans.lock().unwrap();
+        let refs = refs.lock().unwrap();
+        spans
+            .iter()
+            .zip(refs.iter())
+            .filter(|(_, refs)| **refs != 0)
+            .map(|(label, _)| label.clone())
+            .collect()
+    }
+
+    #[derive(Default)]
+    struct Capture {
+        /// The label of each span, indexed by its id minus one.
+        spans: Arc<Mutex<Vec<String>>>,
+        /// The number of handles to each span, indexed like `spans`.
+        refs: Arc<Mutex<Vec<usize>>>,
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[derive(Default)]
+    struct Fields {
+        message: String,
+        fields: String,
+        op: Option<String>,
+        name: Option<String>,
+    }
+
+    impl Visit for Fields {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            match field.name() {
+                "op" => self.op = Some(value.to_string()),
+                "name" => self.name = Some(value.to_string()),
+                _ => self.record_debug(field, &value),
+            }
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            match field.name() {
+                "message" => self.message = alloc::format!("{value:?}"),
+                name => self.fields += &alloc::format!(" {name}={value:?}"),
+            }
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = Fields::default();
+            span.record(&mut fields);
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(fields.name.or(fields.op).unwrap_or_default());
+            self.refs.lock().unwrap().push(1);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn clone_span(&self, span: &Id) -> Id {
+            self.refs.lock().unwrap()[span.into_u64() as usize - 1] += 1;
+            span.clone()
+        }
+
+        fn try_close(&self, span: Id) -> bool {
+            let mut refs = self.refs.lock().unwrap();
+            let refs = &mut refs[span.into_u64() as usize - 1];
+            *refs -= 1;
+            *refs == 0
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut fields = Fields::default();
+            values.record(&mut fields);
+            if let Some(name) = fields.name {
+                self.spans.lock().unwrap()[span.into_u64() as usize - 1] = name;
+            }
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+            let spans = self.spans.lock().unwrap();
+            let span = event
+                .parent()
+                .map_or("", |id| spans[id.into_u64() as usize - 1].as_str());
+            self.events.lock().unwrap().push(alloc::format!(
+                "{span}: {}{}",
+                fields.message,
+                fields.fields
+            ));
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod test {
+    use super::capture::{capture, open_spans};
+    use crate::prelude::*;
+    use crate::test_utils::{
+        step, step_stream, CountingWaker, ScriptedFuture, ScriptedStream, Step,
+    };
+
+    use core::pin::pin;
+    use core::task::Poll;
+
+    #[test]
+    fn join_vec() {
+        let (a, a_handle) = ScriptedFuture::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(2)]);
+        let events = capture(|| {
+            let mut fut = pin!(vec![a, b].join().named("startup-join"));
+            let waker = CountingWaker::new();
+            assert_eq!(step(fut.as_mut(), &waker), Poll::Pending);
+            assert!(a_handle.wake());
+            assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(vec![1, 2]));
+        });
+        assert_eq!(
+            events,
+            [
+                "startup-join: polled index=0 ready=false",
+                "startup-join: polled index=1 ready=true",
+                "startup-join: completed index=1 completed=1",
+                "startup-join: woken index=0",
+                "startup-join: polled index=0 ready=true",
+                "startup-join: completed index=0 completed=2",
+            ]
+        );
+    }
+
+    #[test]
+    fn race_tuple() {
+        let (a, _) = ScriptedFuture::new([Step::WakeLater]);
+        let (b, _) = ScriptedFuture::new([Step::Ready(2)]);
+        let events = capture(|| {
+            let mut fut = pin!((a, b).race());
+            let waker = CountingWaker::new();
+            assert_eq!(step(fut.as_mut(), &waker), Poll::Ready(2));
+        });
+        assert_eq!(
+            events,
+            [
+                "race: polled index=0 ready=false",
+                "race: polled index=1 ready=true",
+                "race: completed index=1 completed=1",
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_pair() {
+        let (a, a_handle) = ScriptedStream::new([Step::WakeLater, Step::Ready(1)]);
+        let (b, _) = ScriptedStream::new(Vec::<Step<u8>>::new());
+        let events = capture(|| {
+            let mut s = pin!((a, b).merge().named("events"));
+            let waker = CountingWaker::new();
+            assert_eq!(step_stream(s.as_mut(), &waker), Poll::Pending);
+            assert!(a_handle.wake());
+            assert_eq!(step_stream(s.as_mut(), &waker), Poll::Ready(Some(1)));
+        });
+        assert_eq!(
+            events,
+            [
+                "events: polled index=0 ready=false",
+                "events: polled index=1 ready=true",
+                "events: completed index=1 completed=1",
+                "events: woken index=0",
+                "events: polled index=0 ready=true",
+            ]
+        );
+    }
+
+    /// Taking apart a join closes its span once the parts are dropped.
+    #[test]
+    fn join_vec_into_parts() {
+        let open = open_spans(|| {
+            let mut join = vec![core::future::ready(1), core::future::ready(2)].join();
+            let out = futures_lite::future::block_on(&mut join);
+            assert_eq!(out, [1, 2]);
+            drop(join.into_parts());
+        });
+        assert!(open.is_empty(), "spans left open: {open:?}");
+    }
+
+    /// Nested operations are traced in spans of their own.
+    #[test]
+    fn nested() {
+        let events = capture(|| {
+            futures_lite::future::block_on(async {
+                let inner = vec![core::future::ready(1)].race().named("inner");
+                (inner, core::future::pending()).race().await
+            });
+        });
+        assert_eq!(
+            events,
+            [
+                "inner: polled index=0 ready=true",
+                "inner: completed index=0 completed=1",
+                "race: polled index=0 ready=true",
+                "race: completed index=0 completed=1",
+            ]
+        );
+    }
+}
+
+#[cfg(all(test, not(feature = "tracing")))]
+mod test {
+    use super::Trace;
+
+    #[test]
+    fn zero_sized() {
+        assert_eq!(core::mem::size_of::<Trace>(), 0);
+    }
+}
@@ -1,12 +1,11 @@
-/// Generate the `match` conditions inside the main polling body. This macro
-/// chooses a random starting point on each call to the given method, making
-/// it "fair".
+/// Generate the `match` conditions inside the main polling body. The index to
+/// match on is provided by an [`Indexer`](crate::utils::Indexer), which rotates
+/// the starting point on each call to the given method, making it "fair".
 ///
-/// The way this algorithm works is: we generate a random number between 0 and
-/// the length of the tuple we have. This number determines which element we
-/// start with. All other cases are mapped as `r + index`, and after we have the
-/// first one, we'll sequentially iterate over all others. The starting point of
-/// the stream is random, but the iteration order of all others is not.
+/// The way this algorithm works is: the indexer yields every position between
+/// 0 and the length of the tuple exactly once, starting one position further
+/// along than it did on the previous call. The starting point is deterministic:
+/// given the same sequence of polls, the same member will be polled first.
 // NOTE(yosh): this macro monstrosity is needed so we can increment each `else
 // if` branch with + 1. When RFC 3086 becomes available to us, we can replace
 // this with `${index($F)}` to get the current iteration.
@@ -35,3 +34,12 @@ macro_rules! tuple_len {
     ($($F:ident,)*) => (0 $(+ crate::utils::tuple_len!(@count_one $F))*);
 }
 pub(crate) use tuple_len;
+
+/// Select the first member of a tuple, such as the member whose output
+/// decides the kind of output of a fallible combinator.
+macro_rules! tuple_first {
+    ($F:ident, $($rest:ident,)*) => {
+        $F
+    };
+}
+pub(crate) use tuple_first;
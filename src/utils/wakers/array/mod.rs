@@ -1,17 +1,17 @@
-#[cfg(not(feature = "std"))]
+#[cfg(not(any(feature = "std", feature = "critical-section")))]
 mod no_std;
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "critical-section"))]
 mod readiness_array;
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "critical-section"))]
 mod waker;
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "critical-section"))]
 mod waker_array;
 
-#[cfg(not(feature = "std"))]
+#[cfg(not(any(feature = "std", feature = "critical-section")))]
 pub(crate) use no_std::WakerArray;
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "critical-section"))]
 pub(crate) use readiness_array::ReadinessArray;
-#[cfg(feature = "std")]
-pub(crate) use waker::InlineWakerArray;
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "critical-section"))]
+pub(crate) use waker::SharedArray;
+#[cfg(any(feature = "std", feature = "critical-section"))]
 pub(crate) use waker_array::WakerArray;
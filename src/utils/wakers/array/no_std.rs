@@ -1,3 +1,5 @@
+use crate::utils::wakers::{Readiness, Wakers};
+use crate::utils::Trace;
 use core::ops::{Deref, DerefMut};
 use core::task::Waker;
 
@@ -11,6 +13,9 @@ impl<const N: usize> ReadinessArray<N> {
         Self { parent_waker: None }
     }
 
+    /// Wakes aren't traced without `std`.
+    pub(crate) fn set_trace(&mut self, _trace: &Trace) {}
+
     /// Returns the old ready state for this id
     pub(crate) fn set_ready(&mut self, _id: usize) -> bool {
         false
@@ -37,8 +42,12 @@ impl<const N: usize> ReadinessArray<N> {
 
     /// Set the parent `Waker`. This needs to be called at the start of every
     /// `poll` function.
+    ///
+    /// The stored waker is only replaced if it wouldn't wake the same task,
+    /// which avoids cloning the waker on every poll.
     pub(crate) fn set_waker(&mut self, parent_waker: &Waker) {
         match &mut self.parent_waker {
+            Some(prev) if prev.will_wake(parent_waker) => {}
             Some(prev) => prev.clone_from(parent_waker),
             None => self.parent_waker = Some(parent_waker.clone()),
         }
@@ -86,3 +95,38 @@ impl<const N: usize> WakerArray<N> {
         }
     }
 }
+
+impl<const N: usize> Readiness for ReadinessArray<N> {
+    fn set_waker(&mut self, parent_waker: &Waker) {
+        ReadinessArray::set_waker(self, parent_waker)
+    }
+
+    fn set_trace(&mut self, trace: &Trace) {
+        ReadinessArray::set_trace(self, trace)
+    }
+
+    fn set_ready(&mut self, index: usize) -> bool {
+        ReadinessArray::set_ready(self, index)
+    }
+
+    fn clear_ready(&mut self, index: usize) -> bool {
+        ReadinessArray::clear_ready(self, index)
+    }
+
+    fn any_ready(&self) -> bool {
+        ReadinessArray::any_ready(self)
+    }
+}
+
+impl<const N: usize> Wakers for WakerArray<N> {
+    type Readiness = ReadinessArray<N>;
+    type Guard<'a> = ReadinessArrayRef<'a, N>;
+
+    fn get(&self, index: usize) -> Option<&Waker> {
+        WakerArray::get(self, index)
+    }
+
+    fn readiness(&mut self) -> Self::Guard<'_> {
+        WakerArray::readiness(self)
+    }
+}
@@ -1,11 +1,20 @@
 use core::task::Waker;
 
+use super::super::BitSet;
+use crate::utils::wakers::Readiness;
+use crate::utils::Trace;
+
 /// Tracks which wakers are "ready" and should be polled.
+///
+/// The readiness markers are stored as a bitmask, which is kept inline for up
+/// to 128 wakers.
 #[derive(Debug)]
 pub(crate) struct ReadinessArray<const N: usize> {
     count: usize,
-    readiness_list: [bool; N],
+    readiness_list: BitSet,
     parent_waker: Option<Waker>,
+    /// The span wakes are traced in.
+    trace: Trace,
 }
 
 impl<const N: usize> ReadinessArray<N> {
@@ -13,16 +22,17 @@ impl<const N: usize> ReadinessArray<N> {
     pub(crate) fn new() -> Self {
         Self {
             count: N,
-            readiness_list: [true; N], // TODO: use a bitarray instead
+            readiness_list: BitSet::new_set(N),
             parent_waker: None,
+            trace: Trace::default(),
         }
     }
 
     /// Returns the old ready state for this id
     pub(crate) fn set_ready(&mut self, id: usize) -> bool {
-        if !self.readiness_list[id] {
+        if !self.readiness_list.get(id) {
             self.count += 1;
-            self.readiness_list[id] = true;
+            self.readiness_list.set(id, true);
 
             false
         } else {
@@ -32,15 +42,15 @@ impl<const N: usize> ReadinessArray<N> {
 
     /// Set all markers to ready.
     pub(crate) fn set_all_ready(&mut self) {
-        self.readiness_list.fill(true);
+        self.readiness_list.set_all(true);
         self.count = N;
     }
 
     /// Returns whether the task id was previously ready
     pub(crate) fn clear_ready(&mut self, id: usize) -> bool {
-        if self.readiness_list[id] {
+        if self.readiness_list.get(id) {
             self.count -= 1;
-            self.readiness_list[id] = false;
+            self.readiness_list.set(id, false);
 
             true
         } else {
@@ -59,12 +69,97 @@ impl<const N: usize> ReadinessArray<N> {
         self.parent_waker.as_ref()
     }
 
+    /// Access the span wakes are traced in.
+    pub(crate) fn trace(&self) -> &Trace {
+        &self.trace
+    }
+
+    /// Trace wakes in the span of `trace`. This needs to be called at the
+    /// start of every `poll` function, next to `set_waker`.
+    pub(crate) fn set_trace(&mut self, trace: &Trace) {
+        self.trace.follow(trace);
+    }
+
     /// Set the parent `Waker`. This needs to be called at the start of every
     /// `poll` function.
+    ///
+    /// The stored waker is only replaced if it wouldn't wake the same task,
+    /// which avoids cloning the waker on every poll. Child wakers look up the
+    /// parent waker when they're woken rather than holding a clone of it, so
+    /// after the task has migrated to another waker, children which
+    /// registered before the migration wake the current waker.
     pub(crate) fn set_waker(&mut self, parent_waker: &Waker) {
         match &mut self.parent_waker {
+            Some(prev) if prev.will_wake(parent_waker) => {}
             Some(prev) => prev.clone_from(parent_waker),
             None => self.parent_waker = Some(parent_waker.clone()),
         }
     }
 }
+
+impl<const N: usize> Readiness for ReadinessArray<N> {
+    fn set_waker(&mut self, parent_waker: &Waker) {
+        ReadinessArray::set_waker(self, parent_waker)
+    }
+
+    fn set_trace(&mut self, trace: &Trace) {
+        ReadinessArray::set_trace(self, trace)
+    }
+
+    fn set_ready(&mut self, index: usize) -> bool {
+        ReadinessArray::set_ready(self, index)
+    }
+
+    fn clear_ready(&mut self, index: usize) -> bool {
+        ReadinessArray::clear_ready(self, index)
+    }
+
+    fn any_ready(&self) -> bool {
+        ReadinessArray::any_ready(self)
+    }
+
+    fn pop_ready(&mut self) -> Option<usize> {
+        // Arrays are only merged round-robin.
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn set_and_clear() {
+        let mut readiness = ReadinessArray::<3>::new();
+        assert!(readiness.any_ready());
+
+        assert!(readiness.clear_ready(0));
+        assert!(!readiness.clear_ready(0));
+        assert!(readiness.clear_ready(1));
+        assert!(readiness.clear_ready(2));
+        assert!(!readiness.any_ready());
+
+        assert!(!readiness.set_ready(1));
+        assert!(readiness.set_ready(1));
+        assert!(readiness.any_ready());
+
+        readiness.set_all_ready();
+        assert!(readiness.clear_ready(0));
+        assert!(readiness.clear_ready(2));
+        assert!(readiness.any_ready());
+    }
+
+    #[test]
+    fn beyond_inline_capacity() {
+        let mut readiness = ReadinessArray::<130>::new();
+        for id in 0..130 {
+            assert!(readiness.clear_ready(id));
+        }
+        assert!(!readiness.any_ready());
+
+        assert!(!readiness.set_ready(129));
+        assert!(readiness.any_ready());
+        assert!(readiness.clear_ready(129));
+        assert!(!readiness.clear_ready(128));
+    }
+}
@@ -1,31 +1,92 @@
-use alloc::sync::Arc;
-use alloc::task::Wake;
-use std::sync::Mutex;
+use core::ptr;
+use core::task::{RawWaker, RawWakerVTable, Waker};
 
 use super::ReadinessArray;
+use crate::utils::wakers::sync::{Arc, Lock, ReadinessLock};
 
-/// An efficient waker which delegates wake events.
-#[derive(Debug, Clone)]
-pub(crate) struct InlineWakerArray<const N: usize> {
-    pub(crate) id: usize,
-    pub(crate) readiness: Arc<Mutex<ReadinessArray<N>>>,
+/// The state shared between all wakers of a `WakerArray`.
+///
+/// All `N` child wakers point into a single allocation: each waker's data
+/// pointer refers to its own entry in `slots`, which stores the index of the
+/// waker. Because `slots` is the first field of this `repr(C)` struct, the
+/// start of the allocation can be recovered from any slot pointer by
+/// subtracting the index.
+#[repr(C)]
+pub(crate) struct SharedArray<const N: usize> {
+    slots: [usize; N],
+    pub(crate) readiness: ReadinessLock<ReadinessArray<N>>,
 }
 
-impl<const N: usize> InlineWakerArray<N> {
-    /// Create a new instance of `InlineWaker`.
-    pub(crate) fn new(id: usize, readiness: Arc<Mutex<ReadinessArray<N>>>) -> Self {
-        Self { id, readiness }
+impl<const N: usize> SharedArray<N> {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        Self::clone_waker,
+        Self::wake,
+        Self::wake_by_ref,
+        Self::drop_waker,
+    );
+
+    /// Create a new instance of `SharedArray`.
+    pub(crate) fn new() -> Self {
+        Self {
+            slots: core::array::from_fn(|i| i),
+            readiness: ReadinessLock::new(ReadinessArray::new()),
+        }
+    }
+
+    /// Create the waker for the given index.
+    pub(crate) fn waker(this: &Arc<Self>, index: usize) -> Waker {
+        let shared = Arc::as_ptr(this);
+        // SAFETY: the waker owns one strong reference to the `Arc`, which is
+        // released again in `drop_waker`. `slot` is derived from the pointer
+        // to the whole allocation rather than from a reference to the slot,
+        // so `from_raw` may use it to access the rest of the allocation. It
+        // remains valid for as long as the waker (or any of its clones) is
+        // alive.
+        unsafe {
+            let slot = ptr::addr_of!((*shared).slots[index]);
+            Arc::increment_strong_count(shared);
+            Waker::from_raw(RawWaker::new(slot as *const (), &Self::VTABLE))
+        }
+    }
+
+    /// Recover a pointer to the shared state and the index from a waker's
+    /// data pointer.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been created by `SharedArray::waker`.
+    unsafe fn from_raw(ptr: *const ()) -> (*const Self, usize) {
+        let slot = ptr as *const usize;
+        let index = *slot;
+        let shared = slot.sub(index) as *const Self;
+        (shared, index)
+    }
+
+    unsafe fn clone_waker(ptr: *const ()) -> RawWaker {
+        let (shared, _) = Self::from_raw(ptr);
+        Arc::increment_strong_count(shared);
+        RawWaker::new(ptr, &Self::VTABLE)
     }
-}
 
-impl<const N: usize> Wake for InlineWakerArray<N> {
-    fn wake(self: Arc<Self>) {
-        let mut readiness = self.readiness.lock().unwrap();
-        if !readiness.set_ready(self.id) {
+    unsafe fn wake(ptr: *const ()) {
+        Self::wake_by_ref(ptr);
+        Self::drop_waker(ptr);
+    }
+
+    unsafe fn wake_by_ref(ptr: *const ()) {
+        let (shared, index) = Self::from_raw(ptr);
+        let mut readiness = (*shared).readiness.acquire();
+        if !readiness.set_ready(index) {
+            readiness.trace().woken(index);
             readiness
                 .parent_waker()
                 .expect("`parent_waker` not available from `Readiness`. Did you forget to call `Readiness::set_waker`?")
                 .wake_by_ref()
         }
     }
+
+    unsafe fn drop_waker(ptr: *const ()) {
+        let (shared, _) = Self::from_raw(ptr);
+        Arc::decrement_strong_count(shared);
+    }
 }
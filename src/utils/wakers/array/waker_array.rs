@@ -1,25 +1,26 @@
-use alloc::sync::Arc;
 use core::array;
 use core::task::Waker;
-use std::sync::{Mutex, MutexGuard};
 
-use super::{InlineWakerArray, ReadinessArray};
+use super::{ReadinessArray, SharedArray};
+use crate::utils::wakers::sync::{Arc, Lock, ReadinessGuard};
+use crate::utils::wakers::Wakers;
 
 /// A collection of wakers which delegate to an in-line waker.
+///
+/// All wakers share a single allocation with the readiness state, so creating
+/// a `WakerArray` allocates exactly once regardless of its length.
 pub(crate) struct WakerArray<const N: usize> {
     wakers: [Waker; N],
-    readiness: Arc<Mutex<ReadinessArray<N>>>,
+    shared: Arc<SharedArray<N>>,
 }
 
 impl<const N: usize> WakerArray<N> {
     /// Create a new instance of `WakerArray`.
     pub(crate) fn new() -> Self {
-        let readiness = Arc::new(Mutex::new(ReadinessArray::new()));
+        let shared = Arc::new(SharedArray::new());
         Self {
-            wakers: array::from_fn(|i| {
-                Arc::new(InlineWakerArray::new(i, readiness.clone())).into()
-            }),
-            readiness,
+            wakers: array::from_fn(|i| SharedArray::waker(&shared, i)),
+            shared,
         }
     }
 
@@ -28,7 +29,106 @@ impl<const N: usize> WakerArray<N> {
     }
 
     /// Access the `Readiness`.
-    pub(crate) fn readiness(&mut self) -> MutexGuard<'_, ReadinessArray<N>> {
-        self.readiness.as_ref().lock().unwrap()
+    pub(crate) fn readiness(&mut self) -> ReadinessGuard<'_, ReadinessArray<N>> {
+        self.shared.readiness.acquire()
+    }
+}
+
+impl<const N: usize> Wakers for WakerArray<N> {
+    type Readiness = ReadinessArray<N>;
+    type Guard<'a> = ReadinessGuard<'a, ReadinessArray<N>>;
+
+    fn get(&self, index: usize) -> Option<&Waker> {
+        WakerArray::get(self, index)
+    }
+
+    fn readiness(&mut self) -> Self::Guard<'_> {
+        WakerArray::readiness(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::CountingWaker;
+    use std::thread;
+
+    #[test]
+    fn concurrent_wakes() {
+        const LEN: usize = 16;
+        let parent = CountingWaker::new();
+        let mut wakers = WakerArray::<LEN>::new();
+        {
+            let mut readiness = wakers.readiness();
+            readiness.set_waker(parent.waker());
+            for index in 0..LEN {
+                readiness.clear_ready(index);
+            }
+            assert!(!readiness.any_ready());
+        }
+
+        // Wake every child many times from multiple threads at once. All of
+        // the wakers point into the same allocation.
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let children: Vec<Waker> =
+                    (0..LEN).map(|i| wakers.get(i).unwrap().clone()).collect();
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        for waker in &children {
+                            waker.wake_by_ref();
+                        }
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // Every child is marked ready, and the parent is only woken on the
+        // transition from "not ready" to "ready".
+        let mut readiness = wakers.readiness();
+        for index in 0..LEN {
+            assert!(readiness.clear_ready(index));
+        }
+        assert_eq!(parent.wakes(), LEN);
+    }
+
+    #[test]
+    fn single_allocation() {
+        let wakers = WakerArray::<4>::new();
+        // One reference is held by the `WakerArray`, and one by each waker.
+        assert_eq!(Arc::strong_count(&wakers.shared), 5);
+
+        let waker = wakers.get(2).unwrap().clone();
+        assert_eq!(Arc::strong_count(&wakers.shared), 6);
+        drop(waker);
+        assert_eq!(Arc::strong_count(&wakers.shared), 5);
+    }
+
+    #[test]
+    fn wakers_outlive_array() {
+        let parent = CountingWaker::new();
+        let mut wakers = WakerArray::<3>::new();
+        {
+            let mut readiness = wakers.readiness();
+            readiness.set_waker(parent.waker());
+            readiness.clear_ready(1);
+        }
+
+        // Executors may hold on to wakers after a poll has finished, and even
+        // after the future itself has been dropped.
+        let a = wakers.get(1).unwrap().clone();
+        let b = a.clone();
+        assert!(a.will_wake(&b));
+        assert!(!a.will_wake(wakers.get(0).unwrap()));
+        drop(wakers);
+
+        b.wake_by_ref();
+        assert_eq!(parent.wakes(), 1);
+        drop(b);
+        a.wake();
+        assert_eq!(parent.wakes(), 1);
     }
 }
@@ -0,0 +1,88 @@
+use smallvec::SmallVec;
+
+const BITS: usize = usize::BITS as usize;
+
+/// A growable set of bits, stored inline for up to 128 entries.
+#[derive(Debug, Default)]
+pub(crate) struct BitSet {
+    blocks: SmallVec<[usize; 2]>,
+    len: usize,
+}
+
+impl BitSet {
+    /// Create a new set of `len` bits, all set to `true`.
+    pub(crate) fn new_set(len: usize) -> Self {
+        let mut this = Self::default();
+        this.grow(len);
+        this
+    }
+
+    /// Create a new set of `len` bits, all set to `false`.
+    pub(crate) fn new_unset(len: usize) -> Self {
+        let mut this = Self::default();
+        this.grow_unset(len);
+        this
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn get(&self, index: usize) -> bool {
+        debug_assert!(index < self.len);
+        self.blocks[index / BITS] & (1 << (index % BITS)) != 0
+    }
+
+    pub(crate) fn set(&mut self, index: usize, value: bool) {
+        debug_assert!(index < self.len);
+        let block = &mut self.blocks[index / BITS];
+        match value {
+            true => *block |= 1 << (index % BITS),
+            false => *block &= !(1 << (index % BITS)),
+        }
+    }
+
+    pub(crate) fn set_all(&mut self, value: bool) {
+        let fill = if value { !0 } else { 0 };
+        self.blocks.iter_mut().for_each(|block| *block = fill);
+        self.clear_unused();
+    }
+
+    /// Grow to `len` bits, setting all new bits to `true`.
+    pub(crate) fn grow(&mut self, len: usize) {
+        debug_assert!(len >= self.len);
+        if self.len % BITS != 0 {
+            // Set the unused bits of the last block.
+            *self.blocks.last_mut().unwrap() |= !0 << (self.len % BITS);
+        }
+        self.blocks.resize(len.div_ceil(BITS), !0);
+        self.len = len;
+        self.clear_unused();
+    }
+
+    /// Grow to `len` bits, setting all new bits to `false`.
+    pub(crate) fn grow_unset(&mut self, len: usize) {
+        debug_assert!(len >= self.len);
+        // The unused bits of the last block are always clear.
+        self.blocks.resize(len.div_ceil(BITS), 0);
+        self.len = len;
+    }
+
+    /// Shrink to `len` bits, returning the number of set bits removed.
+    pub(crate) fn truncate(&mut self, len: usize) -> usize {
+        debug_assert!(len <= self.len);
+        let removed = (len..self.len).filter(|&index| self.get(index)).count();
+        self.blocks.truncate(len.div_ceil(BITS));
+        self.blocks.shrink_to_fit();
+        self.len = len;
+        self.clear_unused();
+        removed
+    }
+
+    /// Clear the bits in the last block which are beyond `len`.
+    fn clear_unused(&mut self) {
+        if self.len % BITS != 0 {
+            *self.blocks.last_mut().unwrap() &= !(!0 << (self.len % BITS));
+        }
+    }
+}
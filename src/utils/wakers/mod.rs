@@ -1,12 +1,63 @@
+use core::ops::DerefMut;
+use core::task::Waker;
+
+use super::Trace;
+
 mod array;
-#[cfg(all(test, feature = "alloc"))]
-mod dummy;
+#[cfg(any(feature = "std", feature = "critical-section"))]
+mod bitset;
+mod pair;
+#[cfg(any(feature = "std", feature = "critical-section"))]
+mod sync;
 #[cfg(feature = "alloc")]
 mod vec;
 
-#[cfg(all(test, feature = "alloc"))]
-pub(crate) use dummy::DummyWaker;
-
 pub(crate) use array::*;
+#[cfg(any(feature = "std", feature = "critical-section"))]
+use bitset::BitSet;
+pub(crate) use pair::WakerPair;
 #[cfg(feature = "alloc")]
 pub(crate) use vec::*;
+
+/// The readiness of the members of an operation, as tracked by [`Wakers`].
+pub(crate) trait Readiness {
+    /// Set the parent `Waker`.
+    fn set_waker(&mut self, parent_waker: &Waker);
+
+    /// Trace the wakes of the members within the span of `trace`, unless
+    /// that's already the case.
+    fn set_trace(&mut self, trace: &Trace);
+
+    /// Mark the member at `index` as ready, returning whether it already was.
+    fn set_ready(&mut self, index: usize) -> bool;
+
+    /// Mark the member at `index` as not ready, returning whether it was.
+    fn clear_ready(&mut self, index: usize) -> bool;
+
+    /// Returns `true` if any of the members are ready.
+    fn any_ready(&self) -> bool;
+
+    /// Take the member which became ready the longest ago, for storages
+    /// which track the order of wakes.
+    #[cfg(any(feature = "std", feature = "critical-section"))]
+    fn pop_ready(&mut self) -> Option<usize>;
+}
+
+/// A collection of wakers with one waker for each member of an operation.
+///
+/// This allows operations to be written once for both arrays and vectors.
+pub(crate) trait Wakers {
+    /// The readiness state which is shared by the wakers.
+    type Readiness: Readiness;
+
+    /// A guard which provides access to the readiness state.
+    type Guard<'a>: DerefMut<Target = Self::Readiness>
+    where
+        Self: 'a;
+
+    /// Returns the waker of the member at `index`.
+    fn get(&self, index: usize) -> Option<&Waker>;
+
+    /// Access the readiness state.
+    fn readiness(&mut self) -> Self::Guard<'_>;
+}
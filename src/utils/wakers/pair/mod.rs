@@ -0,0 +1,9 @@
+#[cfg(not(any(feature = "std", feature = "critical-section")))]
+mod no_std;
+#[cfg(any(feature = "std", feature = "critical-section"))]
+mod waker_pair;
+
+#[cfg(not(any(feature = "std", feature = "critical-section")))]
+pub(crate) use no_std::WakerPair;
+#[cfg(any(feature = "std", feature = "critical-section"))]
+pub(crate) use waker_pair::WakerPair;
@@ -0,0 +1,46 @@
+use core::task::Waker;
+
+use crate::utils::Trace;
+
+/// A pair of wakers which delegate to an in-line waker.
+pub(crate) struct WakerPair {
+    parent_waker: Option<Waker>,
+}
+
+impl WakerPair {
+    /// Create a new instance of `WakerPair`.
+    pub(crate) fn new(_trace: Trace) -> Self {
+        Self { parent_waker: None }
+    }
+
+    pub(crate) fn get(&self, _index: usize) -> Option<&Waker> {
+        self.parent_waker.as_ref()
+    }
+
+    /// Set the parent `Waker`. This needs to be called at the start of every
+    /// `poll` function.
+    ///
+    /// The stored waker is only replaced if it wouldn't wake the same task,
+    /// which avoids cloning the waker on every poll.
+    pub(crate) fn set_waker(&mut self, parent_waker: &Waker) {
+        match &mut self.parent_waker {
+            Some(prev) if prev.will_wake(parent_waker) => {}
+            Some(prev) => prev.clone_from(parent_waker),
+            None => self.parent_waker = Some(parent_waker.clone()),
+        }
+    }
+
+    /// Set the ready state to `true` for the given index, without waking the
+    /// parent.
+    pub(crate) fn set_ready(&self, _index: usize) {}
+
+    /// Returns whether the given index was previously ready
+    pub(crate) fn clear_ready(&self, _index: usize) -> bool {
+        true
+    }
+
+    /// Returns `true` if any of the wakers are ready.
+    pub(crate) fn any_ready(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,209 @@
+use core::ptr;
+use core::sync::atomic::Ordering;
+use core::task::{RawWaker, RawWakerVTable, Waker};
+
+use crate::utils::wakers::sync::{Arc, AtomicU8, Lock, ReadinessGuard, ReadinessLock};
+use crate::utils::Trace;
+
+/// A pair of wakers which delegate to an in-line waker.
+///
+/// This is a specialization of `WakerArray<2>`. Both wakers share a single
+/// allocation with the readiness state, which is stored as two bits in an
+/// atomic, so marking a waker as ready or not ready never takes a lock. The
+/// lock on the parent waker is only taken when the parent is replaced, or
+/// when a child waker transitions from "not ready" to "ready".
+pub(crate) struct WakerPair {
+    wakers: [Waker; 2],
+    shared: Arc<SharedPair>,
+}
+
+impl WakerPair {
+    /// Create a new instance of `WakerPair`, whose wakes are traced in the
+    /// span of `trace`.
+    pub(crate) fn new(trace: Trace) -> Self {
+        let shared = Arc::new(SharedPair {
+            slots: [0, 1],
+            readiness: AtomicU8::new(0b11),
+            parent_waker: ReadinessLock::new(None),
+            trace,
+        });
+        Self {
+            wakers: [SharedPair::waker(&shared, 0), SharedPair::waker(&shared, 1)],
+            shared,
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> Option<&Waker> {
+        self.wakers.get(index)
+    }
+
+    /// Set the parent `Waker`. This needs to be called at the start of every
+    /// `poll` function.
+    ///
+    /// The stored waker is only replaced if it wouldn't wake the same task,
+    /// which avoids cloning the waker on every poll.
+    pub(crate) fn set_waker(&mut self, parent_waker: &Waker) {
+        let mut prev = self.shared.parent_waker();
+        match &mut *prev {
+            Some(prev) if prev.will_wake(parent_waker) => {}
+            Some(prev) => prev.clone_from(parent_waker),
+            None => *prev = Some(parent_waker.clone()),
+        }
+    }
+
+    /// Set the ready state to `true` for the given index, without waking the
+    /// parent.
+    pub(crate) fn set_ready(&self, index: usize) {
+        self.shared.readiness.fetch_or(1 << index, Ordering::AcqRel);
+    }
+
+    /// Returns whether the given index was previously ready
+    pub(crate) fn clear_ready(&self, index: usize) -> bool {
+        let bit = 1 << index;
+        self.shared.readiness.fetch_and(!bit, Ordering::AcqRel) & bit != 0
+    }
+
+    /// Returns `true` if any of the wakers are ready.
+    pub(crate) fn any_ready(&self) -> bool {
+        self.shared.readiness.load(Ordering::Acquire) != 0
+    }
+}
+
+/// The state shared between both wakers of a `WakerPair`.
+///
+/// Each waker's data pointer refers to its own entry in `slots`, the same way
+/// as for `SharedArray`.
+#[repr(C)]
+struct SharedPair {
+    slots: [usize; 2],
+    readiness: AtomicU8,
+    parent_waker: ReadinessLock<Option<Waker>>,
+    trace: Trace,
+}
+
+impl SharedPair {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        Self::clone_waker,
+        Self::wake,
+        Self::wake_by_ref,
+        Self::drop_waker,
+    );
+
+    /// Access the parent waker.
+    fn parent_waker(&self) -> ReadinessGuard<'_, Option<Waker>> {
+        self.parent_waker.acquire()
+    }
+
+    /// Create the waker for the given index.
+    fn waker(this: &Arc<Self>, index: usize) -> Waker {
+        let shared = Arc::as_ptr(this);
+        // SAFETY: the waker owns one strong reference to the `Arc`, which is
+        // released again in `drop_waker`. `slot` is derived from the pointer
+        // to the whole allocation rather than from a reference to the slot,
+        // so `from_raw` may use it to access the rest of the allocation. It
+        // remains valid for as long as the waker (or any of its clones) is
+        // alive.
+        unsafe {
+            let slot = ptr::addr_of!((*shared).slots[index]);
+            Arc::increment_strong_count(shared);
+            Waker::from_raw(RawWaker::new(slot as *const (), &Self::VTABLE))
+        }
+    }
+
+    /// Recover a pointer to the shared state and the index from a waker's
+    /// data pointer.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been created by `SharedPair::waker`.
+    unsafe fn from_raw(ptr: *const ()) -> (*const Self, usize) {
+        let slot = ptr as *const usize;
+        let index = *slot;
+        let shared = slot.sub(index) as *const Self;
+        (shared, index)
+    }
+
+    unsafe fn clone_waker(ptr: *const ()) -> RawWaker {
+        let (shared, _) = Self::from_raw(ptr);
+        Arc::increment_strong_count(shared);
+        RawWaker::new(ptr, &Self::VTABLE)
+    }
+
+    unsafe fn wake(ptr: *const ()) {
+        Self::wake_by_ref(ptr);
+        Self::drop_waker(ptr);
+    }
+
+    unsafe fn wake_by_ref(ptr: *const ()) {
+        let (shared, index) = Self::from_raw(ptr);
+        let bit = 1 << index;
+        if (*shared).readiness.fetch_or(bit, Ordering::AcqRel) & bit == 0 {
+            (*shared).trace.woken(index);
+            (*shared)
+                .parent_waker()
+                .as_ref()
+                .expect("`parent_waker` not available from `WakerPair`. Did you forget to call `WakerPair::set_waker`?")
+                .wake_by_ref()
+        }
+    }
+
+    unsafe fn drop_waker(ptr: *const ()) {
+        let (shared, _) = Self::from_raw(ptr);
+        Arc::decrement_strong_count(shared);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::CountingWaker;
+    use std::thread;
+
+    #[test]
+    fn concurrent_wakes() {
+        let parent = CountingWaker::new();
+        let mut wakers = WakerPair::new(Trace::default());
+        wakers.set_waker(parent.waker());
+        assert!(wakers.clear_ready(0));
+        assert!(wakers.clear_ready(1));
+        assert!(!wakers.any_ready());
+
+        // Wake both children many times from multiple threads at once. The
+        // readiness bits are updated without taking the lock, so only the
+        // thread which flips a bit may wake the parent.
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let children = [0, 1].map(|i| wakers.get(i).unwrap().clone());
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        for waker in &children {
+                            waker.wake_by_ref();
+                        }
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(wakers.clear_ready(0));
+        assert!(wakers.clear_ready(1));
+        assert_eq!(parent.wakes(), 2);
+    }
+
+    #[test]
+    fn wakers_outlive_pair() {
+        let parent = CountingWaker::new();
+        let mut wakers = WakerPair::new(Trace::default());
+        wakers.set_waker(parent.waker());
+        wakers.clear_ready(1);
+
+        let waker = wakers.get(1).unwrap().clone();
+        assert!(!waker.will_wake(wakers.get(0).unwrap()));
+        drop(wakers);
+
+        waker.wake();
+        assert_eq!(parent.wakes(), 1);
+    }
+}
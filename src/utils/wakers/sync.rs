@@ -0,0 +1,258 @@
+//! The primitives which share the readiness state between the wakers of an
+//! operation and the operation itself.
+//!
+//! With `std` the state is protected by a `Mutex`. The `critical-section`
+//! feature replaces it with a lock which is held within a critical section,
+//! and `Arc` with the one of `portable-atomic-util`, so the wakers also work
+//! on targets without `std` or compare-and-swap, such as
+//! `thumbv6m-none-eabi`. If both features are enabled, the critical section
+//! is used.
+
+use core::ops::DerefMut;
+
+#[cfg(not(feature = "critical-section"))]
+pub(crate) use alloc::sync::Arc;
+#[cfg(not(feature = "critical-section"))]
+pub(crate) use core::sync::atomic::AtomicU8;
+#[cfg(feature = "critical-section")]
+pub(crate) use critical_section_lock::{CriticalSectionLock as ReadinessLock, OnceCell};
+#[cfg(feature = "critical-section")]
+pub(crate) use portable_atomic::AtomicU8;
+#[cfg(feature = "critical-section")]
+pub(crate) use portable_atomic_util::Arc;
+#[cfg(not(feature = "critical-section"))]
+pub(crate) use std::sync::{Mutex as ReadinessLock, OnceLock as OnceCell};
+
+/// A lock around state which is shared between wakers.
+pub(crate) trait Lock<T> {
+    /// The guard which releases the lock when it's dropped.
+    type Guard<'a>: DerefMut<Target = T>
+    where
+        Self: 'a;
+
+    /// Create a new, unlocked lock.
+    fn new(value: T) -> Self;
+
+    /// Acquire the lock.
+    fn acquire(&self) -> Self::Guard<'_>;
+}
+
+/// The guard of a [`ReadinessLock`].
+pub(crate) type ReadinessGuard<'a, T> = <ReadinessLock<T> as Lock<T>>::Guard<'a>;
+
+#[cfg(not(feature = "critical-section"))]
+impl<T> Lock<T> for std::sync::Mutex<T> {
+    type Guard<'a>
+        = std::sync::MutexGuard<'a, T>
+    where
+        T: 'a;
+
+    fn new(value: T) -> Self {
+        std::sync::Mutex::new(value)
+    }
+
+    /// The lock is only poisoned if a parent waker panicked while it was
+    /// held. The readiness state is still consistent in that case, so the
+    /// poison is ignored.
+    fn acquire(&self) -> Self::Guard<'_> {
+        self.lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(feature = "critical-section")]
+mod critical_section_lock {
+    use core::cell::{RefCell, RefMut, UnsafeCell};
+    use core::mem::ManuallyDrop;
+    use core::ops::{Deref, DerefMut};
+
+    use critical_section::{CriticalSection, Mutex, RestoreState};
+
+    use super::Lock;
+
+    /// A lock which is held within a critical section.
+    ///
+    /// Parent wakers are woken while the lock is held, so they run within
+    /// the critical section too. Locking it again while it's held panics,
+    /// where a `Mutex` would deadlock.
+    pub(crate) struct CriticalSectionLock<T> {
+        inner: Mutex<RefCell<T>>,
+    }
+
+    impl<T> Lock<T> for CriticalSectionLock<T> {
+        type Guard<'a>
+            = CriticalSectionGuard<'a, T>
+        where
+            T: 'a;
+
+        fn new(value: T) -> Self {
+            Self {
+                inner: Mutex::new(RefCell::new(value)),
+            }
+        }
+
+        fn acquire(&self) -> Self::Guard<'_> {
+            // SAFETY: the critical section is released when the guard is
+            // dropped. Guards are only ever held within a single scope, so
+            // critical sections are released in the reverse order of being
+            // acquired.
+            let restore = unsafe { critical_section::acquire() };
+            // SAFETY: we're in a critical section until the guard is dropped,
+            // and the guard can't outlive it.
+            let cs = unsafe { CriticalSection::new() };
+            match self.inner.borrow(cs).try_borrow_mut() {
+                Ok(value) => CriticalSectionGuard {
+                    value: ManuallyDrop::new(value),
+                    restore,
+                },
+                Err(_) => {
+                    // SAFETY: we acquired this critical section above.
+                    unsafe { critical_section::release(restore) };
+                    panic!("the readiness lock was locked again while it was held");
+                }
+            }
+        }
+    }
+
+    /// The guard of a [`CriticalSectionLock`], which leaves the critical
+    /// section when it's dropped.
+    pub(crate) struct CriticalSectionGuard<'a, T> {
+        value: ManuallyDrop<RefMut<'a, T>>,
+        restore: RestoreState,
+    }
+
+    impl<T> Deref for CriticalSectionGuard<'_, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.value
+        }
+    }
+
+    impl<T> DerefMut for CriticalSectionGuard<'_, T> {
+        fn deref_mut(&mut self) -> &mut T {
+            &mut self.value
+        }
+    }
+
+    impl<T> Drop for CriticalSectionGuard<'_, T> {
+        fn drop(&mut self) {
+            // SAFETY: the borrow is released before the critical section it
+            // was taken in, and neither is used again.
+            unsafe {
+                ManuallyDrop::drop(&mut self.value);
+                critical_section::release(self.restore);
+            }
+        }
+    }
+
+    /// A cell which is initialized once, within a critical section.
+    ///
+    /// This provides the subset of `std::sync::OnceLock` used by the wakers.
+    pub(crate) struct OnceCell<T> {
+        value: UnsafeCell<Option<T>>,
+    }
+
+    // SAFETY: the value is only written once, within a critical section, and
+    // never moved or mutated through a shared reference afterwards.
+    unsafe impl<T: Send + Sync> Sync for OnceCell<T> {}
+    unsafe impl<T: Send> Send for OnceCell<T> {}
+
+    impl<T> OnceCell<T> {
+        pub(crate) const fn new() -> Self {
+            Self {
+                value: UnsafeCell::new(None),
+            }
+        }
+
+        pub(crate) fn get(&self) -> Option<&T> {
+            // SAFETY: the value is never mutated through a shared reference
+            // once it's been set.
+            critical_section::with(|_| unsafe { (*self.value.get()).as_ref() })
+        }
+
+        pub(crate) fn get_mut(&mut self) -> Option<&mut T> {
+            self.value.get_mut().as_mut()
+        }
+
+        pub(crate) fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+            if let Some(value) = self.get() {
+                return value;
+            }
+            // The value is created outside of the critical section, and
+            // discarded if another thread was faster.
+            let value = f();
+            critical_section::with(|_| {
+                let slot = self.value.get();
+                // SAFETY: we're in a critical section, and the value is only
+                // written if it hasn't been set before, so there are no
+                // references to it yet.
+                unsafe {
+                    if (*slot).is_none() {
+                        slot.write(Some(value));
+                    }
+                }
+            });
+            self.get().expect("the value was just set")
+        }
+    }
+
+    impl<T> From<T> for OnceCell<T> {
+        fn from(value: T) -> Self {
+            Self {
+                value: UnsafeCell::new(Some(value)),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn lock_nested() {
+            let outer = CriticalSectionLock::new(1);
+            let inner = CriticalSectionLock::new(2);
+            let mut a = outer.acquire();
+            let mut b = inner.acquire();
+            *a += *b;
+            *b += 1;
+            drop(b);
+            drop(a);
+            assert_eq!(*outer.acquire(), 3);
+            assert_eq!(*inner.acquire(), 3);
+        }
+
+        #[test]
+        #[should_panic(expected = "locked again while it was held")]
+        fn lock_reentrant() {
+            let lock = CriticalSectionLock::new(());
+            let _guard = lock.acquire();
+            drop(lock.acquire());
+        }
+
+        #[test]
+        fn lock_released_after_reentrant_panic() {
+            let lock = CriticalSectionLock::new(());
+            {
+                let _guard = lock.acquire();
+                let res = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                    drop(lock.acquire());
+                }));
+                assert!(res.is_err());
+            }
+            drop(lock.acquire());
+        }
+
+        #[test]
+        fn once_cell() {
+            let mut cell = OnceCell::new();
+            assert!(cell.get().is_none());
+            assert_eq!(*cell.get_or_init(|| 1), 1);
+            assert_eq!(*cell.get_or_init(|| 2), 1);
+            *cell.get_mut().unwrap() = 3;
+            assert_eq!(cell.get(), Some(&3));
+            assert_eq!(OnceCell::from(4).get(), Some(&4));
+        }
+    }
+}
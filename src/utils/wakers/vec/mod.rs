@@ -1,17 +1,17 @@
-#[cfg(not(feature = "std"))]
+#[cfg(not(any(feature = "std", feature = "critical-section")))]
 mod no_std;
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "critical-section"))]
 mod readiness_vec;
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "critical-section"))]
 mod waker;
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "critical-section"))]
 mod waker_vec;
 
-#[cfg(not(feature = "std"))]
+#[cfg(not(any(feature = "std", feature = "critical-section")))]
 pub(crate) use no_std::WakerVec;
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "critical-section"))]
 pub(crate) use readiness_vec::ReadinessVec;
-#[cfg(feature = "std")]
-pub(crate) use waker::InlineWakerVec;
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "critical-section"))]
+pub(crate) use waker::SharedVec;
+#[cfg(any(feature = "std", feature = "critical-section"))]
 pub(crate) use waker_vec::WakerVec;
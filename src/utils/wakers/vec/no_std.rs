@@ -1,3 +1,5 @@
+use crate::utils::wakers::{Readiness, Wakers};
+use crate::utils::Trace;
 use core::ops::{Deref, DerefMut};
 use core::task::Waker;
 
@@ -11,14 +13,38 @@ impl ReadinessVec {
         Self { parent_waker: None }
     }
 
+    /// Wakes aren't traced without `std`.
+    pub(crate) fn set_trace(&mut self, _trace: &Trace) {}
+
     /// Returns the old ready state for this id
     pub(crate) fn set_ready(&mut self, _id: usize) -> bool {
         false
     }
 
+    /// Set the ready state to `true` for the given index, and wake the parent
+    /// waker.
+    ///
+    /// This is used when a slot is filled from outside of `poll`, so that a
+    /// task which is already waiting on the collection is woken. The parent
+    /// is woken even if the slot was already marked ready, since slots are
+    /// marked ready when they're created by growing the collection.
+    pub(crate) fn set_ready_and_wake(&mut self, index: usize) {
+        self.set_ready(index);
+        if let Some(parent_waker) = &self.parent_waker {
+            parent_waker.wake_by_ref();
+        }
+    }
+
+    /// Wakes can't be told apart, so the order in which slots become ready
+    /// isn't tracked.
+    pub(crate) fn track_order(&mut self) {}
+
     /// Set all markers to ready.
     pub(crate) fn set_all_ready(&mut self) {}
 
+    /// Permanently mark the given index as not ready.
+    pub(crate) fn mask(&mut self, _id: usize) {}
+
     /// Returns whether the task id was previously ready
     pub(crate) fn clear_ready(&mut self, _id: usize) -> bool {
         true
@@ -37,8 +63,12 @@ impl ReadinessVec {
 
     /// Set the parent `Waker`. This needs to be called at the start of every
     /// `poll` function.
+    ///
+    /// The stored waker is only replaced if it wouldn't wake the same task,
+    /// which avoids cloning the waker on every poll.
     pub(crate) fn set_waker(&mut self, parent_waker: &Waker) {
         match &mut self.parent_waker {
+            Some(prev) if prev.will_wake(parent_waker) => {}
             Some(prev) => prev.clone_from(parent_waker),
             None => self.parent_waker = Some(parent_waker.clone()),
         }
@@ -86,6 +116,11 @@ impl WakerVec {
         Self { readiness }
     }
 
+    /// There's no shared state to allocate, so this is the same as `new`.
+    pub(crate) fn lazy(len: usize) -> Self {
+        Self::new(len)
+    }
+
     pub(crate) fn get(&self, _index: usize) -> Option<&Waker> {
         self.readiness.parent_waker()
     }
@@ -102,3 +137,38 @@ impl WakerVec {
         self.readiness.resize(len);
     }
 }
+
+impl Readiness for ReadinessVec {
+    fn set_waker(&mut self, parent_waker: &Waker) {
+        ReadinessVec::set_waker(self, parent_waker)
+    }
+
+    fn set_trace(&mut self, trace: &Trace) {
+        ReadinessVec::set_trace(self, trace)
+    }
+
+    fn set_ready(&mut self, index: usize) -> bool {
+        ReadinessVec::set_ready(self, index)
+    }
+
+    fn clear_ready(&mut self, index: usize) -> bool {
+        ReadinessVec::clear_ready(self, index)
+    }
+
+    fn any_ready(&self) -> bool {
+        ReadinessVec::any_ready(self)
+    }
+}
+
+impl Wakers for WakerVec {
+    type Readiness = ReadinessVec;
+    type Guard<'a> = ReadinessVecRef<'a>;
+
+    fn get(&self, index: usize) -> Option<&Waker> {
+        WakerVec::get(self, index)
+    }
+
+    fn readiness(&mut self) -> Self::Guard<'_> {
+        WakerVec::readiness(self)
+    }
+}
@@ -1,13 +1,32 @@
+use alloc::collections::VecDeque;
 use core::task::Waker;
-use fixedbitset::FixedBitSet;
+
+use super::super::BitSet;
+use crate::utils::wakers::Readiness;
+use crate::utils::Trace;
 
 /// Tracks which wakers are "ready" and should be polled.
 #[derive(Debug)]
 pub(crate) struct ReadinessVec {
     ready_count: usize,
     max_count: usize,
-    readiness_list: FixedBitSet,
+    readiness_list: BitSet,
+    /// Slots which haven't been masked, and can still become ready.
+    live_list: BitSet,
+    masked_count: usize,
+    /// Slots which are masked until they're resumed.
+    paused_list: BitSet,
+    /// Paused slots which were woken while they were paused.
+    parked_list: BitSet,
     parent_waker: Option<Waker>,
+    /// The order in which slots became ready, if it's being tracked.
+    ///
+    /// Slots are only appended on the transition from "not ready" to "ready",
+    /// so a slot is never queued twice while it's ready. Entries of slots
+    /// which were cleared through `clear_ready` are skipped by `pop_ready`.
+    order: Option<VecDeque<usize>>,
+    /// The span wakes are traced in.
+    trace: Trace,
 }
 
 impl ReadinessVec {
@@ -16,36 +35,152 @@ impl ReadinessVec {
         Self {
             ready_count: len,
             max_count: len,
-            // See https://github.com/petgraph/fixedbitset/issues/101
-            readiness_list: FixedBitSet::with_capacity_and_blocks(len, std::iter::repeat(!0)),
+            readiness_list: BitSet::new_set(len),
+            live_list: BitSet::new_set(len),
+            masked_count: 0,
+            paused_list: BitSet::new_unset(len),
+            parked_list: BitSet::new_unset(len),
             parent_waker: None,
+            order: None,
+            trace: Trace::default(),
         }
     }
 
+    /// Start recording the order in which slots become ready, so they can be
+    /// taken in that order through `pop_ready`.
+    ///
+    /// Slots which are already ready are queued in index order.
+    pub(crate) fn track_order(&mut self) {
+        if self.order.is_none() {
+            let ready = (0..self.len()).filter(|&index| self.readiness_list.get(index));
+            self.order = Some(ready.collect());
+        }
+    }
+
+    /// Clear and return the slot which became ready the longest time ago.
+    ///
+    /// This always returns `None` unless `track_order` has been called.
+    pub(crate) fn pop_ready(&mut self) -> Option<usize> {
+        while let Some(index) = self.order.as_mut()?.pop_front() {
+            if self.clear_ready(index) {
+                return Some(index);
+            }
+        }
+        None
+    }
+
     /// Set the ready state to `true` for the given index
     ///
-    /// Returns the old ready state for this id
+    /// Returns the old ready state for this id. Masked slots are reported as
+    /// ready, so waking them never wakes the parent. Wakes of paused slots
+    /// are kept until they're resumed.
     pub(crate) fn set_ready(&mut self, index: usize) -> bool {
-        if !self.readiness_list[index] {
+        if !self.live_list.get(index) {
+            if self.paused_list.get(index) {
+                self.parked_list.set(index, true);
+            }
+            true
+        } else if !self.readiness_list.get(index) {
             self.ready_count += 1;
             self.readiness_list.set(index, true);
+            if let Some(order) = &mut self.order {
+                order.push_back(index);
+            }
             false
         } else {
             true
         }
     }
 
-    /// Set all markers to ready.
+    /// Set the ready state to `true` for the given index, and wake the parent
+    /// waker.
+    ///
+    /// This is used when a slot is filled from outside of `poll`, so that a
+    /// task which is already waiting on the collection is woken. The parent
+    /// is woken even if the slot was already marked ready, since slots are
+    /// marked ready when they're created by growing the collection.
+    pub(crate) fn set_ready_and_wake(&mut self, index: usize) {
+        self.set_ready(index);
+        if let Some(parent_waker) = &self.parent_waker {
+            parent_waker.wake_by_ref();
+        }
+    }
+
+    /// Set all markers to ready, except for masked slots.
     pub(crate) fn set_all_ready(&mut self) {
-        self.readiness_list.set_range(.., true);
+        self.readiness_list.set_all(true);
         self.ready_count = self.max_count;
+        if self.masked_count != 0 {
+            for index in 0..self.max_count {
+                if !self.live_list.get(index) {
+                    self.readiness_list.set(index, false);
+                    if self.paused_list.get(index) {
+                        self.parked_list.set(index, true);
+                    }
+                }
+            }
+            self.ready_count -= self.masked_count;
+        }
+        if let Some(order) = &mut self.order {
+            order.clear();
+            let live = &self.live_list;
+            order.extend((0..self.max_count).filter(|&index| live.get(index)));
+        }
+    }
+
+    /// Permanently mark the given index as not ready, for children which have
+    /// completed. Wakes of a masked slot are ignored.
+    pub(crate) fn mask(&mut self, index: usize) {
+        if self.live_list.get(index) {
+            self.clear_ready(index);
+            self.live_list.set(index, false);
+            self.masked_count += 1;
+        } else {
+            // A paused slot stays masked for good.
+            self.paused_list.set(index, false);
+            self.parked_list.set(index, false);
+        }
+    }
+
+    /// Mask the given index until it's resumed. If the slot was ready, or is
+    /// woken while it's paused, it becomes ready again once it's resumed.
+    ///
+    /// Returns `false` if the slot was already masked or paused.
+    pub(crate) fn pause(&mut self, index: usize) -> bool {
+        if !self.live_list.get(index) {
+            return false;
+        }
+        let ready = self.clear_ready(index);
+        self.live_list.set(index, false);
+        self.masked_count += 1;
+        self.paused_list.set(index, true);
+        self.parked_list.set(index, ready);
+        true
+    }
+
+    /// Unmask a paused slot. If it was woken while it was paused, it's marked
+    /// ready and the parent is woken.
+    ///
+    /// Returns `false` if the slot wasn't paused.
+    pub(crate) fn resume(&mut self, index: usize) -> bool {
+        if !self.paused_list.get(index) {
+            return false;
+        }
+        self.paused_list.set(index, false);
+        self.live_list.set(index, true);
+        self.masked_count -= 1;
+        if self.parked_list.get(index) {
+            self.parked_list.set(index, false);
+            self.set_ready_and_wake(index);
+        }
+        true
     }
 
     /// Set the ready state to `false` for the given index
     ///
     /// Returns whether the task id was previously ready
     pub(crate) fn clear_ready(&mut self, index: usize) -> bool {
-        if self.readiness_list[index] {
+        if self.readiness_list.get(index) {
             self.ready_count -= 1;
             self.readiness_list.set(index, false);
             true
@@ -57,8 +192,16 @@ impl ReadinessVec {
     /// Returns whether the task id was previously ready
     #[allow(unused)]
     pub(crate) fn clear_all_ready(&mut self) {
-        self.readiness_list.set_range(.., false);
+        self.readiness_list.set_all(false);
         self.ready_count = 0;
+        if let Some(order) = &mut self.order {
+            order.clear();
+        }
+    }
+
+    /// Returns the number of slots tracked.
+    pub(crate) fn len(&self) -> usize {
+        self.readiness_list.len()
     }
 
     /// Returns `true` if any of the wakers are ready.
@@ -74,13 +217,31 @@ impl ReadinessVec {
 
     /// Set the parent `Waker`. This needs to be called at the start of every
     /// `poll` function.
+    ///
+    /// The stored waker is only replaced if it wouldn't wake the same task,
+    /// which avoids cloning the waker on every poll. Child wakers look up the
+    /// parent waker when they're woken rather than holding a clone of it, so
+    /// after the task has migrated to another waker, children which
+    /// registered before the migration wake the current waker.
     pub(crate) fn set_waker(&mut self, parent_waker: &Waker) {
         match &mut self.parent_waker {
+            Some(prev) if prev.will_wake(parent_waker) => {}
             Some(prev) => prev.clone_from(parent_waker),
             None => self.parent_waker = Some(parent_waker.clone()),
         }
     }
 
+    /// Access the span wakes are traced in.
+    pub(crate) fn trace(&self) -> &Trace {
+        &self.trace
+    }
+
+    /// Trace wakes in the span of `trace`. This needs to be called at the
+    /// start of every `poll` function, next to `set_waker`.
+    pub(crate) fn set_trace(&mut self, trace: &Trace) {
+        self.trace.follow(trace);
+    }
+
     /// Resize `readiness` to the new length.
     ///
     /// If new entries are created, they will be marked as 'ready'.
@@ -89,31 +250,164 @@ impl ReadinessVec {
 
         let old_len = self.readiness_list.len();
         match len.cmp(&old_len) {
-            std::cmp::Ordering::Less => {
+            core::cmp::Ordering::Less => {
                 // shrink
-                self.ready_count -= self.readiness_list.count_ones(len..);
-                self.readiness_list = FixedBitSet::with_capacity_and_blocks(
-                    len,
-                    self.readiness_list.as_slice().iter().cloned(),
-                );
+                self.ready_count -= self.readiness_list.truncate(len);
+                self.masked_count -= (old_len - len) - self.live_list.truncate(len);
+                self.paused_list.truncate(len);
+                self.parked_list.truncate(len);
+                if let Some(order) = &mut self.order {
+                    order.retain(|&index| index < len);
+                }
             }
-            std::cmp::Ordering::Equal => {
+            core::cmp::Ordering::Equal => {
                 // no-op
             }
-            std::cmp::Ordering::Greater => {
+            core::cmp::Ordering::Greater => {
                 // grow
                 self.readiness_list.grow(len);
-                self.readiness_list.set_range(old_len..len, true);
+                self.live_list.grow(len);
+                self.paused_list.grow_unset(len);
+                self.parked_list.grow_unset(len);
                 self.ready_count += len - old_len;
+                if let Some(order) = &mut self.order {
+                    order.extend(old_len..len);
+                }
             }
         }
     }
 }
 
+impl Readiness for ReadinessVec {
+    fn set_waker(&mut self, parent_waker: &Waker) {
+        ReadinessVec::set_waker(self, parent_waker)
+    }
+
+    fn set_trace(&mut self, trace: &Trace) {
+        ReadinessVec::set_trace(self, trace)
+    }
+
+    fn set_ready(&mut self, index: usize) -> bool {
+        ReadinessVec::set_ready(self, index)
+    }
+
+    fn clear_ready(&mut self, index: usize) -> bool {
+        ReadinessVec::clear_ready(self, index)
+    }
+
+    fn any_ready(&self) -> bool {
+        ReadinessVec::any_ready(self)
+    }
+
+    fn pop_ready(&mut self) -> Option<usize> {
+        ReadinessVec::pop_ready(self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn set_and_clear() {
+        let mut readiness = ReadinessVec::new(3);
+        assert!(readiness.any_ready());
+
+        assert!(readiness.clear_ready(0));
+        assert!(!readiness.clear_ready(0));
+        assert!(readiness.clear_ready(1));
+        assert!(readiness.clear_ready(2));
+        assert!(!readiness.any_ready());
+
+        assert!(!readiness.set_ready(1));
+        assert!(readiness.set_ready(1));
+        assert!(readiness.any_ready());
+
+        readiness.set_all_ready();
+        assert!(readiness.clear_ready(0));
+        assert!(readiness.clear_ready(2));
+        assert!(readiness.any_ready());
+    }
+
+    #[test]
+    fn mask() {
+        let mut readiness = ReadinessVec::new(3);
+        readiness.mask(1);
+        assert!(readiness.clear_ready(0));
+        assert!(!readiness.clear_ready(1));
+        assert!(readiness.clear_ready(2));
+        assert!(!readiness.any_ready());
+
+        // Waking a masked slot is ignored.
+        assert!(readiness.set_ready(1));
+        assert!(!readiness.any_ready());
+
+        readiness.set_all_ready();
+        assert!(readiness.clear_ready(0));
+        assert!(readiness.clear_ready(2));
+        assert!(!readiness.any_ready());
+
+        // Truncating a masked slot frees it up again.
+        readiness.resize(1);
+        readiness.resize(3);
+        assert!(readiness.clear_ready(1));
+        assert!(readiness.clear_ready(2));
+        assert!(!readiness.any_ready());
+    }
+
+    #[test]
+    fn pause() {
+        let mut readiness = ReadinessVec::new(3);
+        assert!(readiness.clear_ready(0));
+        assert!(readiness.pause(0));
+        assert!(!readiness.pause(0));
+        assert!(readiness.pause(1));
+
+        // Wakes of a paused slot are kept until it's resumed.
+        assert!(readiness.set_ready(0));
+        assert!(readiness.clear_ready(2));
+        assert!(!readiness.any_ready());
+        assert!(readiness.resume(0));
+        assert!(!readiness.resume(0));
+        assert!(readiness.clear_ready(0));
+        // The slot was ready when it was paused.
+        assert!(readiness.resume(1));
+        assert!(readiness.clear_ready(1));
+        assert!(!readiness.any_ready());
+
+        // A paused slot which is masked can't be resumed.
+        assert!(readiness.pause(2));
+        readiness.mask(2);
+        assert!(!readiness.resume(2));
+        assert!(readiness.set_ready(2));
+        assert!(!readiness.any_ready());
+    }
+
+    #[test]
+    fn wake_order() {
+        let mut readiness = ReadinessVec::new(4);
+        readiness.clear_all_ready();
+        readiness.track_order();
+        assert_eq!(readiness.pop_ready(), None);
+
+        readiness.set_ready(2);
+        readiness.set_ready(0);
+        // Slots which are already ready aren't queued again.
+        readiness.set_ready(2);
+        readiness.set_ready(3);
+        // A slot which was cleared in the meantime is skipped.
+        readiness.clear_ready(0);
+        assert_eq!(readiness.pop_ready(), Some(2));
+        assert_eq!(readiness.pop_ready(), Some(3));
+        assert_eq!(readiness.pop_ready(), None);
+        assert!(!readiness.any_ready());
+
+        readiness.resize(6);
+        assert_eq!(readiness.pop_ready(), Some(4));
+        assert_eq!(readiness.pop_ready(), Some(5));
+        assert_eq!(readiness.pop_ready(), None);
+    }
+
     #[test]
     fn resize() {
         let mut readiness = ReadinessVec::new(10);
@@ -127,4 +421,31 @@ mod test {
         readiness.resize(10);
         assert!(readiness.any_ready());
     }
+
+    #[test]
+    fn resize_across_blocks() {
+        let mut readiness = ReadinessVec::new(3);
+        readiness.clear_all_ready();
+        readiness.resize(200);
+        for index in 0..3 {
+            assert!(!readiness.clear_ready(index));
+        }
+        for index in 3..200 {
+            assert!(readiness.clear_ready(index));
+        }
+        assert!(!readiness.any_ready());
+
+        readiness.set_ready(150);
+        readiness.set_ready(1);
+        readiness.resize(100);
+        assert!(readiness.clear_ready(1));
+        assert!(!readiness.any_ready());
+
+        readiness.set_all_ready();
+        readiness.resize(130);
+        for index in 0..130 {
+            assert!(readiness.clear_ready(index));
+        }
+        assert!(!readiness.any_ready());
+    }
 }
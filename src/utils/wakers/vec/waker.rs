@@ -1,31 +1,207 @@
-use alloc::sync::Arc;
-use alloc::task::Wake;
-use std::sync::Mutex;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, vec::Vec};
+
+use core::cell::UnsafeCell;
+use core::mem::ManuallyDrop;
+use core::ptr::{self, NonNull};
+use core::task::{RawWaker, RawWakerVTable, Waker};
+use smallvec::SmallVec;
 
 use super::ReadinessVec;
+use crate::utils::wakers::sync::{Arc, Lock, ReadinessLock};
+
+/// The number of slots stored inline in `SharedVec`.
+pub(crate) const INLINE_SLOTS: usize = 8;
 
-/// An efficient waker which delegates wake events.
-#[derive(Debug, Clone)]
-pub(crate) struct InlineWakerVec {
-    pub(crate) id: usize,
-    pub(crate) readiness: Arc<Mutex<ReadinessVec>>,
+/// The entry each child waker points to.
+struct Slot {
+    shared: *const SharedVec,
+    index: usize,
+    /// A waker pointing at this slot which does not own a reference count.
+    /// It's only ever handed out by reference, so it's never dropped.
+    waker: ManuallyDrop<Waker>,
 }
 
-impl InlineWakerVec {
-    /// Create a new instance of `InlineWaker`.
-    pub(crate) fn new(id: usize, readiness: Arc<Mutex<ReadinessVec>>) -> Self {
-        Self { id, readiness }
+impl Slot {
+    /// Create the slot which will be stored at `address`.
+    fn new(address: *const Slot, shared: *const SharedVec, index: usize) -> Self {
+        let raw = RawWaker::new(address as *const (), &SharedVec::VTABLE);
+        Self {
+            shared,
+            index,
+            // SAFETY: the vtable upholds the `RawWaker` contract, and the
+            // slot outlives every waker pointing at it.
+            waker: ManuallyDrop::new(unsafe { Waker::from_raw(raw) }),
+        }
     }
 }
 
-impl Wake for InlineWakerVec {
-    fn wake(self: Arc<Self>) {
-        let mut readiness = self.readiness.lock().unwrap();
-        if !readiness.set_ready(self.id) {
+/// The state shared between all wakers of a `WakerVec`.
+///
+/// Each child waker's data pointer refers to its own `Slot`, which stores a
+/// pointer back to this struct together with the index of the waker. The
+/// first `INLINE_SLOTS` slots are stored inline, which means a `WakerVec` of
+/// up to that length only needs a single allocation. Any further slots are
+/// allocated in chunks which are never moved, so growing the collection only
+/// allocates for the new slots and leaves existing wakers valid.
+pub(crate) struct SharedVec {
+    pub(crate) readiness: ReadinessLock<ReadinessVec>,
+    inline: [Slot; INLINE_SLOTS],
+    /// NOTE: only ever accessed by the owning `WakerVec`, never by the wakers
+    /// themselves. Those only access their own slot.
+    chunks: UnsafeCell<SmallVec<[NonNull<[Slot]>; 1]>>,
+}
+
+// SAFETY: the slots are only mutated while they're being created, and the
+// chunk list is only accessed by the single owning `WakerVec`.
+unsafe impl Send for SharedVec {}
+unsafe impl Sync for SharedVec {}
+
+impl SharedVec {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        Self::clone_waker,
+        Self::wake,
+        Self::wake_by_ref,
+        Self::drop_waker,
+    );
+
+    /// Create a new instance of `SharedVec` with `len` slots.
+    pub(crate) fn new(len: usize) -> Arc<Self> {
+        let this = Arc::new_cyclic(|weak| {
+            // We know the final address of the struct before it's been
+            // created, which allows the inline slots to point to themselves.
+            let shared: *const Self = weak.as_ptr();
+            Self {
+                readiness: ReadinessLock::new(ReadinessVec::new(len)),
+                inline: core::array::from_fn(|index| {
+                    // SAFETY: we only compute the address, we don't read it.
+                    let address = unsafe { ptr::addr_of!((*shared).inline[index]) };
+                    Slot::new(address, shared, index)
+                }),
+                chunks: UnsafeCell::new(SmallVec::new()),
+            }
+        });
+        if len > INLINE_SLOTS {
+            // SAFETY: we have the only reference.
+            unsafe { this.grow(INLINE_SLOTS, len - INLINE_SLOTS) };
+        }
+        this
+    }
+
+    /// Returns the number of allocated slots.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called by the owning `WakerVec`.
+    pub(crate) unsafe fn capacity(&self) -> usize {
+        let chunks = &*self.chunks.get();
+        INLINE_SLOTS + chunks.iter().map(|chunk| chunk.len()).sum::<usize>()
+    }
+
+    /// Allocate `additional` slots on the heap, starting at index `start`.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called by the owning `WakerVec`, and `start` must be
+    /// equal to the current capacity.
+    pub(crate) unsafe fn grow(&self, start: usize, additional: usize) {
+        if additional == 0 {
+            return;
+        }
+        let shared: *const Self = self;
+        // The slots are never pushed beyond the capacity, so the buffer won't
+        // be reallocated and we know their final addresses up front.
+        let mut slots: Vec<Slot> = Vec::with_capacity(additional);
+        let base = slots.as_ptr();
+        for i in 0..additional {
+            slots.push(Slot::new(base.add(i), shared, start + i));
+        }
+        debug_assert_eq!(slots.as_ptr(), base);
+        let chunk = NonNull::from(Box::leak(slots.into_boxed_slice()));
+        (*self.chunks.get()).push(chunk);
+    }
+
+    /// Free all chunks which only hold slots at or beyond `len`.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called by the owning `WakerVec`, and only when no child
+    /// wakers are alive.
+    pub(crate) unsafe fn truncate(&self, len: usize) {
+        let chunks = &mut *self.chunks.get();
+        let mut start = INLINE_SLOTS;
+        let keep = chunks
+            .iter()
+            .take_while(|chunk| {
+                let keep = start < len;
+                start += chunk.len();
+                keep
+            })
+            .count();
+        for chunk in chunks.drain(keep..) {
+            drop(Box::from_raw(chunk.as_ptr()));
+        }
+    }
+
+    /// Get the borrowed waker for the given index.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called by the owning `WakerVec`.
+    pub(crate) unsafe fn get(&self, index: usize) -> Option<&Waker> {
+        if let Some(slot) = self.inline.get(index) {
+            return Some(&slot.waker);
+        }
+        let mut index = index - INLINE_SLOTS;
+        for chunk in (*self.chunks.get()).iter() {
+            if let Some(slot) = chunk.as_ref().get(index) {
+                return Some(&slot.waker);
+            }
+            index -= chunk.len();
+        }
+        None
+    }
+
+    unsafe fn clone_waker(ptr: *const ()) -> RawWaker {
+        let slot = &*(ptr as *const Slot);
+        Arc::increment_strong_count(slot.shared);
+        RawWaker::new(ptr, &Self::VTABLE)
+    }
+
+    unsafe fn wake(ptr: *const ()) {
+        Self::wake_by_ref(ptr);
+        Self::drop_waker(ptr);
+    }
+
+    unsafe fn wake_by_ref(ptr: *const ()) {
+        let slot = &*(ptr as *const Slot);
+        let mut readiness = (*slot.shared).readiness.acquire();
+        // The collection may have shrunk since this waker was handed out, in
+        // which case the slot no longer exists and there is nothing to wake.
+        if slot.index >= readiness.len() {
+            return;
+        }
+        if !readiness.set_ready(slot.index) {
+            readiness.trace().woken(slot.index);
             readiness
                 .parent_waker()
                 .expect("`parent_waker` not available from `Readiness`. Did you forget to call `Readiness::set_waker`?")
                 .wake_by_ref()
         }
     }
+
+    unsafe fn drop_waker(ptr: *const ()) {
+        let slot = &*(ptr as *const Slot);
+        Arc::decrement_strong_count(slot.shared);
+    }
+}
+
+impl Drop for SharedVec {
+    fn drop(&mut self) {
+        for chunk in self.chunks.get_mut().drain(..) {
+            // SAFETY: the chunk was created by leaking a `Box`, and no wakers
+            // pointing into it are alive anymore.
+            drop(unsafe { Box::from_raw(chunk.as_ptr()) });
+        }
+    }
 }
@@ -1,16 +1,15 @@
-#[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::vec::Vec;
-
-use alloc::sync::Arc;
 use core::task::Waker;
-use std::sync::{Mutex, MutexGuard};
 
-use super::{InlineWakerVec, ReadinessVec};
+use super::{ReadinessVec, SharedVec};
+use crate::utils::wakers::sync::{Arc, Lock, OnceCell, ReadinessGuard};
+use crate::utils::wakers::Wakers;
 
 /// A collection of wakers which delegate to an in-line waker.
 pub(crate) struct WakerVec {
-    wakers: Vec<Waker>,
-    readiness: Arc<Mutex<ReadinessVec>>,
+    /// The shared state is allocated when it's first accessed, for
+    /// collections created with `WakerVec::lazy`.
+    shared: OnceCell<Arc<SharedVec>>,
+    len: usize,
 }
 
 impl Default for WakerVec {
@@ -22,35 +21,164 @@ impl Default for WakerVec {
 impl WakerVec {
     /// Create a new instance of `WakerVec`.
     pub(crate) fn new(len: usize) -> Self {
-        let readiness = Arc::new(Mutex::new(ReadinessVec::new(len)));
-        let wakers = (0..len)
-            .map(|i| Arc::new(InlineWakerVec::new(i, readiness.clone())).into())
-            .collect();
-        Self { wakers, readiness }
+        Self {
+            shared: OnceCell::from(SharedVec::new(len)),
+            len,
+        }
+    }
+
+    /// Create a new instance of `WakerVec`, which only allocates its shared
+    /// state once it's first accessed.
+    ///
+    /// This is used by collections with a single member, which poll it with
+    /// the parent waker directly and never access their wakers.
+    pub(crate) fn lazy(len: usize) -> Self {
+        Self {
+            shared: OnceCell::new(),
+            len,
+        }
+    }
+
+    fn shared(&self) -> &Arc<SharedVec> {
+        self.shared.get_or_init(|| SharedVec::new(self.len))
     }
 
     pub(crate) fn get(&self, index: usize) -> Option<&Waker> {
-        self.wakers.get(index)
+        if index >= self.len {
+            return None;
+        }
+        // SAFETY: we're the owning `WakerVec`.
+        unsafe { self.shared().get(index) }
     }
 
     /// Access the `Readiness`.
-    pub(crate) fn readiness(&self) -> MutexGuard<'_, ReadinessVec> {
-        self.readiness.lock().unwrap()
+    pub(crate) fn readiness(&self) -> ReadinessGuard<'_, ReadinessVec> {
+        self.shared().readiness.acquire()
     }
 
     /// Resize the `WakerVec` to the new size.
     pub(crate) fn resize(&mut self, len: usize) {
-        // If we grow the vec we'll need to extend beyond the current index.
-        // Which means the first position is the current length, and every position
-        // beyond that is incremented by 1.
-        let mut index = self.wakers.len();
-        self.wakers.resize_with(len, || {
-            let ret = Arc::new(InlineWakerVec::new(index, self.readiness.clone())).into();
-            index += 1;
-            ret
-        });
-
-        let mut readiness = self.readiness.lock().unwrap();
+        self.len = len;
+        // A lazy `WakerVec` which hasn't been accessed yet is allocated with
+        // the new size right away once it is.
+        let Some(shared) = self.shared.get_mut() else {
+            return;
+        };
+        // SAFETY: we're the owning `WakerVec`, and we only call `grow` with
+        // the current capacity. Chunks are only freed when no child wakers
+        // exist which might still point into them.
+        unsafe {
+            let capacity = shared.capacity();
+            if len > capacity {
+                shared.grow(capacity, len - capacity);
+            } else if len < capacity && Arc::strong_count(shared) == 1 {
+                shared.truncate(len);
+            }
+        }
+
+        let mut readiness = self.readiness();
         readiness.resize(len);
     }
 }
+
+impl Wakers for WakerVec {
+    type Readiness = ReadinessVec;
+    type Guard<'a> = ReadinessGuard<'a, ReadinessVec>;
+
+    fn get(&self, index: usize) -> Option<&Waker> {
+        WakerVec::get(self, index)
+    }
+
+    fn readiness(&mut self) -> Self::Guard<'_> {
+        WakerVec::readiness(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::CountingWaker;
+    use std::thread;
+
+    #[test]
+    fn concurrent_wakes_across_chunks() {
+        let parent = CountingWaker::new();
+        let mut wakers = WakerVec::new(4);
+        // Grow in steps, so the slots are spread over the inline storage and
+        // several heap chunks.
+        wakers.resize(12);
+        wakers.resize(20);
+        let len = 20;
+        {
+            let mut readiness = wakers.readiness();
+            readiness.set_waker(parent.waker());
+            readiness.clear_all_ready();
+            assert!(!readiness.any_ready());
+        }
+
+        // Wake every child many times from multiple threads at once.
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let children: Vec<Waker> =
+                    (0..len).map(|i| wakers.get(i).unwrap().clone()).collect();
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        for waker in &children {
+                            waker.wake_by_ref();
+                        }
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // Each slot recovers its own index, wherever it's stored.
+        let mut readiness = wakers.readiness();
+        for index in 0..len {
+            assert!(readiness.clear_ready(index));
+        }
+        assert_eq!(parent.wakes(), len);
+    }
+
+    #[test]
+    fn lazy() {
+        let mut wakers = WakerVec::lazy(1);
+        assert!(wakers.shared.get().is_none());
+        wakers.resize(20);
+        assert!(wakers.shared.get().is_none());
+
+        assert!(wakers.get(19).is_some());
+        assert!(wakers.get(20).is_none());
+        assert!(wakers.readiness().clear_ready(19));
+        assert_eq!(wakers.readiness().len(), 20);
+    }
+
+    #[test]
+    fn resize_keeps_wakers_valid() {
+        let parent = CountingWaker::new();
+        let mut wakers = WakerVec::new(2);
+        wakers.readiness().set_waker(parent.waker());
+        wakers.readiness().clear_all_ready();
+
+        let first = wakers.get(1).unwrap().clone();
+        wakers.resize(100);
+        wakers.readiness().clear_all_ready();
+        assert!(wakers.get(99).is_some());
+        assert!(wakers.get(100).is_none());
+
+        // A waker handed out before growing still wakes the right slot.
+        first.wake();
+        assert!(wakers.readiness().clear_ready(1));
+        wakers.get(99).unwrap().wake_by_ref();
+        assert!(wakers.readiness().clear_ready(99));
+
+        // Wakers for slots which were removed are ignored.
+        let last = wakers.get(99).unwrap().clone();
+        wakers.resize(10);
+        last.wake();
+        assert!(!wakers.readiness().any_ready());
+        assert_eq!(parent.wakes(), 2);
+    }
+}
@@ -0,0 +1,237 @@
+//! Count the heap allocations performed by the `Vec`-based operations.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::future;
+
+use futures_concurrency::prelude::*;
+use futures_concurrency::vec;
+use futures_lite::future::block_on;
+use futures_lite::prelude::*;
+use futures_lite::stream;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the output of `f`, and the number of allocations it performed on
+/// the current thread.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    // Make sure the executor's thread-local state has been initialized.
+    block_on(async {});
+    let before = ALLOCATIONS.with(Cell::get);
+    let out = f();
+    (out, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn merge_vec() {
+    // Up to 8 wakers are stored inline in the shared waker state.
+    let streams: Vec<_> = (0..8).map(stream::once).collect();
+    let (merge, allocations) = count_allocations(|| streams.merge());
+    assert_eq!(allocations, 1);
+
+    // Polling the merge does not allocate any further.
+    let (sum, allocations) = count_allocations(|| block_on(merge.fold(0, |a, b| a + b)));
+    assert_eq!(sum, 28);
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn merge_vec_spilled() {
+    // Any further wakers are stored in a single additional allocation.
+    let streams: Vec<_> = (0..20).map(stream::once).collect();
+    let (merge, allocations) = count_allocations(|| streams.merge());
+    assert_eq!(allocations, 2);
+
+    let (sum, allocations) = count_allocations(|| block_on(merge.fold(0, |a, b| a + b)));
+    assert_eq!(sum, 190);
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn merge_from_iter() {
+    // The streams are moved into their storage directly, without going
+    // through an intermediate `Vec`.
+    let (merge, allocations) =
+        count_allocations(|| (0..8).map(stream::once).collect::<vec::Merge<_>>());
+    assert_eq!(allocations, 2);
+
+    let (sum, allocations) = count_allocations(|| block_on(merge.fold(0, |a, b| a + b)));
+    assert_eq!(sum, 28);
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn merge_array() {
+    // Only the shared waker state is allocated, while a `Vec` of the same
+    // streams also stores its streams on the heap.
+    let (merge, allocations) = count_allocations(|| {
+        [0, 1, 2, 3]
+            .map(|i| stream::iter(i * 10..i * 10 + 3))
+            .merge()
+    });
+    assert_eq!(allocations, 1);
+
+    let (sum, allocations) = count_allocations(|| block_on(merge.fold(0, |a, b| a + b)));
+    assert_eq!(sum, (0..4).flat_map(|i| i * 10..i * 10 + 3).sum::<i32>());
+    assert_eq!(allocations, 0);
+
+    let (_, vec_allocations) = count_allocations(|| {
+        (0..4)
+            .map(|i| stream::iter(i * 10..i * 10 + 3))
+            .collect::<Vec<_>>()
+            .merge()
+    });
+    assert!(vec_allocations > 1, "{vec_allocations} allocations");
+}
+
+#[test]
+fn merge_tuple_2() {
+    // Both wakers and their readiness share a single allocation.
+    let (merge, allocations) = count_allocations(|| (stream::once(1), stream::once(2)).merge());
+    assert_eq!(allocations, 1);
+
+    let (sum, allocations) = count_allocations(|| block_on(merge.fold(0, |a, b| a + b)));
+    assert_eq!(sum, 3);
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn join_vec() {
+    let futures: Vec<_> = (0..8).map(future::ready).collect();
+    let (join, allocations) = count_allocations(|| futures.join());
+    // The shared waker state, and the outputs.
+    assert_eq!(allocations, 2);
+
+    let (out, allocations) = count_allocations(|| block_on(join));
+    assert_eq!(out, (0..8).collect::<Vec<_>>());
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn join_vec_reuse_parts() {
+    use futures_concurrency::vec::{Join, JoinParts};
+
+    let mut parts = Some(JoinParts::default());
+    let mut run = |batch: usize| {
+        let (out, allocations) = count_allocations(|| {
+            let mut next = parts.take().unwrap();
+            next.futures_mut()
+                .extend((0..20).map(|n| future::ready(batch + n)));
+            let mut join = Join::from_parts(next);
+            let out = block_on(&mut join);
+            parts = Some(join.into_parts());
+            out
+        });
+        assert_eq!(out, (batch..batch + 20).collect::<Vec<_>>());
+        allocations
+    };
+
+    // Warm up the buffers.
+    run(0);
+
+    // Afterwards, only the outputs are allocated.
+    for batch in 1..100 {
+        assert_eq!(run(batch), 1);
+    }
+}
+
+#[test]
+fn single_member() {
+    // A single member is polled with the caller's waker directly, so no
+    // shared waker state is allocated.
+    let streams = vec![stream::once(1)];
+    let (merge, allocations) = count_allocations(|| streams.merge());
+    assert_eq!(allocations, 0);
+    let (sum, allocations) = count_allocations(|| block_on(merge.fold(0, |a, b| a + b)));
+    assert_eq!(sum, 1);
+    assert_eq!(allocations, 0);
+
+    let futures = vec![future::ready(1)];
+    let (join, allocations) = count_allocations(|| futures.join());
+    // Only the outputs are allocated.
+    assert_eq!(allocations, 1);
+    let (out, allocations) = count_allocations(|| block_on(join));
+    assert_eq!(out, [1]);
+    assert_eq!(allocations, 0);
+
+    let futures = vec![future::ready(Ok::<_, ()>(1))];
+    let (join, allocations) = count_allocations(|| futures.try_join());
+    assert_eq!(allocations, 1);
+    let (out, allocations) = count_allocations(|| block_on(join));
+    assert_eq!(out, Ok(vec![1]));
+    assert_eq!(allocations, 0);
+
+    let futures = vec![future::ready(1)];
+    let (race, allocations) = count_allocations(|| futures.race());
+    assert_eq!(allocations, 0);
+    let (out, allocations) = count_allocations(|| block_on(race));
+    assert_eq!(out, 1);
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn join_vec_unit() {
+    // Outputs of `()` take up no space, so joining them allocates exactly
+    // one buffer less than joining outputs which do, even once the shared
+    // waker state has spilled.
+    let sized: Vec<_> = (0..1000).map(|_| future::ready(0u8)).collect();
+    let (_, sized) = count_allocations(|| sized.join());
+
+    let futures: Vec<_> = (0..1000).map(|_| future::ready(())).collect();
+    let (join, allocations) = count_allocations(|| futures.join());
+    assert_eq!(allocations, sized - 1);
+    let (out, allocations) = count_allocations(|| block_on(join));
+    assert_eq!(out.len(), 1000);
+    assert_eq!(allocations, 0);
+
+    let sized: Vec<_> = (0..1000).map(|_| future::ready(Ok::<_, ()>(0u8))).collect();
+    let (_, sized) = count_allocations(|| sized.try_join());
+
+    let futures: Vec<_> = (0..1000).map(|_| future::ready(Ok::<_, ()>(()))).collect();
+    let (join, allocations) = count_allocations(|| futures.try_join());
+    assert_eq!(allocations, sized - 1);
+    let (out, allocations) = count_allocations(|| block_on(join));
+    assert_eq!(out.map(|out| out.len()), Ok(1000));
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn zip_with_vec() {
+    let streams = || -> Vec<_> { (0..3).map(|n| stream::repeat(n).take(100)).collect() };
+
+    // Every row of a zip is yielded as a new `Vec`.
+    let zip = streams().zip();
+    let (sum, allocations) =
+        count_allocations(|| block_on(zip.map(|row| row.iter().sum()).fold(0, |a, b: i32| a + b)));
+    assert_eq!(sum, 300);
+    assert_eq!(allocations, 100);
+
+    // Combining the rows in place reuses a single buffer for every row.
+    let zip = streams().zip().with(|row| row.sum::<i32>());
+    let (sum, allocations) = count_allocations(|| block_on(zip.fold(0, |a, b| a + b)));
+    assert_eq!(sum, 300);
+    assert_eq!(allocations, 1);
+}
@@ -0,0 +1,182 @@
+//! Check that the operations are `Send` and `Sync` exactly when their members are.
+
+use std::future::{Future, Ready};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+use futures_concurrency::future::{self, FutureGroup};
+use futures_concurrency::prelude::*;
+use futures_concurrency::stream::{self, StreamGroup};
+use futures_core::Stream;
+
+fn is_send<T: Send>(_: &T) {}
+fn is_sync<T: Sync>(_: &T) {}
+
+/// Fails to compile if `T` implements the given trait, by making the method
+/// call ambiguous between the blanket and the conditional impl.
+macro_rules! assert_not_impl {
+    ($value:expr, $trait:path) => {{
+        trait AmbiguousIfImpl<A> {
+            fn check(&self) {}
+        }
+        impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+        struct Invalid;
+        impl<T: ?Sized + $trait> AmbiguousIfImpl<Invalid> for T {}
+        AmbiguousIfImpl::check(&$value);
+    }};
+}
+
+/// A future and stream which is `Send` and `Sync`, but whose output is neither.
+struct LocalOutput;
+
+impl Future for LocalOutput {
+    type Output = Rc<()>;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Poll::Ready(Rc::new(()))
+    }
+}
+
+impl Stream for LocalOutput {
+    type Item = Rc<()>;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(None)
+    }
+}
+
+/// A future and stream which is neither `Send` nor `Sync`.
+struct Local(#[allow(dead_code)] Rc<()>);
+
+impl Future for Local {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Poll::Ready(())
+    }
+}
+
+impl Stream for Local {
+    type Item = ();
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(None)
+    }
+}
+
+fn ready() -> Ready<Result<u8, u8>> {
+    std::future::ready(Ok(1))
+}
+
+fn local() -> Local {
+    Local(Rc::new(()))
+}
+
+#[test]
+fn futures_are_send_and_sync() {
+    macro_rules! check {
+        ($($value:expr),* $(,)?) => {$(
+            let value = $value;
+            is_send(&value);
+            is_sync(&value);
+        )*};
+    }
+    check!(
+        (ready(), ready()).join(),
+        [ready(), ready()].join(),
+        vec![ready(), ready()].join(),
+        (ready(), ready()).try_join(),
+        [ready(), ready()].try_join(),
+        vec![ready(), ready()].try_join(),
+        (ready(), ready()).race(),
+        [ready(), ready()].race(),
+        vec![ready(), ready()].race(),
+        (ready(), ready()).race_ok(),
+        [ready(), ready()].race_ok(),
+        vec![ready(), ready()].race_ok(),
+        FutureGroup::<Ready<u8>>::new(),
+        future::Fuse::new(ready()),
+    );
+}
+
+#[test]
+fn streams_are_send_and_sync() {
+    macro_rules! check {
+        ($($value:expr),* $(,)?) => {$(
+            let value = $value;
+            is_send(&value);
+            is_sync(&value);
+        )*};
+    }
+    let once = || stream::from_iter(Some(1));
+    check!(
+        (once(), once()).merge(),
+        [once(), once()].merge(),
+        vec![once(), once()].merge(),
+        (once(), once()).chain(),
+        [once(), once()].chain(),
+        vec![once(), once()].chain(),
+        (once(), once()).zip(),
+        [once(), once()].zip(),
+        vec![once(), once()].zip(),
+        StreamGroup::<stream::FromIter<std::option::IntoIter<u8>>>::new(),
+        stream::Fuse::new(once()),
+    );
+}
+
+/// Operations which never store the output of their members are `Send` and
+/// `Sync` regardless of the output type.
+#[test]
+fn independent_of_output() {
+    macro_rules! check {
+        ($($value:expr),* $(,)?) => {$(
+            let value = $value;
+            is_send(&value);
+            is_sync(&value);
+        )*};
+    }
+    check!(
+        (LocalOutput, LocalOutput).race(),
+        [LocalOutput, LocalOutput].race(),
+        vec![LocalOutput, LocalOutput].race(),
+        (LocalOutput, LocalOutput).merge(),
+        [LocalOutput, LocalOutput].merge(),
+        vec![LocalOutput, LocalOutput].merge(),
+        (LocalOutput, LocalOutput).chain(),
+        [LocalOutput, LocalOutput].chain(),
+        vec![LocalOutput, LocalOutput].chain(),
+    );
+    let mut group = StreamGroup::new();
+    group.insert(LocalOutput);
+    is_send(&group);
+    is_sync(&group);
+}
+
+#[test]
+fn not_send_or_sync_with_local_members() {
+    macro_rules! check {
+        ($($value:expr),* $(,)?) => {$(
+            let value = $value;
+            assert_not_impl!(value, Send);
+            assert_not_impl!(value, Sync);
+        )*};
+    }
+    check!(
+        (local(), local()).join(),
+        [local(), local()].join(),
+        vec![local(), local()].join(),
+        (local(), local()).race(),
+        [local(), local()].race(),
+        vec![local(), local()].race(),
+        (local(), local()).merge(),
+        [local(), local()].merge(),
+        vec![local(), local()].merge(),
+        (local(), local()).chain(),
+        [local(), local()].chain(),
+        vec![local(), local()].chain(),
+        (local(), local()).zip(),
+        [local(), local()].zip(),
+        vec![local(), local()].zip(),
+    );
+}
@@ -0,0 +1,101 @@
+//! Every `Vec` operation accepts boxed trait objects, which is how futures and
+//! streams of different types are usually combined.
+
+#![cfg(feature = "alloc")]
+
+use std::future::{pending, ready, Future};
+use std::pin::Pin;
+
+use futures_concurrency::prelude::*;
+use futures_core::Stream;
+use futures_lite::future::block_on;
+use futures_lite::prelude::*;
+use futures_lite::stream;
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+type BoxStream<T> = Pin<Box<dyn Stream<Item = T> + Send>>;
+
+#[test]
+fn join() {
+    block_on(async {
+        let futs: Vec<BoxFuture<u8>> = vec![Box::pin(ready(1)), Box::pin(async { 2 })];
+        assert_eq!(futs.join().await, vec![1, 2]);
+    })
+}
+
+#[test]
+fn try_join() {
+    block_on(async {
+        let futs: Vec<BoxFuture<Result<u8, ()>>> =
+            vec![Box::pin(ready(Ok(1))), Box::pin(async { Err(()) })];
+        assert_eq!(futs.try_join().await, Err(()));
+    })
+}
+
+#[test]
+fn race() {
+    block_on(async {
+        let futs: Vec<BoxFuture<u8>> = vec![Box::pin(pending()), Box::pin(async { 2 })];
+        assert_eq!(futs.race().await, 2);
+    })
+}
+
+#[test]
+fn race_ok() {
+    block_on(async {
+        let futs: Vec<BoxFuture<Result<u8, &str>>> =
+            vec![Box::pin(ready(Err("oh no"))), Box::pin(async { Ok(2) })];
+        assert_eq!(futs.race_ok().await.unwrap(), 2);
+    })
+}
+
+#[test]
+fn merge() {
+    block_on(async {
+        let streams: Vec<BoxStream<u8>> =
+            vec![Box::pin(stream::once(1)), Box::pin(stream::iter([2, 3]))];
+        let mut out: Vec<_> = streams.merge().collect().await;
+        out.sort_unstable();
+        assert_eq!(out, vec![1, 2, 3]);
+    })
+}
+
+#[test]
+fn chain() {
+    block_on(async {
+        let streams: Vec<BoxStream<u8>> =
+            vec![Box::pin(stream::once(1)), Box::pin(stream::iter([2, 3]))];
+        let out: Vec<_> = streams.chain().collect().await;
+        assert_eq!(out, vec![1, 2, 3]);
+    })
+}
+
+#[test]
+fn zip() {
+    block_on(async {
+        let streams: Vec<BoxStream<u8>> = vec![
+            Box::pin(stream::iter([1, 2])),
+            Box::pin(stream::iter([3, 4])),
+        ];
+        let out: Vec<_> = streams.zip().collect().await;
+        assert_eq!(out, vec![vec![1, 3], vec![2, 4]]);
+    })
+}
+
+#[test]
+fn boxed_concurrency() {
+    block_on(async {
+        let futs = vec![
+            ready(1).boxed_concurrency(),
+            async { 2 }.boxed_concurrency(),
+        ];
+        assert_eq!(futs.join().await, vec![1, 2]);
+
+        let streams = vec![
+            stream::once(1).boxed_concurrency(),
+            stream::iter([2, 3]).boxed_concurrency(),
+        ];
+        let out: Vec<_> = streams.chain().collect().await;
+        assert_eq!(out, vec![1, 2, 3]);
+    })
+}
@@ -0,0 +1,80 @@
+//! Futures which are pinned elsewhere can be passed to the concurrency
+//! operations by reference, and remain usable afterwards.
+
+use futures_concurrency::prelude::*;
+use futures_lite::future::{self, block_on};
+use std::pin::pin;
+
+#[test]
+fn race_then_finish_loser() {
+    block_on(async {
+        let mut slow = pin!(async {
+            future::yield_now().await;
+            future::yield_now().await;
+            "slow"
+        });
+        let mut fast = pin!(async { "fast" });
+
+        assert_eq!((slow.as_mut(), fast.as_mut()).race().await, "fast");
+
+        // The loser keeps its progress, and can be awaited to completion.
+        assert_eq!(slow.await, "slow");
+    })
+}
+
+#[test]
+fn race_ok_then_finish_loser() {
+    block_on(async {
+        let mut err = pin!(async { Err::<&str, _>("oh no") });
+        let mut slow = pin!(async {
+            future::yield_now().await;
+            future::yield_now().await;
+            Ok::<_, &str>("slow")
+        });
+        let mut ok = pin!(async { Ok("ok") });
+
+        let out = (err.as_mut(), slow.as_mut(), ok.as_mut()).race_ok().await;
+        assert!(matches!(out, Ok("ok")));
+        assert!(matches!(slow.await, Ok("slow")));
+    })
+}
+
+#[test]
+fn join_unpin_by_mut() {
+    block_on(async {
+        let mut a = future::ready(1);
+        let mut b = future::ready("two");
+        assert_eq!((&mut a, &mut b).join().await, (1, "two"));
+    })
+}
+
+#[test]
+fn select_loop() {
+    block_on(async {
+        // Race a long-running future against a series of short ones, without
+        // restarting the long-running future each time around the loop.
+        let mut long = pin!(async {
+            for _ in 0..3 {
+                future::yield_now().await;
+            }
+            "done"
+        });
+
+        let mut events = 0;
+        loop {
+            let tick = async {
+                future::yield_now().await;
+                None
+            };
+            let long = async { Some(long.as_mut().await) };
+            match (long, tick).race().await {
+                Some(out) => {
+                    assert_eq!(out, "done");
+                    break;
+                }
+                None => events += 1,
+            }
+        }
+        assert!(events > 0);
+    })
+}
@@ -0,0 +1,9 @@
+//! Check that fallible operations reject members which don't resolve to
+//! `Result`, or whose output can't be inferred, that races reject the empty
+//! tuple, and that the handles of a scope can't escape it.
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
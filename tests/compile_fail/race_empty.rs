@@ -0,0 +1,6 @@
+use futures_concurrency::prelude::*;
+
+fn main() {
+    // A race without any futures would never have a winner.
+    let _ = ().race();
+}
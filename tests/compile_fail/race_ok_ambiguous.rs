@@ -0,0 +1,7 @@
+use futures_concurrency::prelude::*;
+use std::future::pending;
+
+fn main() {
+    // Neither member decides the output or the error type of the race.
+    let _ = (pending(), pending()).race_ok();
+}
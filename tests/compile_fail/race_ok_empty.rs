@@ -0,0 +1,6 @@
+use futures_concurrency::prelude::*;
+
+fn main() {
+    // There is no future to determine the output of the race.
+    let _ = ().race_ok();
+}
@@ -0,0 +1,7 @@
+use futures_concurrency::prelude::*;
+use std::future::ready;
+
+fn main() {
+    // `Option`s are raced with `race_some`, and never mixed with `Result`s.
+    let _ = (ready(None::<u8>), ready(Ok::<u8, ()>(2))).race_ok();
+}
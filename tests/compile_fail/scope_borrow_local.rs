@@ -0,0 +1,10 @@
+use futures_concurrency::future::scope;
+
+fn main() {
+    // Futures can't borrow the locals of the closure, which are gone by the
+    // time the scope is polled.
+    let _ = scope(|s| {
+        let local = 1;
+        s.add(async { local + 1 });
+    });
+}
@@ -0,0 +1,11 @@
+use futures_concurrency::future::scope;
+
+fn main() {
+    // The handle can't outlive the closure, so nothing can be added to the
+    // scope after it has completed.
+    let mut leaked = None;
+    let _ = scope(|s| {
+        leaked = Some(s.clone());
+        s.add(async {});
+    });
+}
@@ -0,0 +1,7 @@
+use futures_concurrency::prelude::*;
+use std::future::ready;
+
+fn main() {
+    // Infallible futures are joined with `join`.
+    let _ = (ready(1), ready(2)).try_join();
+}
@@ -0,0 +1,7 @@
+use futures_concurrency::prelude::*;
+use std::future::ready;
+
+fn main() {
+    // `Option`s are joined with `join_some`, and never mixed with `Result`s.
+    let _ = (ready(Some(1)), ready(Ok::<_, ()>(2))).try_join();
+}
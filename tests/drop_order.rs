@@ -0,0 +1,141 @@
+//! The members of tuple operations are dropped in the order of the tuple.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+use futures_concurrency::prelude::*;
+use futures_lite::future::poll_once;
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+/// Records its name in the log when it's dropped.
+struct Guard(String, Log);
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        self.1.borrow_mut().push(self.0.clone());
+    }
+}
+
+impl fmt::Debug for Guard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A future which is either pending forever, or completes on its first poll.
+struct Member<T> {
+    _guard: Guard,
+    output: Option<T>,
+}
+
+impl<T> Future for Member<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<T> {
+        // SAFETY: `output` is never pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        match this.output.take() {
+            Some(output) => Poll::Ready(output),
+            None => Poll::Pending,
+        }
+    }
+}
+
+fn pending<T>(name: &str, log: &Log) -> Member<T> {
+    Member {
+        _guard: Guard(format!("future {name}"), log.clone()),
+        output: None,
+    }
+}
+
+fn ready<T>(name: &str, log: &Log, output: T) -> Member<T> {
+    Member {
+        _guard: Guard(format!("future {name}"), log.clone()),
+        output: Some(output),
+    }
+}
+
+fn guard(name: &str, log: &Log) -> Guard {
+    Guard(name.to_string(), log.clone())
+}
+
+fn take(log: &Log) -> Vec<String> {
+    log.take()
+}
+
+fn all_pending(log: &Log) -> (Member<()>, Member<()>, Member<()>) {
+    (pending("a", log), pending("b", log), pending("c", log))
+}
+
+#[test]
+fn join() {
+    let log = Log::default();
+    drop(all_pending(&log).join());
+    assert_eq!(take(&log), ["future a", "future b", "future c"]);
+
+    let output = |name| ready(name, &log, guard(&format!("output {name}"), &log));
+    let mut fut = Box::pin(
+        (
+            pending::<()>("a", &log),
+            output("b"),
+            pending::<()>("c", &log),
+        )
+            .join(),
+    );
+    assert!(futures_lite::future::block_on(poll_once(fut.as_mut())).is_none());
+    // The future which completed is dropped right away.
+    assert_eq!(take(&log), ["future b"]);
+    drop(fut);
+    assert_eq!(take(&log), ["output b", "future a", "future c"]);
+}
+
+#[test]
+fn try_join() {
+    let log = Log::default();
+    let ok = |name| ready(name, &log, Ok(guard(&format!("output {name}"), &log)));
+    let fut = (
+        pending::<Result<Guard, ()>>("a", &log),
+        ok("b"),
+        ready("c", &log, Err::<(), _>(())),
+    );
+    let mut fut = Box::pin(fut.try_join());
+    let res = futures_lite::future::block_on(poll_once(fut.as_mut()));
+    assert_eq!(res.map(|res| res.is_err()), Some(true));
+    assert_eq!(take(&log), ["future b", "future c"]);
+    drop(fut);
+    assert_eq!(take(&log), ["output b", "future a"]);
+}
+
+#[test]
+fn race() {
+    let log = Log::default();
+    drop(all_pending(&log).race());
+    assert_eq!(take(&log), ["future a", "future b", "future c"]);
+
+    let mut fut = Box::pin((pending("a", &log), ready("b", &log, ()), pending("c", &log)).race());
+    assert!(futures_lite::future::block_on(poll_once(fut.as_mut())).is_some());
+    assert!(take(&log).is_empty());
+    drop(fut);
+    assert_eq!(take(&log), ["future a", "future b", "future c"]);
+}
+
+#[test]
+fn race_ok() {
+    let log = Log::default();
+    let err = |name| ready(name, &log, Err(guard(&format!("error {name}"), &log)));
+    let fut = (
+        err("a"),
+        pending::<Result<(), Guard>>("b", &log),
+        pending("c", &log),
+    );
+    let mut fut = Box::pin(fut.race_ok());
+    assert!(futures_lite::future::block_on(poll_once(fut.as_mut())).is_none());
+    assert!(take(&log).is_empty());
+    drop(fut);
+    assert_eq!(take(&log), ["error a", "future a", "future b", "future c"]);
+}
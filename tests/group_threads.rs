@@ -0,0 +1,67 @@
+//! Members can be inserted into a group while other members are being woken
+//! from other threads.
+
+#![cfg(feature = "std")]
+
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::sync::{mpsc, Arc, Mutex};
+use std::task::Poll;
+use std::thread;
+
+use futures::channel::mpsc::{unbounded, UnboundedSender};
+use futures_concurrency::stream::StreamGroup;
+use futures_core::Stream;
+use futures_lite::future::block_on;
+
+const STREAMS: usize = 10_000;
+
+#[test]
+fn insert_while_consuming() {
+    let group = Arc::new(Mutex::new(StreamGroup::new()));
+
+    // Keep the group from becoming empty while streams are still being
+    // inserted.
+    let (keepalive, rx) = unbounded::<usize>();
+    group.lock().unwrap().insert(rx);
+
+    // Send a single item on every stream from another thread, which wakes
+    // the stream while others are being inserted and the group grows.
+    let (senders, received) = mpsc::channel::<UnboundedSender<usize>>();
+    let sender = thread::spawn(move || {
+        for tx in received {
+            tx.unbounded_send(1).unwrap();
+        }
+    });
+
+    let inserter = thread::spawn({
+        let group = group.clone();
+        move || {
+            for _ in 0..STREAMS {
+                let (tx, rx) = unbounded();
+                group.lock().unwrap().insert(rx);
+                senders.send(tx).unwrap();
+            }
+        }
+    });
+
+    let mut total = 0;
+    block_on(poll_fn(|cx| loop {
+        let mut group = group.lock().unwrap();
+        match Pin::new(&mut *group).poll_next(cx) {
+            Poll::Ready(Some(n)) => {
+                total += n;
+                if total == STREAMS {
+                    return Poll::Ready(());
+                }
+            }
+            Poll::Ready(None) => panic!("the group ended early"),
+            Poll::Pending => return Poll::Pending,
+        }
+    }));
+
+    inserter.join().unwrap();
+    sender.join().unwrap();
+    drop(keepalive);
+    assert_eq!(total, STREAMS);
+}
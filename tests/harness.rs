@@ -0,0 +1,123 @@
+//! Drive the concurrency operations through random interleavings of wakes,
+//! spurious wakes, and parent waker replacements.
+//!
+//! Every seed is a separate, reproducible run. The harness itself asserts
+//! that no member is polled after it completed, that no wake is lost, and
+//! that merged streams yield every item exactly once.
+
+#![cfg(feature = "interleaving-harness")]
+
+use futures_concurrency::prelude::*;
+use futures_concurrency::test_utils::harness::Harness;
+
+const SEEDS: u64 = 512;
+
+/// Pick between one and four members for the run.
+fn members(harness: &mut Harness) -> usize {
+    harness.below(4) + 1
+}
+
+#[test]
+fn merge() {
+    for seed in 0..SEEDS {
+        let mut harness = Harness::new(seed);
+        let items: Vec<Vec<_>> = (0..members(&mut harness))
+            .map(|member| (0..harness.below(4)).map(|n| (member, n)).collect())
+            .collect();
+        let total = items.iter().map(Vec::len).sum::<usize>();
+
+        let report = harness.run_stream(items, |streams| streams.merge());
+        assert_eq!(report.output.len(), total, "seed {seed}");
+        // The merge only ends once all of its streams have ended.
+        assert!(report.finished.iter().all(|done| *done), "seed {seed}");
+    }
+}
+
+#[test]
+fn join() {
+    for seed in 0..SEEDS {
+        let mut harness = Harness::new(seed);
+        let outputs: Vec<_> = (0..members(&mut harness)).collect();
+
+        let report = harness.run_future(outputs.clone(), |futures| futures.join());
+        assert_eq!(report.output, outputs, "seed {seed}");
+        assert!(report.finished.iter().all(|done| *done), "seed {seed}");
+    }
+}
+
+#[test]
+fn try_join() {
+    for seed in 0..SEEDS {
+        let mut harness = Harness::new(seed);
+        let outputs: Vec<Result<usize, usize>> = (0..members(&mut harness))
+            .map(|n| if harness.below(3) == 0 { Err(n) } else { Ok(n) })
+            .collect();
+
+        let report = harness.run_future(outputs.clone(), |futures| futures.try_join());
+        match report.output {
+            // A failure is reported as soon as its future fails.
+            Err(n) => {
+                assert_eq!(outputs[n], Err(n), "seed {seed}");
+                assert!(report.finished[n], "seed {seed}");
+            }
+            Ok(values) => {
+                let expected: Vec<_> = outputs.iter().map(|out| out.unwrap()).collect();
+                assert_eq!(values, expected, "seed {seed}");
+                assert!(report.finished.iter().all(|done| *done), "seed {seed}");
+            }
+        }
+    }
+}
+
+#[test]
+fn race() {
+    for seed in 0..SEEDS {
+        let mut harness = Harness::new(seed);
+        let outputs: Vec<_> = (0..members(&mut harness)).collect();
+
+        let report = harness.run_future(outputs, |futures| futures.race());
+        // The race completes with the output of the first future which
+        // completed, and none of the others.
+        assert!(report.finished[report.output], "seed {seed}");
+        assert_eq!(
+            report.finished.iter().filter(|done| **done).count(),
+            1,
+            "seed {seed}"
+        );
+    }
+}
+
+#[test]
+fn race_ok() {
+    for seed in 0..SEEDS {
+        let mut harness = Harness::new(seed);
+        let outputs: Vec<Result<usize, usize>> = (0..members(&mut harness))
+            .map(|n| if harness.below(2) == 0 { Err(n) } else { Ok(n) })
+            .collect();
+
+        let report = harness.run_future(outputs.clone(), |futures| futures.race_ok());
+        match report.output {
+            Ok(n) => {
+                assert_eq!(outputs[n], Ok(n), "seed {seed}");
+                assert!(report.finished[n], "seed {seed}");
+            }
+            // Only once every future has failed, does the race fail.
+            Err(errors) => {
+                let expected: Vec<_> = outputs.iter().map(|out| out.unwrap_err()).collect();
+                assert_eq!(errors.into_inner(), expected, "seed {seed}");
+                assert!(report.finished.iter().all(|done| *done), "seed {seed}");
+            }
+        }
+    }
+}
+
+#[test]
+fn array_join() {
+    for seed in 0..SEEDS {
+        let report = Harness::new(seed).run_future(vec![0, 1, 2], |futures| {
+            let [a, b, c]: [_; 3] = futures.try_into().ok().unwrap();
+            [a, b, c].join()
+        });
+        assert_eq!(report.output, [0, 1, 2], "seed {seed}");
+    }
+}
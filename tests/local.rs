@@ -0,0 +1,133 @@
+//! Futures and streams which aren't `Send` can be combined, and the
+//! operations run on a single-threaded executor.
+
+#![cfg(feature = "alloc")]
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use futures::executor::LocalPool;
+use futures::task::LocalSpawnExt;
+use futures_concurrency::prelude::*;
+use futures_lite::future::yield_now;
+use futures_lite::prelude::*;
+use futures_lite::stream;
+
+/// Spawn a future which holds an `Rc` across an await point on a `LocalPool`,
+/// and run it to completion.
+fn run_local<Fut>(make: impl FnOnce(Rc<Cell<u8>>) -> Fut) -> u8
+where
+    Fut: Future<Output = ()> + 'static,
+{
+    let counter = Rc::new(Cell::new(0));
+    let mut pool = LocalPool::new();
+    pool.spawner().spawn_local(make(counter.clone())).unwrap();
+    pool.run();
+    counter.get()
+}
+
+/// A future which isn't `Send`, and which needs to be woken before it
+/// completes.
+async fn bump(counter: Rc<Cell<u8>>) -> u8 {
+    yield_now().await;
+    counter.set(counter.get() + 1);
+    counter.get()
+}
+
+/// A stream which isn't `Send`, and which needs to be woken before each item.
+fn bumps(counter: Rc<Cell<u8>>, len: usize) -> impl Stream<Item = Rc<Cell<u8>>> {
+    stream::repeat(counter)
+        .take(len)
+        .then(|counter| async move {
+            yield_now().await;
+            counter.set(counter.get() + 1);
+            counter
+        })
+}
+
+#[test]
+fn join() {
+    let count = run_local(|c| async move {
+        let (a, b) = (bump(c.clone()), bump(c.clone())).join().await;
+        assert_eq!(a + b, 3);
+        let out = [bump(c.clone()), bump(c.clone())].join().await;
+        assert_eq!(out.len(), 2);
+        let out = vec![bump(c.clone()), bump(c.clone())].join().await;
+        assert_eq!(out.len(), 2);
+    });
+    assert_eq!(count, 6);
+}
+
+#[test]
+fn try_join() {
+    let count = run_local(|c| async move {
+        let ok = |c| async move { Ok::<_, ()>(bump(c).await) };
+        assert!((ok(c.clone()), ok(c.clone())).try_join().await.is_ok());
+        assert!([ok(c.clone()), ok(c.clone())].try_join().await.is_ok());
+        assert!(vec![ok(c.clone()), ok(c.clone())].try_join().await.is_ok());
+    });
+    assert_eq!(count, 6);
+}
+
+#[test]
+fn race() {
+    let count = run_local(|c| async move {
+        (bump(c.clone()), bump(c.clone())).race().await;
+        [bump(c.clone()), bump(c.clone())].race().await;
+        vec![bump(c.clone()), bump(c.clone())].race().await;
+    });
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn race_ok() {
+    let count = run_local(|c| async move {
+        let ok = |c| async move { Ok::<_, ()>(bump(c).await) };
+        assert!((ok(c.clone()), ok(c.clone())).race_ok().await.is_ok());
+        assert!([ok(c.clone()), ok(c.clone())].race_ok().await.is_ok());
+        assert!(vec![ok(c.clone()), ok(c.clone())].race_ok().await.is_ok());
+    });
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn merge() {
+    let count = run_local(|c| async move {
+        let s = (bumps(c.clone(), 2), bumps(c.clone(), 2)).merge();
+        assert_eq!(s.count().await, 4);
+        let s = [bumps(c.clone(), 2), bumps(c.clone(), 2)].merge();
+        assert_eq!(s.count().await, 4);
+        let s = vec![bumps(c.clone(), 2), bumps(c.clone(), 2)].merge();
+        assert_eq!(s.count().await, 4);
+    });
+    assert_eq!(count, 12);
+}
+
+#[test]
+fn zip_and_chain() {
+    let count = run_local(|c| async move {
+        let s = vec![bumps(c.clone(), 2), bumps(c.clone(), 2)].zip();
+        assert_eq!(s.count().await, 2);
+        let s = vec![bumps(c.clone(), 2), bumps(c.clone(), 2)].chain();
+        assert_eq!(s.count().await, 4);
+    });
+    assert_eq!(count, 8);
+}
+
+#[test]
+fn boxed_local() {
+    let count = run_local(|c| async move {
+        let futures = vec![
+            bump(c.clone()).boxed_concurrency_local(),
+            async { 0 }.boxed_concurrency_local(),
+        ];
+        assert_eq!(futures.join().await.len(), 2);
+
+        let streams = vec![
+            bumps(c.clone(), 1).boxed_concurrency_local(),
+            stream::once(c.clone()).boxed_concurrency_local(),
+        ];
+        assert_eq!(streams.merge().count().await, 2);
+    });
+    assert_eq!(count, 2);
+}
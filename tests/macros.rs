@@ -0,0 +1,230 @@
+//! Expands the variadic macros with argument lists both within and beyond the
+//! tuple length supported by the combinator traits.
+
+use futures_concurrency::array::AggregateError;
+use futures_concurrency::{join, race, race_ok, try_join};
+use futures_lite::future::block_on;
+use std::future::{pending, ready};
+
+#[test]
+fn join() {
+    block_on(async {
+        assert_eq!(join!(ready(0)).await, (0,));
+        assert_eq!(join!(ready(0), ready("one")).await, (0, "one"));
+
+        let out = join!(
+            ready(0),
+            ready(1),
+            ready(2),
+            ready(3),
+            ready(4),
+            ready(5),
+            ready(6),
+            ready(7),
+            ready(8),
+            ready(9),
+            ready(10),
+            ready(11),
+            ready("twelve"),
+        )
+        .await;
+        assert_eq!(out.0, 0);
+        assert_eq!(out.11, 11);
+        assert_eq!(out.12, "twelve");
+
+        let out = join!(
+            ready(0),
+            ready(1),
+            ready(2),
+            ready(3),
+            ready(4),
+            ready(5),
+            ready(6),
+            ready(7),
+            ready(8),
+            ready(9),
+            ready(10),
+            ready(11),
+            ready(12),
+            ready(13),
+            ready(14),
+            ready(15),
+            ready(16),
+            ready(17),
+            ready(18),
+            ready("nineteen")
+        )
+        .await;
+        assert_eq!(out.0, 0);
+        assert_eq!(out.12, 12);
+        assert_eq!(out.19, "nineteen");
+    })
+}
+
+#[test]
+fn try_join() {
+    block_on(async {
+        assert_eq!(try_join!(ready(Ok::<_, ()>(0))).await, Ok((0,)));
+        assert_eq!(
+            try_join!(ready(Ok::<_, ()>(0)), ready(Ok("one"))).await,
+            Ok((0, "one"))
+        );
+
+        let out = try_join!(
+            ready(Ok::<_, &str>(0)),
+            ready(Ok(1)),
+            ready(Ok(2)),
+            ready(Ok(3)),
+            ready(Ok(4)),
+            ready(Ok(5)),
+            ready(Ok(6)),
+            ready(Ok(7)),
+            ready(Ok(8)),
+            ready(Ok(9)),
+            ready(Ok(10)),
+            ready(Ok(11)),
+            ready(Ok("twelve")),
+        )
+        .await;
+        let out = out.unwrap();
+        assert_eq!(out.0, 0);
+        assert_eq!(out.11, 11);
+        assert_eq!(out.12, "twelve");
+
+        let out = try_join!(
+            ready(Ok::<_, &str>(0)),
+            ready(Ok(1)),
+            ready(Ok(2)),
+            ready(Ok(3)),
+            ready(Ok(4)),
+            ready(Ok(5)),
+            ready(Ok(6)),
+            ready(Ok(7)),
+            ready(Ok(8)),
+            ready(Ok(9)),
+            ready(Ok(10)),
+            ready(Ok(11)),
+            ready(Ok(12)),
+            ready(Ok(13)),
+            ready(Ok(14)),
+            ready(Ok(15)),
+            ready(Ok(16)),
+            ready(Ok(17)),
+            ready(Err::<u8, _>("oh no")),
+            ready(Ok(19)),
+        )
+        .await;
+        assert!(matches!(out, Err("oh no")));
+    })
+}
+
+#[test]
+fn race() {
+    block_on(async {
+        assert_eq!(race!(ready(0)).await, 0);
+        assert_eq!(race!(pending(), ready(1)).await, 1);
+
+        let out = race!(
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            ready(12),
+        )
+        .await;
+        assert_eq!(out, 12);
+
+        let out = race!(
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            pending(),
+            ready(19),
+        )
+        .await;
+        assert_eq!(out, 19);
+    })
+}
+
+#[test]
+fn race_ok() {
+    block_on(async {
+        assert!(matches!(race_ok!(ready(Ok::<_, ()>(0))).await, Ok(0)));
+        assert!(matches!(
+            race_ok!(ready(Err(())), ready(Ok::<_, ()>(1))).await,
+            Ok(1)
+        ));
+
+        // The error type is the same for short and nested argument lists.
+        let err: Result<(), AggregateError<u8, 2>> = race_ok!(ready(Err(0)), ready(Err(1))).await;
+        assert_eq!(err.unwrap_err().into_inner(), [0, 1]);
+
+        let out = race_ok!(
+            ready(Err(0)),
+            ready(Err(1)),
+            ready(Err(2)),
+            ready(Err(3)),
+            ready(Err(4)),
+            ready(Err(5)),
+            ready(Err(6)),
+            ready(Err(7)),
+            ready(Err(8)),
+            ready(Err(9)),
+            ready(Err(10)),
+            ready(Err(11)),
+            ready(Ok::<_, u8>("twelve")),
+        )
+        .await;
+        assert!(matches!(out, Ok("twelve")));
+
+        let err = race_ok!(
+            ready(Err::<(), _>(0)),
+            ready(Err(1)),
+            ready(Err(2)),
+            ready(Err(3)),
+            ready(Err(4)),
+            ready(Err(5)),
+            ready(Err(6)),
+            ready(Err(7)),
+            ready(Err(8)),
+            ready(Err(9)),
+            ready(Err(10)),
+            ready(Err(11)),
+            ready(Err(12)),
+            ready(Err(13)),
+            ready(Err(14)),
+            ready(Err(15)),
+            ready(Err(16)),
+            ready(Err(17)),
+            ready(Err(18)),
+            ready(Err(19)),
+        )
+        .await;
+        let err: AggregateError<u8, 20> = err.unwrap_err();
+        let expected: [u8; 20] = core::array::from_fn(|i| i as u8);
+        assert_eq!(err.into_inner(), expected);
+    })
+}
@@ -1,5 +1,8 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::future;
 use futures_concurrency::{array::AggregateError, prelude::*};
 use futures_lite::future::block_on;
@@ -79,6 +82,28 @@ fn merge_array_4() {
     })
 }
 
+/// Without `std`, every member is polled on each wake of the operation. Members
+/// which wake themselves must still all be driven to completion.
+#[test]
+fn merge_array_pending() {
+    block_on(async {
+        let delayed = |items: [u8; 2]| {
+            stream::iter(items).then(|n| async move {
+                futures_lite::future::yield_now().await;
+                n
+            })
+        };
+        let s = [delayed([1, 2]), delayed([3, 4]), delayed([5, 6])].merge();
+        let mut s = core::pin::pin!(s);
+
+        let mut counter = 0;
+        while let Some(n) = s.next().await {
+            counter += n;
+        }
+        assert_eq!(counter, 21);
+    })
+}
+
 #[test]
 fn zip_array_3() {
     use futures_concurrency::stream::Zip;
@@ -94,3 +119,83 @@ fn zip_array_3() {
         assert_eq!(s.next().await, None);
     })
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn join_vec() {
+    use alloc::vec;
+
+    block_on(async {
+        let fut = vec![future::ready("hello"), future::ready("world")].join();
+        assert_eq!(fut.await, ["hello", "world"]);
+    });
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn race_ok_vec() {
+    use alloc::vec;
+    use futures_concurrency::vec::AggregateError;
+
+    block_on(async {
+        let res: Result<&str, AggregateError<&str>> =
+            vec![future::ready(Err("oh no")), future::ready(Ok("world"))]
+                .race_ok()
+                .await;
+        assert_eq!(res.unwrap(), "world");
+    })
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn merge_vec_3() {
+    use alloc::vec;
+
+    block_on(async {
+        let a = stream::once(1);
+        let b = stream::once(2);
+        let c = stream::once(3);
+        let mut s = vec![a, b, c].merge();
+
+        let mut counter = 0;
+        while let Some(n) = s.next().await {
+            counter += n;
+        }
+        assert_eq!(counter, 6);
+    })
+}
+
+#[cfg(feature = "critical-section")]
+#[test]
+fn merge_vec_pause() {
+    use alloc::vec;
+
+    block_on(async {
+        let mut s = vec![stream::iter([1, 2]), stream::iter([3, 4])].merge();
+        s.pause(0);
+        assert_eq!(s.next().await, Some(3));
+        assert_eq!(s.next().await, Some(4));
+        s.resume(0);
+        assert_eq!(s.next().await, Some(1));
+        assert_eq!(s.next().await, Some(2));
+        assert_eq!(s.next().await, None);
+    })
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn future_group() {
+    use futures_concurrency::future::FutureGroup;
+
+    block_on(async {
+        let mut group = FutureGroup::new();
+        group.insert(future::ready(2));
+        group.insert(future::ready(4));
+
+        let mut out = 0;
+        while let Some(n) = group.next().await {
+            out += n;
+        }
+        assert_eq!(out, 6);
+    })
+}
@@ -0,0 +1,51 @@
+//! Implements the `Join` and `Merge` traits for a container defined outside of
+//! the crate, reusing the crate's own combinators.
+
+#![cfg(feature = "alloc")]
+
+use std::collections::VecDeque;
+use std::future::{ready, IntoFuture};
+
+use futures_concurrency::future::Join;
+use futures_concurrency::stream::{IntoStream, Merge};
+use futures_concurrency::vec;
+use futures_lite::{future::block_on, stream, StreamExt};
+
+/// A container which is not known to `futures-concurrency`.
+struct Queue<T>(VecDeque<T>);
+
+impl<Fut: IntoFuture> Join for Queue<Fut> {
+    type Output = Vec<Fut::Output>;
+    type Future = vec::Join<Fut::IntoFuture>;
+
+    fn join(self) -> Self::Future {
+        vec::Join::new(self.0.into_iter().map(IntoFuture::into_future).collect())
+    }
+}
+
+impl<S: IntoStream> Merge for Queue<S> {
+    type Item = S::Item;
+    type Stream = vec::Merge<S::IntoStream>;
+
+    fn merge(self) -> Self::Stream {
+        vec::Merge::new(self.0.into_iter().map(IntoStream::into_stream).collect())
+    }
+}
+
+#[test]
+fn join() {
+    block_on(async {
+        let queue = Queue(VecDeque::from([ready(1), ready(2), ready(3)]));
+        assert_eq!(queue.join().await, [1, 2, 3]);
+    })
+}
+
+#[test]
+fn merge() {
+    block_on(async {
+        let queue = Queue(VecDeque::from([stream::iter(0..2), stream::iter(2..4)]));
+        let mut out: Vec<_> = queue.merge().collect().await;
+        out.sort_unstable();
+        assert_eq!(out, [0, 1, 2, 3]);
+    })
+}
@@ -0,0 +1,101 @@
+//! The `Vec` operations accept members which aren't `Unpin`, such as the
+//! futures produced by `async fn`, without requiring them to be boxed.
+
+#![cfg(feature = "alloc")]
+
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_concurrency::future::FutureGroup;
+use futures_concurrency::prelude::*;
+use futures_lite::future::{self, block_on};
+use futures_lite::{stream, StreamExt};
+
+async fn double(n: u32) -> u32 {
+    future::yield_now().await;
+    n * 2
+}
+
+async fn checked(n: u32) -> Result<u32, u32> {
+    future::yield_now().await;
+    if n % 2 == 0 {
+        Ok(n)
+    } else {
+        Err(n)
+    }
+}
+
+/// A future which explicitly opts out of `Unpin`.
+struct Pinned(u32, PhantomPinned);
+
+impl Future for Pinned {
+    type Output = u32;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Poll::Ready(self.0)
+    }
+}
+
+#[test]
+fn join() {
+    block_on(async {
+        assert_eq!(vec![double(1), double(2)].join().await, [2, 4]);
+        let pinned = vec![Pinned(1, PhantomPinned), Pinned(2, PhantomPinned)];
+        assert_eq!(pinned.join().await, [1, 2]);
+        assert_eq!(
+            vec![checked(2), checked(4)].try_join().await,
+            Ok(vec![2, 4])
+        );
+        assert_eq!(vec![checked(2), checked(3)].try_join().await, Err(3));
+    })
+}
+
+#[test]
+fn race() {
+    block_on(async {
+        let winner = vec![double(1), double(2)].race().await;
+        assert!(winner == 2 || winner == 4);
+        let pinned = vec![Pinned(1, PhantomPinned), Pinned(2, PhantomPinned)];
+        assert_eq!(pinned.race().await, 1);
+        assert_eq!(vec![checked(1), checked(2)].race_ok().await.unwrap(), 2);
+    })
+}
+
+#[test]
+fn merge() {
+    block_on(async {
+        let streams = vec![
+            stream::once_future(double(1)),
+            stream::once_future(double(2)),
+        ];
+        let mut items: Vec<_> = streams.merge().collect().await;
+        items.sort_unstable();
+        assert_eq!(items, [2, 4]);
+
+        let streams = vec![
+            stream::once_future(double(1)),
+            stream::once_future(double(2)),
+        ];
+        assert_eq!(streams.zip().collect::<Vec<_>>().await, [vec![2, 4]]);
+
+        let streams = vec![
+            stream::once_future(double(1)),
+            stream::once_future(double(2)),
+        ];
+        assert_eq!(streams.chain().collect::<Vec<_>>().await, [2, 4]);
+    })
+}
+
+#[test]
+fn future_group() {
+    block_on(async {
+        let mut group = FutureGroup::new();
+        group.insert(double(1));
+        group.insert(double(2));
+        let mut items: Vec<_> = group.collect().await;
+        items.sort_unstable();
+        assert_eq!(items, [2, 4]);
+    })
+}
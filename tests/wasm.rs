@@ -0,0 +1,48 @@
+//! Smoke tests which run the operations in a JavaScript host, where there's
+//! no entropy source and no OS threads.
+//!
+//! Run with `wasm-pack test --node -- --test wasm`.
+
+#![cfg(target_arch = "wasm32")]
+
+use futures_concurrency::prelude::*;
+use futures_lite::future::yield_now;
+use futures_lite::prelude::*;
+use futures_lite::stream;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Resolve to `value` after yielding to the host `n` times.
+async fn after(n: usize, value: u8) -> u8 {
+    for _ in 0..n {
+        yield_now().await;
+    }
+    value
+}
+
+#[wasm_bindgen_test]
+async fn merge() {
+    let a = stream::iter([1, 2]);
+    let b = stream::iter([3, 4]);
+    let mut out: Vec<_> = (a, b).merge().collect().await;
+    out.sort_unstable();
+    assert_eq!(out, [1, 2, 3, 4]);
+
+    let streams = vec![stream::once(1), stream::once(2), stream::once(3)];
+    let mut out: Vec<_> = streams.merge().collect().await;
+    out.sort_unstable();
+    assert_eq!(out, [1, 2, 3]);
+}
+
+#[wasm_bindgen_test]
+async fn join() {
+    assert_eq!((after(2, 1), after(1, 2)).join().await, (1, 2));
+    assert_eq!([after(2, 1), after(1, 2)].join().await, [1, 2]);
+    assert_eq!(vec![after(2, 1), after(1, 2)].join().await, [1, 2]);
+}
+
+#[wasm_bindgen_test]
+async fn race() {
+    assert_eq!((after(2, 1), after(0, 2)).race().await, 2);
+    assert_eq!([after(2, 1), after(0, 2)].race().await, 2);
+    assert_eq!(vec![after(2, 1), after(0, 2)].race().await, 2);
+}